@@ -134,7 +134,8 @@ fn main() -> io::Result<()> {
         let mut stream = MmapStream::with_buffers(&dev, Type::VideoCapture, buffer_count).unwrap();
 
         loop {
-            let (buf, _) = stream.next().unwrap();
+            let (bufs, _, _) = stream.next().unwrap();
+            let buf: &[u8] = &bufs[0];
             let data = match &format.fourcc.repr {
                 b"RGB3" => buf.to_vec(),
                 b"MJPG" => {
@@ -2,6 +2,7 @@ use std::io;
 use std::time::Instant;
 
 use v4l::buffer::Type;
+use v4l::device::PollFlags;
 use v4l::io::Queue;
 use v4l::prelude::*;
 use v4l::video::Capture;
@@ -33,7 +34,7 @@ fn main() -> io::Result<()> {
 
     // warm up
     let buf = queue.dequeue()?;
-    let _ = queue.enqueue(&buf)?;
+    queue.enqueue(&buf)?;
 
     let start = Instant::now();
     let mut megabytes_ps: f64 = 0.0;
@@ -41,7 +42,7 @@ fn main() -> io::Result<()> {
         let t0 = Instant::now();
 
         // check whether there's something for us to do
-        dev.handle().poll(libc::POLLIN, -1)?;
+        dev.handle().poll(PollFlags::IN, -1)?;
 
         // remove a buffer from the drivers' outgoing queue
         let buf = queue.dequeue()?;
@@ -32,7 +32,8 @@ fn main() -> io::Result<()> {
     let mut megabytes_ps: f64 = 0.0;
     for i in 0..count {
         let t0 = Instant::now();
-        let (buf, meta) = stream.next()?;
+        let (bufs, meta, _) = stream.next()?;
+        let buf = &bufs[0];
         let duration_us = t0.elapsed().as_micros();
 
         let cur = buf.len() as f64 / 1_048_576.0 * 1_000_000.0 / duration_us as f64;
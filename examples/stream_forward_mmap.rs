@@ -40,8 +40,7 @@ fn main() -> io::Result<()> {
         || source_fmt.height != sink_fmt.height
         || source_fmt.fourcc != sink_fmt.fourcc
     {
-        return Err(io::Error::new(
-            io::ErrorKind::Other,
+        return Err(io::Error::other(
             "failed to enforce source format on sink device",
         ));
     }
@@ -58,8 +57,9 @@ fn main() -> io::Result<()> {
     let mut megabytes_ps: f64 = 0.0;
     for i in 0..count {
         let t0 = Instant::now();
-        let (buf_in, buf_in_meta) = CaptureStream::next(&mut cap_stream)?;
-        let (buf_out, buf_out_meta) = OutputStream::next(&mut out_stream)?;
+        let (bufs_in, buf_in_meta, _) = CaptureStream::next(&mut cap_stream)?;
+        let (bufs_out, buf_out_meta) = OutputStream::next(&mut out_stream)?;
+        let (buf_in, buf_out) = (&bufs_in[0], &mut bufs_out[0]);
 
         // Output devices generally cannot know the exact size of the output buffers for
         // compressed formats (e.g. MJPG). They will however allocate a size that is always
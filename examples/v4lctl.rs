@@ -0,0 +1,245 @@
+//! A small subset of v4l2-ctl, built on the public API of the crate
+//!
+//! Usage: v4lctl [-d <device>] [--list-devices] [--list-formats-ext]
+//!               [--get-ctrl <name>[,<name>...]] [--set-ctrl <name>=<value>[,...]]
+//!               [--stream-to-file <file> [--stream-count <n>]]
+//!
+//! Control names are matched like v4l2-ctl does, e.g. "exposure_absolute". Options may be
+//! given more than once and are processed in the order above.
+
+use std::env;
+use std::fs::File;
+use std::io::{self, Write};
+use std::process;
+
+use v4l::buffer::Type;
+use v4l::context;
+use v4l::control::{Control, Type as ControlType, Value};
+use v4l::framesize::FrameSizeEnum;
+use v4l::io::traits::CaptureStream;
+use v4l::prelude::*;
+use v4l::video::Capture;
+
+const USAGE: &str = "usage: v4lctl [-d <device>] [--list-devices] [--list-formats-ext]
+              [--get-ctrl <name>[,<name>...]] [--set-ctrl <name>=<value>[,...]]
+              [--stream-to-file <file> [--stream-count <n>]]";
+
+#[derive(Default)]
+struct Options {
+    device: Option<String>,
+    list_devices: bool,
+    list_formats: bool,
+    get_ctrls: Vec<String>,
+    set_ctrls: Vec<(String, String)>,
+    stream_to: Option<String>,
+    stream_count: Option<usize>,
+}
+
+fn invalid(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg)
+}
+
+fn parse_args() -> io::Result<Options> {
+    let mut opts = Options::default();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        // both "--opt value" and "--opt=value" are accepted
+        let (name, inline) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => (name.to_string(), Some(value)),
+            _ => (arg.clone(), None),
+        };
+        let mut value = || match inline {
+            Some(value) => Ok(value.to_string()),
+            None => args
+                .next()
+                .ok_or_else(|| invalid(format!("{} requires an argument", name))),
+        };
+
+        match name.as_str() {
+            "-d" | "--device" => opts.device = Some(value()?),
+            "--list-devices" => opts.list_devices = true,
+            "--list-formats-ext" => opts.list_formats = true,
+            "--get-ctrl" => opts
+                .get_ctrls
+                .extend(value()?.split(',').map(str::to_string)),
+            "--set-ctrl" => {
+                for ctrl in value()?.split(',') {
+                    let (name, value) = ctrl
+                        .split_once('=')
+                        .ok_or_else(|| invalid(format!("missing value for control {}", ctrl)))?;
+                    opts.set_ctrls.push((name.to_string(), value.to_string()));
+                }
+            }
+            "--stream-to-file" => opts.stream_to = Some(value()?),
+            "--stream-count" => {
+                let count = value()?;
+                opts.stream_count = Some(
+                    count
+                        .parse()
+                        .map_err(|_| invalid(format!("invalid frame count {}", count)))?,
+                );
+            }
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                process::exit(0);
+            }
+            _ => return Err(invalid(format!("unknown option {}\n{}", arg, USAGE))),
+        }
+    }
+    Ok(opts)
+}
+
+fn open(device: Option<&str>) -> io::Result<Device> {
+    match device {
+        // a plain number is an index, as with v4l2-ctl
+        Some(device) => match device.parse::<usize>() {
+            Ok(index) => Device::new(index),
+            Err(_) => Device::with_path(device),
+        },
+        None => Device::new(0),
+    }
+}
+
+fn list_devices() {
+    for group in context::enum_groups() {
+        println!("{} ({}):", group.card, group.bus);
+        for node in group.nodes() {
+            println!("\t{}", node.path().display());
+        }
+        println!();
+    }
+}
+
+fn list_formats(dev: &Device) -> io::Result<()> {
+    println!("ioctl: VIDIOC_ENUM_FMT");
+    for format in dev.enum_formats()? {
+        println!(
+            "\t[{}]: '{}' ({})",
+            format.index, format.fourcc, format.description
+        );
+
+        for framesize in dev.enum_framesizes(format.fourcc)? {
+            match framesize.size {
+                FrameSizeEnum::Discrete(size) => {
+                    println!("\t\tSize: Discrete {}", size);
+                    for interval in
+                        dev.enum_frameintervals(format.fourcc, size.width, size.height)?
+                    {
+                        println!("\t\t\tInterval: {}", interval);
+                    }
+                }
+                // stepwise ranges can cover millions of sizes, they are not expanded
+                size => println!("\t\tSize: {}", size),
+            }
+        }
+        println!();
+    }
+    Ok(())
+}
+
+fn get_ctrl(dev: &Device, name: &str) -> io::Result<()> {
+    let ctrl = dev.control_by_name(name)?;
+    match ctrl.value {
+        Value::None => println!("{}: (button)", name),
+        Value::Integer(value) => println!("{}: {}", name, value),
+        Value::Boolean(value) => println!("{}: {}", name, value as u8),
+        Value::String(value) => println!("{}: '{}'", name, value),
+        value => println!("{}: {:?}", name, value),
+    }
+    Ok(())
+}
+
+fn set_ctrl(dev: &Device, name: &str, value: &str) -> io::Result<()> {
+    let id = dev.control_id(name)?;
+    let desc = dev
+        .query_controls()?
+        .into_iter()
+        .find(|desc| desc.id == id)
+        .ok_or_else(|| invalid(format!("control {} disappeared", name)))?;
+
+    let integer = || {
+        value
+            .parse::<i64>()
+            .map_err(|_| invalid(format!("invalid value {} for control {}", value, name)))
+    };
+    let value = match desc.typ {
+        ControlType::Integer
+        | ControlType::Integer64
+        | ControlType::Menu
+        | ControlType::IntegerMenu
+        | ControlType::Bitmask => Value::Integer(integer()?),
+        ControlType::Boolean => Value::Boolean(integer()? != 0),
+        ControlType::Button => Value::None,
+        ControlType::String => Value::String(value.to_string()),
+        typ => {
+            return Err(invalid(format!(
+                "cannot set control {} of type {}",
+                name, typ
+            )))
+        }
+    };
+    dev.set_control(Control { id, value })
+}
+
+fn stream_to_file(dev: &Device, path: &str, count: Option<usize>) -> io::Result<()> {
+    let format = dev.format()?;
+    eprintln!("Streaming {} to {}", format.fourcc, path);
+
+    let mut file = File::create(path)?;
+    let mut stream = MmapStream::new(dev, Type::VideoCapture)?;
+    let mut frames = 0;
+    while count.is_none_or(|count| frames < count) {
+        let (bufs, meta, _) = stream.next()?;
+        // compressed formats only fill part of the buffer
+        let buf = &bufs[0];
+        let used = match meta.bytesused as usize {
+            0 => buf.len(),
+            used => used.min(buf.len()),
+        };
+        file.write_all(&buf[..used])?;
+        frames += 1;
+        eprint!("<");
+    }
+    eprintln!();
+    file.flush()
+}
+
+fn run(opts: &Options) -> io::Result<()> {
+    if opts.list_devices {
+        list_devices();
+    }
+
+    let needs_device = opts.list_formats
+        || !opts.get_ctrls.is_empty()
+        || !opts.set_ctrls.is_empty()
+        || opts.stream_to.is_some();
+    if !needs_device {
+        if !opts.list_devices {
+            println!("{}", USAGE);
+        }
+        return Ok(());
+    }
+
+    let dev = open(opts.device.as_deref())?;
+    if opts.list_formats {
+        list_formats(&dev)?;
+    }
+    for (name, value) in &opts.set_ctrls {
+        set_ctrl(&dev, name, value)?;
+    }
+    for name in &opts.get_ctrls {
+        get_ctrl(&dev, name)?;
+    }
+    if let Some(path) = &opts.stream_to {
+        stream_to_file(&dev, path, opts.stream_count)?;
+    }
+    Ok(())
+}
+
+fn main() {
+    let result = parse_args().and_then(|opts| run(&opts));
+    if let Err(e) = result {
+        eprintln!("v4lctl: {}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,273 @@
+//! Combined audio and video capture
+//!
+//! Webcams usually come with a microphone, which shows up as a separate ALSA device. A
+//! [`Session`] captures from both on background threads and stamps all packets with
+//! presentation timestamps of a common clock, so they can be muxed or played back in sync.
+//!
+//! The audio sample clock serves as master clock: audio packets are stamped with the number of
+//! samples captured before them, video frames are mapped onto the audio clock with a
+//! [`ClockSync`]. Consequently, the audio stream never needs to be resampled.
+//!
+//! This module is only available with the `av` feature.
+
+use std::{
+    io,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use alsa::pcm::{Access, Format, HwParams, PCM};
+use alsa::{Direction, ValueOr};
+
+use crate::clock::ClockSync;
+use crate::frame::{Frame, Planes};
+use crate::io::traits::CaptureStream;
+
+/// Number of video frames to estimate the clock drift from
+const SYNC_WINDOW: usize = 300;
+
+/// Audio capture parameters
+#[derive(Debug, Clone)]
+pub struct AudioConfig {
+    /// ALSA PCM name, e.g. "default" or "hw:1,0"
+    pub device: String,
+    /// Sample rate in Hz, the closest rate the device supports is used
+    pub rate: u32,
+    /// Number of interleaved channels
+    pub channels: u32,
+    /// Duration of the audio packets
+    pub period: Duration,
+}
+
+impl Default for AudioConfig {
+    fn default() -> Self {
+        AudioConfig {
+            device: "default".to_string(),
+            rate: 48000,
+            channels: 2,
+            period: Duration::from_millis(20),
+        }
+    }
+}
+
+/// Captured data with its presentation timestamp
+pub enum Packet {
+    /// Video frame
+    Video { frame: Frame, pts: Duration },
+    /// Interleaved signed 16 bit audio samples
+    Audio { samples: Vec<i16>, pts: Duration },
+}
+
+impl Packet {
+    /// Returns the presentation timestamp
+    pub fn pts(&self) -> Duration {
+        match self {
+            Packet::Video { pts, .. } | Packet::Audio { pts, .. } => *pts,
+        }
+    }
+}
+
+/// Position of the audio sample clock
+struct AudioClock {
+    rate: u32,
+    /// Number of samples per channel captured so far
+    frames: u64,
+    /// Time the last samples were read, `None` before the first period was captured
+    read_at: Option<Instant>,
+}
+
+impl AudioClock {
+    /// Returns the current reading, interpolated since the last period
+    fn now(&self) -> Option<Duration> {
+        let read_at = self.read_at?;
+        let captured = Duration::from_secs_f64(self.frames as f64 / self.rate as f64);
+        Some(captured + read_at.elapsed())
+    }
+}
+
+struct Shared {
+    clock: Mutex<AudioClock>,
+    stop: AtomicBool,
+}
+
+/// Synchronized audio and video capture
+///
+/// Video frames captured before the first audio period cannot be placed on the audio clock,
+/// they are dropped. Packets are queued without limit, so the receiver has to keep up with the
+/// capture rate.
+///
+/// # Example
+///
+/// ```
+/// use v4l::av::{AudioConfig, Packet, Session};
+/// use v4l::buffer::Type;
+/// use v4l::prelude::*;
+///
+/// if let Ok(dev) = Device::new(0) {
+///     let stream = MmapStream::new(&dev, Type::VideoCapture).unwrap();
+///     if let Ok(mut session) = Session::start(stream, &AudioConfig::default()) {
+///         for _ in 0..100 {
+///             match session.recv() {
+///                 Some(Packet::Video { pts, .. }) => println!("video {:?}", pts),
+///                 Some(Packet::Audio { pts, .. }) => println!("audio {:?}", pts),
+///                 None => break,
+///             }
+///         }
+///         session.stop().unwrap();
+///     }
+/// }
+/// ```
+pub struct Session {
+    shared: Arc<Shared>,
+    packets: mpsc::Receiver<Packet>,
+    threads: Vec<thread::JoinHandle<io::Result<()>>>,
+}
+
+impl Session {
+    /// Opens the audio device and starts capturing from both sources
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - Video capture stream
+    /// * `audio` - Audio capture parameters
+    pub fn start<S>(mut stream: S, audio: &AudioConfig) -> io::Result<Self>
+    where
+        S: for<'b> CaptureStream<'b> + Send + 'static,
+        S::Item: Planes,
+    {
+        let (pcm, rate, period) = open(audio).map_err(alsa_error)?;
+        let channels = audio.channels as usize;
+
+        let shared = Arc::new(Shared {
+            clock: Mutex::new(AudioClock {
+                rate,
+                frames: 0,
+                read_at: None,
+            }),
+            stop: AtomicBool::new(false),
+        });
+        let (tx, packets) = mpsc::channel();
+
+        let audio_shared = shared.clone();
+        let audio_tx = tx.clone();
+        let audio_thread = thread::spawn(move || -> io::Result<()> {
+            let reader = pcm.io_i16().map_err(alsa_error)?;
+            pcm.start().map_err(alsa_error)?;
+
+            let mut buf = vec![0i16; period * channels];
+            while !audio_shared.stop.load(Ordering::Relaxed) {
+                let frames = match reader.readi(&mut buf) {
+                    Ok(frames) => frames,
+                    // overruns are recovered from, the lost samples simply shift the clock
+                    Err(e) => {
+                        pcm.try_recover(e, true).map_err(alsa_error)?;
+                        continue;
+                    }
+                };
+
+                let pts = {
+                    let mut clock = audio_shared.clock.lock().unwrap();
+                    let pts = Duration::from_secs_f64(clock.frames as f64 / rate as f64);
+                    clock.frames += frames as u64;
+                    clock.read_at = Some(Instant::now());
+                    pts
+                };
+                let samples = buf[..frames * channels].to_vec();
+                if audio_tx.send(Packet::Audio { samples, pts }).is_err() {
+                    break;
+                }
+            }
+
+            let _ = pcm.drop();
+            Ok(())
+        });
+
+        let video_shared = shared.clone();
+        let video_thread = thread::spawn(move || -> io::Result<()> {
+            let mut sync = ClockSync::new(SYNC_WINDOW);
+            while !video_shared.stop.load(Ordering::Relaxed) {
+                let frame = Frame::capture(&mut stream)?;
+                let master = match video_shared.clock.lock().unwrap().now() {
+                    Some(master) => master,
+                    None => continue,
+                };
+
+                let pts = sync.update(frame.meta.timestamp, master);
+                if tx.send(Packet::Video { frame, pts }).is_err() {
+                    break;
+                }
+            }
+            Ok(())
+        });
+
+        Ok(Session {
+            shared,
+            packets,
+            threads: vec![audio_thread, video_thread],
+        })
+    }
+
+    /// Returns the next packet, blocking until one is available
+    ///
+    /// Returns `None` once both capture threads stopped.
+    pub fn recv(&self) -> Option<Packet> {
+        self.packets.recv().ok()
+    }
+
+    /// Returns the next packet, waiting at most for `timeout`
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Maximum time to wait
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<Packet> {
+        self.packets.recv_timeout(timeout).ok()
+    }
+
+    /// Stops capturing
+    ///
+    /// Returns the first error which made a capture thread stop prematurely, if any.
+    pub fn stop(&mut self) -> io::Result<()> {
+        self.shared.stop.store(true, Ordering::Relaxed);
+        let mut result = Ok(());
+        for thread in self.threads.drain(..) {
+            let res = thread
+                .join()
+                .unwrap_or_else(|_| Err(io::Error::other("capture thread panicked")));
+            if result.is_ok() {
+                result = res;
+            }
+        }
+        result
+    }
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        let _ = self.stop();
+    }
+}
+
+/// Opens and configures the PCM, returns it with the actual rate and period in frames
+fn open(config: &AudioConfig) -> alsa::Result<(PCM, u32, usize)> {
+    let pcm = PCM::new(&config.device, Direction::Capture, false)?;
+    let (rate, period) = {
+        let hwp = HwParams::any(&pcm)?;
+        hwp.set_channels(config.channels)?;
+        let rate = hwp.set_rate_near(config.rate, ValueOr::Nearest)?;
+        hwp.set_format(Format::s16())?;
+        hwp.set_access(Access::RWInterleaved)?;
+        let period = (config.period.as_secs_f64() * rate as f64) as alsa::pcm::Frames;
+        let period = hwp.set_period_size_near(period.max(1), ValueOr::Nearest)?;
+        pcm.hw_params(&hwp)?;
+        (rate, period as usize)
+    };
+    Ok((pcm, rate, period))
+}
+
+fn alsa_error(e: alsa::Error) -> io::Error {
+    io::Error::from_raw_os_error(e.errno())
+}
@@ -0,0 +1,1071 @@
+//! In-memory capture device
+//!
+//! The [`Mock`] backend implements the subset of the V4L2 API which is used by this crate for
+//! single-planar video capture: capability and format negotiation, frame size and interval
+//! enumeration, streaming parameters, a few user and camera controls as well as MMAP and USERPTR
+//! streaming I/O. Frames are
+//! produced on demand whenever a queued buffer is dequeued, so tests run as fast as possible
+//! while the buffer timestamps still advance according to the configured frame interval.
+//!
+//! [`Mock::mem2mem`] simulates a memory-to-memory device instead, which copies the buffers
+//! queued on its OUTPUT queue to the buffers of its CAPTURE queue.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    io,
+    os::fd::{AsRawFd, FromRawFd, OwnedFd, RawFd},
+    os::raw::c_void,
+    ptr, slice,
+    sync::{Arc, Mutex},
+};
+
+use crate::backend::DeviceBackend;
+use crate::buffer;
+use crate::capability;
+use crate::control;
+use crate::device::PlanarDevice;
+use crate::format::{FieldOrder, Format, FourCC};
+use crate::fraction::Fraction;
+use crate::memory::Memory;
+use crate::parameters;
+use crate::v4l2::videodev::v4l2_ext_controls;
+use crate::v4l2::vidioc;
+use crate::v4l_sys::*;
+
+/// Maximum number of buffers which can be requested at once
+const MAX_BUFFERS: u32 = 32;
+
+/// Memory offset of the OUTPUT buffers, mem2mem drivers tell the queues apart the same way
+const OUTPUT_OFFSET: usize = 1 << 30;
+
+/// Frame content generator
+///
+/// Invoked with the buffer to fill, the active format and the sequence number of the frame.
+pub type Generator = dyn FnMut(&mut [u8], &Format, u32) + Send;
+
+/// Pixel format offered by the mock device
+#[derive(Debug, Clone)]
+pub struct PixelFormat {
+    /// Four character code
+    pub fourcc: FourCC,
+    /// Human readable description
+    pub description: String,
+    /// Supported frame sizes (width, height)
+    pub sizes: Vec<(u32, u32)>,
+}
+
+impl PixelFormat {
+    /// Returns a pixel format description
+    ///
+    /// # Arguments
+    ///
+    /// * `fourcc` - Four character code
+    /// * `description` - Human readable description
+    /// * `sizes` - Supported frame sizes (width, height)
+    pub fn new(fourcc: FourCC, description: &str, sizes: &[(u32, u32)]) -> Self {
+        PixelFormat {
+            fourcc,
+            description: description.to_string(),
+            sizes: sizes.to_vec(),
+        }
+    }
+}
+
+/// Simulated capture device
+///
+/// Clones share the same device state, so a test can keep a clone around to reconfigure the
+/// device or inject errors after handing it over to the code under test.
+///
+/// # Example
+///
+/// ```
+/// use v4l::backend::Mock;
+/// use v4l::buffer::Type;
+/// use v4l::io::traits::CaptureStream;
+/// use v4l::prelude::*;
+/// use v4l::video::Capture;
+///
+/// let mock = Mock::new();
+/// let dev: Device = mock.device().unwrap();
+/// let fmt = dev.format().unwrap();
+///
+/// let mut stream = MmapStream::with_buffers(&dev, Type::VideoCapture, 4).unwrap();
+/// let (planes, meta, _) = stream.next().unwrap();
+/// assert_eq!(planes[0].len(), fmt.size as usize);
+/// assert_eq!(meta.sequence, 0);
+/// ```
+#[derive(Clone)]
+pub struct Mock {
+    state: Arc<Mutex<State>>,
+}
+
+impl Mock {
+    /// Returns a mock device offering YUYV and RGB3 frames at 640x480 and 1280x720
+    pub fn new() -> Self {
+        let sizes = [(640, 480), (1280, 720)];
+        Self::with_formats(vec![
+            PixelFormat::new(FourCC::new(b"YUYV"), "YUYV 4:2:2", &sizes),
+            PixelFormat::new(FourCC::new(b"RGB3"), "24-bit RGB 8-8-8", &sizes),
+        ])
+    }
+
+    /// Returns a mock device offering the given pixel formats
+    ///
+    /// The first size of the first pixel format is active initially.
+    ///
+    /// # Arguments
+    ///
+    /// * `formats` - Pixel formats, must not be empty
+    pub fn with_formats(formats: Vec<PixelFormat>) -> Self {
+        assert!(
+            !formats.is_empty() && formats.iter().all(|fmt| !fmt.sizes.is_empty()),
+            "mock device requires at least one pixel format with one frame size"
+        );
+
+        let (width, height) = formats[0].sizes[0];
+        let format = layout(Format::new(width, height, formats[0].fourcc));
+        Mock {
+            state: Arc::new(Mutex::new(State {
+                formats,
+                format,
+                intervals: vec![Fraction::new(1, 30), Fraction::new(1, 15)],
+                interval: Fraction::new(1, 30),
+                generator: Box::new(gradient),
+                errors: HashMap::new(),
+                controls: controls(),
+                capture: Queue::new(0),
+                output: None,
+                sequence: 0,
+            })),
+        }
+    }
+
+    /// Returns a memory-to-memory mock device offering YUYV frames at 640x480 and 1280x720
+    ///
+    /// Every buffer queued on the OUTPUT queue is copied to the next buffer of the CAPTURE
+    /// queue, including its timestamp. Both queues share the same format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::backend::Mock;
+    /// use v4l::device::Device;
+    /// use v4l::io::m2m::Stream;
+    ///
+    /// let dev: Device = Mock::mem2mem().device().unwrap();
+    /// let mut stream = Stream::new(&dev).unwrap();
+    /// let (planes, meta) = stream.input().unwrap();
+    /// planes[0][..4].copy_from_slice(b"v4l2");
+    /// meta.bytesused = 4;
+    /// stream.queue_input().unwrap();
+    ///
+    /// let (planes, meta) = stream.output().unwrap();
+    /// assert_eq!(&planes[0][..meta.bytesused as usize], b"v4l2");
+    /// ```
+    pub fn mem2mem() -> Self {
+        let sizes = [(640, 480), (1280, 720)];
+        let mock = Self::with_formats(vec![PixelFormat::new(
+            FourCC::new(b"YUYV"),
+            "YUYV 4:2:2",
+            &sizes,
+        )]);
+        mock.state.lock().unwrap().output = Some(Queue::new(OUTPUT_OFFSET));
+        mock
+    }
+
+    /// Replaces the frame content generator
+    ///
+    /// By default, frames contain a gradient which moves by one byte with every frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `generator` - Closure filling a buffer
+    pub fn set_generator<F>(&self, generator: F)
+    where
+        F: FnMut(&mut [u8], &Format, u32) + Send + 'static,
+    {
+        self.state.lock().unwrap().generator = Box::new(generator);
+    }
+
+    /// Lets the next invocation of an ioctl fail
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - IO control code (see [`vidioc`])
+    /// * `errno` - Error code to report (e.g. `libc::EIO`)
+    pub fn fail_next(&self, request: vidioc::_IOC_TYPE, errno: i32) {
+        self.state.lock().unwrap().errors.insert(request, errno);
+    }
+
+    /// Returns the number of frames produced since the stream was last started
+    pub fn frame_count(&self) -> u32 {
+        self.state.lock().unwrap().sequence
+    }
+
+    /// Returns whether the device is currently streaming
+    ///
+    /// Memory-to-memory devices report the state of their CAPTURE queue.
+    pub fn is_streaming(&self) -> bool {
+        self.state.lock().unwrap().capture.streaming
+    }
+
+    /// Returns a new device backed by this mock
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::backend::Mock;
+    /// use v4l::device::Device;
+    ///
+    /// let dev: Device = Mock::new().device().unwrap();
+    /// let caps = dev.query_caps().unwrap();
+    /// assert_eq!(caps.driver, "mock");
+    /// ```
+    pub fn device<const M: bool>(&self) -> io::Result<PlanarDevice<M>> {
+        // An eventfd is used as placeholder so the handle owns a genuine file descriptor.
+        let fd = unsafe { libc::eventfd(0, libc::EFD_CLOEXEC | libc::EFD_NONBLOCK) };
+        if fd == -1 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+        Ok(PlanarDevice::with_backend(fd, Arc::new(self.clone())))
+    }
+}
+
+impl Default for Mock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DeviceBackend for Mock {
+    unsafe fn ioctl(
+        &self,
+        _fd: RawFd,
+        request: vidioc::_IOC_TYPE,
+        argp: *mut c_void,
+    ) -> io::Result<()> {
+        let mut state = self.state.lock().unwrap();
+        if let Some(errno) = state.errors.remove(&request) {
+            return Err(io::Error::from_raw_os_error(errno));
+        }
+
+        let res = match request {
+            vidioc::VIDIOC_QUERYCAP => state.querycap(&mut *(argp as *mut v4l2_capability)),
+            vidioc::VIDIOC_ENUM_FMT => state.enum_fmt(&mut *(argp as *mut v4l2_fmtdesc)),
+            vidioc::VIDIOC_G_FMT => state.g_fmt(&mut *(argp as *mut v4l2_format)),
+            vidioc::VIDIOC_S_FMT => state.s_fmt(&mut *(argp as *mut v4l2_format), true),
+            vidioc::VIDIOC_TRY_FMT => state.s_fmt(&mut *(argp as *mut v4l2_format), false),
+            vidioc::VIDIOC_ENUM_FRAMESIZES => {
+                state.enum_framesizes(&mut *(argp as *mut v4l2_frmsizeenum))
+            }
+            vidioc::VIDIOC_ENUM_FRAMEINTERVALS => {
+                state.enum_frameintervals(&mut *(argp as *mut v4l2_frmivalenum))
+            }
+            vidioc::VIDIOC_G_PARM => state.g_parm(&mut *(argp as *mut v4l2_streamparm)),
+            vidioc::VIDIOC_S_PARM => state.s_parm(&mut *(argp as *mut v4l2_streamparm)),
+            vidioc::VIDIOC_QUERY_EXT_CTRL => {
+                state.query_ext_ctrl(&mut *(argp as *mut v4l2_query_ext_ctrl))
+            }
+            vidioc::VIDIOC_G_EXT_CTRLS => state.g_ext_ctrls(&mut *(argp as *mut v4l2_ext_controls)),
+            vidioc::VIDIOC_S_EXT_CTRLS => state.s_ext_ctrls(&mut *(argp as *mut v4l2_ext_controls)),
+            vidioc::VIDIOC_REQBUFS => state.reqbufs(&mut *(argp as *mut v4l2_requestbuffers)),
+            vidioc::VIDIOC_QUERYBUF => state.querybuf(&mut *(argp as *mut v4l2_buffer)),
+            vidioc::VIDIOC_QBUF => state.qbuf(&mut *(argp as *mut v4l2_buffer)),
+            vidioc::VIDIOC_DQBUF => state.dqbuf(&mut *(argp as *mut v4l2_buffer)),
+            vidioc::VIDIOC_STREAMON => state.streamon(*(argp as *const u32)),
+            vidioc::VIDIOC_STREAMOFF => state.streamoff(*(argp as *const u32)),
+            _ => Err(libc::ENOTTY),
+        };
+
+        res.map_err(io::Error::from_raw_os_error)
+    }
+
+    unsafe fn mmap(
+        &self,
+        start: *mut c_void,
+        length: usize,
+        prot: libc::c_int,
+        flags: libc::c_int,
+        _fd: RawFd,
+        offset: libc::off_t,
+    ) -> io::Result<*mut c_void> {
+        let state = self.state.lock().unwrap();
+        let queue = match &state.output {
+            Some(output) if offset >= 0 && offset as usize >= output.offset => output,
+            _ => &state.capture,
+        };
+        let offset = offset - queue.offset as libc::off_t;
+        let pool = match &queue.pool {
+            Some(pool) if offset >= 0 && offset as usize + length <= pool.len => pool,
+            _ => return Err(io::Error::from_raw_os_error(libc::EINVAL)),
+        };
+
+        // Mapping the pool's memfd gives the caller a view of the very same memory the frames
+        // are generated into, just like mapping a real device node does.
+        let ptr = libc::mmap(start, length, prot, flags, pool.fd.as_raw_fd(), offset);
+        if ptr == libc::MAP_FAILED {
+            Err(io::Error::last_os_error())
+        } else {
+            Ok(ptr)
+        }
+    }
+
+    unsafe fn munmap(&self, start: *mut c_void, length: usize) -> io::Result<()> {
+        match libc::munmap(start, length) {
+            0 => Ok(()),
+            _ => Err(io::Error::last_os_error()),
+        }
+    }
+
+    fn poll(&self, _fd: RawFd, events: i16, _timeout: i32) -> io::Result<i16> {
+        let state = self.state.lock().unwrap();
+        if let Some(output) = &state.output {
+            // results are ready once there is an input to process, inputs once processed
+            let mut revents = 0;
+            if state.capture.streaming && !state.capture.queue.is_empty() && output.pending() {
+                revents |= libc::POLLIN;
+            }
+            if output.streaming && !output.done.is_empty() {
+                revents |= libc::POLLOUT;
+            }
+            return Ok(events & revents);
+        }
+
+        // Frames are generated on demand, so queued buffers are always ready. Without a running
+        // stream, nothing will ever become ready. The real drivers signal POLLERR in that case,
+        // leaving it to VIDIOC_DQBUF to report the actual error.
+        if !state.capture.streaming {
+            Ok(libc::POLLERR)
+        } else if !state.capture.queue.is_empty() {
+            Ok(events & (libc::POLLIN | libc::POLLOUT))
+        } else {
+            Ok(0)
+        }
+    }
+
+    fn close(&self, fd: RawFd) -> io::Result<()> {
+        let mut state = self.state.lock().unwrap();
+        state.capture.reset();
+        if let Some(output) = &mut state.output {
+            output.reset();
+        }
+
+        match unsafe { libc::close(fd) } {
+            0 => Ok(()),
+            _ => Err(io::Error::last_os_error()),
+        }
+    }
+}
+
+/// Buffer as seen by the simulated driver
+struct Buffer {
+    queued: bool,
+    userptr: usize,
+    length: u32,
+    /// Payload and timestamp of a queued OUTPUT buffer
+    bytesused: u32,
+    timestamp: timeval,
+}
+
+/// Buffers of one direction
+struct Queue {
+    memory: Memory,
+    buffers: Vec<Buffer>,
+    pool: Option<Pool>,
+    /// Buffers owned by the driver, oldest first
+    queue: VecDeque<u32>,
+    /// Processed OUTPUT buffers waiting to be dequeued
+    done: VecDeque<u32>,
+    streaming: bool,
+    /// Memory offset of the first buffer
+    offset: usize,
+}
+
+impl Queue {
+    fn new(offset: usize) -> Self {
+        Queue {
+            memory: Memory::Mmap,
+            buffers: Vec::new(),
+            pool: None,
+            queue: VecDeque::new(),
+            done: VecDeque::new(),
+            streaming: false,
+            offset,
+        }
+    }
+
+    /// Returns whether a queued OUTPUT buffer waits to be processed
+    fn pending(&self) -> bool {
+        self.streaming && !self.queue.is_empty()
+    }
+
+    /// Returns the memory of a buffer
+    fn data(&self, index: u32) -> *mut u8 {
+        match &self.pool {
+            Some(pool) => (pool.addr + index as usize * pool.buf_len) as *mut u8,
+            None => self.buffers[index as usize].userptr as *mut u8,
+        }
+    }
+
+    fn describe(&self, index: u32, buf: &mut v4l2_buffer, m2m: bool) {
+        let buffer = &self.buffers[index as usize];
+        let mut flags = if m2m {
+            buffer::Flags::TIMESTAMP_COPY
+        } else {
+            buffer::Flags::TIMESTAMP_MONOTONIC
+        };
+        if buffer.queued {
+            flags |= buffer::Flags::QUEUED;
+        }
+
+        buf.index = index;
+        buf.memory = self.memory as u32;
+        buf.length = buffer.length;
+        match (self.memory, &self.pool) {
+            (Memory::Mmap, Some(pool)) => {
+                flags |= buffer::Flags::MAPPED;
+                buf.m.offset = (self.offset + index as usize * pool.buf_len) as u32;
+            }
+            _ => buf.m.userptr = buffer.userptr as std::os::raw::c_ulong,
+        }
+        buf.flags = flags.into();
+    }
+
+    /// Returns all buffers to userspace
+    fn stop(&mut self) {
+        self.streaming = false;
+        self.queue.clear();
+        self.done.clear();
+        for buffer in &mut self.buffers {
+            buffer.queued = false;
+        }
+    }
+
+    fn reset(&mut self) {
+        self.stop();
+        self.buffers.clear();
+        self.pool = None;
+    }
+}
+
+/// Memory backing the MMAP buffers
+struct Pool {
+    fd: OwnedFd,
+    /// Address of the mapping the frames are written to
+    addr: usize,
+    /// Total size of the pool
+    len: usize,
+    /// Page aligned size of a single buffer
+    buf_len: usize,
+}
+
+impl Pool {
+    fn new(count: u32, size: u32) -> Result<Self, i32> {
+        let page = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+        let buf_len = (size as usize).div_ceil(page) * page;
+        let len = buf_len * count as usize;
+
+        unsafe {
+            let fd = libc::memfd_create(b"v4l-mock\0".as_ptr() as *const libc::c_char, 0);
+            if fd == -1 {
+                return Err(errno());
+            }
+            let fd = OwnedFd::from_raw_fd(fd);
+            if libc::ftruncate(fd.as_raw_fd(), len as libc::off_t) == -1 {
+                return Err(errno());
+            }
+
+            let addr = libc::mmap(
+                ptr::null_mut(),
+                len,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_SHARED,
+                fd.as_raw_fd(),
+                0,
+            );
+            if addr == libc::MAP_FAILED {
+                return Err(errno());
+            }
+
+            Ok(Pool {
+                fd,
+                addr: addr as usize,
+                len,
+                buf_len,
+            })
+        }
+    }
+}
+
+impl Drop for Pool {
+    fn drop(&mut self) {
+        unsafe {
+            libc::munmap(self.addr as *mut c_void, self.len);
+        }
+    }
+}
+
+/// Control as seen by the simulated driver
+struct Control {
+    id: u32,
+    name: &'static str,
+    typ: control::Type,
+    minimum: i64,
+    maximum: i64,
+    step: u64,
+    default: i64,
+    value: i64,
+}
+
+impl Control {
+    fn new(
+        id: u32,
+        name: &'static str,
+        typ: control::Type,
+        (minimum, maximum, step): (i64, i64, u64),
+        default: i64,
+    ) -> Self {
+        Control {
+            id,
+            name,
+            typ,
+            minimum,
+            maximum,
+            step,
+            default,
+            value: default,
+        }
+    }
+
+    /// Returns a value adjusted to the range and step of the control, as the kernel does
+    fn adjust(&self, value: i64) -> i64 {
+        match self.typ {
+            control::Type::Boolean => (value != 0) as i64,
+            _ => {
+                let value = value.clamp(self.minimum, self.maximum);
+                let steps = (value - self.minimum + self.step as i64 / 2) / self.step as i64;
+                (self.minimum + steps * self.step as i64).min(self.maximum)
+            }
+        }
+    }
+}
+
+struct State {
+    formats: Vec<PixelFormat>,
+    format: Format,
+    intervals: Vec<Fraction>,
+    interval: Fraction,
+    generator: Box<Generator>,
+    errors: HashMap<vidioc::_IOC_TYPE, i32>,
+    controls: Vec<Control>,
+    capture: Queue,
+    /// OUTPUT queue of memory-to-memory devices
+    output: Option<Queue>,
+    sequence: u32,
+}
+
+impl State {
+    fn check_type(&self, typ: u32) -> Result<(), i32> {
+        if typ == buffer::Type::VideoCapture as u32
+            || (typ == buffer::Type::VideoOutput as u32 && self.output.is_some())
+        {
+            Ok(())
+        } else {
+            Err(libc::EINVAL)
+        }
+    }
+
+    fn queue_mut(&mut self, typ: u32) -> Result<&mut Queue, i32> {
+        self.check_type(typ)?;
+        match &mut self.output {
+            Some(output) if typ == buffer::Type::VideoOutput as u32 => Ok(output),
+            _ => Ok(&mut self.capture),
+        }
+    }
+
+    /// Returns whether any queue holds buffers, which locks the format
+    fn busy(&self) -> bool {
+        !self.capture.buffers.is_empty()
+            || self
+                .output
+                .as_ref()
+                .is_some_and(|output| !output.buffers.is_empty())
+    }
+
+    fn pixel_format(&self, fourcc: u32) -> Option<&PixelFormat> {
+        self.formats
+            .iter()
+            .find(|fmt| u32::from(fmt.fourcc) == fourcc)
+    }
+
+    fn querycap(&mut self, caps: &mut v4l2_capability) -> Result<(), i32> {
+        *caps = unsafe { std::mem::zeroed() };
+        copy_str(&mut caps.driver, "mock");
+        copy_str(&mut caps.card, "Mock device");
+        copy_str(&mut caps.bus_info, "platform:mock");
+        caps.version = 0x0001_0000;
+        let kind = if self.output.is_some() {
+            capability::Flags::VIDEO_M2M
+        } else {
+            capability::Flags::VIDEO_CAPTURE
+        };
+        caps.device_caps = (kind | capability::Flags::STREAMING).into();
+        caps.capabilities = caps.device_caps | u32::from(capability::Flags::DEVICE_CAPS);
+        Ok(())
+    }
+
+    fn enum_fmt(&mut self, desc: &mut v4l2_fmtdesc) -> Result<(), i32> {
+        self.check_type(desc.type_)?;
+        let fmt = self.formats.get(desc.index as usize).ok_or(libc::EINVAL)?;
+
+        desc.flags = 0;
+        desc.pixelformat = fmt.fourcc.into();
+        copy_str(&mut desc.description, &fmt.description);
+        Ok(())
+    }
+
+    fn g_fmt(&mut self, fmt: &mut v4l2_format) -> Result<(), i32> {
+        self.check_type(fmt.type_)?;
+        fmt.fmt.pix = self.format.into();
+        Ok(())
+    }
+
+    fn s_fmt(&mut self, fmt: &mut v4l2_format, apply: bool) -> Result<(), i32> {
+        self.check_type(fmt.type_)?;
+        if apply && self.busy() {
+            return Err(libc::EBUSY);
+        }
+
+        // like real drivers, adjust the request to the closest supported format
+        let (width, height, fourcc) = unsafe {
+            (
+                fmt.fmt.pix.width,
+                fmt.fmt.pix.height,
+                fmt.fmt.pix.pixelformat,
+            )
+        };
+        let pixfmt = self.pixel_format(fourcc).unwrap_or(&self.formats[0]);
+        let (width, height) = pixfmt
+            .sizes
+            .iter()
+            .copied()
+            .min_by_key(|&(w, h)| {
+                (i64::from(w) - i64::from(width)).abs() + (i64::from(h) - i64::from(height)).abs()
+            })
+            .unwrap();
+
+        let format = layout(Format::new(width, height, pixfmt.fourcc));
+        fmt.fmt.pix = format.into();
+        if apply {
+            self.format = format;
+        }
+        Ok(())
+    }
+
+    fn enum_framesizes(&mut self, size: &mut v4l2_frmsizeenum) -> Result<(), i32> {
+        let fmt = self.pixel_format(size.pixel_format).ok_or(libc::EINVAL)?;
+        let (width, height) = *fmt.sizes.get(size.index as usize).ok_or(libc::EINVAL)?;
+
+        size.type_ = v4l2_frmsizetypes_V4L2_FRMSIZE_TYPE_DISCRETE;
+        size.__bindgen_anon_1.discrete = v4l2_frmsize_discrete { width, height };
+        Ok(())
+    }
+
+    fn enum_frameintervals(&mut self, ival: &mut v4l2_frmivalenum) -> Result<(), i32> {
+        let fmt = self.pixel_format(ival.pixel_format).ok_or(libc::EINVAL)?;
+        if !fmt.sizes.contains(&(ival.width, ival.height)) {
+            return Err(libc::EINVAL);
+        }
+        let interval = *self
+            .intervals
+            .get(ival.index as usize)
+            .ok_or(libc::EINVAL)?;
+
+        ival.type_ = v4l2_frmivaltypes_V4L2_FRMIVAL_TYPE_DISCRETE;
+        ival.__bindgen_anon_1.discrete = interval.into();
+        Ok(())
+    }
+
+    fn g_parm(&mut self, parm: &mut v4l2_streamparm) -> Result<(), i32> {
+        if parm.type_ != buffer::Type::VideoCapture as u32 {
+            return Err(libc::EINVAL);
+        }
+        parm.parm.capture = v4l2_captureparm {
+            capability: parameters::Capabilities::TIME_PER_FRAME.into(),
+            timeperframe: self.interval.into(),
+            readbuffers: 0,
+            ..unsafe { std::mem::zeroed() }
+        };
+        Ok(())
+    }
+
+    fn s_parm(&mut self, parm: &mut v4l2_streamparm) -> Result<(), i32> {
+        if parm.type_ != buffer::Type::VideoCapture as u32 {
+            return Err(libc::EINVAL);
+        }
+        let requested = Fraction::from(unsafe { parm.parm.capture.timeperframe });
+        if requested.numerator != 0 && requested.denominator != 0 {
+            // pick the closest supported interval
+            let fps = |frac: &Fraction| f64::from(frac.denominator) / f64::from(frac.numerator);
+            let target = fps(&requested);
+            self.interval = *self
+                .intervals
+                .iter()
+                .min_by(|a, b| {
+                    (fps(a) - target)
+                        .abs()
+                        .partial_cmp(&(fps(b) - target).abs())
+                        .unwrap()
+                })
+                .unwrap();
+        }
+
+        self.g_parm(parm)
+    }
+
+    fn query_ext_ctrl(&mut self, query: &mut v4l2_query_ext_ctrl) -> Result<(), i32> {
+        let next = V4L2_CTRL_FLAG_NEXT_CTRL | V4L2_CTRL_FLAG_NEXT_COMPOUND;
+        let id = query.id & !next;
+        let ctrl = if query.id & next != 0 {
+            self.controls
+                .iter()
+                .filter(|ctrl| ctrl.id > id)
+                .min_by_key(|ctrl| ctrl.id)
+        } else {
+            self.controls.iter().find(|ctrl| ctrl.id == id)
+        }
+        .ok_or(libc::EINVAL)?;
+
+        *query = unsafe { std::mem::zeroed() };
+        query.id = ctrl.id;
+        query.type_ = ctrl.typ as u32;
+        for (dst, src) in query.name.iter_mut().zip(ctrl.name.bytes()) {
+            *dst = src as _;
+        }
+        query.minimum = ctrl.minimum;
+        query.maximum = ctrl.maximum;
+        query.step = ctrl.step;
+        query.default_value = ctrl.default;
+        query.elem_size = std::mem::size_of::<i32>() as u32;
+        query.elems = 1;
+        Ok(())
+    }
+
+    /// Returns the index of a control by its identifier
+    ///
+    /// Fails with EINVAL and points `error_idx` to the offending control if there is none, or if
+    /// it does not belong to the class the controls were passed as.
+    fn find_control(
+        &self,
+        ctrls: &mut v4l2_ext_controls,
+        pos: usize,
+        id: u32,
+    ) -> Result<usize, i32> {
+        let class = ctrls.which;
+        let index = self.controls.iter().position(|ctrl| ctrl.id == id);
+        match index {
+            // V4L2_CTRL_WHICH_CUR_VAL (zero) allows mixing controls of different classes
+            Some(index) if class == 0 || id & 0xFFFF_0000 == class => Ok(index),
+            _ => {
+                ctrls.error_idx = pos as u32;
+                Err(libc::EINVAL)
+            }
+        }
+    }
+
+    fn g_ext_ctrls(&mut self, ctrls: &mut v4l2_ext_controls) -> Result<(), i32> {
+        if ctrls.count == 0 {
+            return Ok(());
+        }
+        // The array belongs to the caller and is not accessed by anybody else during the ioctl.
+        let list = unsafe { slice::from_raw_parts_mut(ctrls.controls, ctrls.count as usize) };
+        for (pos, ext) in list.iter_mut().enumerate() {
+            let index = self.find_control(ctrls, pos, ext.id)?;
+            let value = self.controls[index].value;
+            match self.controls[index].typ {
+                control::Type::Integer64 => ext.__bindgen_anon_1.value64 = value,
+                _ => ext.__bindgen_anon_1.value = value as i32,
+            }
+        }
+        Ok(())
+    }
+
+    fn s_ext_ctrls(&mut self, ctrls: &mut v4l2_ext_controls) -> Result<(), i32> {
+        if ctrls.which == V4L2_CTRL_WHICH_REQUEST_VAL {
+            // media requests are not supported
+            return Err(libc::EINVAL);
+        }
+        if ctrls.count == 0 {
+            return Ok(());
+        }
+        // The array belongs to the caller and is not accessed by anybody else during the ioctl.
+        let list = unsafe { slice::from_raw_parts(ctrls.controls, ctrls.count as usize) };
+
+        // all controls are checked before any is applied, the update is atomic
+        let mut updates = Vec::with_capacity(list.len());
+        for (pos, ext) in list.iter().enumerate() {
+            let index = self.find_control(ctrls, pos, ext.id)?;
+            let ctrl = &self.controls[index];
+            let value = unsafe {
+                match ctrl.typ {
+                    control::Type::Integer64 => ext.__bindgen_anon_1.value64,
+                    _ => i64::from(ext.__bindgen_anon_1.value),
+                }
+            };
+            updates.push((index, ctrl.adjust(value)));
+        }
+        for (index, value) in updates {
+            self.controls[index].value = value;
+        }
+        Ok(())
+    }
+
+    fn reqbufs(&mut self, req: &mut v4l2_requestbuffers) -> Result<(), i32> {
+        let memory = match req.memory {
+            m if m == Memory::Mmap as u32 => Memory::Mmap,
+            m if m == Memory::UserPtr as u32 => Memory::UserPtr,
+            _ => return Err(libc::EINVAL),
+        };
+        let size = self.format.size;
+        let queue = self.queue_mut(req.type_)?;
+        if queue.streaming {
+            return Err(libc::EBUSY);
+        }
+
+        queue.reset();
+
+        let count = req.count.min(MAX_BUFFERS);
+        if count > 0 && memory == Memory::Mmap {
+            queue.pool = Some(Pool::new(count, size)?);
+        }
+        for _ in 0..count {
+            queue.buffers.push(Buffer {
+                queued: false,
+                userptr: 0,
+                length: size,
+                bytesused: 0,
+                timestamp: timeval {
+                    tv_sec: 0,
+                    tv_usec: 0,
+                },
+            });
+        }
+
+        queue.memory = memory;
+        req.count = count;
+        Ok(())
+    }
+
+    fn querybuf(&mut self, buf: &mut v4l2_buffer) -> Result<(), i32> {
+        let m2m = self.output.is_some();
+        let queue = self.queue_mut(buf.type_)?;
+        if buf.index as usize >= queue.buffers.len() {
+            return Err(libc::EINVAL);
+        }
+
+        queue.describe(buf.index, buf, m2m);
+        Ok(())
+    }
+
+    fn qbuf(&mut self, buf: &mut v4l2_buffer) -> Result<(), i32> {
+        let m2m = self.output.is_some();
+        let size = self.format.size;
+        let queue = self.queue_mut(buf.type_)?;
+        if buf.memory != queue.memory as u32 {
+            return Err(libc::EINVAL);
+        }
+        let memory = queue.memory;
+        let buffer = queue
+            .buffers
+            .get_mut(buf.index as usize)
+            .ok_or(libc::EINVAL)?;
+        if buffer.queued {
+            return Err(libc::EINVAL);
+        }
+
+        if memory == Memory::UserPtr {
+            let userptr = unsafe { buf.m.userptr } as usize;
+            if userptr == 0 || buf.length < size {
+                return Err(libc::EINVAL);
+            }
+            buffer.userptr = userptr;
+            buffer.length = buf.length;
+        }
+
+        buffer.queued = true;
+        buffer.bytesused = buf.bytesused.min(buffer.length);
+        buffer.timestamp = buf.timestamp;
+        queue.queue.push_back(buf.index);
+        queue.describe(buf.index, buf, m2m);
+        Ok(())
+    }
+
+    fn dqbuf(&mut self, buf: &mut v4l2_buffer) -> Result<(), i32> {
+        let m2m = self.output.is_some();
+        let queue = self.queue_mut(buf.type_)?;
+        if buf.memory != queue.memory as u32 || !queue.streaming {
+            return Err(libc::EINVAL);
+        }
+
+        if buf.type_ == buffer::Type::VideoOutput as u32 {
+            // processed inputs are handed back as they are
+            let index = queue.done.pop_front().ok_or(libc::EAGAIN)?;
+            let buffer = &mut queue.buffers[index as usize];
+            buffer.queued = false;
+            let (bytesused, timestamp) = (buffer.bytesused, buffer.timestamp);
+            queue.describe(index, buf, m2m);
+            buf.flags |= u32::from(buffer::Flags::DONE);
+            buf.bytesused = bytesused;
+            buf.timestamp = timestamp;
+            return Ok(());
+        }
+
+        let size = self.format.size as usize;
+        let sequence = self.sequence;
+        let (bytesused, timestamp) = match &mut self.output {
+            Some(output) => {
+                if !output.pending() || self.capture.queue.is_empty() {
+                    return Err(libc::EAGAIN);
+                }
+                let input = output.queue.pop_front().unwrap();
+                let index = self.capture.queue[0];
+                output.done.push_back(input);
+
+                let len = (output.buffers[input as usize].bytesused as usize).min(size);
+                // Both buffers are owned by the driver until they get dequeued, so nobody else
+                // may be accessing them right now.
+                unsafe {
+                    ptr::copy_nonoverlapping(output.data(input), self.capture.data(index), len);
+                }
+                (len as u32, output.buffers[input as usize].timestamp)
+            }
+            None => {
+                if self.capture.queue.is_empty() {
+                    return Err(libc::EAGAIN);
+                }
+                let index = self.capture.queue[0];
+                // The buffer is owned by the driver until it gets dequeued, so nobody else may
+                // be accessing it right now.
+                let data = unsafe { slice::from_raw_parts_mut(self.capture.data(index), size) };
+                (self.generator)(data, &self.format, sequence);
+
+                // derive the timestamp from the frame interval to get a steady clock
+                let usec = u64::from(sequence) * 1_000_000 * u64::from(self.interval.numerator)
+                    / u64::from(self.interval.denominator);
+                let timestamp = timeval {
+                    tv_sec: (usec / 1_000_000) as _,
+                    tv_usec: (usec % 1_000_000) as _,
+                };
+                (size as u32, timestamp)
+            }
+        };
+        self.sequence += 1;
+
+        let index = self.capture.queue.pop_front().unwrap();
+        self.capture.buffers[index as usize].queued = false;
+        self.capture.describe(index, buf, m2m);
+        buf.flags |= u32::from(buffer::Flags::DONE);
+        buf.bytesused = bytesused;
+        buf.field = FieldOrder::Progressive as u32;
+        buf.sequence = sequence;
+        buf.timestamp = timestamp;
+        Ok(())
+    }
+
+    fn streamon(&mut self, typ: u32) -> Result<(), i32> {
+        let queue = self.queue_mut(typ)?;
+        if queue.buffers.is_empty() {
+            return Err(libc::EINVAL);
+        }
+
+        if !queue.streaming {
+            queue.streaming = true;
+            if typ == buffer::Type::VideoCapture as u32 {
+                self.sequence = 0;
+            }
+        }
+        Ok(())
+    }
+
+    fn streamoff(&mut self, typ: u32) -> Result<(), i32> {
+        // all buffers are returned to userspace
+        self.queue_mut(typ)?.stop();
+        Ok(())
+    }
+}
+
+/// Returns the controls of the mock device, in the user and camera control classes
+fn controls() -> Vec<Control> {
+    vec![
+        Control::new(
+            V4L2_CID_BRIGHTNESS,
+            "Brightness",
+            control::Type::Integer,
+            (0, 255, 1),
+            128,
+        ),
+        Control::new(
+            V4L2_CID_CONTRAST,
+            "Contrast",
+            control::Type::Integer,
+            (0, 255, 1),
+            32,
+        ),
+        Control::new(
+            V4L2_CID_HFLIP,
+            "Horizontal Flip",
+            control::Type::Boolean,
+            (0, 1, 1),
+            0,
+        ),
+        Control::new(
+            V4L2_CID_EXPOSURE_ABSOLUTE,
+            "Exposure Time, Absolute",
+            control::Type::Integer,
+            (1, 5000, 1),
+            156,
+        ),
+    ]
+}
+
+/// Fills in the stride and size of a format
+fn layout(mut format: Format) -> Format {
+    let (width, height) = (format.width, format.height);
+    let (stride, size) = match &format.fourcc.repr {
+        b"GREY" => (width, width * height),
+        b"NV12" | b"YU12" | b"YV12" => (width, width * height * 3 / 2),
+        b"RGB3" | b"BGR3" => (width * 3, width * height * 3),
+        b"RGB4" | b"BGR4" | b"AR24" | b"XR24" => (width * 4, width * height * 4),
+        // compressed formats have no stride, reserve the size of a raw frame
+        b"MJPG" | b"JPEG" | b"H264" => (0, width * height * 2),
+        // assume 16 bits per pixel for everything else (e.g. YUYV, UYVY)
+        _ => (width * 2, width * height * 2),
+    };
+
+    format.field_order = FieldOrder::Progressive;
+    format.stride = stride;
+    format.size = size;
+    format
+}
+
+/// Default frame content: a gradient which moves by one byte with every frame
+fn gradient(buf: &mut [u8], _format: &Format, sequence: u32) {
+    for (i, byte) in buf.iter_mut().enumerate() {
+        *byte = (i as u32).wrapping_add(sequence) as u8;
+    }
+}
+
+/// Copies a string into a fixed size, NUL terminated buffer
+fn copy_str(dst: &mut [u8], src: &str) {
+    let len = src.len().min(dst.len() - 1);
+    dst[..len].copy_from_slice(&src.as_bytes()[..len]);
+    dst[len..].iter_mut().for_each(|byte| *byte = 0);
+}
+
+fn errno() -> i32 {
+    io::Error::last_os_error()
+        .raw_os_error()
+        .unwrap_or(libc::EIO)
+}
@@ -0,0 +1,207 @@
+//! Pluggable device access layer
+//!
+//! Every interaction of a [`Handle`](crate::device::Handle) with the underlying device node
+//! (ioctls, memory mappings, polling) is dispatched through a [`DeviceBackend`]. By default, the
+//! [`System`] backend forwards these calls to the kernel (or libv4l, depending on the enabled
+//! features). The [`mock`] backend on the other hand simulates a capture device in memory, so
+//! applications can test their capture logic without any /dev/video nodes being present.
+
+use std::{
+    io,
+    os::{fd::RawFd, raw::c_void},
+    time::Instant,
+};
+
+use crate::v4l2;
+use crate::v4l2::vidioc;
+
+pub mod mock;
+pub use mock::Mock;
+
+/// Low-level device access
+///
+/// All methods receive the file descriptor of the handle they are invoked on, so stateless
+/// backends such as [`System`] can be shared between any number of handles.
+pub trait DeviceBackend: Send + Sync {
+    /// Performs an ioctl on the device
+    ///
+    /// # Arguments
+    ///
+    /// * `fd` - File descriptor
+    /// * `request` - IO control code (see [`vidioc`])
+    /// * `argp` - Pointer to the argument exchanged with the device
+    ///
+    /// # Safety
+    ///
+    /// `argp` must point to valid memory with the layout expected for `request`.
+    unsafe fn ioctl(
+        &self,
+        fd: RawFd,
+        request: vidioc::_IOC_TYPE,
+        argp: *mut c_void,
+    ) -> io::Result<()>;
+
+    /// Maps device memory into the address space of the process
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Starting address hint
+    /// * `length` - Length of the mapping
+    /// * `prot` - Memory protection flags (e.g. PROT_READ)
+    /// * `flags` - Mapping flags (e.g. MAP_SHARED)
+    /// * `fd` - File descriptor
+    /// * `offset` - Offset of the buffer as reported by the device
+    ///
+    /// # Safety
+    ///
+    /// The returned memory must only be accessed for as long as the mapping is alive.
+    unsafe fn mmap(
+        &self,
+        start: *mut c_void,
+        length: usize,
+        prot: libc::c_int,
+        flags: libc::c_int,
+        fd: RawFd,
+        offset: libc::off_t,
+    ) -> io::Result<*mut c_void>;
+
+    /// Unmaps memory which was previously mapped by [`DeviceBackend::mmap`]
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Address of the mapping
+    /// * `length` - Length of the mapping
+    ///
+    /// # Safety
+    ///
+    /// The memory must not be accessed anymore afterwards.
+    unsafe fn munmap(&self, start: *mut c_void, length: usize) -> io::Result<()>;
+
+    /// Polls the device for I/O events
+    ///
+    /// Returns the events which occurred (revents), i.e. zero in case of a timeout.
+    ///
+    /// # Arguments
+    ///
+    /// * `fd` - File descriptor
+    /// * `events` - The events you are interested in (e.g. POLLIN)
+    /// * `timeout` - Timeout in milliseconds, a negative value means infinite timeout
+    fn poll(&self, fd: RawFd, events: i16, timeout: i32) -> io::Result<i16>;
+
+    /// Closes the device
+    ///
+    /// # Arguments
+    ///
+    /// * `fd` - File descriptor
+    fn close(&self, fd: RawFd) -> io::Result<()>;
+}
+
+/// Backend talking to the actual device nodes
+///
+/// Depending on the enabled features, calls are routed through libv4l or issued directly, see
+/// [`v4l2::Library`] to choose per device.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct System;
+
+impl DeviceBackend for System {
+    unsafe fn ioctl(
+        &self,
+        fd: RawFd,
+        request: vidioc::_IOC_TYPE,
+        argp: *mut c_void,
+    ) -> io::Result<()> {
+        v4l2::ioctl(fd, request, argp)
+    }
+
+    unsafe fn mmap(
+        &self,
+        start: *mut c_void,
+        length: usize,
+        prot: libc::c_int,
+        flags: libc::c_int,
+        fd: RawFd,
+        offset: libc::off_t,
+    ) -> io::Result<*mut c_void> {
+        v4l2::mmap(start, length, prot, flags, fd, offset)
+    }
+
+    unsafe fn munmap(&self, start: *mut c_void, length: usize) -> io::Result<()> {
+        v4l2::munmap(start, length)
+    }
+
+    fn poll(&self, fd: RawFd, events: i16, timeout: i32) -> io::Result<i16> {
+        poll(fd, events, timeout)
+    }
+
+    fn close(&self, fd: RawFd) -> io::Result<()> {
+        v4l2::close(fd)
+    }
+}
+
+impl DeviceBackend for v4l2::Library {
+    unsafe fn ioctl(
+        &self,
+        fd: RawFd,
+        request: vidioc::_IOC_TYPE,
+        argp: *mut c_void,
+    ) -> io::Result<()> {
+        v4l2::Library::ioctl(*self, fd, request, argp)
+    }
+
+    unsafe fn mmap(
+        &self,
+        start: *mut c_void,
+        length: usize,
+        prot: libc::c_int,
+        flags: libc::c_int,
+        fd: RawFd,
+        offset: libc::off_t,
+    ) -> io::Result<*mut c_void> {
+        v4l2::Library::mmap(*self, start, length, prot, flags, fd, offset)
+    }
+
+    unsafe fn munmap(&self, start: *mut c_void, length: usize) -> io::Result<()> {
+        v4l2::Library::munmap(*self, start, length)
+    }
+
+    fn poll(&self, fd: RawFd, events: i16, timeout: i32) -> io::Result<i16> {
+        poll(fd, events, timeout)
+    }
+
+    fn close(&self, fd: RawFd) -> io::Result<()> {
+        v4l2::Library::close(*self, fd)
+    }
+}
+
+/// Polls a device node, restarting the call if it gets interrupted
+fn poll(fd: RawFd, events: i16, timeout: i32) -> io::Result<i16> {
+    let start = Instant::now();
+    let mut remaining = timeout;
+
+    loop {
+        let mut pollfd = libc::pollfd {
+            fd,
+            events,
+            revents: 0,
+        };
+        match unsafe { libc::poll(&mut pollfd, 1, remaining) } {
+            -1 => {
+                let err = io::Error::last_os_error();
+                if err.kind() != io::ErrorKind::Interrupted {
+                    return Err(err);
+                }
+
+                if timeout > 0 {
+                    let elapsed = start.elapsed().as_millis().min(i32::MAX as u128) as i32;
+                    remaining = (timeout - elapsed).max(0);
+                }
+            }
+            ret => {
+                // A return value of zero means that we timed out, revents is left zeroed
+                // then. Otherwise, the single fd had I/O activity.
+                assert!(ret == 0 || ret == 1);
+                return Ok(pollfd.revents);
+            }
+        }
+    }
+}
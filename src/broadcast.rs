@@ -0,0 +1,334 @@
+use std::{
+    collections::VecDeque,
+    io,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Condvar, Mutex, Weak,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::frame::{Frame, Planes};
+use crate::io::traits::CaptureStream;
+use crate::sched::ThreadOptions;
+
+/// Bounded queue shared between the capture thread and a subscriber
+struct Channel {
+    state: Mutex<ChannelState>,
+    ready: Condvar,
+}
+
+struct ChannelState {
+    frames: VecDeque<Arc<Frame>>,
+    capacity: usize,
+    dropped: u64,
+    closed: bool,
+}
+
+impl Channel {
+    fn send(&self, frame: Arc<Frame>) {
+        let mut state = self.state.lock().unwrap();
+        if state.frames.len() == state.capacity {
+            // slow subscribers must not stall the others, so they lose their oldest frames
+            state.frames.pop_front();
+            state.dropped += 1;
+        }
+        state.frames.push_back(frame);
+        self.ready.notify_one();
+    }
+
+    fn close(&self) {
+        self.state.lock().unwrap().closed = true;
+        self.ready.notify_all();
+    }
+}
+
+struct Shared {
+    subscribers: Mutex<Subscribers>,
+    stop: AtomicBool,
+}
+
+/// Subscribers along with whether they were closed, both guarded by one lock
+struct Subscribers {
+    channels: Vec<Weak<Channel>>,
+    closed: bool,
+}
+
+impl Shared {
+    fn broadcast(&self, frame: Frame) {
+        let frame = Arc::new(frame);
+        let mut subscribers = self.subscribers.lock().unwrap();
+        // subscribers which went away are pruned along the way
+        subscribers
+            .channels
+            .retain(|channel| match channel.upgrade() {
+                Some(channel) => {
+                    channel.send(frame.clone());
+                    true
+                }
+                None => false,
+            });
+    }
+
+    fn close(&self) {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        subscribers.closed = true;
+        for channel in subscribers.channels.drain(..) {
+            if let Some(channel) = channel.upgrade() {
+                channel.close();
+            }
+        }
+    }
+}
+
+/// Distributes the frames of a single capture stream to multiple subscribers
+///
+/// The stream is driven by a background thread. Every frame is copied exactly once and shared
+/// among all subscribers, so e.g. a preview, a recorder and an analysis pipeline can consume
+/// the frames of the same camera independently. Each subscriber has a bounded queue: once it is
+/// full, the oldest frame is dropped in favor of the new one.
+///
+/// # Example
+///
+/// ```
+/// use v4l::broadcast::FrameBroadcaster;
+/// use v4l::buffer::Type;
+/// use v4l::prelude::*;
+///
+/// if let Ok(dev) = Device::new(0) {
+///     let stream = UserptrStream::new(&dev, Type::VideoCapture).unwrap();
+///     let mut broadcaster = FrameBroadcaster::new();
+///     let preview = broadcaster.subscribe(1);
+///     let _recording = broadcaster.subscribe(30);
+///     broadcaster.start(stream).unwrap();
+///
+///     while let Some(frame) = preview.recv() {
+///         println!("seq: {}", frame.meta.sequence);
+///     }
+/// }
+/// ```
+pub struct FrameBroadcaster {
+    shared: Arc<Shared>,
+    thread: Option<thread::JoinHandle<io::Result<()>>>,
+}
+
+impl FrameBroadcaster {
+    /// Returns a broadcaster without any subscribers
+    pub fn new() -> Self {
+        FrameBroadcaster {
+            shared: Arc::new(Shared {
+                subscribers: Mutex::new(Subscribers {
+                    channels: Vec::new(),
+                    closed: false,
+                }),
+                stop: AtomicBool::new(false),
+            }),
+            thread: None,
+        }
+    }
+
+    /// Returns a new subscriber
+    ///
+    /// Subscribers can be added at any time, they receive all frames captured afterwards. Once the
+    /// broadcaster stopped, new subscribers are closed right away.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Maximum number of frames queued for the subscriber (at least one)
+    pub fn subscribe(&self, capacity: usize) -> Subscriber {
+        // registering under the lock of close() makes sure the subscriber is closed either way
+        let mut subscribers = self.shared.subscribers.lock().unwrap();
+        let channel = Arc::new(Channel {
+            state: Mutex::new(ChannelState {
+                frames: VecDeque::new(),
+                capacity: capacity.max(1),
+                dropped: 0,
+                closed: subscribers.closed,
+            }),
+            ready: Condvar::new(),
+        });
+
+        if !subscribers.closed {
+            subscribers.channels.push(Arc::downgrade(&channel));
+        }
+        Subscriber { channel }
+    }
+
+    /// Starts capturing from a stream on a background thread
+    ///
+    /// Capturing stops once the broadcaster is stopped or dropped, or if the stream reports an
+    /// error. All subscribers are closed afterwards. A broadcaster is started only once, further
+    /// attempts fail with [`io::ErrorKind::AlreadyExists`].
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - Capture stream
+    pub fn start<S>(&mut self, stream: S) -> io::Result<()>
+    where
+        S: for<'b> CaptureStream<'b> + Send + 'static,
+        S::Item: Planes,
+    {
+        self.start_with(stream, ThreadOptions::default())
+    }
+
+    /// Starts capturing from a stream on a background thread with the given scheduling
+    ///
+    /// Like [`FrameBroadcaster::start`], but the thread is pinned to CPUs or raised to realtime
+    /// priority first. Failing to apply the options stops the broadcaster right away.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - Capture stream
+    /// * `options` - Scheduling options of the capture thread
+    pub fn start_with<S>(&mut self, mut stream: S, options: ThreadOptions) -> io::Result<()>
+    where
+        S: for<'b> CaptureStream<'b> + Send + 'static,
+        S::Item: Planes,
+    {
+        // stopped broadcasters closed their subscribers for good
+        if self.thread.is_some() || self.shared.stop.load(Ordering::Relaxed) {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                "broadcaster was started already",
+            ));
+        }
+
+        let shared = self.shared.clone();
+        self.thread = Some(thread::spawn(move || {
+            let mut res = options.apply();
+            while res.is_ok() && !shared.stop.load(Ordering::Relaxed) {
+                match Frame::capture(&mut stream) {
+                    Ok(frame) => shared.broadcast(frame),
+                    Err(e) => {
+                        res = Err(e);
+                        break;
+                    }
+                }
+            }
+
+            // late subscribers must not wait for frames which will never arrive
+            shared.stop.store(true, Ordering::Relaxed);
+            shared.close();
+            res
+        }));
+        Ok(())
+    }
+
+    /// Returns the number of live subscribers
+    pub fn subscribers(&self) -> usize {
+        self.shared
+            .subscribers
+            .lock()
+            .unwrap()
+            .channels
+            .iter()
+            .filter(|channel| channel.strong_count() > 0)
+            .count()
+    }
+
+    /// Stops capturing and closes all subscribers
+    ///
+    /// Returns the error which made the background thread stop prematurely, if any.
+    pub fn stop(&mut self) -> io::Result<()> {
+        self.shared.stop.store(true, Ordering::Relaxed);
+        let res = match self.thread.take() {
+            Some(thread) => thread
+                .join()
+                .unwrap_or_else(|_| Err(io::Error::other("broadcast thread panicked"))),
+            None => Ok(()),
+        };
+
+        self.shared.close();
+        res
+    }
+}
+
+impl Default for FrameBroadcaster {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for FrameBroadcaster {
+    fn drop(&mut self) {
+        let _ = self.stop();
+    }
+}
+
+/// Receiving end of a [`FrameBroadcaster`]
+///
+/// Dropping the subscriber unsubscribes it.
+pub struct Subscriber {
+    channel: Arc<Channel>,
+}
+
+impl Subscriber {
+    /// Waits for the next frame
+    ///
+    /// Returns `None` once the broadcaster stopped and all queued frames have been received.
+    pub fn recv(&self) -> Option<Arc<Frame>> {
+        let mut state = self.channel.state.lock().unwrap();
+        loop {
+            if let Some(frame) = state.frames.pop_front() {
+                return Some(frame);
+            }
+            if state.closed {
+                return None;
+            }
+            state = self.channel.ready.wait(state).unwrap();
+        }
+    }
+
+    /// Waits for the next frame for a limited amount of time
+    ///
+    /// Returns `None` in case of a timeout or if the broadcaster stopped.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Maximum time to wait
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<Arc<Frame>> {
+        let deadline = Instant::now() + timeout;
+        let mut state = self.channel.state.lock().unwrap();
+        loop {
+            if let Some(frame) = state.frames.pop_front() {
+                return Some(frame);
+            }
+            let now = Instant::now();
+            if state.closed || now >= deadline {
+                return None;
+            }
+            state = self
+                .channel
+                .ready
+                .wait_timeout(state, deadline - now)
+                .unwrap()
+                .0;
+        }
+    }
+
+    /// Returns the next frame if one is queued already
+    pub fn try_recv(&self) -> Option<Arc<Frame>> {
+        self.channel.state.lock().unwrap().frames.pop_front()
+    }
+
+    /// Returns the number of frames which were dropped because the queue was full
+    pub fn dropped(&self) -> u64 {
+        self.channel.state.lock().unwrap().dropped
+    }
+
+    /// Returns whether the broadcaster stopped
+    ///
+    /// Frames which are still queued can be received nonetheless.
+    pub fn is_closed(&self) -> bool {
+        self.channel.state.lock().unwrap().closed
+    }
+}
+
+impl Iterator for Subscriber {
+    type Item = Arc<Frame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.recv()
+    }
+}
@@ -1,7 +1,7 @@
 use bitflags::bitflags;
 use std::{convert::TryInto, fmt, mem};
 
-use v4l2_sys::v4l2_buffer;
+use v4l2_sys::{v4l2_buffer, v4l2_plane};
 
 use crate::{memory::Memory, timestamp::Timestamp};
 
@@ -34,10 +34,7 @@ pub enum Type {
 
 impl Type {
     pub fn planar(&self) -> bool {
-        match self {
-            Type::VideoCaptureMplane | Type::VideoOutputMplane => true,
-            _ => false,
-        }
+        matches!(self, Type::VideoCaptureMplane | Type::VideoOutputMplane)
     }
 }
 
@@ -166,21 +163,69 @@ impl From<v4l2_buffer> for Metadata {
     }
 }
 
-impl Into<v4l2_buffer> for Metadata {
-    fn into(self) -> v4l2_buffer {
+impl From<Metadata> for v4l2_buffer {
+    fn from(meta: Metadata) -> Self {
         unsafe {
             v4l2_buffer {
-                index: self.index,
-                type_: self.type_,
-                bytesused: self.bytesused,
-                flags: self.flags.into(),
-                field: self.field,
-                timestamp: self.timestamp.into(),
-                sequence: self.sequence,
-                memory: self.memory as u32,
-                length: self.length,
+                index: meta.index,
+                type_: meta.type_,
+                bytesused: meta.bytesused,
+                flags: meta.flags.into(),
+                field: meta.field,
+                timestamp: meta.timestamp.into(),
+                sequence: meta.sequence,
+                memory: meta.memory as u32,
+                length: meta.length,
                 ..mem::zeroed()
             }
         }
     }
 }
+
+/// Memory plane of a buffer as reported by `VIDIOC_QUERYBUF`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Plane {
+    /// Size of the plane (not payload!)
+    pub length: u32,
+    /// Number of bytes occupied by the data in the plane
+    pub bytesused: u32,
+    /// Offset of the data from the start of the plane
+    pub data_offset: u32,
+    /// Offset to pass to mmap(), only meaningful for `Memory::Mmap` buffers
+    pub mem_offset: u32,
+}
+
+impl From<&v4l2_plane> for Plane {
+    fn from(plane: &v4l2_plane) -> Self {
+        Self {
+            length: plane.length,
+            bytesused: plane.bytesused,
+            data_offset: plane.data_offset,
+            mem_offset: unsafe { plane.m.mem_offset },
+        }
+    }
+}
+
+/// Full state of a buffer as reported by `VIDIOC_QUERYBUF`
+///
+/// The state can be queried at any time, e.g. to find out which buffers are stuck in the
+/// driver (`Flags::QUEUED`) or ready to be dequeued (`Flags::DONE`).
+#[derive(Clone)]
+pub struct Info {
+    /// Buffer metadata, including the flags
+    pub meta: Metadata,
+    /// Memory planes, single-planar buffers have exactly one
+    pub planes: Vec<Plane>,
+}
+
+impl Info {
+    /// Returns whether the buffer is in the incoming queue of the driver
+    pub fn is_queued(&self) -> bool {
+        self.meta.flags.contains(Flags::QUEUED)
+    }
+
+    /// Returns whether the buffer is in the outgoing queue of the driver
+    pub fn is_done(&self) -> bool {
+        self.meta.flags.contains(Flags::DONE)
+    }
+}
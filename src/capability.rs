@@ -39,6 +39,7 @@ bitflags! {
         const META_OUTPUT           = 0x08000000;
 
         const TOUCH                 = 0x10000000;
+        const IO_MC                 = 0x20000000;
 
         const DEVICE_CAPS           = 0x80000000;
     }
@@ -62,6 +63,94 @@ impl fmt::Display for Flags {
     }
 }
 
+/// Driver version, encoded like `KERNEL_VERSION()`
+///
+/// In-tree drivers report the version of the kernel they are part of. Versions are ordered, so
+/// workarounds for specific driver releases can be written as comparisons.
+///
+/// # Example
+///
+/// ```
+/// use v4l::capability::Version;
+///
+/// let version = Version::from(0x0006_0102);
+/// assert_eq!(version, Version::new(6, 1, 2));
+/// assert!(version.at_least(5, 15, 0));
+/// assert!(version < Version::new(6, 2, 0));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: u8,
+    pub minor: u8,
+    pub patch: u8,
+}
+
+impl Version {
+    /// Returns a version
+    ///
+    /// # Arguments
+    ///
+    /// * `major` - Major version
+    /// * `minor` - Minor version
+    /// * `patch` - Patch level
+    pub const fn new(major: u8, minor: u8, patch: u8) -> Self {
+        Version {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Returns whether this version is the given one or newer
+    ///
+    /// # Arguments
+    ///
+    /// * `major` - Major version
+    /// * `minor` - Minor version
+    /// * `patch` - Patch level
+    pub fn at_least(&self, major: u8, minor: u8, patch: u8) -> bool {
+        *self >= Version::new(major, minor, patch)
+    }
+}
+
+impl From<u32> for Version {
+    fn from(version: u32) -> Self {
+        Version::new(
+            ((version >> 16) & 0xff) as u8,
+            ((version >> 8) & 0xff) as u8,
+            (version & 0xff) as u8,
+        )
+    }
+}
+
+impl From<Version> for u32 {
+    fn from(version: Version) -> Self {
+        (u32::from(version.major) << 16)
+            | (u32::from(version.minor) << 8)
+            | u32::from(version.patch)
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Returns the string stored in a zero padded char array
+///
+/// Drivers are supposed to report UTF-8, but some fill in names in a legacy encoding. Those are
+/// decoded as Latin-1, which cannot fail and maps every byte to exactly one char, so nothing is
+/// lost.
+fn string(bytes: &[u8]) -> String {
+    let len = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    let bytes = &bytes[..len];
+    match str::from_utf8(bytes) {
+        Ok(s) => s.to_string(),
+        Err(_) => bytes.iter().map(|&b| char::from(b)).collect(),
+    }
+}
+
 #[derive(Debug)]
 /// Device capabilities
 pub struct Capabilities {
@@ -71,8 +160,8 @@ pub struct Capabilities {
     pub card: String,
     /// Bus name, e.g. USB or PCI
     pub bus: String,
-    /// Version number MAJOR.MINOR.PATCH
-    pub version: (u8, u8, u8),
+    /// Driver version
+    pub version: Version,
 
     /// Capability flags
     pub capabilities: Flags,
@@ -81,23 +170,10 @@ pub struct Capabilities {
 impl From<v4l2_capability> for Capabilities {
     fn from(cap: v4l2_capability) -> Self {
         Self {
-            driver: str::from_utf8(&cap.driver)
-                .unwrap()
-                .trim_matches(char::from(0))
-                .to_string(),
-            card: str::from_utf8(&cap.card)
-                .unwrap()
-                .trim_matches(char::from(0))
-                .to_string(),
-            bus: str::from_utf8(&cap.bus_info)
-                .unwrap()
-                .trim_matches(char::from(0))
-                .to_string(),
-            version: (
-                ((cap.version >> 16) & 0xff) as u8,
-                ((cap.version >> 8) & 0xff) as u8,
-                (cap.version & 0xff) as u8,
-            ),
+            driver: string(&cap.driver),
+            card: string(&cap.card),
+            bus: string(&cap.bus_info),
+            version: Version::from(cap.version),
             capabilities: Flags::from(cap.device_caps),
         }
     }
@@ -108,11 +184,7 @@ impl fmt::Display for Capabilities {
         writeln!(f, "Driver      : {}", self.driver)?;
         writeln!(f, "Card        : {}", self.card)?;
         writeln!(f, "Bus         : {}", self.bus)?;
-        writeln!(
-            f,
-            "Version     : {}.{}.{}",
-            self.version.0, self.version.1, self.version.2
-        )?;
+        writeln!(f, "Version     : {}", self.version)?;
         writeln!(f, "Capabilities : {}", self.capabilities)?;
         Ok(())
     }
@@ -0,0 +1,159 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::timestamp::Timestamp;
+
+/// Largest deviation of the clock rates which is considered plausible, 1%
+const MAX_DRIFT: f64 = 0.01;
+
+/// Maps buffer timestamps onto a master clock
+///
+/// Buffer timestamps are taken from the monotonic clock of the kernel (or even a clock of the
+/// device), while recordings are usually synchronized to another clock, e.g. the sample clock
+/// of an audio device. The two clocks run at slightly different rates, so a constant offset
+/// is not enough to keep audio and video in sync over a longer recording.
+///
+/// Pairs of buffer timestamps and master clock readings taken when the buffer was dequeued are
+/// collected in a sliding window. The rate of the master clock relative to the buffer clock is
+/// estimated with a least squares fit. The offset is taken from the earliest reading relative
+/// to the fit, as dequeueing only ever adds latency, which removes the scheduling jitter from
+/// the presentation timestamps.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use v4l::clock::ClockSync;
+/// use v4l::timestamp::Timestamp;
+///
+/// let mut sync = ClockSync::new(64);
+/// // the master clock runs 100 ppm faster and reads 2 seconds at the first frame
+/// for i in 0..100i64 {
+///     let buffer = Timestamp::new(10 + i / 30, (i % 30) * 33_333);
+///     let elapsed = Duration::from(buffer) - Duration::from_secs(10);
+///     let master = Duration::from_secs(2) + elapsed.mul_f64(1.0001);
+///     sync.update(buffer, master);
+/// }
+/// assert!((sync.drift_ppm() - 100.0).abs() < 1.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClockSync {
+    /// Maximum number of samples
+    window: usize,
+    /// Buffer timestamp and master clock reading of the first sample, in seconds
+    base: Option<(f64, f64)>,
+    /// Buffer timestamps and master clock readings relative to the base
+    samples: VecDeque<(f64, f64)>,
+    /// Rate of the master clock relative to the buffer clock
+    rate: f64,
+    /// Master clock reading at the base buffer timestamp, relative to the base
+    offset: f64,
+    /// Last presentation timestamp
+    last: Option<Duration>,
+}
+
+impl ClockSync {
+    /// Returns a synchronizer without any samples
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - Number of samples to consider, e.g. a few seconds worth of frames
+    pub fn new(window: usize) -> Self {
+        ClockSync {
+            window: window.max(2),
+            base: None,
+            samples: VecDeque::new(),
+            rate: 1.0,
+            offset: 0.0,
+            last: None,
+        }
+    }
+
+    /// Adds a sample and returns the presentation timestamp of the buffer
+    ///
+    /// The returned timestamps never decrease, even when the estimate changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - Timestamp of a dequeued buffer
+    /// * `master` - Reading of the master clock right after the buffer was dequeued
+    pub fn update(&mut self, buffer: Timestamp, master: Duration) -> Duration {
+        let buffer = seconds(buffer);
+        let master = master.as_secs_f64();
+        let (buffer_base, master_base) = *self.base.get_or_insert((buffer, master));
+
+        if self.samples.len() == self.window {
+            self.samples.pop_front();
+        }
+        self.samples
+            .push_back((buffer - buffer_base, master - master_base));
+        self.estimate();
+
+        let mut pts = self.map(buffer);
+        if let Some(last) = self.last {
+            pts = pts.max(last);
+        }
+        self.last = Some(pts);
+        pts
+    }
+
+    /// Returns the presentation timestamp of a buffer without adding a sample
+    ///
+    /// Returns `None` if no sample was added yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - Timestamp of a buffer
+    pub fn pts(&self, buffer: Timestamp) -> Option<Duration> {
+        self.base.map(|_| self.map(seconds(buffer)))
+    }
+
+    /// Returns how much faster the master clock runs, in parts per million
+    pub fn drift_ppm(&self) -> f64 {
+        (self.rate - 1.0) * 1_000_000.0
+    }
+
+    /// Drops all samples, e.g. after the stream was restarted
+    pub fn reset(&mut self) {
+        self.base = None;
+        self.samples.clear();
+        self.rate = 1.0;
+        self.offset = 0.0;
+        self.last = None;
+    }
+
+    fn map(&self, buffer: f64) -> Duration {
+        let (buffer_base, master_base) = self.base.unwrap_or_default();
+        let master = master_base + self.offset + self.rate * (buffer - buffer_base);
+        Duration::from_secs_f64(master.max(0.0))
+    }
+
+    fn estimate(&mut self) {
+        let n = self.samples.len() as f64;
+        let (sum_x, sum_y) = self
+            .samples
+            .iter()
+            .fold((0.0, 0.0), |(sx, sy), &(x, y)| (sx + x, sy + y));
+        let (mean_x, mean_y) = (sum_x / n, sum_y / n);
+        let (cov, var) = self.samples.iter().fold((0.0, 0.0), |(cov, var), &(x, y)| {
+            (
+                cov + (x - mean_x) * (y - mean_y),
+                var + (x - mean_x) * (x - mean_x),
+            )
+        });
+
+        // a single sample or samples within a millisecond do not tell anything about the rate
+        if var > 1e-6 {
+            self.rate = (cov / var).clamp(1.0 - MAX_DRIFT, 1.0 + MAX_DRIFT);
+        }
+        self.offset = self
+            .samples
+            .iter()
+            .map(|&(x, y)| y - self.rate * x)
+            .fold(f64::INFINITY, f64::min);
+    }
+}
+
+fn seconds(ts: Timestamp) -> f64 {
+    ts.sec as f64 + ts.usec as f64 / 1_000_000.0
+}
@@ -0,0 +1,306 @@
+//! H.264 Annex B byte stream helpers
+//!
+//! Encoders (hardware codecs as well as UVC cameras delivering H264) hand out buffers in the
+//! byte stream format defined in Annex B of the H.264 specification: NAL units separated by
+//! start codes (`00 00 01` or `00 00 00 01`). The helpers in here split such buffers into NAL
+//! units and find the keyframes, which is all that is needed to mux or stream the data.
+
+use std::fmt;
+
+/// NAL unit type
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NalType {
+    /// Coded slice of a non-IDR picture
+    Slice,
+    /// Coded slice of an IDR picture
+    Idr,
+    /// Supplemental enhancement information
+    Sei,
+    /// Sequence parameter set
+    Sps,
+    /// Picture parameter set
+    Pps,
+    /// Access unit delimiter
+    Aud,
+    /// Any other type
+    Other(u8),
+}
+
+impl From<u8> for NalType {
+    fn from(typ: u8) -> Self {
+        match typ {
+            1 => NalType::Slice,
+            5 => NalType::Idr,
+            6 => NalType::Sei,
+            7 => NalType::Sps,
+            8 => NalType::Pps,
+            9 => NalType::Aud,
+            typ => NalType::Other(typ),
+        }
+    }
+}
+
+impl fmt::Display for NalType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NalType::Slice => write!(f, "Slice"),
+            NalType::Idr => write!(f, "IDR"),
+            NalType::Sei => write!(f, "SEI"),
+            NalType::Sps => write!(f, "SPS"),
+            NalType::Pps => write!(f, "PPS"),
+            NalType::Aud => write!(f, "AUD"),
+            NalType::Other(typ) => write!(f, "Other({})", typ),
+        }
+    }
+}
+
+/// NAL unit within a byte stream
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Nal<'a> {
+    /// Offset of the start code within the buffer
+    pub offset: usize,
+    /// NAL unit including its header, without start code and trailing zero bytes
+    pub data: &'a [u8],
+}
+
+impl<'a> Nal<'a> {
+    /// Returns the type of the NAL unit
+    pub fn typ(&self) -> NalType {
+        NalType::from(self.header() & 0x1f)
+    }
+
+    /// Returns the nal_ref_idc field, zero means the unit is not used for reference
+    pub fn ref_idc(&self) -> u8 {
+        (self.header() >> 5) & 0x3
+    }
+
+    /// Returns the payload following the NAL unit header
+    pub fn payload(&self) -> &'a [u8] {
+        self.data.get(1..).unwrap_or(&[])
+    }
+
+    fn header(&self) -> u8 {
+        self.data.first().copied().unwrap_or(0)
+    }
+}
+
+/// Iterator over the NAL units of a byte stream
+///
+/// Data in front of the first start code is skipped.
+pub struct NalUnits<'a> {
+    data: &'a [u8],
+    /// Offset of the next start code, if any
+    next: Option<(usize, usize)>,
+}
+
+impl<'a> Iterator for NalUnits<'a> {
+    type Item = Nal<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (offset, start) = self.next?;
+        self.next = find_start_code(self.data, start).map(|(pos, len)| (pos, pos + len));
+
+        let end = self.next.map(|(pos, _)| pos).unwrap_or(self.data.len());
+        let mut data = &self.data[start..end];
+        // trailing_zero_8bits, e.g. the first byte of a four byte start code
+        while let [rest @ .., 0] = data {
+            data = rest;
+        }
+
+        Some(Nal { offset, data })
+    }
+}
+
+/// Returns the position and length of the next start code at or after `from`
+fn find_start_code(data: &[u8], from: usize) -> Option<(usize, usize)> {
+    let pos = data.get(from..)?.windows(3).position(|w| w == [0, 0, 1])? + from;
+
+    if pos > from && data[pos - 1] == 0 {
+        Some((pos - 1, 4))
+    } else {
+        Some((pos, 3))
+    }
+}
+
+/// Splits a byte stream into NAL units
+///
+/// # Arguments
+///
+/// * `data` - Encoded buffer as dequeued from the device
+///
+/// # Example
+///
+/// ```
+/// use v4l::codec::annexb::{self, NalType};
+///
+/// let buf = [0, 0, 0, 1, 0x67, 0x42, 0, 0, 1, 0x68, 0xce, 0, 0, 1, 0x65, 0x88];
+/// let types: Vec<_> = annexb::nal_units(&buf).map(|nal| nal.typ()).collect();
+/// assert_eq!(types, [NalType::Sps, NalType::Pps, NalType::Idr]);
+/// ```
+pub fn nal_units(data: &[u8]) -> NalUnits<'_> {
+    NalUnits {
+        data,
+        next: find_start_code(data, 0).map(|(pos, len)| (pos, pos + len)),
+    }
+}
+
+/// Returns whether the buffer contains an IDR picture, i.e. a keyframe
+///
+/// # Arguments
+///
+/// * `data` - Encoded buffer
+pub fn is_keyframe(data: &[u8]) -> bool {
+    nal_units(data).any(|nal| nal.typ() == NalType::Idr)
+}
+
+/// Returns the offset at which a decoder can start decoding the buffer
+///
+/// This is the start of the parameter sets (or access unit delimiter) preceding the first IDR
+/// picture, or the IDR picture itself if there are none. Returns `None` if the buffer does not
+/// contain a keyframe.
+///
+/// # Arguments
+///
+/// * `data` - Encoded buffer
+///
+/// # Example
+///
+/// ```
+/// use v4l::codec::annexb;
+///
+/// let buf = [0, 0, 1, 0x41, 0x9a, 0, 0, 1, 0x67, 0x42, 0, 0, 1, 0x65, 0x88];
+/// assert_eq!(annexb::keyframe_offset(&buf), Some(5));
+/// ```
+pub fn keyframe_offset(data: &[u8]) -> Option<usize> {
+    let mut start = None;
+    for nal in nal_units(data) {
+        match nal.typ() {
+            NalType::Idr => return Some(start.unwrap_or(nal.offset)),
+            NalType::Aud | NalType::Sps | NalType::Pps | NalType::Sei => {
+                start.get_or_insert(nal.offset);
+            }
+            _ => start = None,
+        }
+    }
+
+    None
+}
+
+/// Returns the first sequence and picture parameter sets of the buffer
+///
+/// Muxers need them to build the decoder configuration (e.g. the `avcC` box of MP4 files).
+///
+/// # Arguments
+///
+/// * `data` - Encoded buffer
+pub fn parameter_sets(data: &[u8]) -> (Option<&[u8]>, Option<&[u8]>) {
+    let mut sps = None;
+    let mut pps = None;
+    for nal in nal_units(data) {
+        match nal.typ() {
+            NalType::Sps if sps.is_none() => sps = Some(nal.data),
+            NalType::Pps if pps.is_none() => pps = Some(nal.data),
+            _ => {}
+        }
+        if sps.is_some() && pps.is_some() {
+            break;
+        }
+    }
+
+    (sps, pps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// SPS and PPS behind four byte start codes, an IDR slice behind a three byte one
+    const STREAM: [u8; 20] = [
+        0, 0, 0, 1, 0x67, 0x42, 0x00, 0x1f, 0, 0, 0, 1, 0x68, 0xce, 0, 0, 1, 0x65, 0x88, 0x84,
+    ];
+
+    fn units(data: &[u8]) -> Vec<(usize, NalType, &[u8])> {
+        nal_units(data)
+            .map(|nal| (nal.offset, nal.typ(), nal.data))
+            .collect()
+    }
+
+    #[test]
+    fn splits_at_start_codes() {
+        assert_eq!(
+            units(&STREAM),
+            [
+                (0, NalType::Sps, &[0x67, 0x42, 0x00, 0x1f][..]),
+                (8, NalType::Pps, &[0x68, 0xce][..]),
+                (14, NalType::Idr, &[0x65, 0x88, 0x84][..]),
+            ]
+        );
+    }
+
+    #[test]
+    fn strips_leading_data_and_trailing_zeros() {
+        let buf = [0xff, 0, 0, 1, 0x09, 0xf0, 0, 0, 0, 0, 0, 1, 0x41, 0x9a, 0];
+        assert_eq!(
+            units(&buf),
+            [
+                (1, NalType::Aud, &[0x09, 0xf0][..]),
+                (8, NalType::Slice, &[0x41, 0x9a][..]),
+            ]
+        );
+        assert!(units(&[0x41, 0x9a, 0, 0]).is_empty());
+        assert_eq!(units(&[0, 0, 1]), [(0, NalType::Other(0), &[][..])]);
+    }
+
+    #[test]
+    fn splits_chunks_at_any_position() {
+        let complete = units(&STREAM);
+        for split in 0..=STREAM.len() {
+            let (head, tail) = STREAM.split_at(split);
+
+            // units cut off by the end of the chunk are truncated, zeros of a start code which
+            // continues in the next chunk are trimmed like trailing zeros
+            for (unit, (offset, _, data)) in units(head).into_iter().zip(&complete) {
+                assert_eq!(unit.0, *offset, "split at {}", split);
+                assert!(data.starts_with(unit.2), "split at {}", split);
+            }
+
+            // the start code of a unit has to be complete for the unit to be found
+            let found: Vec<_> = units(tail).into_iter().map(|unit| unit.2).collect();
+            let expected: Vec<_> = complete
+                .iter()
+                .filter(|(offset, _, _)| {
+                    let start = offset + STREAM[*offset..].iter().position(|&b| b == 1).unwrap();
+                    start >= split + 2
+                })
+                .map(|unit| unit.2)
+                .collect();
+            assert_eq!(found, expected, "split at {}", split);
+        }
+
+        // the chunks meet within the `00 00 | 01` of the start code in front of the IDR slice
+        let (head, tail) = STREAM.split_at(16);
+        assert_eq!(units(head).last().unwrap().2, [0x68, 0xce]);
+        assert!(units(tail).is_empty());
+    }
+
+    #[test]
+    fn finds_keyframes() {
+        assert!(is_keyframe(&STREAM));
+        assert_eq!(keyframe_offset(&STREAM), Some(0));
+        assert_eq!(
+            parameter_sets(&STREAM),
+            (Some(&STREAM[4..8]), Some(&STREAM[12..14]))
+        );
+
+        let inter = [0, 0, 1, 0x41, 0x9a, 0, 0, 1, 0x41, 0x9b];
+        assert!(!is_keyframe(&inter));
+        assert_eq!(keyframe_offset(&inter), None);
+        assert_eq!(parameter_sets(&inter), (None, None));
+
+        // slices in between detach the parameter sets from the keyframe
+        let buf = [
+            0, 0, 1, 0x67, 0x42, 0, 0, 1, 0x41, 0x9a, 0, 0, 1, 0x65, 0x88,
+        ];
+        assert_eq!(keyframe_offset(&buf), Some(10));
+    }
+}
@@ -0,0 +1,805 @@
+//! H.264 parameter set and slice header parsing
+//!
+//! Stateless decoders (see [`stateless`](super::stateless)) leave the bitstream parsing to the
+//! application: the parameter sets and slice headers have to be handed to the driver as
+//! controls. The parsers in here cover the syntax elements those decoders need, starting with
+//! the payload of a NAL unit as returned by [`Nal::payload`](super::annexb::Nal::payload).
+
+use std::collections::HashMap;
+use std::io;
+
+use crate::codec::annexb::{Nal, NalType};
+
+/// Flat scaling list, used if the stream does not carry any
+pub const FLAT_4X4: [u8; 16] = [16; 16];
+/// Flat 8x8 scaling list
+pub const FLAT_8X8: [u8; 64] = [16; 64];
+
+/// Default intra 4x4 scaling list (Table 7-3), in zigzag order
+const DEFAULT_4X4_INTRA: [u8; 16] = [
+    6, 13, 13, 20, 20, 20, 28, 28, 28, 28, 32, 32, 32, 37, 37, 42,
+];
+/// Default inter 4x4 scaling list, in zigzag order
+const DEFAULT_4X4_INTER: [u8; 16] = [
+    10, 14, 14, 20, 20, 20, 24, 24, 24, 24, 27, 27, 27, 30, 30, 34,
+];
+/// Default intra 8x8 scaling list (Table 7-4), in zigzag order
+const DEFAULT_8X8_INTRA: [u8; 64] = [
+    6, 10, 10, 13, 11, 13, 16, 16, 16, 16, 18, 18, 18, 18, 18, 23, 23, 23, 23, 23, 23, 25, 25, 25,
+    25, 25, 25, 25, 27, 27, 27, 27, 27, 27, 27, 27, 29, 29, 29, 29, 29, 29, 29, 31, 31, 31, 31, 31,
+    31, 33, 33, 33, 33, 33, 36, 36, 36, 36, 38, 38, 38, 40, 40, 42,
+];
+/// Default inter 8x8 scaling list, in zigzag order
+const DEFAULT_8X8_INTER: [u8; 64] = [
+    9, 13, 13, 15, 13, 15, 17, 17, 17, 17, 19, 19, 19, 19, 19, 21, 21, 21, 21, 21, 21, 22, 22, 22,
+    22, 22, 22, 22, 24, 24, 24, 24, 24, 24, 24, 24, 25, 25, 25, 25, 25, 25, 25, 27, 27, 27, 27, 27,
+    27, 28, 28, 28, 28, 28, 30, 30, 30, 30, 32, 32, 32, 33, 33, 35,
+];
+
+/// Raster position of the coefficients of a 4x4 block in zigzag order
+pub const ZIGZAG_4X4: [usize; 16] = [0, 1, 4, 8, 5, 2, 3, 6, 9, 12, 13, 10, 7, 11, 14, 15];
+/// Raster position of the coefficients of an 8x8 block in zigzag order
+pub const ZIGZAG_8X8: [usize; 64] = [
+    0, 1, 8, 16, 9, 2, 3, 10, 17, 24, 32, 25, 18, 11, 4, 5, 12, 19, 26, 33, 40, 48, 41, 34, 27, 20,
+    13, 6, 7, 14, 21, 28, 35, 42, 49, 56, 57, 50, 43, 36, 29, 22, 15, 23, 30, 37, 44, 51, 58, 59,
+    52, 45, 38, 31, 39, 46, 53, 60, 61, 54, 47, 55, 62, 63,
+];
+
+/// Removes the emulation prevention bytes (`00 00 03`) from a NAL unit payload
+///
+/// # Arguments
+///
+/// * `data` - NAL unit payload
+pub fn unescape(data: &[u8]) -> Vec<u8> {
+    let mut rbsp = Vec::with_capacity(data.len());
+    let mut zeros = 0;
+    for &byte in data {
+        if zeros >= 2 && byte == 3 {
+            zeros = 0;
+            continue;
+        }
+
+        zeros = if byte == 0 { zeros + 1 } else { 0 };
+        rbsp.push(byte);
+    }
+    rbsp
+}
+
+fn truncated() -> io::Error {
+    io::Error::new(io::ErrorKind::UnexpectedEof, "truncated bitstream")
+}
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Reads the syntax elements of a raw byte sequence payload (RBSP)
+pub struct BitReader<'a> {
+    data: &'a [u8],
+    /// Position in bits
+    pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    /// Returns a reader starting at the first bit
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Payload without emulation prevention bytes, see [`unescape`]
+    pub fn new(data: &'a [u8]) -> Self {
+        BitReader { data, pos: 0 }
+    }
+
+    /// Returns the number of bits read so far
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Reads a single bit, u(1)
+    pub fn flag(&mut self) -> io::Result<bool> {
+        let byte = self.data.get(self.pos / 8).ok_or_else(truncated)?;
+        let bit = (byte >> (7 - self.pos % 8)) & 1;
+        self.pos += 1;
+        Ok(bit == 1)
+    }
+
+    /// Reads an unsigned integer of up to 32 bits, u(n)
+    pub fn bits(&mut self, n: u32) -> io::Result<u32> {
+        let mut val = 0u32;
+        for _ in 0..n {
+            val = (val << 1) | self.flag()? as u32;
+        }
+        Ok(val)
+    }
+
+    /// Reads an unsigned Exp-Golomb code, ue(v)
+    pub fn ue(&mut self) -> io::Result<u32> {
+        let mut zeros = 0;
+        while !self.flag()? {
+            zeros += 1;
+            if zeros > 31 {
+                return Err(invalid("Exp-Golomb code exceeds 32 bits"));
+            }
+        }
+
+        Ok(((1u64 << zeros) - 1 + u64::from(self.bits(zeros)?)) as u32)
+    }
+
+    /// Reads a signed Exp-Golomb code, se(v)
+    pub fn se(&mut self) -> io::Result<i32> {
+        let val = self.ue()?;
+        if val % 2 == 1 {
+            Ok(((val / 2) + 1) as i32)
+        } else {
+            Ok(-((val / 2) as i32))
+        }
+    }
+
+    /// Returns whether there is more data in front of the RBSP trailing bits
+    pub fn more_rbsp_data(&self) -> bool {
+        // the last bit set in the payload is the rbsp_stop_one_bit
+        let last = self.data.iter().rposition(|&byte| byte != 0);
+        match last {
+            Some(i) => {
+                let stop = i * 8 + 7 - self.data[i].trailing_zeros() as usize;
+                self.pos < stop
+            }
+            None => false,
+        }
+    }
+}
+
+/// Reads a scaling list into `list`
+///
+/// Returns `false` if the default scaling list is to be used instead.
+fn scaling_list(r: &mut BitReader, list: &mut [u8]) -> io::Result<bool> {
+    let mut last = 8i32;
+    let mut next = 8i32;
+    for (j, val) in list.iter_mut().enumerate() {
+        if next != 0 {
+            let delta = r.se()?;
+            next = (last + delta + 256) % 256;
+            if j == 0 && next == 0 {
+                return Ok(false);
+            }
+        }
+        *val = if next == 0 { last } else { next } as u8;
+        last = i32::from(*val);
+    }
+    Ok(true)
+}
+
+/// Reads the scaling lists of a parameter set
+///
+/// Lists which are not present are inferred from the default lists if `fallback` is `None`
+/// (fall-back rule A), from the lists of the sequence parameter set otherwise (fall-back
+/// rule B).
+fn scaling_lists(
+    r: &mut BitReader,
+    count: usize,
+    lists_4x4: &mut [[u8; 16]; 6],
+    lists_8x8: &mut [[u8; 64]; 6],
+    fallback: Option<&Sps>,
+) -> io::Result<()> {
+    for i in 0..count {
+        let present = r.flag()?;
+        if i < 6 {
+            let default = if i < 3 {
+                DEFAULT_4X4_INTRA
+            } else {
+                DEFAULT_4X4_INTER
+            };
+            lists_4x4[i] = if present {
+                let mut list = [0; 16];
+                if scaling_list(r, &mut list)? {
+                    list
+                } else {
+                    default
+                }
+            } else if i == 0 || i == 3 {
+                fallback.map_or(default, |sps| sps.scaling_lists_4x4[i])
+            } else {
+                lists_4x4[i - 1]
+            };
+        } else {
+            let i = i - 6;
+            let default = if i % 2 == 0 {
+                DEFAULT_8X8_INTRA
+            } else {
+                DEFAULT_8X8_INTER
+            };
+            lists_8x8[i] = if present {
+                let mut list = [0; 64];
+                if scaling_list(r, &mut list)? {
+                    list
+                } else {
+                    default
+                }
+            } else if i < 2 {
+                fallback.map_or(default, |sps| sps.scaling_lists_8x8[i])
+            } else {
+                lists_8x8[i - 2]
+            };
+        }
+    }
+    Ok(())
+}
+
+/// Sequence parameter set
+#[derive(Debug, Clone)]
+pub struct Sps {
+    pub profile_idc: u8,
+    /// constraint_set0_flag .. constraint_set5_flag, in bitstream order (MSB first)
+    pub constraint_flags: u8,
+    pub level_idc: u8,
+    pub id: u8,
+    pub chroma_format_idc: u8,
+    pub separate_colour_plane: bool,
+    pub bit_depth_luma_minus8: u8,
+    pub bit_depth_chroma_minus8: u8,
+    pub qpprime_y_zero_transform_bypass: bool,
+    pub scaling_matrix_present: bool,
+    /// 4x4 scaling lists in zigzag order
+    pub scaling_lists_4x4: [[u8; 16]; 6],
+    /// 8x8 scaling lists in zigzag order
+    pub scaling_lists_8x8: [[u8; 64]; 6],
+    pub log2_max_frame_num_minus4: u8,
+    pub pic_order_cnt_type: u8,
+    pub log2_max_pic_order_cnt_lsb_minus4: u8,
+    pub delta_pic_order_always_zero: bool,
+    pub offset_for_non_ref_pic: i32,
+    pub offset_for_top_to_bottom_field: i32,
+    pub offset_for_ref_frame: Vec<i32>,
+    pub max_num_ref_frames: u8,
+    pub gaps_in_frame_num_allowed: bool,
+    pub pic_width_in_mbs_minus1: u16,
+    pub pic_height_in_map_units_minus1: u16,
+    pub frame_mbs_only: bool,
+    pub mb_adaptive_frame_field: bool,
+    pub direct_8x8_inference: bool,
+    /// Frame cropping offsets (left, right, top, bottom) in crop units
+    pub frame_crop: Option<(u32, u32, u32, u32)>,
+}
+
+impl Sps {
+    /// Parses a sequence parameter set
+    ///
+    /// The VUI parameters are not parsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - NAL unit payload, emulation prevention bytes are removed internally
+    pub fn parse(payload: &[u8]) -> io::Result<Self> {
+        let rbsp = unescape(payload);
+        let mut r = BitReader::new(&rbsp);
+
+        let profile_idc = r.bits(8)? as u8;
+        let constraint_flags = r.bits(8)? as u8;
+        let level_idc = r.bits(8)? as u8;
+        let id = r.ue()?;
+        if id > 31 {
+            return Err(invalid("invalid seq_parameter_set_id"));
+        }
+
+        let mut sps = Sps {
+            profile_idc,
+            constraint_flags,
+            level_idc,
+            id: id as u8,
+            chroma_format_idc: 1,
+            separate_colour_plane: false,
+            bit_depth_luma_minus8: 0,
+            bit_depth_chroma_minus8: 0,
+            qpprime_y_zero_transform_bypass: false,
+            scaling_matrix_present: false,
+            scaling_lists_4x4: [FLAT_4X4; 6],
+            scaling_lists_8x8: [FLAT_8X8; 6],
+            log2_max_frame_num_minus4: 0,
+            pic_order_cnt_type: 0,
+            log2_max_pic_order_cnt_lsb_minus4: 0,
+            delta_pic_order_always_zero: false,
+            offset_for_non_ref_pic: 0,
+            offset_for_top_to_bottom_field: 0,
+            offset_for_ref_frame: Vec::new(),
+            max_num_ref_frames: 0,
+            gaps_in_frame_num_allowed: false,
+            pic_width_in_mbs_minus1: 0,
+            pic_height_in_map_units_minus1: 0,
+            frame_mbs_only: true,
+            mb_adaptive_frame_field: false,
+            direct_8x8_inference: false,
+            frame_crop: None,
+        };
+
+        if matches!(
+            profile_idc,
+            100 | 110 | 122 | 244 | 44 | 83 | 86 | 118 | 128 | 138 | 139 | 134 | 135
+        ) {
+            sps.chroma_format_idc = r.ue()? as u8;
+            if sps.chroma_format_idc > 3 {
+                return Err(invalid("invalid chroma_format_idc"));
+            }
+            if sps.chroma_format_idc == 3 {
+                sps.separate_colour_plane = r.flag()?;
+            }
+            sps.bit_depth_luma_minus8 = r.ue()? as u8;
+            sps.bit_depth_chroma_minus8 = r.ue()? as u8;
+            sps.qpprime_y_zero_transform_bypass = r.flag()?;
+            sps.scaling_matrix_present = r.flag()?;
+            if sps.scaling_matrix_present {
+                let count = if sps.chroma_format_idc != 3 { 8 } else { 12 };
+                scaling_lists(
+                    &mut r,
+                    count,
+                    &mut sps.scaling_lists_4x4,
+                    &mut sps.scaling_lists_8x8,
+                    None,
+                )?;
+            }
+        }
+
+        sps.log2_max_frame_num_minus4 = r.ue()? as u8;
+        if sps.log2_max_frame_num_minus4 > 12 {
+            return Err(invalid("invalid log2_max_frame_num_minus4"));
+        }
+        sps.pic_order_cnt_type = r.ue()? as u8;
+        match sps.pic_order_cnt_type {
+            0 => {
+                sps.log2_max_pic_order_cnt_lsb_minus4 = r.ue()? as u8;
+                if sps.log2_max_pic_order_cnt_lsb_minus4 > 12 {
+                    return Err(invalid("invalid log2_max_pic_order_cnt_lsb_minus4"));
+                }
+            }
+            1 => {
+                sps.delta_pic_order_always_zero = r.flag()?;
+                sps.offset_for_non_ref_pic = r.se()?;
+                sps.offset_for_top_to_bottom_field = r.se()?;
+                let count = r.ue()?;
+                if count > 255 {
+                    return Err(invalid("invalid num_ref_frames_in_pic_order_cnt_cycle"));
+                }
+                for _ in 0..count {
+                    sps.offset_for_ref_frame.push(r.se()?);
+                }
+            }
+            2 => {}
+            _ => return Err(invalid("invalid pic_order_cnt_type")),
+        }
+
+        sps.max_num_ref_frames = r.ue()?.min(16) as u8;
+        sps.gaps_in_frame_num_allowed = r.flag()?;
+        sps.pic_width_in_mbs_minus1 = r.ue()? as u16;
+        sps.pic_height_in_map_units_minus1 = r.ue()? as u16;
+        sps.frame_mbs_only = r.flag()?;
+        if !sps.frame_mbs_only {
+            sps.mb_adaptive_frame_field = r.flag()?;
+        }
+        sps.direct_8x8_inference = r.flag()?;
+        if r.flag()? {
+            sps.frame_crop = Some((r.ue()?, r.ue()?, r.ue()?, r.ue()?));
+        }
+
+        Ok(sps)
+    }
+
+    /// Returns the width of the decoded pictures in pixels
+    pub fn width(&self) -> u32 {
+        (u32::from(self.pic_width_in_mbs_minus1) + 1) * 16
+    }
+
+    /// Returns the height of the decoded pictures in pixels
+    pub fn height(&self) -> u32 {
+        (2 - self.frame_mbs_only as u32) * (u32::from(self.pic_height_in_map_units_minus1) + 1) * 16
+    }
+
+    /// Returns the visible area of the decoded pictures as (x, y, width, height)
+    pub fn crop(&self) -> (u32, u32, u32, u32) {
+        let (left, right, top, bottom) = match self.frame_crop {
+            Some(crop) => crop,
+            None => return (0, 0, self.width(), self.height()),
+        };
+
+        let chroma_array_type = if self.separate_colour_plane {
+            0
+        } else {
+            self.chroma_format_idc
+        };
+        let (unit_x, unit_y) = match chroma_array_type {
+            1 => (2, 2),
+            2 => (2, 1),
+            _ => (1, 1),
+        };
+        let unit_y = unit_y * (2 - self.frame_mbs_only as u32);
+
+        let x = (left * unit_x).min(self.width());
+        let y = (top * unit_y).min(self.height());
+        (
+            x,
+            y,
+            self.width().saturating_sub(x + right * unit_x),
+            self.height().saturating_sub(y + bottom * unit_y),
+        )
+    }
+
+    /// Returns MaxFrameNum, the modulus of the frame_num syntax element
+    pub fn max_frame_num(&self) -> u32 {
+        1 << (u32::from(self.log2_max_frame_num_minus4) + 4)
+    }
+
+    /// Returns the maximum number of frames held in the decoded picture buffer
+    ///
+    /// The limit is derived from the level (Table A-1), streams may use less.
+    pub fn max_dpb_frames(&self) -> usize {
+        let max_dpb_mbs = match self.level_idc {
+            // level 1b is signalled as 11 with constraint_set3_flag in the baseline profiles
+            9 | 10 => 396,
+            11 if self.constraint_flags & 0x10 != 0 => 396,
+            11 => 900,
+            12 | 13 | 20 => 2376,
+            21 => 4752,
+            22 | 30 => 8100,
+            31 => 18000,
+            32 => 20480,
+            40 | 41 => 32768,
+            42 => 34816,
+            50 => 110_400,
+            51 | 52 => 184_320,
+            _ => 696_320,
+        };
+        let frame_mbs = (self.width() / 16) * (self.height() / 16);
+        (max_dpb_mbs / frame_mbs.max(1)).clamp(u32::from(self.max_num_ref_frames).max(1), 16)
+            as usize
+    }
+}
+
+/// Picture parameter set
+#[derive(Debug, Clone)]
+pub struct Pps {
+    pub id: u8,
+    pub sps_id: u8,
+    pub entropy_coding_mode: bool,
+    pub bottom_field_pic_order_in_frame_present: bool,
+    pub num_slice_groups_minus1: u32,
+    pub num_ref_idx_l0_default_active_minus1: u8,
+    pub num_ref_idx_l1_default_active_minus1: u8,
+    pub weighted_pred: bool,
+    pub weighted_bipred_idc: u8,
+    pub pic_init_qp_minus26: i8,
+    pub pic_init_qs_minus26: i8,
+    pub chroma_qp_index_offset: i8,
+    pub deblocking_filter_control_present: bool,
+    pub constrained_intra_pred: bool,
+    pub redundant_pic_cnt_present: bool,
+    pub transform_8x8_mode: bool,
+    pub scaling_matrix_present: bool,
+    /// 4x4 scaling lists in zigzag order, inherited from the SPS if not present
+    pub scaling_lists_4x4: [[u8; 16]; 6],
+    /// 8x8 scaling lists in zigzag order, inherited from the SPS if not present
+    pub scaling_lists_8x8: [[u8; 64]; 6],
+    pub second_chroma_qp_index_offset: i8,
+}
+
+impl Pps {
+    /// Parses a picture parameter set
+    ///
+    /// Slice groups (flexible macroblock ordering) are not supported.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - NAL unit payload, emulation prevention bytes are removed internally
+    /// * `sps` - Known sequence parameter sets by their ID
+    pub fn parse(payload: &[u8], sps: &HashMap<u8, Sps>) -> io::Result<Self> {
+        let rbsp = unescape(payload);
+        let mut r = BitReader::new(&rbsp);
+
+        let id = r.ue()?;
+        if id > 255 {
+            return Err(invalid("invalid pic_parameter_set_id"));
+        }
+        let sps_id = r.ue()?;
+        let sps = sps
+            .get(&(sps_id as u8))
+            .filter(|_| sps_id <= 31)
+            .ok_or_else(|| invalid("PPS refers to an unknown SPS"))?;
+
+        let mut pps = Pps {
+            id: id as u8,
+            sps_id: sps_id as u8,
+            entropy_coding_mode: r.flag()?,
+            bottom_field_pic_order_in_frame_present: r.flag()?,
+            num_slice_groups_minus1: r.ue()?,
+            num_ref_idx_l0_default_active_minus1: 0,
+            num_ref_idx_l1_default_active_minus1: 0,
+            weighted_pred: false,
+            weighted_bipred_idc: 0,
+            pic_init_qp_minus26: 0,
+            pic_init_qs_minus26: 0,
+            chroma_qp_index_offset: 0,
+            deblocking_filter_control_present: false,
+            constrained_intra_pred: false,
+            redundant_pic_cnt_present: false,
+            transform_8x8_mode: false,
+            scaling_matrix_present: false,
+            scaling_lists_4x4: sps.scaling_lists_4x4,
+            scaling_lists_8x8: sps.scaling_lists_8x8,
+            second_chroma_qp_index_offset: 0,
+        };
+        if pps.num_slice_groups_minus1 > 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "slice groups are not supported",
+            ));
+        }
+
+        pps.num_ref_idx_l0_default_active_minus1 = r.ue()?.min(31) as u8;
+        pps.num_ref_idx_l1_default_active_minus1 = r.ue()?.min(31) as u8;
+        pps.weighted_pred = r.flag()?;
+        pps.weighted_bipred_idc = r.bits(2)? as u8;
+        pps.pic_init_qp_minus26 = r.se()? as i8;
+        pps.pic_init_qs_minus26 = r.se()? as i8;
+        pps.chroma_qp_index_offset = r.se()? as i8;
+        pps.deblocking_filter_control_present = r.flag()?;
+        pps.constrained_intra_pred = r.flag()?;
+        pps.redundant_pic_cnt_present = r.flag()?;
+        pps.second_chroma_qp_index_offset = pps.chroma_qp_index_offset;
+
+        if r.more_rbsp_data() {
+            pps.transform_8x8_mode = r.flag()?;
+            pps.scaling_matrix_present = r.flag()?;
+            if pps.scaling_matrix_present {
+                let lists_8x8 = if sps.chroma_format_idc != 3 { 2 } else { 6 };
+                let count = 6 + lists_8x8 * pps.transform_8x8_mode as usize;
+                // without SPS lists, fall-back rule A applies to the PPS as well
+                let fallback = Some(sps).filter(|sps| sps.scaling_matrix_present);
+                scaling_lists(
+                    &mut r,
+                    count,
+                    &mut pps.scaling_lists_4x4,
+                    &mut pps.scaling_lists_8x8,
+                    fallback,
+                )?;
+            }
+            pps.second_chroma_qp_index_offset = r.se()? as i8;
+        }
+
+        Ok(pps)
+    }
+}
+
+/// Slice type
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SliceType {
+    P,
+    B,
+    I,
+    Sp,
+    Si,
+}
+
+/// Memory management control operation of the decoded reference picture marking
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Mmco {
+    /// Marks a short-term picture as unused, given as difference_of_pic_nums_minus1
+    UnmarkShortTerm(u32),
+    /// Marks a long-term picture as unused, given as long_term_pic_num
+    UnmarkLongTerm(u32),
+    /// Converts a short-term picture to a long-term picture, given as
+    /// (difference_of_pic_nums_minus1, long_term_frame_idx)
+    ShortToLongTerm(u32, u32),
+    /// Sets the maximum long-term frame index, given as max_long_term_frame_idx_plus1
+    MaxLongTermFrameIdx(u32),
+    /// Marks all reference pictures as unused
+    UnmarkAll,
+    /// Marks the current picture as long-term, given as long_term_frame_idx
+    CurrentToLongTerm(u32),
+}
+
+/// Slice header, up to and including the decoded reference picture marking
+#[derive(Debug, Clone)]
+pub struct SliceHeader {
+    /// Whether the slice belongs to an IDR picture
+    pub idr: bool,
+    pub nal_ref_idc: u8,
+    pub first_mb_in_slice: u32,
+    pub slice_type: SliceType,
+    pub pps_id: u8,
+    pub colour_plane_id: u8,
+    pub frame_num: u32,
+    pub field_pic: bool,
+    pub bottom_field: bool,
+    pub idr_pic_id: u32,
+    pub pic_order_cnt_lsb: u32,
+    pub delta_pic_order_cnt_bottom: i32,
+    pub delta_pic_order_cnt: [i32; 2],
+    pub redundant_pic_cnt: u32,
+    pub direct_spatial_mv_pred: bool,
+    pub num_ref_idx_l0_active_minus1: u8,
+    pub num_ref_idx_l1_active_minus1: u8,
+    pub no_output_of_prior_pics: bool,
+    pub long_term_reference: bool,
+    /// Memory management control operations, empty for the sliding window mode
+    pub mmco: Vec<Mmco>,
+    /// Size of the picture order count syntax elements in bits
+    pub pic_order_cnt_bit_size: u32,
+    /// Size of the dec_ref_pic_marking() syntax structure in bits
+    pub dec_ref_pic_marking_bit_size: u32,
+}
+
+impl SliceHeader {
+    /// Parses the header of a slice NAL unit
+    ///
+    /// # Arguments
+    ///
+    /// * `nal` - Slice or IDR slice NAL unit
+    /// * `sps` - Known sequence parameter sets by their ID
+    /// * `pps` - Known picture parameter sets by their ID
+    pub fn parse(nal: &Nal, sps: &HashMap<u8, Sps>, pps: &HashMap<u8, Pps>) -> io::Result<Self> {
+        let idr = match nal.typ() {
+            NalType::Slice => false,
+            NalType::Idr => true,
+            _ => return Err(invalid("NAL unit is no slice")),
+        };
+
+        // the header is short, only unescape what is needed
+        let payload = nal.payload();
+        let rbsp = unescape(&payload[..payload.len().min(1024)]);
+        let mut r = BitReader::new(&rbsp);
+
+        let first_mb_in_slice = r.ue()?;
+        let slice_type = match r.ue()? % 5 {
+            0 => SliceType::P,
+            1 => SliceType::B,
+            2 => SliceType::I,
+            3 => SliceType::Sp,
+            _ => SliceType::Si,
+        };
+        let pps_id = r.ue()?;
+        let pps = pps
+            .get(&(pps_id as u8))
+            .filter(|_| pps_id <= 255)
+            .ok_or_else(|| invalid("slice refers to an unknown PPS"))?;
+        let sps = sps
+            .get(&pps.sps_id)
+            .ok_or_else(|| invalid("slice refers to an unknown SPS"))?;
+
+        let mut header = SliceHeader {
+            idr,
+            nal_ref_idc: nal.ref_idc(),
+            first_mb_in_slice,
+            slice_type,
+            pps_id: pps.id,
+            colour_plane_id: 0,
+            frame_num: 0,
+            field_pic: false,
+            bottom_field: false,
+            idr_pic_id: 0,
+            pic_order_cnt_lsb: 0,
+            delta_pic_order_cnt_bottom: 0,
+            delta_pic_order_cnt: [0; 2],
+            redundant_pic_cnt: 0,
+            direct_spatial_mv_pred: false,
+            num_ref_idx_l0_active_minus1: pps.num_ref_idx_l0_default_active_minus1,
+            num_ref_idx_l1_active_minus1: pps.num_ref_idx_l1_default_active_minus1,
+            no_output_of_prior_pics: false,
+            long_term_reference: false,
+            mmco: Vec::new(),
+            pic_order_cnt_bit_size: 0,
+            dec_ref_pic_marking_bit_size: 0,
+        };
+
+        if sps.separate_colour_plane {
+            header.colour_plane_id = r.bits(2)? as u8;
+        }
+        header.frame_num = r.bits(u32::from(sps.log2_max_frame_num_minus4) + 4)?;
+        if !sps.frame_mbs_only {
+            header.field_pic = r.flag()?;
+            if header.field_pic {
+                header.bottom_field = r.flag()?;
+            }
+        }
+        if idr {
+            header.idr_pic_id = r.ue()?;
+        }
+
+        let poc_start = r.position();
+        if sps.pic_order_cnt_type == 0 {
+            header.pic_order_cnt_lsb =
+                r.bits(u32::from(sps.log2_max_pic_order_cnt_lsb_minus4) + 4)?;
+            if pps.bottom_field_pic_order_in_frame_present && !header.field_pic {
+                header.delta_pic_order_cnt_bottom = r.se()?;
+            }
+        }
+        if sps.pic_order_cnt_type == 1 && !sps.delta_pic_order_always_zero {
+            header.delta_pic_order_cnt[0] = r.se()?;
+            if pps.bottom_field_pic_order_in_frame_present && !header.field_pic {
+                header.delta_pic_order_cnt[1] = r.se()?;
+            }
+        }
+        header.pic_order_cnt_bit_size = (r.position() - poc_start) as u32;
+
+        if pps.redundant_pic_cnt_present {
+            header.redundant_pic_cnt = r.ue()?;
+        }
+        if slice_type == SliceType::B {
+            header.direct_spatial_mv_pred = r.flag()?;
+        }
+        if matches!(slice_type, SliceType::P | SliceType::Sp | SliceType::B) && r.flag()? {
+            header.num_ref_idx_l0_active_minus1 = r.ue()?.min(31) as u8;
+            if slice_type == SliceType::B {
+                header.num_ref_idx_l1_active_minus1 = r.ue()?.min(31) as u8;
+            }
+        }
+
+        // ref_pic_list_modification()
+        if !matches!(slice_type, SliceType::I | SliceType::Si) {
+            let lists = if slice_type == SliceType::B { 2 } else { 1 };
+            for _ in 0..lists {
+                if r.flag()? {
+                    loop {
+                        match r.ue()? {
+                            0..=2 => {
+                                r.ue()?;
+                            }
+                            3 => break,
+                            _ => return Err(invalid("invalid modification_of_pic_nums_idc")),
+                        }
+                    }
+                }
+            }
+        }
+
+        // pred_weight_table(), skipped
+        if (pps.weighted_pred && matches!(slice_type, SliceType::P | SliceType::Sp))
+            || (pps.weighted_bipred_idc == 1 && slice_type == SliceType::B)
+        {
+            let chroma = !sps.separate_colour_plane && sps.chroma_format_idc != 0;
+            r.ue()?;
+            if chroma {
+                r.ue()?;
+            }
+            let mut counts = vec![header.num_ref_idx_l0_active_minus1];
+            if slice_type == SliceType::B {
+                counts.push(header.num_ref_idx_l1_active_minus1);
+            }
+            for count in counts {
+                for _ in 0..=count {
+                    if r.flag()? {
+                        r.se()?;
+                        r.se()?;
+                    }
+                    if chroma && r.flag()? {
+                        for _ in 0..4 {
+                            r.se()?;
+                        }
+                    }
+                }
+            }
+        }
+
+        // dec_ref_pic_marking()
+        if header.nal_ref_idc != 0 {
+            let start = r.position();
+            if idr {
+                header.no_output_of_prior_pics = r.flag()?;
+                header.long_term_reference = r.flag()?;
+            } else if r.flag()? {
+                loop {
+                    let mmco = match r.ue()? {
+                        0 => break,
+                        1 => Mmco::UnmarkShortTerm(r.ue()?),
+                        2 => Mmco::UnmarkLongTerm(r.ue()?),
+                        3 => Mmco::ShortToLongTerm(r.ue()?, r.ue()?),
+                        4 => Mmco::MaxLongTermFrameIdx(r.ue()?),
+                        5 => Mmco::UnmarkAll,
+                        6 => Mmco::CurrentToLongTerm(r.ue()?),
+                        _ => return Err(invalid("invalid memory_management_control_operation")),
+                    };
+                    header.mmco.push(mmco);
+                }
+            }
+            header.dec_ref_pic_marking_bit_size = (r.position() - start) as u32;
+        }
+
+        Ok(header)
+    }
+}
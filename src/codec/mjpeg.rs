@@ -0,0 +1,329 @@
+//! Motion JPEG (MJPG) frame helpers
+//!
+//! Every MJPEG frame is a baseline JPEG image. Devices tend to hand out buffers which are larger
+//! than the actual image, padded with zeros or garbage after the end of image marker. Frames may
+//! also be truncated if the USB transfer was interrupted. Finally, the AVI1 MJPEG flavor used by
+//! many UVC cameras omits the Huffman tables and relies on the standard ones defined in the JPEG
+//! specification, which not every decoder knows how to deal with.
+
+use std::{borrow::Cow, io};
+
+/// Start of image marker
+pub const SOI: [u8; 2] = [0xff, 0xd8];
+/// End of image marker
+pub const EOI: [u8; 2] = [0xff, 0xd9];
+
+/// Define Huffman table(s) marker
+const DHT: u8 = 0xc4;
+/// Start of scan marker
+pub(crate) const SOS: u8 = 0xda;
+
+/// Validates a frame and trims any trailing padding
+///
+/// Returns the JPEG image, i.e. everything from the start of image to the (last) end of image
+/// marker.
+///
+/// # Arguments
+///
+/// * `data` - Frame data as dequeued from the device
+///
+/// # Example
+///
+/// ```
+/// use v4l::codec::mjpeg;
+///
+/// let buf = [0xff, 0xd8, 0x01, 0x02, 0xff, 0xd9, 0x00, 0x00];
+/// assert_eq!(mjpeg::validate(&buf).unwrap(), &buf[..6]);
+/// assert!(mjpeg::validate(&buf[..4]).is_err());
+/// ```
+pub fn validate(data: &[u8]) -> io::Result<&[u8]> {
+    if !data.starts_with(&SOI) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "missing JPEG start of image marker",
+        ));
+    }
+
+    // embedded thumbnails have markers of their own, so the image ends at the last marker
+    match data[SOI.len()..].windows(2).rposition(|w| w == EOI) {
+        Some(pos) => Ok(&data[..SOI.len() + pos + EOI.len()]),
+        None => Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "missing JPEG end of image marker (truncated frame?)",
+        )),
+    }
+}
+
+/// Marker segment in front of the scan data
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct Segment<'a> {
+    /// Offset of the marker within the image
+    pub offset: usize,
+    /// Marker type, i.e. the byte following `0xff`
+    pub marker: u8,
+    /// Segment data without marker and length field
+    pub data: &'a [u8],
+}
+
+/// Returns the marker segments of an image up to and including the start of scan
+pub(crate) fn segments(data: &[u8]) -> io::Result<Vec<Segment<'_>>> {
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, "malformed JPEG marker segment");
+
+    if !data.starts_with(&SOI) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "missing JPEG start of image marker",
+        ));
+    }
+
+    let mut segments = Vec::new();
+    let mut pos = SOI.len();
+    loop {
+        if pos + 1 >= data.len() || data[pos] != 0xff {
+            return Err(invalid());
+        }
+
+        let marker = data[pos + 1];
+        match marker {
+            // fill bytes
+            0xff => pos += 1,
+            // standalone markers without a length field (TEM, RSTn)
+            0x01 | 0xd0..=0xd7 => {
+                segments.push(Segment {
+                    offset: pos,
+                    marker,
+                    data: &[],
+                });
+                pos += 2;
+            }
+            _ => {
+                if pos + 3 >= data.len() {
+                    return Err(invalid());
+                }
+                let len = u16::from_be_bytes([data[pos + 2], data[pos + 3]]) as usize;
+                if len < 2 || pos + 2 + len > data.len() {
+                    return Err(invalid());
+                }
+
+                segments.push(Segment {
+                    offset: pos,
+                    marker,
+                    data: &data[pos + 4..pos + 2 + len],
+                });
+                if marker == SOS {
+                    return Ok(segments);
+                }
+                pos += 2 + len;
+            }
+        }
+    }
+}
+
+/// Returns the position of the first marker segment of the given type in front of the scan data
+fn find_segment(data: &[u8], marker: u8) -> io::Result<Option<usize>> {
+    Ok(segments(data)?
+        .iter()
+        .find(|segment| segment.marker == marker)
+        .map(|segment| segment.offset))
+}
+
+/// Returns whether the frame carries its own Huffman tables
+///
+/// # Arguments
+///
+/// * `data` - JPEG image
+pub fn has_huffman_tables(data: &[u8]) -> io::Result<bool> {
+    Ok(find_segment(data, DHT)?.is_some())
+}
+
+/// Returns the frame with the standard Huffman tables inserted if it lacks tables of its own
+///
+/// The tables are the ones from section K.3 of the JPEG specification (ITU T.81), which the
+/// AVI1 MJPEG format mandates when a frame does not define any. Frames which already contain
+/// Huffman tables are returned as is.
+///
+/// # Arguments
+///
+/// * `data` - JPEG image
+///
+/// # Example
+///
+/// ```
+/// use v4l::codec::mjpeg;
+///
+/// // SOI, SOS (empty scan header) and EOI
+/// let frame = [0xff, 0xd8, 0xff, 0xda, 0x00, 0x02, 0xff, 0xd9];
+/// assert!(!mjpeg::has_huffman_tables(&frame).unwrap());
+///
+/// let fixed = mjpeg::with_huffman_tables(&frame).unwrap();
+/// assert!(mjpeg::has_huffman_tables(&fixed).unwrap());
+/// ```
+pub fn with_huffman_tables(data: &[u8]) -> io::Result<Cow<'_, [u8]>> {
+    if has_huffman_tables(data)? {
+        return Ok(Cow::Borrowed(data));
+    }
+
+    // the tables must be defined before the scan which uses them
+    let sos = find_segment(data, SOS)?.ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "missing JPEG start of scan marker",
+        )
+    })?;
+
+    let tables = standard_huffman_tables();
+    let mut fixed = Vec::with_capacity(data.len() + tables.len());
+    fixed.extend_from_slice(&data[..sos]);
+    fixed.extend_from_slice(&tables);
+    fixed.extend_from_slice(&data[sos..]);
+    Ok(Cow::Owned(fixed))
+}
+
+/// Returns a DHT marker segment defining the standard Huffman tables (ITU T.81, K.3)
+fn standard_huffman_tables() -> Vec<u8> {
+    #[rustfmt::skip]
+    const TABLES: [(u8, [u8; 16], &[u8]); 4] = [
+        // luminance DC
+        (0x00, [0, 1, 5, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b]),
+        // chrominance DC
+        (0x01, [0, 3, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0],
+            &[0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b]),
+        // luminance AC
+        (0x10, [0, 2, 1, 3, 3, 2, 4, 3, 5, 5, 4, 4, 0, 0, 1, 0x7d],
+            &[
+                0x01, 0x02, 0x03, 0x00, 0x04, 0x11, 0x05, 0x12, 0x21, 0x31, 0x41, 0x06, 0x13, 0x51, 0x61, 0x07,
+                0x22, 0x71, 0x14, 0x32, 0x81, 0x91, 0xa1, 0x08, 0x23, 0x42, 0xb1, 0xc1, 0x15, 0x52, 0xd1, 0xf0,
+                0x24, 0x33, 0x62, 0x72, 0x82, 0x09, 0x0a, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x25, 0x26, 0x27, 0x28,
+                0x29, 0x2a, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x3a, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49,
+                0x4a, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5a, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69,
+                0x6a, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7a, 0x83, 0x84, 0x85, 0x86, 0x87, 0x88, 0x89,
+                0x8a, 0x92, 0x93, 0x94, 0x95, 0x96, 0x97, 0x98, 0x99, 0x9a, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7,
+                0xa8, 0xa9, 0xaa, 0xb2, 0xb3, 0xb4, 0xb5, 0xb6, 0xb7, 0xb8, 0xb9, 0xba, 0xc2, 0xc3, 0xc4, 0xc5,
+                0xc6, 0xc7, 0xc8, 0xc9, 0xca, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8, 0xd9, 0xda, 0xe1, 0xe2,
+                0xe3, 0xe4, 0xe5, 0xe6, 0xe7, 0xe8, 0xe9, 0xea, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8,
+                0xf9, 0xfa,
+            ]),
+        // chrominance AC
+        (0x11, [0, 2, 1, 2, 4, 4, 3, 4, 7, 5, 4, 4, 0, 1, 2, 0x77],
+            &[
+                0x00, 0x01, 0x02, 0x03, 0x11, 0x04, 0x05, 0x21, 0x31, 0x06, 0x12, 0x41, 0x51, 0x07, 0x61, 0x71,
+                0x13, 0x22, 0x32, 0x81, 0x08, 0x14, 0x42, 0x91, 0xa1, 0xb1, 0xc1, 0x09, 0x23, 0x33, 0x52, 0xf0,
+                0x15, 0x62, 0x72, 0xd1, 0x0a, 0x16, 0x24, 0x34, 0xe1, 0x25, 0xf1, 0x17, 0x18, 0x19, 0x1a, 0x26,
+                0x27, 0x28, 0x29, 0x2a, 0x35, 0x36, 0x37, 0x38, 0x39, 0x3a, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48,
+                0x49, 0x4a, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5a, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68,
+                0x69, 0x6a, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7a, 0x82, 0x83, 0x84, 0x85, 0x86, 0x87,
+                0x88, 0x89, 0x8a, 0x92, 0x93, 0x94, 0x95, 0x96, 0x97, 0x98, 0x99, 0x9a, 0xa2, 0xa3, 0xa4, 0xa5,
+                0xa6, 0xa7, 0xa8, 0xa9, 0xaa, 0xb2, 0xb3, 0xb4, 0xb5, 0xb6, 0xb7, 0xb8, 0xb9, 0xba, 0xc2, 0xc3,
+                0xc4, 0xc5, 0xc6, 0xc7, 0xc8, 0xc9, 0xca, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8, 0xd9, 0xda,
+                0xe2, 0xe3, 0xe4, 0xe5, 0xe6, 0xe7, 0xe8, 0xe9, 0xea, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8,
+                0xf9, 0xfa,
+            ]),
+    ];
+
+    let mut segment = vec![0xff, DHT, 0, 0];
+    for (class, counts, values) in TABLES.iter() {
+        segment.push(*class);
+        segment.extend_from_slice(counts);
+        segment.extend_from_slice(values);
+    }
+
+    // the length field covers everything but the marker itself
+    let len = (segment.len() - 2) as u16;
+    segment[2..4].copy_from_slice(&len.to_be_bytes());
+    segment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns an 8x8 grayscale AVI1 frame (no Huffman tables) of a flat mid-gray block
+    fn frame() -> Vec<u8> {
+        let mut frame = SOI.to_vec();
+        // DQT: table 0, all coefficients quantized by 1
+        frame.extend_from_slice(&[0xff, 0xdb, 0x00, 0x43, 0x00]);
+        frame.extend_from_slice(&[1; 64]);
+        // SOF0: 8 bit, 8x8, one component with table 0 and no subsampling
+        frame.extend_from_slice(&[
+            0xff, 0xc0, 0x00, 0x0b, 0x08, 0x00, 0x08, 0x00, 0x08, 0x01, 0x01, 0x11, 0x00,
+        ]);
+        // SOS: one component with DC and AC table 0
+        frame.extend_from_slice(&[0xff, SOS, 0x00, 0x08, 0x01, 0x01, 0x00, 0x00, 0x3f, 0x00]);
+        // DC difference 0 (code 00), end of block (code 1010), padded with ones
+        frame.push(0b0010_1011);
+        frame.extend_from_slice(&EOI);
+        frame
+    }
+
+    #[test]
+    fn trims_padding_after_the_last_end_of_image() {
+        let mut padded = frame();
+        let len = padded.len();
+        padded.extend_from_slice(&[0x00; 16]);
+        assert_eq!(validate(&padded).unwrap(), &frame()[..]);
+        assert_eq!(validate(&frame()[..len]).unwrap().len(), len);
+    }
+
+    #[test]
+    fn rejects_frames_without_markers() {
+        let frame = frame();
+        let err = validate(&frame[2..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let err = validate(&frame[..frame.len() - 1]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn parses_segments_up_to_the_scan() {
+        let frame = frame();
+        let parsed = segments(&frame).unwrap();
+        let markers: Vec<_> = parsed.iter().map(|s| (s.offset, s.marker)).collect();
+        assert_eq!(markers, [(2, 0xdb), (71, 0xc0), (84, SOS)]);
+        assert_eq!(parsed[2].data, &[0x01, 0x01, 0x00, 0x00, 0x3f, 0x00]);
+
+        // a segment length running past the end of the frame
+        assert!(segments(&frame[..75]).is_err());
+    }
+
+    #[test]
+    fn inserts_standard_tables_before_the_scan() {
+        let frame = frame();
+        assert!(!has_huffman_tables(&frame).unwrap());
+
+        let fixed = with_huffman_tables(&frame).unwrap();
+        assert!(matches!(fixed, Cow::Owned(_)));
+        // 4 tables with class, 16 counts and 12 + 12 + 162 + 162 values, plus the length field
+        assert_eq!(fixed.len(), frame.len() + 2 + 418);
+        assert_eq!(&fixed[..84], &frame[..84]);
+        assert_eq!(&fixed[84..88], &[0xff, DHT, 0x01, 0xa2]);
+        assert_eq!(&fixed[84 + 420..], &frame[84..]);
+
+        let markers: Vec<_> = segments(&fixed).unwrap().iter().map(|s| s.marker).collect();
+        assert_eq!(markers, [0xdb, 0xc0, DHT, SOS]);
+
+        // frames with tables are left alone
+        assert!(has_huffman_tables(&fixed).unwrap());
+        assert!(matches!(
+            with_huffman_tables(&fixed).unwrap(),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn inserted_tables_decode() {
+        let frame = frame();
+        let fixed = with_huffman_tables(&frame).unwrap();
+        let mut decoder = jpeg_decoder::Decoder::new(&fixed[..]);
+        let pixels = decoder.decode().unwrap();
+        assert_eq!(pixels, [128; 64]);
+    }
+
+    #[test]
+    fn requires_a_scan_to_insert_tables() {
+        let frame = frame();
+        let mut truncated = frame[..84].to_vec();
+        truncated.extend_from_slice(&EOI);
+        assert!(with_huffman_tables(&truncated).is_err());
+    }
+}
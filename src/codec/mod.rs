@@ -0,0 +1,13 @@
+//! Helpers for compressed pixel formats
+//!
+//! Many devices (most notably UVC webcams and hardware encoders) deliver compressed frames.
+//! The modules in here deal with the container level of those formats, e.g. splitting or
+//! validating the dequeued buffers. Decoding is only supported through stateless hardware
+//! decoders, see [`stateless`]. Raw frames are scaled and converted by memory-to-memory
+//! converters, see [`transform`].
+
+pub mod annexb;
+pub mod h264;
+pub mod mjpeg;
+pub mod stateless;
+pub mod transform;
@@ -0,0 +1,814 @@
+use std::collections::{HashMap, VecDeque};
+use std::{io, mem, slice};
+
+use crate::buffer::{Flags, Type};
+use crate::codec::annexb::{self, NalType};
+use crate::codec::h264::{Mmco, Pps, SliceHeader, SliceType, Sps, ZIGZAG_4X4, ZIGZAG_8X8};
+use crate::control::{Control, Value};
+use crate::device::MultiPlaneDevice;
+use crate::format::{FourCC, MultiPlaneFormat};
+use crate::io::mmap;
+use crate::io::traits::{CaptureStream, OutputStream, Stream as StreamTrait};
+use crate::io::WouldBlock;
+use crate::media::{MediaDevice, Request};
+use crate::timestamp::Timestamp;
+use crate::v4l_sys::*;
+use crate::video::{Capture, Output};
+
+/// Decoded picture
+pub struct Picture<'a> {
+    /// Memory planes of the capture buffer holding the picture
+    pub planes: Vec<&'a [u8]>,
+    /// Picture order count
+    pub poc: i32,
+    /// Timestamp passed to [`H264Decoder::decode`] along with the access unit
+    pub timestamp: Timestamp,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Marking {
+    ShortTerm,
+    LongTerm(u32),
+}
+
+/// Reference picture in the decoded picture buffer
+struct Reference {
+    buffer: usize,
+    reference_ts: u64,
+    frame_num: u32,
+    top_field_order_cnt: i32,
+    bottom_field_order_cnt: i32,
+    marking: Marking,
+}
+
+/// Picture waiting to be handed out
+struct Pending {
+    buffer: usize,
+    poc: i32,
+    timestamp: Timestamp,
+}
+
+/// State carried from one picture to the next to derive the picture order count
+#[derive(Default)]
+struct PocState {
+    prev_msb: i32,
+    prev_lsb: i32,
+    prev_frame_num_offset: i32,
+    prev_frame_num: u32,
+}
+
+struct Session {
+    width: u32,
+    height: u32,
+    output: mmap::Stream<'static>,
+    capture: mmap::Stream<'static>,
+    format: MultiPlaneFormat,
+    /// Number of pictures which may precede another one in decoding but follow it in display
+    /// order
+    num_reorder: usize,
+    /// Whether a capture buffer is currently owned by the driver
+    queued: Vec<bool>,
+}
+
+/// Stateless H.264 decoder
+///
+/// Access units (one coded picture each, in Annex B byte stream format) are fed in decoding
+/// order, decoded pictures are returned in display order. Parameter sets may be sent in front of
+/// any access unit, the decoder session is started with the first IDR picture. Pictures coded
+/// before that are skipped.
+///
+/// Only progressive (frame) pictures are supported, as are the frame-based decode mode and the
+/// Annex B start code mode of the driver. Gaps in frame_num are not concealed.
+///
+/// # Example
+///
+/// ```
+/// use v4l::codec::stateless::H264Decoder;
+/// use v4l::device::MultiPlaneDevice;
+/// use v4l::media::MediaDevice;
+/// use v4l::timestamp::Timestamp;
+///
+/// if let (Ok(dev), Ok(media)) = (MultiPlaneDevice::new(1), MediaDevice::new(0)) {
+///     let mut decoder = H264Decoder::new(dev, &media).unwrap();
+///     let access_unit: Vec<u8> = Vec::new();
+///     decoder.decode(&access_unit, Timestamp::new(0, 0)).unwrap();
+///     while let Some(picture) = decoder.picture().unwrap() {
+///         println!("POC {} at {}", picture.poc, picture.timestamp);
+///     }
+/// }
+/// ```
+pub struct H264Decoder {
+    device: MultiPlaneDevice,
+    request: Request,
+    sps: HashMap<u8, Sps>,
+    pps: HashMap<u8, Pps>,
+    session: Option<Session>,
+
+    /// Reference pictures
+    dpb: Vec<Reference>,
+    max_long_term_frame_idx: Option<u32>,
+    poc: PocState,
+    /// Decoded pictures not yet handed out, in decoding order
+    pending: Vec<Pending>,
+    /// Pictures ready to be handed out, in display order
+    ready: VecDeque<Pending>,
+    /// Capture buffer of the picture returned last
+    held: Option<usize>,
+    /// Number of decoded pictures, used as OUTPUT timestamp to identify references
+    counter: u64,
+}
+
+impl H264Decoder {
+    /// Returns a decoder
+    ///
+    /// # Arguments
+    ///
+    /// * `device` - Memory-to-memory video node of the decoder
+    /// * `media` - Media device the video node belongs to, used to allocate requests
+    pub fn new(device: MultiPlaneDevice, media: &MediaDevice) -> io::Result<Self> {
+        Ok(H264Decoder {
+            device,
+            request: media.alloc_request()?,
+            sps: HashMap::new(),
+            pps: HashMap::new(),
+            session: None,
+            dpb: Vec::new(),
+            max_long_term_frame_idx: None,
+            poc: PocState::default(),
+            pending: Vec::new(),
+            ready: VecDeque::new(),
+            held: None,
+            counter: 0,
+        })
+    }
+
+    /// Returns the format of the decoded pictures, once the first IDR picture was decoded
+    pub fn format(&self) -> Option<&MultiPlaneFormat> {
+        self.session.as_ref().map(|session| &session.format)
+    }
+
+    /// Decodes an access unit
+    ///
+    /// Decoded pictures are retrieved by [`picture`](Self::picture). Changing the resolution
+    /// within a stream is not supported and reported as [`io::ErrorKind::InvalidData`].
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Access unit, i.e. all NAL units of one coded picture
+    /// * `timestamp` - Arbitrary timestamp, handed out along with the decoded picture
+    pub fn decode(&mut self, data: &[u8], timestamp: Timestamp) -> io::Result<()> {
+        let mut slices = Vec::new();
+        for nal in annexb::nal_units(data) {
+            match nal.typ() {
+                NalType::Sps => {
+                    let sps = Sps::parse(nal.payload())?;
+                    self.sps.insert(sps.id, sps);
+                }
+                NalType::Pps => {
+                    let pps = Pps::parse(nal.payload(), &self.sps)?;
+                    self.pps.insert(pps.id, pps);
+                }
+                NalType::Slice | NalType::Idr => slices.push(nal),
+                _ => {}
+            }
+        }
+
+        let header = match slices.first() {
+            Some(nal) => SliceHeader::parse(nal, &self.sps, &self.pps)?,
+            None => return Ok(()),
+        };
+        let pps = self.pps[&header.pps_id].clone();
+        let sps = self.sps[&pps.sps_id].clone();
+
+        match self.session {
+            Some(ref session) => {
+                if (session.width, session.height) != (sps.width(), sps.height()) {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "resolution changes are not supported",
+                    ));
+                }
+            }
+            // references of non-IDR pictures are unknown
+            None if !header.idr => return Ok(()),
+            None => self.start(&sps)?,
+        }
+
+        let (top, bottom, frame_num_offset) = self.picture_order_count(&sps, &header);
+        let reference_ts = self.counter * 1000;
+        let controls = vec![
+            compound(V4L2_CID_STATELESS_H264_SPS, &sps_control(&sps)),
+            compound(V4L2_CID_STATELESS_H264_PPS, &pps_control(&sps, &pps)),
+            compound(
+                V4L2_CID_STATELESS_H264_SCALING_MATRIX,
+                &scaling_matrix_control(&pps),
+            ),
+            compound(
+                V4L2_CID_STATELESS_H264_DECODE_PARAMS,
+                &self.decode_params_control(&sps, &header, top, bottom),
+            ),
+        ];
+
+        let session = self.session.as_mut().unwrap();
+        {
+            let (bufs, meta, planes) = OutputStream::get(&mut session.output, 0)?;
+            let buf = &mut bufs[0];
+            let mut len = 0;
+            for nal in &slices {
+                let end = len + 3 + nal.data.len();
+                if end > buf.len() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "access unit exceeds the bitstream buffer",
+                    ));
+                }
+
+                buf[len..len + 3].copy_from_slice(&[0, 0, 1]);
+                buf[len + 3..end].copy_from_slice(nal.data);
+                len = end;
+            }
+            planes[0].bytesused = len as u32;
+            meta.bytesused = len as u32;
+            meta.timestamp = Timestamp::new(
+                (self.counter / 1_000_000) as i64,
+                (self.counter % 1_000_000) as i64,
+            );
+        }
+        self.counter += 1;
+
+        self.device.set_request_controls(controls, &self.request)?;
+        OutputStream::queue_request(&mut session.output, 0, Some(&self.request))?;
+        self.request.queue()?;
+        self.request.wait(None)?;
+        self.request.reinit()?;
+
+        OutputStream::dequeue(&mut session.output)?;
+        let buffer = CaptureStream::dequeue(&mut session.capture)?;
+        session.queued[buffer] = false;
+        let (_, meta, _) = CaptureStream::get(&session.capture, buffer)?;
+        if meta.flags.contains(Flags::ERROR) {
+            self.recycle()?;
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "driver failed to decode the picture",
+            ));
+        }
+
+        let mmco5 = header.mmco.contains(&Mmco::UnmarkAll);
+        if header.idr || mmco5 {
+            // all prior pictures precede the current one in display order
+            if header.no_output_of_prior_pics {
+                self.pending.clear();
+            } else {
+                self.bump(0);
+            }
+        }
+
+        let (top, bottom) = if mmco5 {
+            let temp = top.min(bottom);
+            (top - temp, bottom - temp)
+        } else {
+            (top, bottom)
+        };
+        self.mark(&sps, &header, buffer, reference_ts, top, bottom);
+        self.update_poc_state(&header, frame_num_offset, top, mmco5);
+
+        self.pending.push(Pending {
+            buffer,
+            poc: top.min(bottom),
+            timestamp,
+        });
+        let num_reorder = self.session.as_ref().unwrap().num_reorder;
+        self.bump(num_reorder);
+
+        self.recycle()
+    }
+
+    /// Returns the next decoded picture in display order
+    ///
+    /// The capture buffer of the previously returned picture is handed back to the driver.
+    pub fn picture(&mut self) -> io::Result<Option<Picture<'_>>> {
+        self.held = None;
+        self.recycle()?;
+
+        let pending = match self.ready.pop_front() {
+            Some(pending) => pending,
+            None => return Ok(None),
+        };
+        self.held = Some(pending.buffer);
+
+        let session = self.session.as_ref().unwrap();
+        let (bufs, _, _) = CaptureStream::get(&session.capture, pending.buffer)?;
+        Ok(Some(Picture {
+            planes: bufs.iter().map(|plane| &plane[..]).collect(),
+            poc: pending.poc,
+            timestamp: pending.timestamp,
+        }))
+    }
+
+    /// Makes all decoded pictures available through [`picture`](Self::picture)
+    ///
+    /// Call this at the end of the stream, otherwise the last pictures are held back for
+    /// reordering.
+    pub fn flush(&mut self) {
+        self.bump(0);
+    }
+
+    fn start(&mut self, sps: &Sps) -> io::Result<()> {
+        let (width, height) = (sps.width(), sps.height());
+        let mut format = MultiPlaneFormat::single_plane(width, height, FourCC::new(b"S264"));
+        format.size[0] = (width * height * 3 / 2).max(1 << 20);
+        Output::set_format(&self.device, &format)?;
+
+        self.device.set_controls(vec![
+            Control {
+                id: V4L2_CID_STATELESS_H264_DECODE_MODE,
+                value: Value::Integer(
+                    v4l2_stateless_h264_decode_mode_V4L2_STATELESS_H264_DECODE_MODE_FRAME_BASED
+                        as i64,
+                ),
+            },
+            Control {
+                id: V4L2_CID_STATELESS_H264_START_CODE,
+                value: Value::Integer(
+                    v4l2_stateless_h264_start_code_V4L2_STATELESS_H264_START_CODE_ANNEX_B as i64,
+                ),
+            },
+            // the driver derives the capture format from the SPS
+            compound(V4L2_CID_STATELESS_H264_SPS, &sps_control(sps)),
+        ])?;
+
+        let mut format = Capture::format(&self.device)?;
+        let nv12 = FourCC::new(b"NV12");
+        if format.fourcc != nv12 {
+            let mut preferred = format.clone();
+            preferred.fourcc = nv12;
+            if let Ok(actual) = Capture::set_format(&self.device, &preferred) {
+                format = actual;
+            }
+        }
+
+        let num_reorder = if sps.profile_idc == 66 {
+            // baseline streams have no B slices
+            0
+        } else {
+            sps.max_dpb_frames()
+        };
+        // references, pictures held back for reordering, the current and the returned one
+        let count = (usize::from(sps.max_num_ref_frames) + num_reorder + 3).min(32);
+
+        let mut output = mmap::Stream::with_buffers(&self.device, Type::VideoOutputMplane, 1)?;
+        let mut capture =
+            mmap::Stream::with_buffers(&self.device, Type::VideoCaptureMplane, count as u32)?;
+        output.set_would_block(WouldBlock::Poll);
+        capture.set_would_block(WouldBlock::Poll);
+
+        StreamTrait::start(&mut output)?;
+        StreamTrait::start(&mut capture)?;
+        for index in 0..capture.buffer_count() {
+            CaptureStream::queue(&mut capture, index)?;
+        }
+
+        self.session = Some(Session {
+            width,
+            height,
+            queued: vec![true; capture.buffer_count()],
+            output,
+            capture,
+            format,
+            num_reorder,
+        });
+        Ok(())
+    }
+
+    /// Derives the picture order count (8.2.1) as (top, bottom, FrameNumOffset)
+    fn picture_order_count(&self, sps: &Sps, header: &SliceHeader) -> (i32, i32, i32) {
+        let state = &self.poc;
+        let frame_num = header.frame_num as i32;
+        let frame_num_offset = if header.idr {
+            0
+        } else if state.prev_frame_num > header.frame_num {
+            state.prev_frame_num_offset + sps.max_frame_num() as i32
+        } else {
+            state.prev_frame_num_offset
+        };
+
+        match sps.pic_order_cnt_type {
+            0 => {
+                let (prev_msb, prev_lsb) = if header.idr {
+                    (0, 0)
+                } else {
+                    (state.prev_msb, state.prev_lsb)
+                };
+                let max_lsb = 1i32 << (sps.log2_max_pic_order_cnt_lsb_minus4 + 4);
+                let lsb = header.pic_order_cnt_lsb as i32;
+                let msb = if lsb < prev_lsb && prev_lsb - lsb >= max_lsb / 2 {
+                    prev_msb + max_lsb
+                } else if lsb > prev_lsb && lsb - prev_lsb > max_lsb / 2 {
+                    prev_msb - max_lsb
+                } else {
+                    prev_msb
+                };
+
+                let top = msb + lsb;
+                (
+                    top,
+                    top + header.delta_pic_order_cnt_bottom,
+                    frame_num_offset,
+                )
+            }
+            1 => {
+                let cycle = &sps.offset_for_ref_frame;
+                let mut abs_frame_num = if cycle.is_empty() {
+                    0
+                } else {
+                    frame_num_offset + frame_num
+                };
+                if header.nal_ref_idc == 0 && abs_frame_num > 0 {
+                    abs_frame_num -= 1;
+                }
+
+                let mut expected = 0;
+                if abs_frame_num > 0 {
+                    let len = cycle.len() as i32;
+                    let delta_per_cycle: i32 = cycle.iter().sum();
+                    let in_cycle = ((abs_frame_num - 1) % len) as usize;
+                    expected = (abs_frame_num - 1) / len * delta_per_cycle
+                        + cycle[..=in_cycle].iter().sum::<i32>();
+                }
+                if header.nal_ref_idc == 0 {
+                    expected += sps.offset_for_non_ref_pic;
+                }
+
+                let top = expected + header.delta_pic_order_cnt[0];
+                let bottom =
+                    top + sps.offset_for_top_to_bottom_field + header.delta_pic_order_cnt[1];
+                (top, bottom, frame_num_offset)
+            }
+            _ => {
+                let poc = if header.idr {
+                    0
+                } else if header.nal_ref_idc == 0 {
+                    2 * (frame_num_offset + frame_num) - 1
+                } else {
+                    2 * (frame_num_offset + frame_num)
+                };
+                (poc, poc, frame_num_offset)
+            }
+        }
+    }
+
+    fn update_poc_state(
+        &mut self,
+        header: &SliceHeader,
+        frame_num_offset: i32,
+        top: i32,
+        mmco5: bool,
+    ) {
+        let state = &mut self.poc;
+        if mmco5 {
+            // the picture is treated as having frame_num 0 and POC msb 0 from now on
+            state.prev_frame_num_offset = 0;
+            state.prev_frame_num = 0;
+            if header.nal_ref_idc != 0 {
+                state.prev_msb = 0;
+                state.prev_lsb = top;
+            }
+            return;
+        }
+
+        state.prev_frame_num_offset = frame_num_offset;
+        state.prev_frame_num = header.frame_num;
+        if header.nal_ref_idc != 0 {
+            let lsb = header.pic_order_cnt_lsb as i32;
+            state.prev_msb = top - lsb;
+            state.prev_lsb = lsb;
+        }
+    }
+
+    /// Returns PicNum (short-term) or LongTermPicNum (long-term) of a reference frame
+    fn pic_num(sps: &Sps, header: &SliceHeader, reference: &Reference) -> i32 {
+        match reference.marking {
+            Marking::LongTerm(idx) => idx as i32,
+            Marking::ShortTerm if reference.frame_num > header.frame_num => {
+                reference.frame_num as i32 - sps.max_frame_num() as i32
+            }
+            Marking::ShortTerm => reference.frame_num as i32,
+        }
+    }
+
+    fn decode_params_control(
+        &self,
+        sps: &Sps,
+        header: &SliceHeader,
+        top: i32,
+        bottom: i32,
+    ) -> v4l2_ctrl_h264_decode_params {
+        let mut params: v4l2_ctrl_h264_decode_params = unsafe { mem::zeroed() };
+        for (entry, reference) in params.dpb.iter_mut().zip(&self.dpb) {
+            entry.reference_ts = reference.reference_ts;
+            entry.pic_num = Self::pic_num(sps, header, reference) as u32;
+            entry.frame_num = reference.frame_num as u16;
+            entry.fields = V4L2_H264_FRAME_REF as u8;
+            entry.top_field_order_cnt = reference.top_field_order_cnt;
+            entry.bottom_field_order_cnt = reference.bottom_field_order_cnt;
+            entry.flags = V4L2_H264_DPB_ENTRY_FLAG_VALID | V4L2_H264_DPB_ENTRY_FLAG_ACTIVE;
+            if let Marking::LongTerm(_) = reference.marking {
+                entry.flags |= V4L2_H264_DPB_ENTRY_FLAG_LONG_TERM;
+            }
+        }
+
+        params.nal_ref_idc = u16::from(header.nal_ref_idc);
+        params.frame_num = header.frame_num as u16;
+        params.top_field_order_cnt = top;
+        params.bottom_field_order_cnt = bottom;
+        params.idr_pic_id = header.idr_pic_id as u16;
+        params.pic_order_cnt_lsb = header.pic_order_cnt_lsb as u16;
+        params.delta_pic_order_cnt_bottom = header.delta_pic_order_cnt_bottom;
+        params.delta_pic_order_cnt0 = header.delta_pic_order_cnt[0];
+        params.delta_pic_order_cnt1 = header.delta_pic_order_cnt[1];
+        params.dec_ref_pic_marking_bit_size = header.dec_ref_pic_marking_bit_size;
+        params.pic_order_cnt_bit_size = header.pic_order_cnt_bit_size;
+        if header.idr {
+            params.flags |= V4L2_H264_DECODE_PARAM_FLAG_IDR_PIC;
+        }
+        match header.slice_type {
+            SliceType::P | SliceType::Sp => params.flags |= V4L2_H264_DECODE_PARAM_FLAG_PFRAME,
+            SliceType::B => params.flags |= V4L2_H264_DECODE_PARAM_FLAG_BFRAME,
+            _ => {}
+        }
+        params
+    }
+
+    /// Decoded reference picture marking process (8.2.5)
+    fn mark(
+        &mut self,
+        sps: &Sps,
+        header: &SliceHeader,
+        buffer: usize,
+        reference_ts: u64,
+        top: i32,
+        bottom: i32,
+    ) {
+        if header.nal_ref_idc == 0 {
+            return;
+        }
+
+        let mut marking = Marking::ShortTerm;
+        if header.idr {
+            self.dpb.clear();
+            if header.long_term_reference {
+                marking = Marking::LongTerm(0);
+                self.max_long_term_frame_idx = Some(0);
+            } else {
+                self.max_long_term_frame_idx = None;
+            }
+        } else if header.mmco.is_empty() {
+            // sliding window
+            let max = usize::from(sps.max_num_ref_frames).max(1);
+            if self.dpb.len() >= max {
+                let oldest = self
+                    .dpb
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, reference)| reference.marking == Marking::ShortTerm)
+                    .min_by_key(|(_, reference)| Self::pic_num(sps, header, reference))
+                    .map(|(i, _)| i);
+                if let Some(i) = oldest {
+                    self.dpb.remove(i);
+                }
+            }
+        } else {
+            for mmco in &header.mmco {
+                let short_term = |diff: u32| header.frame_num as i32 - (diff as i32 + 1);
+                match *mmco {
+                    Mmco::UnmarkShortTerm(diff) => {
+                        let pic_num = short_term(diff);
+                        self.dpb.retain(|reference| {
+                            reference.marking != Marking::ShortTerm
+                                || Self::pic_num(sps, header, reference) != pic_num
+                        });
+                    }
+                    Mmco::UnmarkLongTerm(pic_num) => self
+                        .dpb
+                        .retain(|reference| reference.marking != Marking::LongTerm(pic_num)),
+                    Mmco::ShortToLongTerm(diff, idx) => {
+                        let pic_num = short_term(diff);
+                        self.dpb
+                            .retain(|reference| reference.marking != Marking::LongTerm(idx));
+                        for reference in &mut self.dpb {
+                            if reference.marking == Marking::ShortTerm
+                                && Self::pic_num(sps, header, reference) == pic_num
+                            {
+                                reference.marking = Marking::LongTerm(idx);
+                            }
+                        }
+                    }
+                    Mmco::MaxLongTermFrameIdx(max_plus1) => {
+                        self.max_long_term_frame_idx = max_plus1.checked_sub(1);
+                        let max = self.max_long_term_frame_idx;
+                        self.dpb.retain(|reference| match reference.marking {
+                            Marking::LongTerm(idx) => matches!(max, Some(max) if idx <= max),
+                            Marking::ShortTerm => true,
+                        });
+                    }
+                    Mmco::UnmarkAll => {
+                        self.dpb.clear();
+                        self.max_long_term_frame_idx = None;
+                    }
+                    Mmco::CurrentToLongTerm(idx) => {
+                        self.dpb
+                            .retain(|reference| reference.marking != Marking::LongTerm(idx));
+                        marking = Marking::LongTerm(idx);
+                    }
+                }
+            }
+        }
+
+        let mmco5 = header.mmco.contains(&Mmco::UnmarkAll);
+        self.dpb.push(Reference {
+            buffer,
+            reference_ts,
+            frame_num: if mmco5 { 0 } else { header.frame_num },
+            top_field_order_cnt: top,
+            bottom_field_order_cnt: bottom,
+            marking,
+        });
+    }
+
+    /// Moves pending pictures to the output queue until at most `keep` pictures are left
+    fn bump(&mut self, keep: usize) {
+        while self.pending.len() > keep {
+            let (i, _) = self
+                .pending
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, pending)| pending.poc)
+                .unwrap();
+            let pending = self.pending.remove(i);
+            self.ready.push_back(pending);
+        }
+    }
+
+    /// Hands capture buffers which are no longer needed back to the driver
+    fn recycle(&mut self) -> io::Result<()> {
+        let session = match self.session {
+            Some(ref mut session) => session,
+            None => return Ok(()),
+        };
+
+        for buffer in 0..session.queued.len() {
+            let used = session.queued[buffer]
+                || self.held == Some(buffer)
+                || self.dpb.iter().any(|reference| reference.buffer == buffer)
+                || self.pending.iter().any(|pending| pending.buffer == buffer)
+                || self.ready.iter().any(|pending| pending.buffer == buffer);
+            if !used {
+                CaptureStream::queue(&mut session.capture, buffer)?;
+                session.queued[buffer] = true;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Wraps a control payload struct
+fn compound<T: Copy>(id: u32, payload: &T) -> Control {
+    let bytes =
+        unsafe { slice::from_raw_parts(payload as *const T as *const u8, mem::size_of::<T>()) };
+    Control {
+        id,
+        value: Value::CompoundPtr(bytes.to_vec()),
+    }
+}
+
+fn sps_control(sps: &Sps) -> v4l2_ctrl_h264_sps {
+    let mut ctrl: v4l2_ctrl_h264_sps = unsafe { mem::zeroed() };
+    ctrl.profile_idc = sps.profile_idc;
+    // the bitstream carries constraint_set0_flag in the MSB, V4L2 in the LSB
+    ctrl.constraint_set_flags = sps.constraint_flags.reverse_bits();
+    ctrl.level_idc = sps.level_idc;
+    ctrl.seq_parameter_set_id = sps.id;
+    ctrl.chroma_format_idc = sps.chroma_format_idc;
+    ctrl.bit_depth_luma_minus8 = sps.bit_depth_luma_minus8;
+    ctrl.bit_depth_chroma_minus8 = sps.bit_depth_chroma_minus8;
+    ctrl.log2_max_frame_num_minus4 = sps.log2_max_frame_num_minus4;
+    ctrl.pic_order_cnt_type = sps.pic_order_cnt_type;
+    ctrl.log2_max_pic_order_cnt_lsb_minus4 = sps.log2_max_pic_order_cnt_lsb_minus4;
+    ctrl.max_num_ref_frames = sps.max_num_ref_frames;
+    ctrl.num_ref_frames_in_pic_order_cnt_cycle = sps.offset_for_ref_frame.len() as u8;
+    for (dst, src) in ctrl
+        .offset_for_ref_frame
+        .iter_mut()
+        .zip(&sps.offset_for_ref_frame)
+    {
+        *dst = *src;
+    }
+    ctrl.offset_for_non_ref_pic = sps.offset_for_non_ref_pic;
+    ctrl.offset_for_top_to_bottom_field = sps.offset_for_top_to_bottom_field;
+    ctrl.pic_width_in_mbs_minus1 = sps.pic_width_in_mbs_minus1;
+    ctrl.pic_height_in_map_units_minus1 = sps.pic_height_in_map_units_minus1;
+
+    let flags = [
+        (
+            sps.separate_colour_plane,
+            V4L2_H264_SPS_FLAG_SEPARATE_COLOUR_PLANE,
+        ),
+        (
+            sps.qpprime_y_zero_transform_bypass,
+            V4L2_H264_SPS_FLAG_QPPRIME_Y_ZERO_TRANSFORM_BYPASS,
+        ),
+        (
+            sps.delta_pic_order_always_zero,
+            V4L2_H264_SPS_FLAG_DELTA_PIC_ORDER_ALWAYS_ZERO,
+        ),
+        (
+            sps.gaps_in_frame_num_allowed,
+            V4L2_H264_SPS_FLAG_GAPS_IN_FRAME_NUM_VALUE_ALLOWED,
+        ),
+        (sps.frame_mbs_only, V4L2_H264_SPS_FLAG_FRAME_MBS_ONLY),
+        (
+            sps.mb_adaptive_frame_field,
+            V4L2_H264_SPS_FLAG_MB_ADAPTIVE_FRAME_FIELD,
+        ),
+        (
+            sps.direct_8x8_inference,
+            V4L2_H264_SPS_FLAG_DIRECT_8X8_INFERENCE,
+        ),
+    ];
+    for (set, flag) in flags {
+        if set {
+            ctrl.flags |= flag;
+        }
+    }
+    ctrl
+}
+
+fn pps_control(sps: &Sps, pps: &Pps) -> v4l2_ctrl_h264_pps {
+    let mut ctrl: v4l2_ctrl_h264_pps = unsafe { mem::zeroed() };
+    ctrl.pic_parameter_set_id = pps.id;
+    ctrl.seq_parameter_set_id = pps.sps_id;
+    ctrl.num_slice_groups_minus1 = pps.num_slice_groups_minus1 as u8;
+    ctrl.num_ref_idx_l0_default_active_minus1 = pps.num_ref_idx_l0_default_active_minus1;
+    ctrl.num_ref_idx_l1_default_active_minus1 = pps.num_ref_idx_l1_default_active_minus1;
+    ctrl.weighted_bipred_idc = pps.weighted_bipred_idc;
+    ctrl.pic_init_qp_minus26 = pps.pic_init_qp_minus26;
+    ctrl.pic_init_qs_minus26 = pps.pic_init_qs_minus26;
+    ctrl.chroma_qp_index_offset = pps.chroma_qp_index_offset;
+    ctrl.second_chroma_qp_index_offset = pps.second_chroma_qp_index_offset;
+
+    let flags = [
+        (
+            pps.entropy_coding_mode,
+            V4L2_H264_PPS_FLAG_ENTROPY_CODING_MODE,
+        ),
+        (
+            pps.bottom_field_pic_order_in_frame_present,
+            V4L2_H264_PPS_FLAG_BOTTOM_FIELD_PIC_ORDER_IN_FRAME_PRESENT,
+        ),
+        (pps.weighted_pred, V4L2_H264_PPS_FLAG_WEIGHTED_PRED),
+        (
+            pps.deblocking_filter_control_present,
+            V4L2_H264_PPS_FLAG_DEBLOCKING_FILTER_CONTROL_PRESENT,
+        ),
+        (
+            pps.constrained_intra_pred,
+            V4L2_H264_PPS_FLAG_CONSTRAINED_INTRA_PRED,
+        ),
+        (
+            pps.redundant_pic_cnt_present,
+            V4L2_H264_PPS_FLAG_REDUNDANT_PIC_CNT_PRESENT,
+        ),
+        (
+            pps.transform_8x8_mode,
+            V4L2_H264_PPS_FLAG_TRANSFORM_8X8_MODE,
+        ),
+        // tells the driver to use the scaling matrix control instead of flat lists
+        (
+            sps.scaling_matrix_present || pps.scaling_matrix_present,
+            V4L2_H264_PPS_FLAG_SCALING_MATRIX_PRESENT,
+        ),
+    ];
+    for (set, flag) in flags {
+        if set {
+            ctrl.flags |= flag as u16;
+        }
+    }
+    ctrl
+}
+
+fn scaling_matrix_control(pps: &Pps) -> v4l2_ctrl_h264_scaling_matrix {
+    let mut ctrl: v4l2_ctrl_h264_scaling_matrix = unsafe { mem::zeroed() };
+    // the parsers keep the lists in zigzag order, V4L2 expects raster order
+    for (dst, src) in ctrl.scaling_list_4x4.iter_mut().zip(&pps.scaling_lists_4x4) {
+        for (i, &val) in src.iter().enumerate() {
+            dst[ZIGZAG_4X4[i]] = val;
+        }
+    }
+    for (dst, src) in ctrl.scaling_list_8x8.iter_mut().zip(&pps.scaling_lists_8x8) {
+        for (i, &val) in src.iter().enumerate() {
+            dst[ZIGZAG_8X8[i]] = val;
+        }
+    }
+    ctrl
+}
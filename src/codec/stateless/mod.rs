@@ -0,0 +1,10 @@
+//! Stateless hardware decoders
+//!
+//! Stateless (a.k.a. request API based) codecs found in many SoCs (Hantro, Rockchip VDEC,
+//! Cedrus, ..) do not parse the bitstream themselves. Instead, the parsed parameter sets and
+//! the reference picture state of every frame are attached to its bitstream buffer as controls
+//! of a media request. The decoders in here take care of that bookkeeping and hand out the
+//! decoded pictures in display order.
+
+pub mod h264;
+pub use h264::H264Decoder;
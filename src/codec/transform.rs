@@ -0,0 +1,129 @@
+//! Memory-to-memory converters
+//!
+//! Many SoCs come with a 2D engine exposed as memory-to-memory video node (i.MX PXP, Rockchip
+//! RGA, ..), which scales, crops and converts between pixel formats and colorspaces. Frames are
+//! queued on the OUTPUT queue of the node and come out converted on its CAPTURE queue.
+//! [`Transform`] negotiates the formats of both queues and converts one frame at a time.
+
+use std::io;
+
+use crate::device::VideoDevice;
+use crate::format::PlanarFormat;
+use crate::io::m2m;
+use crate::video::{Capture, Output};
+
+/// Converter between two formats
+///
+/// # Example
+///
+/// ```
+/// use v4l::codec::transform::Transform;
+/// use v4l::{Device, Format, FourCC};
+///
+/// if let Ok(dev) = Device::new(0) {
+///     let input = Format::new(1920, 1080, FourCC::new(b"YUYV"));
+///     let output = Format::new(640, 360, FourCC::new(b"RGB3"));
+///     let mut transform = Transform::new(&dev, &input, &output).unwrap();
+///
+///     let frame = vec![0u8; 1920 * 1080 * 2];
+///     let planes = transform.convert(&[&frame[..]]).unwrap();
+///     println!("converted to {} bytes", planes[0].len());
+/// }
+/// ```
+pub struct Transform<P> {
+    input: PlanarFormat<P>,
+    output: PlanarFormat<P>,
+    stream: m2m::Stream<'static>,
+}
+
+impl<P> Transform<P> {
+    /// Returns a converter between two formats
+    ///
+    /// The driver may adjust the sizes (e.g. to satisfy alignment constraints), see
+    /// [`Transform::input_format`] and [`Transform::output_format`] for the actual formats.
+    /// Pixel formats the device cannot convert from or to are reported as
+    /// [`io::ErrorKind::Unsupported`].
+    ///
+    /// # Arguments
+    ///
+    /// * `dev` - Memory-to-memory video node (single- or multi-planar)
+    /// * `input` - Format of the frames to convert
+    /// * `output` - Format to convert them to
+    pub fn new<D>(dev: &D, input: &PlanarFormat<P>, output: &PlanarFormat<P>) -> io::Result<Self>
+    where
+        D: VideoDevice + Capture<Format = PlanarFormat<P>> + Output<Format = PlanarFormat<P>>,
+    {
+        let actual_input = Output::set_format(dev, input)?;
+        if actual_input.fourcc != input.fourcc {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("device does not convert from {}", input.fourcc),
+            ));
+        }
+
+        let actual_output = Capture::set_format(dev, output)?;
+        if actual_output.fourcc != output.fourcc {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("device does not convert to {}", output.fourcc),
+            ));
+        }
+
+        // one frame is converted at a time, the driver raises the counts to what it needs
+        let stream = m2m::Stream::with_buffers(dev, 1, 1)?;
+
+        Ok(Transform {
+            input: actual_input,
+            output: actual_output,
+            stream,
+        })
+    }
+
+    /// Returns the format of the frames to convert
+    pub fn input_format(&self) -> &PlanarFormat<P> {
+        &self.input
+    }
+
+    /// Returns the format of the converted frames
+    pub fn output_format(&self) -> &PlanarFormat<P> {
+        &self.output
+    }
+
+    /// Converts a frame, waiting for the device to finish
+    ///
+    /// The converted frame stays valid until the next call.
+    ///
+    /// # Arguments
+    ///
+    /// * `planes` - Memory planes of the frame, a single one for single-planar formats
+    pub fn convert(&mut self, planes: &[&[u8]]) -> io::Result<Vec<&[u8]>> {
+        let (bufs, meta) = self.stream.input()?;
+        if planes.len() != bufs.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("expected {} planes, got {}", bufs.len(), planes.len()),
+            ));
+        }
+        for (buf, plane) in bufs.iter_mut().zip(planes) {
+            if plane.len() > buf.len() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "plane does not fit into the buffer",
+                ));
+            }
+            buf[..plane.len()].copy_from_slice(plane);
+        }
+        // drivers take frames of several planes as filled completely
+        meta.bytesused = planes[0].len() as u32;
+        self.stream.queue_input()?;
+
+        let (bufs, meta) = self.stream.output()?;
+        let mut planes: Vec<&[u8]> = bufs.iter().map(|buf| &buf[..]).collect();
+        if let [plane] = planes.as_mut_slice() {
+            if meta.bytesused > 0 {
+                *plane = &plane[..(meta.bytesused as usize).min(plane.len())];
+            }
+        }
+        Ok(planes)
+    }
+}
@@ -0,0 +1,378 @@
+//! Driver compliance self-test
+//!
+//! A subset of the checks done by v4l2-compliance, for driver developers who test their
+//! drivers from Rust. Only the single-planar video capture API is covered: the capabilities,
+//! the format enumeration and negotiation and the lifecycle of memory mapped buffers.
+//!
+//! Buffers are streamed as part of the test, so the device must not be in use.
+
+use std::collections::HashSet;
+use std::time::Duration;
+use std::{fmt, io};
+
+use crate::buffer::Type;
+use crate::capability::{Capabilities, Flags};
+use crate::device::Device;
+use crate::format::description::Flags as FormatFlags;
+use crate::format::Description as FormatDescription;
+use crate::frameinterval::FrameIntervalEnum;
+use crate::framesize::FrameSizeEnum;
+use crate::io::mmap::Stream;
+use crate::io::traits::CaptureStream;
+use crate::video::Capture;
+
+/// Number of frames captured to check the buffer metadata
+const FRAMES: usize = 4;
+
+/// Time to wait for a single frame
+const FRAME_TIMEOUT: Duration = Duration::from_secs(2);
+
+const QUERYCAP: &str = "VIDIOC_QUERYCAP";
+const ENUM_FMT: &str = "VIDIOC_ENUM_FMT";
+const ENUM_FRAMESIZES: &str = "VIDIOC_ENUM_FRAMESIZES/FRAMEINTERVALS";
+const FMT: &str = "VIDIOC_G/S_FMT";
+const BUFFERS: &str = "VIDIOC_REQBUFS/QBUF/DQBUF";
+
+/// All checks, in the order they are run
+const CHECKS: [&str; 5] = [QUERYCAP, ENUM_FMT, ENUM_FRAMESIZES, FMT, BUFFERS];
+
+/// Result of a single check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    /// The driver misbehaved, with a description of the problem
+    Failed(String),
+    /// The check does not apply to the device, with the reason
+    Skipped(String),
+}
+
+impl From<Result<(), String>> for Outcome {
+    fn from(res: Result<(), String>) -> Self {
+        match res {
+            Ok(()) => Outcome::Passed,
+            Err(msg) => Outcome::Failed(msg),
+        }
+    }
+}
+
+/// Named check along with its outcome
+#[derive(Debug, Clone)]
+pub struct Check {
+    /// Name of the check, usually the ioctls involved
+    pub name: &'static str,
+    pub outcome: Outcome,
+}
+
+/// Outcomes of all checks, in the order they were run
+#[derive(Debug, Clone, Default)]
+pub struct Report {
+    pub checks: Vec<Check>,
+}
+
+impl Report {
+    /// Returns whether no check failed
+    pub fn passed(&self) -> bool {
+        self.failures().next().is_none()
+    }
+
+    /// Returns the failed checks
+    pub fn failures(&self) -> impl Iterator<Item = &Check> {
+        self.checks
+            .iter()
+            .filter(|check| matches!(check.outcome, Outcome::Failed(_)))
+    }
+
+    fn push(&mut self, name: &'static str, outcome: Outcome) {
+        self.checks.push(Check { name, outcome });
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for check in &self.checks {
+            match &check.outcome {
+                Outcome::Passed => writeln!(f, "test {}: OK", check.name)?,
+                Outcome::Failed(msg) => writeln!(f, "test {}: FAIL ({})", check.name, msg)?,
+                Outcome::Skipped(why) => {
+                    writeln!(f, "test {}: OK (Not Supported, {})", check.name, why)?
+                }
+            }
+        }
+        let failed = self.failures().count();
+        write!(
+            f,
+            "Total: {}, Succeeded: {}, Failed: {}",
+            self.checks.len(),
+            self.checks.len() - failed,
+            failed
+        )
+    }
+}
+
+/// Runs all checks against a device
+///
+/// Checks which do not apply to the device or depend on a failed check are skipped.
+///
+/// # Arguments
+///
+/// * `dev` - Device to test
+///
+/// # Example
+///
+/// ```
+/// use v4l::compliance;
+/// use v4l::Device;
+///
+/// if let Ok(dev) = Device::new(0) {
+///     let report = compliance::run(&dev);
+///     println!("{}", report);
+///     for check in report.failures() {
+///         eprintln!("{} failed", check.name);
+///     }
+/// }
+/// ```
+pub fn run(dev: &Device) -> Report {
+    let mut report = Report::default();
+    if let Err(why) = run_checks(dev, &mut report) {
+        for name in &CHECKS[report.checks.len()..] {
+            report.push(name, Outcome::Skipped(why.clone()));
+        }
+    }
+    report
+}
+
+/// Runs the checks until one which the others depend on fails, returns why they stopped
+fn run_checks(dev: &Device, report: &mut Report) -> Result<(), String> {
+    let caps = match dev.query_caps() {
+        Ok(caps) => caps,
+        Err(e) => {
+            report.push(QUERYCAP, Outcome::Failed(e.to_string()));
+            return Err("querying the capabilities failed".to_string());
+        }
+    };
+    report.push(QUERYCAP, querycap(dev, &caps).into());
+    if !caps.capabilities.contains(Flags::VIDEO_CAPTURE) {
+        return Err("no single-planar video capture".to_string());
+    }
+
+    let formats = match enum_formats(dev) {
+        Ok(formats) => formats,
+        Err(msg) => {
+            report.push(ENUM_FMT, Outcome::Failed(msg));
+            return Err("format enumeration failed".to_string());
+        }
+    };
+    report.push(ENUM_FMT, Outcome::Passed);
+    report.push(ENUM_FRAMESIZES, enum_framesizes(dev, &formats));
+    report.push(FMT, negotiate(dev, &formats).into());
+
+    if !caps.capabilities.contains(Flags::STREAMING) {
+        return Err("no streaming I/O".to_string());
+    }
+    report.push(BUFFERS, buffers(dev).into());
+    Ok(())
+}
+
+fn querycap(dev: &Device, caps: &Capabilities) -> Result<(), String> {
+    for (field, value) in [
+        ("driver", &caps.driver),
+        ("card", &caps.card),
+        ("bus_info", &caps.bus),
+    ] {
+        if value.is_empty() {
+            return Err(format!("{} is empty", field));
+        }
+    }
+
+    // the flag only makes sense in the capabilities of the whole device
+    if caps.capabilities.contains(Flags::DEVICE_CAPS) {
+        return Err("device_caps contain V4L2_CAP_DEVICE_CAPS".to_string());
+    }
+    let video = Flags::VIDEO_CAPTURE
+        | Flags::VIDEO_OUTPUT
+        | Flags::VIDEO_CAPTURE_MPLANE
+        | Flags::VIDEO_OUTPUT_MPLANE
+        | Flags::VIDEO_M2M
+        | Flags::VIDEO_M2M_MPLANE
+        | Flags::META_CAPTURE
+        | Flags::META_OUTPUT;
+    if caps.capabilities.intersects(video)
+        && !caps
+            .capabilities
+            .intersects(Flags::STREAMING | Flags::READ_WRITE)
+    {
+        return Err("video node without any I/O method".to_string());
+    }
+
+    let again = dev.query_caps().map_err(|e| e.to_string())?;
+    if (&again.driver, &again.card, &again.bus, again.capabilities)
+        != (&caps.driver, &caps.card, &caps.bus, caps.capabilities)
+    {
+        return Err("capabilities changed between two queries".to_string());
+    }
+    Ok(())
+}
+
+fn enum_formats(dev: &Device) -> Result<Vec<FormatDescription>, String> {
+    let formats = dev.enum_formats().map_err(|e| e.to_string())?;
+    if formats.is_empty() {
+        return Err("no formats".to_string());
+    }
+
+    let mut fourccs = HashSet::new();
+    for (index, format) in formats.iter().enumerate() {
+        if format.index as usize != index {
+            return Err(format!("format {} reported index {}", index, format.index));
+        }
+        if format.description.is_empty() {
+            return Err(format!("{} has no description", format.fourcc));
+        }
+        if !fourccs.insert(format.fourcc.repr) {
+            return Err(format!("{} is enumerated twice", format.fourcc));
+        }
+    }
+    Ok(formats)
+}
+
+fn enum_framesizes(dev: &Device, formats: &[FormatDescription]) -> Outcome {
+    let mut sizes = 0;
+    for format in formats {
+        let framesizes = match dev.enum_framesizes(format.fourcc) {
+            Ok(framesizes) => framesizes,
+            // the ioctl is optional
+            Err(e) if not_supported(&e) => continue,
+            Err(e) => return Outcome::Failed(format!("{}: {}", format.fourcc, e)),
+        };
+
+        for framesize in framesizes {
+            sizes += 1;
+            let fail = |msg: &str| Outcome::Failed(format!("{}: {}", format.fourcc, msg));
+            let discrete = match framesize.size {
+                FrameSizeEnum::Discrete(discrete) => discrete,
+                FrameSizeEnum::Stepwise(stepwise) => {
+                    let valid = stepwise.min_width > 0
+                        && stepwise.min_height > 0
+                        && stepwise.min_width <= stepwise.max_width
+                        && stepwise.min_height <= stepwise.max_height
+                        && stepwise.step_width > 0
+                        && stepwise.step_height > 0;
+                    if !valid {
+                        return fail(&format!("invalid frame size range {}", stepwise));
+                    }
+                    continue;
+                }
+            };
+            if discrete.width == 0 || discrete.height == 0 {
+                return fail(&format!("empty frame size {}", discrete));
+            }
+
+            let intervals =
+                match dev.enum_frameintervals(format.fourcc, discrete.width, discrete.height) {
+                    Ok(intervals) => intervals,
+                    Err(e) if not_supported(&e) => continue,
+                    Err(e) => return fail(&e.to_string()),
+                };
+            for interval in intervals {
+                let valid = match interval.interval {
+                    FrameIntervalEnum::Discrete(fraction) => {
+                        fraction.numerator > 0 && fraction.denominator > 0
+                    }
+                    FrameIntervalEnum::Stepwise(stepwise) => {
+                        stepwise.min.numerator > 0
+                            && stepwise.min.denominator > 0
+                            && stepwise.max.denominator > 0
+                            && stepwise.min.numerator as u64 * stepwise.max.denominator as u64
+                                <= stepwise.max.numerator as u64 * stepwise.min.denominator as u64
+                    }
+                };
+                if !valid {
+                    return fail(&format!("invalid frame interval at {}", discrete));
+                }
+            }
+        }
+    }
+
+    if sizes == 0 {
+        return Outcome::Skipped("no frame sizes enumerated".to_string());
+    }
+    Outcome::Passed
+}
+
+/// Returns whether an enumeration failed because there is nothing to enumerate
+fn not_supported(e: &io::Error) -> bool {
+    matches!(e.raw_os_error(), Some(libc::ENOTTY) | Some(libc::EINVAL))
+}
+
+fn negotiate(dev: &Device, formats: &[FormatDescription]) -> Result<(), String> {
+    let format = dev.format().map_err(|e| e.to_string())?;
+    let desc = formats
+        .iter()
+        .find(|desc| desc.fourcc == format.fourcc)
+        .ok_or_else(|| format!("active format {} is not enumerated", format.fourcc))?;
+    if format.width == 0 || format.height == 0 {
+        return Err(format!(
+            "empty active format {}x{}",
+            format.width, format.height
+        ));
+    }
+    if format.size == 0 {
+        return Err("sizeimage is zero".to_string());
+    }
+    if !desc.flags.contains(FormatFlags::COMPRESSED)
+        && (format.stride as u64 * format.height as u64) > format.size as u64
+    {
+        return Err(format!(
+            "sizeimage {} is smaller than {} lines of {} bytes",
+            format.size, format.height, format.stride
+        ));
+    }
+
+    // setting the active format must not change it
+    let set = dev.set_format(&format).map_err(|e| e.to_string())?;
+    if (set.width, set.height, set.fourcc) != (format.width, format.height, format.fourcc) {
+        return Err(format!(
+            "setting the active format {}x{} {} changed it to {}x{} {}",
+            format.width, format.height, format.fourcc, set.width, set.height, set.fourcc
+        ));
+    }
+    Ok(())
+}
+
+fn buffers(dev: &Device) -> Result<(), String> {
+    {
+        let mut stream =
+            Stream::with_buffers(dev, Type::VideoCapture, 2).map_err(|e| e.to_string())?;
+        stream.set_timeout(FRAME_TIMEOUT);
+
+        let mut last: Option<(u32, Duration)> = None;
+        for _ in 0..FRAMES {
+            let (bufs, meta, _) = CaptureStream::next(&mut stream).map_err(|e| e.to_string())?;
+            let len = bufs[0].len();
+            if meta.bytesused == 0 || meta.bytesused as usize > len {
+                return Err(format!(
+                    "bytesused {} outside of the buffer length {}",
+                    meta.bytesused, len
+                ));
+            }
+
+            let timestamp = Duration::from(meta.timestamp);
+            if let Some((sequence, time)) = last {
+                if meta.sequence <= sequence {
+                    return Err(format!(
+                        "sequence number {} did not increase from {}",
+                        meta.sequence, sequence
+                    ));
+                }
+                if timestamp < time {
+                    return Err("timestamps went backwards".to_string());
+                }
+            }
+            last = Some((meta.sequence, timestamp));
+        }
+    }
+
+    // dropping the stream releases the buffers, so they can be requested again
+    Stream::with_buffers(dev, Type::VideoCapture, 2)
+        .map(|_| ())
+        .map_err(|e| format!("buffers were not released: {}", e))
+}
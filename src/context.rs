@@ -1,5 +1,8 @@
-use std::fs;
 use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+use crate::capability::Flags;
+use crate::device::Device;
 
 /// Returns a list of devices currently known to the system
 ///
@@ -35,6 +38,78 @@ pub fn enum_devices() -> Vec<Node> {
     devices
 }
 
+/// Returns the devices currently known to the system, with their nodes grouped
+///
+/// Many devices expose several video nodes, e.g. UVC cameras have a node for the video stream
+/// and another one for metadata. Nodes with the same bus info belong to the same device. Nodes
+/// which cannot be opened (e.g. due to missing permissions) are skipped.
+///
+/// # Example
+///
+/// ```
+/// use v4l::capability::Flags;
+/// use v4l::context;
+///
+/// for group in context::enum_groups() {
+///     println!("{} ({})", group.card, group.bus);
+///     if let Some(node) = group.node_with(Flags::META_CAPTURE) {
+///         println!("  metadata: {}", node.path().display());
+///     }
+/// }
+/// ```
+pub fn enum_groups() -> Vec<Group> {
+    let mut nodes = enum_devices();
+    nodes.sort_by_key(|node| node.index());
+
+    let mut groups: Vec<Group> = Vec::new();
+    for node in nodes {
+        let caps = match Device::with_path(node.path()).and_then(|dev| dev.query_caps()) {
+            Ok(caps) => caps,
+            Err(_) => continue,
+        };
+
+        match groups.iter_mut().find(|group| group.bus == caps.bus) {
+            Some(group) => group.nodes.push((node, caps.capabilities)),
+            None => groups.push(Group {
+                card: caps.card,
+                bus: caps.bus,
+                nodes: vec![(node, caps.capabilities)],
+            }),
+        }
+    }
+
+    groups
+}
+
+/// Video nodes belonging to the same physical device
+pub struct Group {
+    /// Card name of the first node
+    pub card: String,
+    /// Bus info shared by all nodes
+    pub bus: String,
+    /// Nodes in index order, along with their capabilities
+    nodes: Vec<(Node, Flags)>,
+}
+
+impl Group {
+    /// Returns the nodes in index order
+    pub fn nodes(&self) -> impl Iterator<Item = &Node> {
+        self.nodes.iter().map(|(node, _)| node)
+    }
+
+    /// Returns the first node supporting all of the given capabilities
+    ///
+    /// # Arguments
+    ///
+    /// * `caps` - Capabilities, e.g. [`Flags::VIDEO_CAPTURE`]
+    pub fn node_with(&self, caps: Flags) -> Option<&Node> {
+        self.nodes
+            .iter()
+            .find(|(_, flags)| flags.contains(caps))
+            .map(|(node, _)| node)
+    }
+}
+
 /// Represents a video4linux device node
 pub struct Node {
     /// Device node path
@@ -84,6 +159,24 @@ impl Node {
         index.unwrap()
     }
 
+    /// Returns the properties of the device by parsing its sysfs entry
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::context;
+    /// for dev in context::enum_devices() {
+    ///     if let Ok(info) = dev.info() {
+    ///         println!("{}: {:?}", dev.path().display(), info);
+    ///     }
+    /// }
+    /// ```
+    pub fn info(&self) -> io::Result<DeviceInfo> {
+        let file_name = self.path.file_name().unwrap_or_default();
+        let path = Path::new("/sys/class/video4linux").join(file_name);
+        DeviceInfo::from_sysfs(&fs::canonicalize(path)?)
+    }
+
     /// Returns name of the device by parsing its sysfs entry
     pub fn name(&self) -> Option<String> {
         let index = self.index();
@@ -95,3 +188,86 @@ impl Node {
         }
     }
 }
+
+/// Device properties found in sysfs
+///
+/// Unlike the information returned by the driver, these allow to tell otherwise identical
+/// devices apart. USB properties are `None` for devices which are not connected through USB.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeviceInfo {
+    /// Name of the device node, usually the card name
+    pub name: Option<String>,
+    /// Name of the kernel driver, e.g. uvcvideo
+    pub driver: Option<String>,
+    /// USB vendor ID
+    pub vendor_id: Option<u16>,
+    /// USB product ID
+    pub product_id: Option<u16>,
+    /// Manufacturer string of the USB device
+    pub manufacturer: Option<String>,
+    /// Product string of the USB device
+    pub product: Option<String>,
+    /// Serial number of the USB device
+    pub serial: Option<String>,
+    /// USB link speed in Mbit/s, e.g. 480 for high speed
+    pub speed: Option<u32>,
+}
+
+impl DeviceInfo {
+    /// Reads the properties of a device node
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - sysfs directory of the device node (see [`crate::device::Handle::sysfs_path`])
+    pub fn from_sysfs(path: &Path) -> io::Result<Self> {
+        if !path.is_dir() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "sysfs entry does not exist",
+            ));
+        }
+
+        let mut info = DeviceInfo {
+            name: read_attribute(path, "name"),
+            driver: fs::read_link(path.join("device/driver"))
+                .ok()
+                .and_then(|driver| Some(driver.file_name()?.to_string_lossy().into_owned())),
+            ..Default::default()
+        };
+
+        if let Some(usb) = usb_device_dir(path) {
+            let id = |name| u16::from_str_radix(&read_attribute(&usb, name)?, 16).ok();
+            info.vendor_id = id("idVendor");
+            info.product_id = id("idProduct");
+            info.manufacturer = read_attribute(&usb, "manufacturer");
+            info.product = read_attribute(&usb, "product");
+            info.serial = read_attribute(&usb, "serial");
+            // the speed is given in Mbit/s, low speed is reported as 1.5
+            info.speed = read_attribute(&usb, "speed")
+                .and_then(|speed| speed.parse::<f32>().ok())
+                .map(|speed| speed as u32);
+        }
+
+        Ok(info)
+    }
+}
+
+/// Returns the sysfs directory of the USB device a device node belongs to
+///
+/// # Arguments
+///
+/// * `path` - sysfs directory of the device node
+pub(crate) fn usb_device_dir(path: &Path) -> Option<PathBuf> {
+    // USB interfaces sit below the device, only the device itself has vendor and product IDs
+    path.ancestors()
+        .take_while(|dir| dir.starts_with("/sys/devices"))
+        .find(|dir| dir.join("idVendor").exists())
+        .map(PathBuf::from)
+}
+
+/// Reads a sysfs attribute, trailing whitespace is stripped
+pub(crate) fn read_attribute(dir: &Path, name: &str) -> Option<String> {
+    fs::read_to_string(dir.join(name))
+        .ok()
+        .map(|value| value.trim_end().to_string())
+}
@@ -1,7 +1,10 @@
 use bitflags::bitflags;
 use std::convert::{TryFrom, TryInto};
-use std::{ffi, fmt, mem, str};
+use std::time::{Duration, Instant};
+use std::{error, ffi, fmt, io, mem, str};
 
+use crate::device::{Handle, PollFlags, VideoDevice};
+use crate::event;
 use crate::v4l_sys::*;
 
 /// Control data type
@@ -25,6 +28,7 @@ pub enum Type {
     U16             = 0x0101,
     U32             = 0x0102,
     Area            = 0x0106,
+    Rect            = 0x0107,
 }
 
 impl TryFrom<u32> for Type {
@@ -46,6 +50,7 @@ impl TryFrom<u32> for Type {
             0x0101 => Ok(Type::U16),
             0x0102 => Ok(Type::U32),
             0x0106 => Ok(Type::Area),
+            0x0107 => Ok(Type::Rect),
             _ => Err(()),
         }
     }
@@ -64,18 +69,34 @@ impl fmt::Display for Type {
 }
 
 bitflags! {
+    /// Control flags, as reported in [`Description::flags`]
+    ///
+    /// Some of them change at runtime: [`Flags::GRABBED`] while another file handle streams and
+    /// [`Flags::INACTIVE`] while a related automatic mode is enabled. Query the description again
+    /// to get their current state.
     #[allow(clippy::unreadable_literal)]
     pub struct Flags: u32 {
+        /// Control is permanently disabled and should be ignored
         const DISABLED              = 0x0001;
+        /// Control cannot be changed right now, usually because another process is streaming
         const GRABBED               = 0x0002;
+        /// Control can be read, but not set
         const READ_ONLY             = 0x0004;
+        /// Changing the control may affect other controls, e.g. their ranges
         const UPDATE                = 0x0008;
+        /// Control has no effect right now, e.g. manual exposure while auto exposure is on
         const INACTIVE              = 0x0010;
+        /// Control is best represented as a slider
         const SLIDER                = 0x0020;
+        /// Control can be set, but not read
         const WRITE_ONLY            = 0x0040;
+        /// Control value changes on its own, e.g. gain in auto gain mode
         const VOLATILE              = 0x0080;
+        /// Control value is passed through a pointer
         const HAS_PAYLOAD           = 0x0100;
+        /// Setting the control triggers an action even if the value did not change
         const EXECUTE_ON_WRITE      = 0x0200;
+        /// Changing the control affects the buffer layout, it cannot be set while streaming
         const MODIFY_LAYOUT         = 0x0400;
 
         const NEXT_CTRL             = 0x80000000;
@@ -204,13 +225,85 @@ impl fmt::Display for Description {
     }
 }
 
+/// Error payload of setting a control which is grabbed
+///
+/// Drivers lock some controls while streaming, e.g. those changing the image size, so they are
+/// grabbed as long as any process streams from the device. The errors are of kind
+/// [`io::ErrorKind::ResourceBusy`] and carry the original OS error.
 #[derive(Debug)]
+pub struct Grabbed {
+    /// Identifier of the grabbed control
+    pub id: u32,
+    source: io::Error,
+}
+
+impl Grabbed {
+    /// Returns an I/O error wrapping the original one
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifier of the grabbed control
+    /// * `source` - Error reported by the driver, usually EBUSY
+    pub fn error(id: u32, source: io::Error) -> io::Error {
+        io::Error::new(io::ErrorKind::ResourceBusy, Grabbed { id, source })
+    }
+}
+
+impl fmt::Display for Grabbed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "control {:#x} is grabbed ({})", self.id, self.source)
+    }
+}
+
+impl error::Error for Grabbed {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Error payload of setting a control which is inactive
+///
+/// Manual controls are usually inactive while the matching automatic mode is enabled, e.g.
+/// the exposure time while auto exposure is on. Drivers which refuse to set them cause errors
+/// of kind [`io::ErrorKind::PermissionDenied`], carrying the original OS error.
+#[derive(Debug)]
+pub struct Inactive {
+    /// Identifier of the inactive control
+    pub id: u32,
+    source: io::Error,
+}
+
+impl Inactive {
+    /// Returns an I/O error wrapping the original one
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifier of the inactive control
+    /// * `source` - Error reported by the driver
+    pub fn error(id: u32, source: io::Error) -> io::Error {
+        io::Error::new(io::ErrorKind::PermissionDenied, Inactive { id, source })
+    }
+}
+
+impl fmt::Display for Inactive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "control {:#x} is inactive ({})", self.id, self.source)
+    }
+}
+
+impl error::Error for Inactive {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct Control {
     pub id: u32,
     pub value: Value,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 /// Device control value
 pub enum Value {
     /* buttons */
@@ -250,3 +343,240 @@ impl TryInto<v4l2_control> for Control {
         }
     }
 }
+
+/// Manual controls, their automatic mode control and the values of the latter which leave the
+/// manual control in effect. The first value is the one to switch to.
+const AUTO_MODES: &[(u32, u32, &[i64])] = &[
+    // V4L2_EXPOSURE_MANUAL, V4L2_EXPOSURE_SHUTTER_PRIORITY
+    (V4L2_CID_EXPOSURE_ABSOLUTE, V4L2_CID_EXPOSURE_AUTO, &[1, 2]),
+    // V4L2_EXPOSURE_MANUAL, V4L2_EXPOSURE_APERTURE_PRIORITY
+    (V4L2_CID_IRIS_ABSOLUTE, V4L2_CID_EXPOSURE_AUTO, &[1, 3]),
+    (V4L2_CID_IRIS_RELATIVE, V4L2_CID_EXPOSURE_AUTO, &[1, 3]),
+    (V4L2_CID_GAIN, V4L2_CID_AUTOGAIN, &[0]),
+    (V4L2_CID_FOCUS_ABSOLUTE, V4L2_CID_FOCUS_AUTO, &[0]),
+    (V4L2_CID_FOCUS_RELATIVE, V4L2_CID_FOCUS_AUTO, &[0]),
+    (
+        V4L2_CID_WHITE_BALANCE_TEMPERATURE,
+        V4L2_CID_AUTO_WHITE_BALANCE,
+        &[0],
+    ),
+    (V4L2_CID_RED_BALANCE, V4L2_CID_AUTO_WHITE_BALANCE, &[0]),
+    (V4L2_CID_BLUE_BALANCE, V4L2_CID_AUTO_WHITE_BALANCE, &[0]),
+    (V4L2_CID_HUE, V4L2_CID_HUE_AUTO, &[0]),
+];
+
+/// Returns the identifier of the automatic mode control which overrides a manual control
+///
+/// E.g. `V4L2_CID_EXPOSURE_AUTO` for `V4L2_CID_EXPOSURE_ABSOLUTE`. Only the pairs defined by
+/// the V4L2 API are known, driver specific controls have none.
+///
+/// # Arguments
+///
+/// * `id` - Identifier of the manual control
+pub fn auto_control(id: u32) -> Option<u32> {
+    AUTO_MODES
+        .iter()
+        .find(|(manual, _, _)| *manual == id)
+        .map(|(_, auto, _)| *auto)
+}
+
+/// Sets a manual control, turning its automatic mode off first
+///
+/// Drivers ignore or refuse manual controls while the matching automatic mode is on, often
+/// without reporting an error. If the automatic mode had to be changed, its previous value is
+/// returned so it can be restored with `set_control` later. The automatic mode is restored
+/// right away if setting the manual control fails.
+///
+/// Controls without a known automatic mode (see [`auto_control`]) are just set.
+///
+/// # Arguments
+///
+/// * `dev` - Device the control belongs to
+/// * `ctrl` - Manual control to be set
+///
+/// # Example
+///
+/// ```
+/// use v4l::control::{self, Control, Value};
+/// use v4l::v4l_sys::V4L2_CID_EXPOSURE_ABSOLUTE;
+/// use v4l::Device;
+///
+/// if let Ok(dev) = Device::new(0) {
+///     let exposure = Control {
+///         id: V4L2_CID_EXPOSURE_ABSOLUTE,
+///         value: Value::Integer(250),
+///     };
+///     if let Ok(previous) = control::set_manual(&dev, exposure) {
+///         // ... capture with a fixed exposure time
+///         if let Some(auto) = previous {
+///             dev.set_control(auto).unwrap();
+///         }
+///     }
+/// }
+/// ```
+pub fn set_manual<D: VideoDevice>(dev: &D, ctrl: Control) -> io::Result<Option<Control>> {
+    let handle = dev.handle();
+    let previous = match auto_mode(&handle, ctrl.id)? {
+        Some((previous, off)) => {
+            handle.set_controls(vec![off], None)?;
+            Some(previous)
+        }
+        None => None,
+    };
+
+    if let Err(e) = handle.set_controls(vec![ctrl], None) {
+        if let Some(previous) = previous {
+            let _ = handle.set_controls(vec![previous], None);
+        }
+        return Err(e);
+    }
+    Ok(previous)
+}
+
+/// Returns the automatic mode control overriding a manual control and the value turning it
+/// off, or `None` if it is off already
+fn auto_mode(handle: &Handle, id: u32) -> io::Result<Option<(Control, Control)>> {
+    let (auto, manual) = match AUTO_MODES.iter().find(|(manual, _, _)| *manual == id) {
+        Some((_, auto, manual)) => (*auto, *manual),
+        None => return Ok(None),
+    };
+
+    let previous = match handle.control(auto) {
+        Ok(previous) => previous,
+        // the driver implements the manual control only
+        Err(e) if e.kind() == io::ErrorKind::InvalidInput => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    let off = match previous.value {
+        Value::Integer(value) if !manual.contains(&value) => Value::Integer(manual[0]),
+        Value::Boolean(value) if !manual.contains(&(value as i64)) => {
+            Value::Boolean(manual[0] != 0)
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some((
+        previous,
+        Control {
+            id: auto,
+            value: off,
+        },
+    )))
+}
+
+/// Maximum change of a control value which is still considered stable
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Threshold {
+    /// Control identifier, e.g. `V4L2_CID_EXPOSURE_ABSOLUTE`
+    pub id: u32,
+    /// Maximum absolute change of integer values, other values must not change at all
+    pub delta: i64,
+}
+
+/// Waits until automatically adjusted controls have settled
+///
+/// Auto exposure, gain and focus algorithms need a number of frames to converge after the
+/// stream was started or the scene changed. Instead of sleeping for a fixed time, this watches
+/// the control values and returns once none of them moved by more than its threshold for the
+/// `settle` period. The device has to be streaming, otherwise the values do not change.
+///
+/// Control change events are used to wake up early where the driver sends them, the values are
+/// sampled periodically in any case since many drivers do not report automatic adjustments.
+///
+/// Returns `false` if the controls did not settle within `timeout`.
+///
+/// # Arguments
+///
+/// * `dev` - Device the controls belong to
+/// * `thresholds` - Controls to watch
+/// * `settle` - Time the values have to stay within their thresholds
+/// * `timeout` - Time after which to give up
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use v4l::control::{self, Threshold};
+/// use v4l::v4l_sys::{V4L2_CID_EXPOSURE_ABSOLUTE, V4L2_CID_GAIN};
+/// use v4l::Device;
+///
+/// if let Ok(dev) = Device::new(0) {
+///     let thresholds = [
+///         Threshold { id: V4L2_CID_EXPOSURE_ABSOLUTE, delta: 2 },
+///         Threshold { id: V4L2_CID_GAIN, delta: 1 },
+///     ];
+///     let settle = Duration::from_millis(300);
+///     let timeout = Duration::from_secs(3);
+///     if let Ok(converged) = control::wait_converged(&dev, &thresholds, settle, timeout) {
+///         println!("converged: {}", converged);
+///     }
+/// }
+/// ```
+pub fn wait_converged<D: VideoDevice>(
+    dev: &D,
+    thresholds: &[Threshold],
+    settle: Duration,
+    timeout: Duration,
+) -> io::Result<bool> {
+    let handle = dev.handle();
+
+    // not all drivers support control events, polling the values works regardless
+    let subscribed: Vec<u32> = thresholds
+        .iter()
+        .map(|threshold| threshold.id)
+        .filter(|&id| handle.subscribe_event(event::Type::Ctrl, id).is_ok())
+        .collect();
+
+    let result = watch(&handle, thresholds, settle, timeout);
+
+    for id in subscribed {
+        let _ = handle.unsubscribe_event(event::Type::Ctrl, id);
+    }
+    result
+}
+
+fn watch(
+    handle: &Handle,
+    thresholds: &[Threshold],
+    settle: Duration,
+    timeout: Duration,
+) -> io::Result<bool> {
+    // values are sampled at least this often, even without events
+    const INTERVAL: Duration = Duration::from_millis(50);
+
+    let ids: Vec<u32> = thresholds.iter().map(|threshold| threshold.id).collect();
+    let start = Instant::now();
+    let mut last = handle.get_controls(&ids)?;
+    let mut stable_since = start;
+
+    loop {
+        let now = Instant::now();
+        if now.duration_since(stable_since) >= settle {
+            return Ok(true);
+        }
+        if now.duration_since(start) >= timeout {
+            return Ok(false);
+        }
+
+        let wait = INTERVAL.min(timeout - now.duration_since(start));
+        if handle
+            .poll(PollFlags::PRI, wait.as_millis() as i32)?
+            .contains(PollFlags::PRI)
+        {
+            while handle.dequeue_event().is_ok() {}
+        }
+
+        let values = handle.get_controls(&ids)?;
+        let moved = thresholds
+            .iter()
+            .zip(last.iter().zip(values.iter()))
+            .any(|(threshold, values)| match values {
+                (Value::Integer(old), Value::Integer(new)) => (new - old).abs() > threshold.delta,
+                (old, new) => old != new,
+            });
+        if moved {
+            // only compare against the value the control settled at
+            last = values;
+            stable_since = Instant::now();
+        }
+    }
+}
@@ -0,0 +1,219 @@
+//! Demosaicing of raw Bayer sensor data
+
+use super::unpack::Packing;
+
+/// Color components, as found in the color filter array
+const R: u8 = 0;
+const G: u8 = 1;
+const B: u8 = 2;
+
+/// Raw Bayer format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Bayer {
+    /// Color of the first two pixels of even and odd lines
+    pattern: [[u8; 2]; 2],
+    packing: Packing,
+}
+
+impl Bayer {
+    pub fn new(fourcc: &[u8; 4]) -> Option<Self> {
+        let bayer = |pattern: [u8; 4], packing| {
+            Some(Bayer {
+                pattern: [[pattern[0], pattern[1]], [pattern[2], pattern[3]]],
+                packing,
+            })
+        };
+        const BGGR: [u8; 4] = [B, G, G, R];
+        const GBRG: [u8; 4] = [G, B, R, G];
+        const GRBG: [u8; 4] = [G, R, B, G];
+        const RGGB: [u8; 4] = [R, G, G, B];
+        let bits10 = Packing::Lsb16 { depth: 10 };
+        let bits12 = Packing::Lsb16 { depth: 12 };
+
+        match fourcc {
+            b"BA81" => bayer(BGGR, Packing::Bits8),
+            b"GBRG" => bayer(GBRG, Packing::Bits8),
+            b"GRBG" => bayer(GRBG, Packing::Bits8),
+            b"RGGB" => bayer(RGGB, Packing::Bits8),
+            b"BG10" => bayer(BGGR, bits10),
+            b"GB10" => bayer(GBRG, bits10),
+            b"BA10" => bayer(GRBG, bits10),
+            b"RG10" => bayer(RGGB, bits10),
+            b"BG12" => bayer(BGGR, bits12),
+            b"GB12" => bayer(GBRG, bits12),
+            b"BA12" => bayer(GRBG, bits12),
+            b"RG12" => bayer(RGGB, bits12),
+            b"pBAA" => bayer(BGGR, Packing::Mipi10),
+            b"pGAA" => bayer(GBRG, Packing::Mipi10),
+            b"pgAA" => bayer(GRBG, Packing::Mipi10),
+            b"pRAA" => bayer(RGGB, Packing::Mipi10),
+            b"pBCC" => bayer(BGGR, Packing::Mipi12),
+            b"pGCC" => bayer(GBRG, Packing::Mipi12),
+            b"pgCC" => bayer(GRBG, Packing::Mipi12),
+            b"pRCC" => bayer(RGGB, Packing::Mipi12),
+            _ => None,
+        }
+    }
+
+    /// Returns the storage of the samples
+    pub fn packing(&self) -> Packing {
+        self.packing
+    }
+
+    /// Converts a line with bilinear interpolation
+    ///
+    /// Missing color components are averaged from the closest pixels of that color. Lines and
+    /// columns are mirrored at the borders of the frame, which keeps the color pattern intact.
+    /// Samples with more than 8 bits are scaled down after the interpolation.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - Whole frame
+    /// * `stride` - Bytes per line of the frame
+    /// * `(width, height)` - Size of the frame in pixels
+    /// * `row` - Line to convert
+    /// * `dst` - RGB line
+    pub fn convert_row(
+        &self,
+        src: &[u8],
+        stride: usize,
+        (width, height): (usize, usize),
+        row: usize,
+        dst: &mut [u8],
+    ) {
+        let line_size = self.packing.line_size(width);
+        let mut lines = [vec![0u16; width], vec![0u16; width], vec![0u16; width]];
+        for (line, offset) in lines.iter_mut().zip([-1, 0, 1]) {
+            let y = mirror(row as isize + offset, height);
+            self.packing
+                .unpack_line(&src[y * stride..][..line_size], line);
+        }
+        let [above, current, below] = &lines;
+        let pattern = self.pattern[row % 2];
+        let shift = self.packing.depth() - 8;
+        let scale = |x: u32| (x >> shift) as u8;
+        let avg2 = |a: u16, b: u16| scale((a as u32 + b as u32).div_ceil(2));
+        let avg4 = |a: u16, b: u16, c: u16, d: u16| {
+            scale((a as u32 + b as u32 + c as u32 + d as u32 + 2) / 4)
+        };
+
+        for x in 0..width {
+            let (left, right) = (mirror(x as isize - 1, width), mirror(x as isize + 1, width));
+            let cross = avg4(above[x], below[x], current[left], current[right]);
+            let diagonal = avg4(above[left], above[right], below[left], below[right]);
+            let horizontal = avg2(current[left], current[right]);
+            let vertical = avg2(above[x], below[x]);
+
+            let own = scale(current[x] as u32);
+            let px = match pattern[x % 2] {
+                R => [own, cross, diagonal],
+                B => [diagonal, cross, own],
+                // green pixel, the line also holds either red or blue ones
+                _ if pattern.contains(&R) => [horizontal, own, vertical],
+                _ => [vertical, own, horizontal],
+            };
+            dst[x * 3..x * 3 + 3].copy_from_slice(&px);
+        }
+    }
+}
+
+/// Mirrors an index at the borders of `0..len`
+fn mirror(i: isize, len: usize) -> usize {
+    if i < 0 {
+        1.min(len - 1)
+    } else if i as usize >= len {
+        len.saturating_sub(2)
+    } else {
+        i as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the RGB frame demosaiced from a raw one
+    fn demosaic(bayer: Bayer, src: &[u8], (width, height): (usize, usize)) -> Vec<u8> {
+        let stride = bayer.packing.line_size(width);
+        let mut rgb = vec![0; width * height * 3];
+        for (row, dst) in rgb.chunks_exact_mut(width * 3).enumerate() {
+            bayer.convert_row(src, stride, (width, height), row, dst);
+        }
+        rgb
+    }
+
+    /// Returns a raw frame of a single color, given as samples of the format's depth
+    fn flat(bayer: Bayer, color: [u16; 3], (width, height): (usize, usize)) -> Vec<u8> {
+        let stride = bayer.packing.line_size(width);
+        let mut frame = vec![0; stride * height];
+        for (y, line) in frame.chunks_exact_mut(stride).enumerate() {
+            let samples: Vec<_> = (0..width)
+                .map(|x| color[bayer.pattern[y % 2][x % 2] as usize])
+                .collect();
+            bayer.packing.pack_line(&samples, line);
+        }
+        frame
+    }
+
+    #[test]
+    fn interpolates_missing_components() {
+        let bayer = Bayer::new(b"RGGB").unwrap();
+        // R G
+        // G B
+        let rgb = demosaic(bayer, &[40, 80, 120, 200], (2, 2));
+        #[rustfmt::skip]
+        assert_eq!(rgb, [
+            40, 100, 200,   40, 80, 200,
+            40, 120, 200,   40, 100, 200,
+        ]);
+    }
+
+    #[test]
+    fn keeps_flat_colors_of_all_patterns() {
+        for fourcc in [b"BA81", b"GBRG", b"GRBG", b"RGGB"] {
+            let bayer = Bayer::new(fourcc).unwrap();
+            // odd sizes end the lines and the frame in the middle of the pattern
+            for size in [(2, 2), (3, 3), (5, 2)] {
+                let frame = flat(bayer, [200, 100, 50], size);
+                let rgb = demosaic(bayer, &frame, size);
+                assert!(
+                    rgb.chunks_exact(3).all(|px| px == [200, 100, 50]),
+                    "{:?} {:?}: {:?}",
+                    fourcc,
+                    size,
+                    rgb
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn repeats_the_only_sample_of_single_pixel_frames() {
+        let bayer = Bayer::new(b"BA81").unwrap();
+        assert_eq!(demosaic(bayer, &[50], (1, 1)), [50, 50, 50]);
+    }
+
+    #[test]
+    fn scales_down_deep_samples() {
+        for fourcc in [b"RG10", b"pRAA", b"RG12", b"pRCC"] {
+            let bayer = Bayer::new(fourcc).unwrap();
+            let shift = bayer.packing().depth() - 8;
+            let color = [200 << shift, 100 << shift, 50 << shift];
+            let frame = flat(bayer, color, (6, 2));
+            let rgb = demosaic(bayer, &frame, (6, 2));
+            assert!(
+                rgb.chunks_exact(3).all(|px| px == [200, 100, 50]),
+                "{:?}",
+                fourcc
+            );
+        }
+    }
+
+    #[test]
+    fn mirrors_at_the_borders() {
+        assert_eq!(mirror(-1, 4), 1);
+        assert_eq!(mirror(4, 4), 2);
+        assert_eq!(mirror(-1, 1), 0);
+        assert_eq!(mirror(1, 1), 0);
+    }
+}
@@ -0,0 +1,135 @@
+//! Cropping of frames in software
+
+use std::io;
+
+use crate::format::{Format, Info};
+use crate::rect::Rect;
+
+/// Returns the format of frames cropped by [`crop`], which are packed without padding
+///
+/// Packed formats and the planar YUV formats stored in a single buffer are supported, other
+/// formats fail with [`io::ErrorKind::Unsupported`]. The rectangle has to lie within the frame
+/// and has to be aligned to the chroma subsampling, e.g. to even coordinates and sizes for
+/// YUYV and NV12, otherwise [`io::ErrorKind::InvalidInput`] is returned.
+///
+/// # Arguments
+///
+/// * `format` - Format of the source frames
+/// * `rect` - Region to keep
+pub fn crop_format(format: &Format, rect: &Rect) -> io::Result<Format> {
+    let info = info(format, rect)?;
+    let (stride, size) = info.planes(rect.width, rect.height)[0];
+
+    let mut cropped = *format;
+    cropped.width = rect.width;
+    cropped.height = rect.height;
+    cropped.stride = stride;
+    cropped.size = size;
+    Ok(cropped)
+}
+
+/// Copies a region out of a frame
+///
+/// This complements the selection API for drivers which cannot crop in hardware. Returns the
+/// format of the cropped frame, see [`crop_format`].
+///
+/// # Arguments
+///
+/// * `src` - Source frame, e.g. a dequeued buffer
+/// * `format` - Format of the source frame
+/// * `rect` - Region to keep
+/// * `dst` - Buffer for the cropped frame, at least `size` bytes of the returned format
+///
+/// # Example
+///
+/// ```
+/// use v4l::convert::crop;
+/// use v4l::{Format, FourCC, Rect};
+///
+/// let format = Format::new(4, 2, FourCC::new(b"GREY"));
+/// let mut dst = [0u8; 4];
+/// let cropped = crop(&[0, 1, 2, 3, 4, 5, 6, 7], &format, &Rect::new(1, 0, 2, 2), &mut dst);
+/// assert_eq!(cropped.unwrap().width, 2);
+/// assert_eq!(dst, [1, 2, 5, 6]);
+/// ```
+pub fn crop(src: &[u8], format: &Format, rect: &Rect, dst: &mut [u8]) -> io::Result<Format> {
+    let info = info(format, rect)?;
+    let cropped = crop_format(format, rect)?;
+    if dst.len() < cropped.size as usize {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "destination buffer is smaller than the cropped frame",
+        ));
+    }
+
+    let stride = match format.stride as usize {
+        0 => format.width as usize * info.bpp[0] as usize,
+        stride => stride,
+    };
+    let (mut src_offset, mut dst_offset) = (0, 0);
+    for plane in 0..info.comp_planes {
+        let bpp = info.bpp[plane] as usize;
+        let (sub_x, sub_y) = match plane {
+            0 => (1, 1),
+            _ => (info.subsampling.0 as usize, info.subsampling.1 as usize),
+        };
+        // chroma planes have a stride proportional to the one of the luma plane
+        let src_stride = stride * bpp / (info.bpp[0] as usize * sub_x);
+        let src_size = src_stride * (format.height as usize).div_ceil(sub_y);
+        if src.len() < src_offset + src_size {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "source buffer is smaller than the format",
+            ));
+        }
+
+        let (left, top) = (rect.left as usize / sub_x, rect.top as usize / sub_y);
+        let line = (rect.width as usize).div_ceil(sub_x) * bpp;
+        let lines = (rect.height as usize).div_ceil(sub_y);
+        for y in 0..lines {
+            let from = src_offset + (top + y) * src_stride + left * bpp;
+            dst[dst_offset + y * line..][..line].copy_from_slice(&src[from..][..line]);
+        }
+
+        src_offset += src_size;
+        dst_offset += line * lines;
+    }
+
+    Ok(cropped)
+}
+
+/// Returns the layout of a supported format, after checking the rectangle
+fn info(format: &Format, rect: &Rect) -> io::Result<Info> {
+    let info = Info::new(format.fourcc)
+        .filter(|info| info.mem_planes == 1)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::Unsupported,
+                "no cropping for the pixel format",
+            )
+        })?;
+
+    let inside = rect.left >= 0
+        && rect.top >= 0
+        && rect.left as u64 + rect.width as u64 <= format.width as u64
+        && rect.top as u64 + rect.height as u64 <= format.height as u64;
+    if !inside {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "rectangle exceeds the frame",
+        ));
+    }
+
+    // a partial chroma sample cannot be copied
+    let (sub_x, sub_y) = info.subsampling;
+    let aligned = |offset: i32, size: u32, sub: u32| {
+        (offset as u32).is_multiple_of(sub) && size.is_multiple_of(sub)
+    };
+    if !aligned(rect.left, rect.width, sub_x) || !aligned(rect.top, rect.height, sub_y) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "rectangle is not aligned to the chroma subsampling",
+        ));
+    }
+    Ok(info)
+}
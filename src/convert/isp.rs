@@ -0,0 +1,173 @@
+//! Minimal software image signal processing
+//!
+//! Raw sensors often come with drivers which expose neither automatic white balance nor gamma
+//! correction. Together with the demosaicing of [`super::Converter`], the helpers in here turn
+//! raw frames into something presentable.
+
+/// Range of the gains computed by [`Gains::grey_world`]
+const GAIN_RANGE: (f32, f32) = (0.25, 4.0);
+
+/// White balance gains of the color components
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gains {
+    pub red: f32,
+    pub green: f32,
+    pub blue: f32,
+}
+
+impl Default for Gains {
+    fn default() -> Self {
+        Gains {
+            red: 1.0,
+            green: 1.0,
+            blue: 1.0,
+        }
+    }
+}
+
+impl Gains {
+    /// Returns the gains which balance an RGB frame with the grey-world assumption
+    ///
+    /// The average color of a scene is assumed to be grey, so red and blue are scaled to the
+    /// mean of the green component. Pixels with a saturated component are skipped, as their
+    /// color is not known. The gains are limited to 0.25 - 4.0, which keeps scenes dominated
+    /// by a single color from being distorted completely.
+    ///
+    /// # Arguments
+    ///
+    /// * `rgb` - Frame of packed 24 bit RGB (`RGB3`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::convert::Gains;
+    ///
+    /// let mut rgb = [50, 100, 200, 25, 50, 100];
+    /// let gains = Gains::grey_world(&rgb);
+    /// gains.apply(&mut rgb);
+    /// assert_eq!(rgb, [100, 100, 100, 50, 50, 50]);
+    /// ```
+    pub fn grey_world(rgb: &[u8]) -> Self {
+        let mut sums = [0u64; 3];
+        for px in rgb.chunks_exact(3) {
+            if px.contains(&u8::MAX) {
+                continue;
+            }
+            for (sum, &value) in sums.iter_mut().zip(px) {
+                *sum += value as u64;
+            }
+        }
+
+        if sums[1] == 0 {
+            return Gains::default();
+        }
+        let gain = |sum: u64| match sum {
+            0 => 1.0,
+            sum => (sums[1] as f32 / sum as f32).clamp(GAIN_RANGE.0, GAIN_RANGE.1),
+        };
+        Gains {
+            red: gain(sums[0]),
+            green: 1.0,
+            blue: gain(sums[2]),
+        }
+    }
+
+    /// Scales the color components of an RGB frame
+    ///
+    /// # Arguments
+    ///
+    /// * `rgb` - Frame of packed 24 bit RGB (`RGB3`)
+    pub fn apply(&self, rgb: &mut [u8]) {
+        let luts = [
+            Lut::gain(self.red),
+            Lut::gain(self.green),
+            Lut::gain(self.blue),
+        ];
+        for px in rgb.chunks_exact_mut(3) {
+            for (value, lut) in px.iter_mut().zip(&luts) {
+                *value = lut.table[*value as usize];
+            }
+        }
+    }
+}
+
+/// Lookup table mapping 8 bit values
+///
+/// Works for any frame of 8 bit samples, no matter whether raw, YUV or RGB. Tables are
+/// combined with [`Lut::then`], so gamma and contrast are applied in a single pass.
+///
+/// # Example
+///
+/// ```
+/// use v4l::convert::Lut;
+///
+/// let lut = Lut::contrast(1.2, 10).then(&Lut::gamma(2.2));
+/// let mut frame = vec![0u8, 64, 128, 255];
+/// lut.apply(&mut frame);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Lut {
+    pub table: [u8; 256],
+}
+
+impl Default for Lut {
+    fn default() -> Self {
+        Lut::identity()
+    }
+}
+
+impl Lut {
+    /// Returns a table which maps every value onto itself
+    pub fn identity() -> Self {
+        Lut::from_fn(|x| x)
+    }
+
+    /// Returns a gamma correction table
+    ///
+    /// # Arguments
+    ///
+    /// * `gamma` - Display gamma, e.g. 2.2 for sRGB; values above 1.0 brighten the midtones
+    pub fn gamma(gamma: f32) -> Self {
+        Lut::from_fn(|x| x.powf(1.0 / gamma))
+    }
+
+    /// Returns a table which adjusts contrast and brightness
+    ///
+    /// # Arguments
+    ///
+    /// * `contrast` - Factor of the distance to the midpoint, 1.0 keeps the contrast
+    /// * `brightness` - Offset added to all values, 0 keeps the brightness
+    pub fn contrast(contrast: f32, brightness: i32) -> Self {
+        Lut::from_fn(|x| (x - 0.5) * contrast + 0.5 + brightness as f32 / 255.0)
+    }
+
+    /// Returns a table which applies `self` and then `next`
+    pub fn then(&self, next: &Lut) -> Self {
+        let mut table = [0u8; 256];
+        for (out, &value) in table.iter_mut().zip(self.table.iter()) {
+            *out = next.table[value as usize];
+        }
+        Lut { table }
+    }
+
+    /// Maps all values of a buffer in place
+    pub fn apply(&self, buf: &mut [u8]) {
+        for value in buf {
+            *value = self.table[*value as usize];
+        }
+    }
+
+    /// Returns a table which multiplies values by a factor
+    fn gain(gain: f32) -> Self {
+        Lut::from_fn(|x| x * gain)
+    }
+
+    /// Builds a table from a function on values normalized to 0.0 - 1.0
+    fn from_fn(f: impl Fn(f32) -> f32) -> Self {
+        let mut table = [0u8; 256];
+        for (i, out) in table.iter_mut().enumerate() {
+            *out = (f(i as f32 / 255.0) * 255.0).round().clamp(0.0, 255.0) as u8;
+        }
+        Lut { table }
+    }
+}
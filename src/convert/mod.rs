@@ -0,0 +1,281 @@
+//! Pixel format conversion
+//!
+//! Cameras usually deliver YUV formats, while most consumers (GUI toolkits, image codecs,
+//! machine learning models) expect RGB. The converter in here turns the common capture formats
+//! into packed 24 bit RGB (`RGB3`). The hot loops use SSE2 on x86 and NEON on ARM if the CPU
+//! supports them, the scalar code is used otherwise and for the pixels at the end of a line.
+//!
+//! Colors are converted with the BT.601 coefficients for limited range YUV, which is what
+//! the vast majority of webcams produce. Raw Bayer frames of image sensors are demosaiced with
+//! bilinear interpolation. [`Gains`] and [`Lut`] complete a minimal software ISP for sensors
+//! without hardware white balance, [`Transform`] rotates and flips frames and [`Scaler`]
+//! resizes them for previews. [`crop`] copies a region of a frame for drivers which cannot crop
+//! in hardware.
+
+use std::io;
+
+use crate::format::{Format, FourCC};
+
+use bayer::Bayer;
+
+mod bayer;
+mod crop;
+mod isp;
+#[cfg(target_arch = "aarch64")]
+mod neon;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod scale;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod sse2;
+mod transform;
+mod unpack;
+
+pub use crop::{crop, crop_format};
+pub use isp::{Gains, Lut};
+#[cfg(feature = "rayon")]
+pub use parallel::ParallelConverter;
+pub use scale::{Filter, Scaler};
+pub use transform::{Rotation, Transform};
+pub use unpack::Unpacker;
+
+/// Source pixel formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Source {
+    /// Packed 4:2:2, Y0 U Y1 V
+    Yuyv,
+    /// Semi-planar 4:2:0, a luma plane followed by a plane of interleaved U and V samples
+    Nv12,
+    /// Raw sensor data behind a color filter array
+    Bayer(Bayer),
+}
+
+/// Instruction set used for the conversion
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Isa {
+    Scalar,
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    Sse2,
+    #[cfg(target_arch = "aarch64")]
+    Neon,
+}
+
+impl Isa {
+    fn detect() -> Self {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        if is_x86_feature_detected!("sse2") {
+            return Isa::Sse2;
+        }
+        #[cfg(target_arch = "aarch64")]
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            return Isa::Neon;
+        }
+        Isa::Scalar
+    }
+}
+
+/// Converter of frames into packed 24 bit RGB
+///
+/// # Example
+///
+/// ```
+/// use v4l::convert::Converter;
+/// use v4l::{Format, FourCC};
+///
+/// let format = Format::new(2, 1, FourCC::new(b"YUYV"));
+/// let converter = Converter::new(&format).unwrap();
+/// let mut rgb = vec![0u8; converter.rgb_size()];
+/// converter.convert(&[235, 128, 16, 128], &mut rgb).unwrap();
+/// assert_eq!(rgb, [255, 255, 255, 0, 0, 0]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Converter {
+    source: Source,
+    width: usize,
+    height: usize,
+    stride: usize,
+    isa: Isa,
+}
+
+impl Converter {
+    /// Returns a converter for frames of the given format
+    ///
+    /// YUYV, NV12 and the 8, 10 and 12 bit Bayer formats (unpacked and MIPI packed) are
+    /// supported, other formats fail with [`io::ErrorKind::Unsupported`].
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - Format of the frames to convert
+    pub fn new(format: &Format) -> io::Result<Self> {
+        let source = match &format.fourcc.repr {
+            b"YUYV" => Source::Yuyv,
+            b"NV12" => Source::Nv12,
+            fourcc => match Bayer::new(fourcc) {
+                Some(bayer) => Source::Bayer(bayer),
+                None => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::Unsupported,
+                        "no RGB conversion for the pixel format",
+                    ))
+                }
+            },
+        };
+
+        let width = format.width as usize;
+        let line = match source {
+            Source::Yuyv => width * 2,
+            Source::Nv12 => width,
+            Source::Bayer(bayer) => bayer.packing().line_size(width),
+        };
+        let stride = match format.stride as usize {
+            0 => line,
+            stride => stride,
+        };
+
+        Ok(Converter {
+            source,
+            width,
+            height: format.height as usize,
+            stride,
+            isa: Isa::detect(),
+        })
+    }
+
+    /// Returns the format of the converted frames
+    pub fn rgb_format(&self) -> Format {
+        let mut format = Format::new(self.width as u32, self.height as u32, FourCC::new(b"RGB3"));
+        format.stride = self.width as u32 * 3;
+        format.size = self.rgb_size() as u32;
+        format
+    }
+
+    /// Returns the number of bytes of a converted frame
+    pub fn rgb_size(&self) -> usize {
+        self.width * self.height * 3
+    }
+
+    /// Converts a frame
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - Frame in the source format
+    /// * `dst` - Buffer for the RGB frame, at least [`Converter::rgb_size`] bytes
+    pub fn convert(&self, src: &[u8], dst: &mut [u8]) -> io::Result<()> {
+        self.check(src, dst)?;
+        self.convert_rows(src, &mut dst[..self.rgb_size()], 0);
+        Ok(())
+    }
+
+    /// Verifies that the buffers are large enough for a frame
+    pub(crate) fn check(&self, src: &[u8], dst: &[u8]) -> io::Result<()> {
+        if src.len() < self.src_size() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "source buffer is smaller than the format",
+            ));
+        }
+        if dst.len() < self.rgb_size() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "destination buffer is smaller than the RGB frame",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the minimum size of a source frame, the last line may lack its padding
+    fn src_size(&self) -> usize {
+        if self.height == 0 {
+            return 0;
+        }
+        match self.source {
+            Source::Yuyv => self.stride * (self.height - 1) + self.width * 2,
+            Source::Nv12 => self.stride * (self.height + self.height.div_ceil(2) - 1) + self.width,
+            Source::Bayer(bayer) => {
+                self.stride * (self.height - 1) + bayer.packing().line_size(self.width)
+            }
+        }
+    }
+
+    /// Converts consecutive lines, `dst` holds the RGB lines starting at `first`
+    ///
+    /// The source buffer must have been checked before.
+    pub(crate) fn convert_rows(&self, src: &[u8], dst: &mut [u8], first: usize) {
+        let (width, stride) = (self.width, self.stride);
+        if width == 0 {
+            return;
+        }
+        for (i, rgb) in dst.chunks_exact_mut(width * 3).enumerate() {
+            let row = first + i;
+            match self.source {
+                Source::Yuyv => {
+                    let yuyv = &src[row * stride..][..width * 2];
+                    yuyv_row(self.isa, yuyv, rgb, width);
+                }
+                Source::Nv12 => {
+                    let y = &src[row * stride..][..width];
+                    let uv = &src[(self.height + row / 2) * stride..][..width];
+                    nv12_row(self.isa, y, uv, rgb, width);
+                }
+                Source::Bayer(bayer) => {
+                    bayer.convert_row(src, stride, (width, self.height), row, rgb);
+                }
+            }
+        }
+    }
+}
+
+fn yuyv_row(isa: Isa, src: &[u8], dst: &mut [u8], width: usize) {
+    match isa {
+        Isa::Scalar => yuyv_row_scalar(src, dst, width),
+        // the instruction set was detected at runtime
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        Isa::Sse2 => unsafe { sse2::yuyv_row(src, dst, width) },
+        #[cfg(target_arch = "aarch64")]
+        Isa::Neon => unsafe { neon::yuyv_row(src, dst, width) },
+    }
+}
+
+fn nv12_row(isa: Isa, y: &[u8], uv: &[u8], dst: &mut [u8], width: usize) {
+    match isa {
+        Isa::Scalar => nv12_row_scalar(y, uv, dst, width),
+        // the instruction set was detected at runtime
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        Isa::Sse2 => unsafe { sse2::nv12_row(y, uv, dst, width) },
+        #[cfg(target_arch = "aarch64")]
+        Isa::Neon => unsafe { neon::nv12_row(y, uv, dst, width) },
+    }
+}
+
+/// Converts a line of YUYV pixels, starting at an even pixel
+fn yuyv_row_scalar(src: &[u8], dst: &mut [u8], width: usize) {
+    for x in 0..width {
+        let (u, v) = (src[x / 2 * 4 + 1], src[x / 2 * 4 + 3]);
+        dst[x * 3..x * 3 + 3].copy_from_slice(&rgb(src[x * 2], u, v));
+    }
+}
+
+/// Converts a line of NV12 pixels, starting at an even pixel
+fn nv12_row_scalar(y: &[u8], uv: &[u8], dst: &mut [u8], width: usize) {
+    for x in 0..width {
+        let (u, v) = (uv[x / 2 * 2], uv[x / 2 * 2 + 1]);
+        dst[x * 3..x * 3 + 3].copy_from_slice(&rgb(y[x], u, v));
+    }
+}
+
+/// Converts a single pixel
+///
+/// The SIMD implementations compute exactly the same values.
+#[inline]
+fn rgb(y: u8, u: u8, v: u8) -> [u8; 3] {
+    let c = 298 * (y as i32 - 16);
+    let d = u as i32 - 128;
+    let e = v as i32 - 128;
+    let clamp = |x: i32| ((x + 128) >> 8).clamp(0, 255) as u8;
+
+    [
+        clamp(c + 409 * e),
+        clamp(c - 100 * d - 208 * e),
+        clamp(c + 516 * d),
+    ]
+}
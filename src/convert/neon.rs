@@ -0,0 +1,103 @@
+//! NEON implementation, 16 pixels per iteration
+
+use std::arch::aarch64::*;
+
+/// Converts a line of YUYV pixels
+///
+/// # Safety
+///
+/// The CPU has to support NEON.
+#[target_feature(enable = "neon")]
+pub unsafe fn yuyv_row(src: &[u8], dst: &mut [u8], width: usize) {
+    assert!(src.len() >= width * 2 && dst.len() >= width * 3);
+
+    let mut x = 0;
+    while x + 16 <= width {
+        // deinterleaves into even luma, U, odd luma and V samples
+        let px = vld4_u8(src.as_ptr().add(x * 2));
+        store(px.0, px.2, px.1, px.3, dst.as_mut_ptr().add(x * 3));
+        x += 16;
+    }
+
+    super::yuyv_row_scalar(&src[x * 2..], &mut dst[x * 3..], width - x);
+}
+
+/// Converts a line of NV12 pixels
+///
+/// # Safety
+///
+/// The CPU has to support NEON.
+#[target_feature(enable = "neon")]
+pub unsafe fn nv12_row(y: &[u8], uv: &[u8], dst: &mut [u8], width: usize) {
+    assert!(y.len() >= width && uv.len() >= width && dst.len() >= width * 3);
+
+    let mut x = 0;
+    while x + 16 <= width {
+        let luma = vld2_u8(y.as_ptr().add(x));
+        let chroma = vld2_u8(uv.as_ptr().add(x));
+        store(luma.0, luma.1, chroma.0, chroma.1, dst.as_mut_ptr().add(x * 3));
+        x += 16;
+    }
+
+    super::nv12_row_scalar(&y[x..], &uv[x..], &mut dst[x * 3..], width - x);
+}
+
+/// Converts 16 pixels and stores them as RGB
+///
+/// Even and odd pixels are passed separately, as they share the chroma samples.
+#[inline]
+#[target_feature(enable = "neon")]
+unsafe fn store(even: uint8x8_t, odd: uint8x8_t, u: uint8x8_t, v: uint8x8_t, dst: *mut u8) {
+    let d = vreinterpretq_s16_u16(vsubl_u8(u, vdup_n_u8(128)));
+    let e = vreinterpretq_s16_u16(vsubl_u8(v, vdup_n_u8(128)));
+
+    let even = rgb(even, d, e);
+    let odd = rgb(odd, d, e);
+    vst3q_u8(
+        dst,
+        uint8x16x3_t(zip(even.0, odd.0), zip(even.1, odd.1), zip(even.2, odd.2)),
+    );
+}
+
+/// Interleaves the values of even and odd pixels
+#[inline]
+#[target_feature(enable = "neon")]
+unsafe fn zip(even: uint8x8_t, odd: uint8x8_t) -> uint8x16_t {
+    let z = vzip_u8(even, odd);
+    vcombine_u8(z.0, z.1)
+}
+
+/// Converts 8 pixels into separate R, G and B vectors
+#[inline]
+#[target_feature(enable = "neon")]
+unsafe fn rgb(y: uint8x8_t, d: int16x8_t, e: int16x8_t) -> uint8x8x3_t {
+    // wraps around for values below 16, which yields the correct negative value as i16
+    let c = vreinterpretq_s16_u16(vsubl_u8(y, vdup_n_u8(16)));
+    let (c_lo, c_hi) = (vget_low_s16(c), vget_high_s16(c));
+    let (d_lo, d_hi) = (vget_low_s16(d), vget_high_s16(d));
+    let (e_lo, e_hi) = (vget_low_s16(e), vget_high_s16(e));
+
+    let r = narrow(
+        vmlal_n_s16(vmull_n_s16(c_lo, 298), e_lo, 409),
+        vmlal_n_s16(vmull_n_s16(c_hi, 298), e_hi, 409),
+    );
+    let g = narrow(
+        vmlsl_n_s16(vmlsl_n_s16(vmull_n_s16(c_lo, 298), d_lo, 100), e_lo, 208),
+        vmlsl_n_s16(vmlsl_n_s16(vmull_n_s16(c_hi, 298), d_hi, 100), e_hi, 208),
+    );
+    let b = narrow(
+        vmlal_n_s16(vmull_n_s16(c_lo, 298), d_lo, 516),
+        vmlal_n_s16(vmull_n_s16(c_hi, 298), d_hi, 516),
+    );
+    uint8x8x3_t(r, g, b)
+}
+
+/// Rounds and scales 32 bit values back down, saturating to 8 bits
+#[inline]
+#[target_feature(enable = "neon")]
+unsafe fn narrow(lo: int32x4_t, hi: int32x4_t) -> uint8x8_t {
+    vqmovn_u16(vcombine_u16(
+        vqrshrun_n_s32::<8>(lo),
+        vqrshrun_n_s32::<8>(hi),
+    ))
+}
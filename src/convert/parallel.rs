@@ -0,0 +1,80 @@
+//! Multi-threaded conversion
+
+use std::io;
+
+use rayon::prelude::*;
+
+use super::Converter;
+use crate::format::Format;
+
+/// Lines converted by a thread at once
+const TILE_ROWS: usize = 16;
+
+/// Converter of frames into packed 24 bit RGB, spreading the work over the rayon thread pool
+///
+/// The frame is split into tiles of consecutive lines which are converted in parallel. This
+/// pays off for large frames (e.g. 4K), for small ones the synchronization overhead dominates
+/// and [`Converter`] is the better choice. Both produce the same output.
+///
+/// # Example
+///
+/// ```
+/// use v4l::convert::ParallelConverter;
+/// use v4l::{Format, FourCC};
+///
+/// let format = Format::new(3840, 2160, FourCC::new(b"NV12"));
+/// let converter = ParallelConverter::new(&format).unwrap();
+/// let nv12 = vec![128u8; 3840 * 2160 * 3 / 2];
+/// let mut rgb = vec![0u8; converter.rgb_size()];
+/// converter.convert(&nv12, &mut rgb).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ParallelConverter {
+    inner: Converter,
+}
+
+impl ParallelConverter {
+    /// Returns a converter for frames of the given format
+    ///
+    /// Supports the same formats as [`Converter::new`], other formats fail with
+    /// [`io::ErrorKind::Unsupported`].
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - Format of the frames to convert
+    pub fn new(format: &Format) -> io::Result<Self> {
+        Ok(ParallelConverter {
+            inner: Converter::new(format)?,
+        })
+    }
+
+    /// Returns the format of the converted frames
+    pub fn rgb_format(&self) -> Format {
+        self.inner.rgb_format()
+    }
+
+    /// Returns the number of bytes of a converted frame
+    pub fn rgb_size(&self) -> usize {
+        self.inner.rgb_size()
+    }
+
+    /// Converts a frame
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - Frame in the source format
+    /// * `dst` - Buffer for the RGB frame, at least [`ParallelConverter::rgb_size`] bytes
+    pub fn convert(&self, src: &[u8], dst: &mut [u8]) -> io::Result<()> {
+        self.inner.check(src, dst)?;
+        if self.inner.width == 0 {
+            return Ok(());
+        }
+
+        let tile = self.inner.width * 3 * TILE_ROWS;
+        dst[..self.rgb_size()]
+            .par_chunks_mut(tile)
+            .enumerate()
+            .for_each(|(i, dst)| self.inner.convert_rows(src, dst, i * TILE_ROWS));
+        Ok(())
+    }
+}
@@ -0,0 +1,351 @@
+//! Resizing of frames
+
+use std::io;
+
+use crate::format::{Format, Info};
+
+/// Interpolation between source pixels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Filter {
+    /// Picks the closest source pixel, fastest
+    Nearest,
+    /// Blends the four closest source pixels
+    Bilinear,
+}
+
+/// Source position of an output pixel along one axis
+#[derive(Debug, Clone, Copy)]
+struct Tap {
+    first: usize,
+    second: usize,
+    /// Weight of the second pixel, out of 256
+    weight: u32,
+}
+
+/// Returns the taps for scaling `src` pixels to `dst` pixels
+///
+/// Pixel centers are aligned, so the image neither shifts nor loses its border pixels.
+fn taps(src: usize, dst: usize, filter: Filter) -> Vec<Tap> {
+    (0..dst)
+        .map(|i| {
+            let center = (i as f64 + 0.5) * src as f64 / dst as f64;
+            match filter {
+                Filter::Nearest => {
+                    let first = (center as usize).min(src - 1);
+                    Tap {
+                        first,
+                        second: first,
+                        weight: 0,
+                    }
+                }
+                Filter::Bilinear => {
+                    let pos = (center - 0.5).clamp(0.0, (src - 1) as f64);
+                    let first = pos as usize;
+                    Tap {
+                        first,
+                        second: (first + 1).min(src - 1),
+                        weight: ((pos - first as f64) * 256.0) as u32,
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+/// Layout and taps of a plane
+#[derive(Debug, Clone)]
+struct Plane {
+    /// Bytes per element, e.g. a pixel or a pair of chroma samples
+    bpp: usize,
+    src_stride: usize,
+    src_height: usize,
+    x: Vec<Tap>,
+    y: Vec<Tap>,
+}
+
+/// Scaler of YUV and RGB frames
+///
+/// Packed formats and the planar YUV formats stored in a single buffer are supported. Every
+/// plane is scaled on its own, packed 4:2:2 formats such as YUYV in macropixels of two pixels.
+/// Only the closest source pixels are taken into account, which makes the scaler fast enough
+/// for previews but causes aliasing when shrinking by more than a factor of two.
+///
+/// # Example
+///
+/// ```
+/// use v4l::convert::{Filter, Scaler};
+/// use v4l::{Format, FourCC};
+///
+/// let format = Format::new(3840, 2160, FourCC::new(b"NV12"));
+/// let scaler = Scaler::new(&format, 640, 360, Filter::Bilinear).unwrap();
+/// let nv12 = vec![128u8; 3840 * 2160 * 3 / 2];
+/// let mut preview = vec![0u8; scaler.format().size as usize];
+/// scaler.scale(&nv12, &mut preview).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Scaler {
+    filter: Filter,
+    format: Format,
+    planes: Vec<Plane>,
+}
+
+impl Scaler {
+    /// Returns a scaler for frames of the given format
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - Format of the source frames, of even width for packed 4:2:2 formats
+    /// * `width` - Width of the scaled frames, even for packed 4:2:2 formats
+    /// * `height` - Height of the scaled frames
+    /// * `filter` - Interpolation between source pixels
+    pub fn new(format: &Format, width: u32, height: u32, filter: Filter) -> io::Result<Self> {
+        let info = Info::new(format.fourcc)
+            .filter(|info| info.mem_planes == 1)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "no scaling for the pixel format",
+                )
+            })?;
+        // packed 4:2:2 formats are scaled in macropixels
+        let macropixels = info.comp_planes == 1 && info.subsampling.0 == 2;
+        if format.width == 0 || format.height == 0 || width == 0 || height == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "cannot scale from or to an empty frame",
+            ));
+        }
+        if macropixels && (format.width % 2 == 1 || width % 2 == 1) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "widths have to be even for packed 4:2:2 formats",
+            ));
+        }
+
+        let (src_width, src_height) = (format.width as usize, format.height as usize);
+        let stride = match format.stride as usize {
+            0 => src_width * info.bpp[0] as usize,
+            stride => stride,
+        };
+        let planes = (0..info.comp_planes)
+            .map(|plane| {
+                let mut bpp = info.bpp[plane] as usize;
+                let (sub_x, sub_y) = match plane {
+                    0 if macropixels => (2, 1),
+                    0 => (1, 1),
+                    _ => (info.subsampling.0 as usize, info.subsampling.1 as usize),
+                };
+                if macropixels {
+                    bpp *= 2;
+                }
+                let src_width = src_width.div_ceil(sub_x);
+                let src_height = src_height.div_ceil(sub_y);
+                Plane {
+                    bpp,
+                    // chroma planes have a stride proportional to the one of the luma plane, but
+                    // need room for the chroma samples of the last column of odd widths
+                    src_stride: match plane {
+                        0 => stride,
+                        _ => (stride * bpp / (info.bpp[0] as usize * sub_x)).max(src_width * bpp),
+                    },
+                    src_height,
+                    x: taps(src_width, (width as usize).div_ceil(sub_x), filter),
+                    y: taps(src_height, (height as usize).div_ceil(sub_y), filter),
+                }
+            })
+            .collect();
+
+        let (dst_stride, dst_size) = info.planes(width, height)[0];
+        let mut scaled = *format;
+        scaled.width = width;
+        scaled.height = height;
+        scaled.stride = dst_stride;
+        scaled.size = dst_size;
+
+        Ok(Scaler {
+            filter,
+            format: scaled,
+            planes,
+        })
+    }
+
+    /// Returns the format of the scaled frames, which are packed without padding
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Scales a frame
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - Frame in the source format
+    /// * `dst` - Buffer for the scaled frame, at least `size` bytes of [`Scaler::format`]
+    pub fn scale(&self, src: &[u8], dst: &mut [u8]) -> io::Result<()> {
+        let src_size: usize = self
+            .planes
+            .iter()
+            .map(|plane| plane.src_stride * plane.src_height)
+            .sum();
+        if src.len() < src_size {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "source buffer is smaller than the format",
+            ));
+        }
+        if dst.len() < self.format.size as usize {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "destination buffer is smaller than the scaled frame",
+            ));
+        }
+
+        let (mut src, mut dst) = (src, dst);
+        for plane in &self.planes {
+            let dst_stride = plane.x.len() * plane.bpp;
+            let (src_plane, src_rest) = src.split_at(plane.src_stride * plane.src_height);
+            let (dst_plane, dst_rest) = dst.split_at_mut(dst_stride * plane.y.len());
+            self.plane(plane, src_plane, dst_plane);
+            src = src_rest;
+            dst = dst_rest;
+        }
+        Ok(())
+    }
+
+    fn plane(&self, plane: &Plane, src: &[u8], dst: &mut [u8]) {
+        let bpp = plane.bpp;
+        let dst_stride = plane.x.len() * bpp;
+        for (ty, line) in plane.y.iter().zip(dst.chunks_exact_mut(dst_stride)) {
+            let first = &src[ty.first * plane.src_stride..];
+            let second = &src[ty.second * plane.src_stride..];
+            for (tx, px) in plane.x.iter().zip(line.chunks_exact_mut(bpp)) {
+                let (a, b) = (tx.first * bpp, tx.second * bpp);
+                match self.filter {
+                    Filter::Nearest => px.copy_from_slice(&first[a..a + bpp]),
+                    Filter::Bilinear => {
+                        for (i, value) in px.iter_mut().enumerate() {
+                            let lerp = |line: &[u8]| {
+                                line[a + i] as u32 * (256 - tx.weight)
+                                    + line[b + i] as u32 * tx.weight
+                            };
+                            let sum = lerp(first) * (256 - ty.weight) + lerp(second) * ty.weight;
+                            *value = ((sum + (1 << 15)) >> 16) as u8;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FourCC;
+
+    /// Scales a frame without line padding
+    fn scale(
+        fourcc: &[u8; 4],
+        (width, height): (u32, u32),
+        src: &[u8],
+        size: (u32, u32),
+        filter: Filter,
+    ) -> Vec<u8> {
+        let format = Format::new(width, height, FourCC::new(fourcc));
+        let scaler = Scaler::new(&format, size.0, size.1, filter).unwrap();
+        let mut dst = vec![0; scaler.format().size as usize];
+        scaler.scale(src, &mut dst).unwrap();
+        dst
+    }
+
+    #[test]
+    fn picks_the_closest_pixels() {
+        let line = [10, 20, 30];
+        assert_eq!(
+            scale(b"GREY", (3, 1), &line, (5, 1), Filter::Nearest),
+            [10, 10, 20, 30, 30]
+        );
+        assert_eq!(scale(b"GREY", (3, 1), &line, (1, 1), Filter::Nearest), [20]);
+        assert_eq!(
+            scale(b"GREY", (1, 3), &line, (1, 2), Filter::Nearest),
+            [10, 30]
+        );
+    }
+
+    #[test]
+    fn blends_neighboring_pixels() {
+        assert_eq!(
+            scale(b"GREY", (2, 1), &[0, 255], (3, 1), Filter::Bilinear),
+            [0, 128, 255]
+        );
+        // shrinking an odd width hits the center pixel exactly
+        assert_eq!(
+            scale(b"GREY", (3, 1), &[10, 20, 30], (1, 1), Filter::Bilinear),
+            [20]
+        );
+        #[rustfmt::skip]
+        let frame = [
+            0, 0, 0,
+            0, 90, 0,
+            0, 0, 0,
+        ];
+        assert_eq!(
+            scale(b"GREY", (3, 3), &frame, (1, 1), Filter::Bilinear),
+            [90]
+        );
+    }
+
+    #[test]
+    fn scales_single_pixels() {
+        for filter in [Filter::Nearest, Filter::Bilinear] {
+            let rgb = scale(b"RGB3", (1, 1), &[1, 2, 3], (3, 2), filter);
+            assert_eq!(rgb, [1, 2, 3].repeat(6));
+            assert_eq!(scale(b"RGB3", (3, 2), &rgb, (1, 1), filter), [1, 2, 3]);
+        }
+    }
+
+    #[test]
+    fn keeps_odd_sized_planar_frames() {
+        for (fourcc, chroma) in [(b"NV12", 8), (b"YU12", 8), (b"NV16", 12), (b"422P", 12)] {
+            // 3x3 luma samples followed by the chroma planes of 2 samples (pairs) per line
+            let frame: Vec<u8> = (0..9 + chroma).collect();
+            for filter in [Filter::Nearest, Filter::Bilinear] {
+                let scaled = scale(fourcc, (3, 3), &frame, (3, 3), filter);
+                assert_eq!(scaled, frame, "{:?} {:?}", fourcc, filter);
+            }
+        }
+    }
+
+    #[test]
+    fn scales_packed_422_in_macropixels() {
+        let yuyv = [16, 128, 32, 64, 48, 200, 64, 100];
+        let scaled = scale(b"YUYV", (4, 1), &yuyv, (2, 1), Filter::Nearest);
+        assert_eq!(scaled, [48, 200, 64, 100]);
+        let scaled = scale(b"YUYV", (4, 1), &yuyv, (6, 1), Filter::Nearest);
+        assert_eq!(
+            scaled,
+            [16, 128, 32, 64, 48, 200, 64, 100, 48, 200, 64, 100]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_sizes() {
+        let yuyv = Format::new(4, 2, FourCC::new(b"YUYV"));
+        let err = Scaler::new(&yuyv, 3, 2, Filter::Nearest).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        let odd = Format::new(3, 2, FourCC::new(b"YUYV"));
+        let err = Scaler::new(&odd, 2, 2, Filter::Nearest).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        let err = Scaler::new(&yuyv, 0, 2, Filter::Nearest).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+        let mjpg = Format::new(4, 2, FourCC::new(b"MJPG"));
+        let err = Scaler::new(&mjpg, 2, 2, Filter::Nearest).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+
+        let scaler = Scaler::new(&yuyv, 2, 2, Filter::Nearest).unwrap();
+        let err = scaler.scale(&[0; 15], &mut [0; 8]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        let err = scaler.scale(&[0; 16], &mut [0; 7]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,111 @@
+//! SSE2 implementation, 8 pixels per iteration
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Converts a line of YUYV pixels
+///
+/// # Safety
+///
+/// The CPU has to support SSE2.
+#[target_feature(enable = "sse2")]
+pub unsafe fn yuyv_row(src: &[u8], dst: &mut [u8], width: usize) {
+    assert!(src.len() >= width * 2 && dst.len() >= width * 3);
+
+    let mut x = 0;
+    while x + 8 <= width {
+        let px = _mm_loadu_si128(src.as_ptr().add(x * 2) as *const __m128i);
+        // luma in the even bytes, alternating U and V in the odd ones
+        let y = _mm_and_si128(px, _mm_set1_epi16(0xff));
+        let uv = _mm_srli_epi16(px, 8);
+        store(y, uv, &mut dst[x * 3..x * 3 + 24]);
+        x += 8;
+    }
+
+    super::yuyv_row_scalar(&src[x * 2..], &mut dst[x * 3..], width - x);
+}
+
+/// Converts a line of NV12 pixels
+///
+/// # Safety
+///
+/// The CPU has to support SSE2.
+#[target_feature(enable = "sse2")]
+pub unsafe fn nv12_row(y: &[u8], uv: &[u8], dst: &mut [u8], width: usize) {
+    assert!(y.len() >= width && uv.len() >= width && dst.len() >= width * 3);
+
+    let zero = _mm_setzero_si128();
+    let mut x = 0;
+    while x + 8 <= width {
+        let luma = _mm_loadl_epi64(y.as_ptr().add(x) as *const __m128i);
+        let chroma = _mm_loadl_epi64(uv.as_ptr().add(x) as *const __m128i);
+        store(
+            _mm_unpacklo_epi8(luma, zero),
+            _mm_unpacklo_epi8(chroma, zero),
+            &mut dst[x * 3..x * 3 + 24],
+        );
+        x += 8;
+    }
+
+    super::nv12_row_scalar(&y[x..], &uv[x..], &mut dst[x * 3..], width - x);
+}
+
+/// Converts 8 pixels and stores them as RGB
+///
+/// `y` holds 8 luma samples, `uv` 4 pairs of U and V samples, each widened to 16 bits.
+#[inline]
+#[target_feature(enable = "sse2")]
+unsafe fn store(y: __m128i, uv: __m128i, dst: &mut [u8]) {
+    // every chroma sample is shared by two neighboring pixels
+    let u = _mm_shufflehi_epi16(_mm_shufflelo_epi16(uv, 0xa0), 0xa0);
+    let v = _mm_shufflehi_epi16(_mm_shufflelo_epi16(uv, 0xf5), 0xf5);
+
+    let c = _mm_sub_epi16(y, _mm_set1_epi16(16));
+    let d = _mm_sub_epi16(u, _mm_set1_epi16(128));
+    let e = _mm_sub_epi16(v, _mm_set1_epi16(128));
+    let zero = _mm_setzero_si128();
+
+    let r = narrow(dot(c, e, 298, 409));
+    let (g_cd, g_e) = (dot(c, d, 298, -100), dot(e, zero, -208, 0));
+    let g = narrow((
+        _mm_add_epi32(g_cd.0, g_e.0),
+        _mm_add_epi32(g_cd.1, g_e.1),
+    ));
+    let b = narrow(dot(c, d, 298, 516));
+
+    // saturate to 8 bits, R and G share a register
+    let mut rg = [0u8; 16];
+    let mut bb = [0u8; 16];
+    _mm_storeu_si128(rg.as_mut_ptr() as *mut __m128i, _mm_packus_epi16(r, g));
+    _mm_storeu_si128(bb.as_mut_ptr() as *mut __m128i, _mm_packus_epi16(b, zero));
+
+    for (i, px) in dst.chunks_exact_mut(3).enumerate() {
+        px[0] = rg[i];
+        px[1] = rg[i + 8];
+        px[2] = bb[i];
+    }
+}
+
+/// Returns `a * ka + b * kb` of 8 lanes as two vectors of 32 bit values
+#[inline]
+#[target_feature(enable = "sse2")]
+unsafe fn dot(a: __m128i, b: __m128i, ka: i16, kb: i16) -> (__m128i, __m128i) {
+    let k = _mm_set1_epi32(((kb as i32) << 16) | (ka as u16 as i32));
+    (
+        _mm_madd_epi16(_mm_unpacklo_epi16(a, b), k),
+        _mm_madd_epi16(_mm_unpackhi_epi16(a, b), k),
+    )
+}
+
+/// Rounds and scales 32 bit values back down, saturating to 16 bits
+#[inline]
+#[target_feature(enable = "sse2")]
+unsafe fn narrow(v: (__m128i, __m128i)) -> __m128i {
+    let round = _mm_set1_epi32(128);
+    _mm_packs_epi32(
+        _mm_srai_epi32(_mm_add_epi32(v.0, round), 8),
+        _mm_srai_epi32(_mm_add_epi32(v.1, round), 8),
+    )
+}
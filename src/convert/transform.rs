@@ -0,0 +1,313 @@
+//! Rotation and flipping of frames
+
+use std::io;
+
+use crate::control::{Control, Flags, Value};
+use crate::device::VideoDevice;
+use crate::format::{Format, Info};
+use crate::v4l_sys::{V4L2_CID_HFLIP, V4L2_CID_ROTATE, V4L2_CID_VFLIP};
+
+/// Clockwise rotation
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    #[default]
+    Deg0,
+    Deg90,
+    Deg180,
+    Deg270,
+}
+
+impl Rotation {
+    /// Returns the angle in degrees, as used by `V4L2_CID_ROTATE`
+    pub fn degrees(&self) -> i64 {
+        match self {
+            Rotation::Deg0 => 0,
+            Rotation::Deg90 => 90,
+            Rotation::Deg180 => 180,
+            Rotation::Deg270 => 270,
+        }
+    }
+
+    /// Returns whether width and height are swapped
+    fn transposes(&self) -> bool {
+        matches!(self, Rotation::Deg90 | Rotation::Deg270)
+    }
+}
+
+/// Geometric transformation of frames
+///
+/// The image is flipped first, then rotated.
+///
+/// # Example
+///
+/// ```
+/// use v4l::convert::{Rotation, Transform};
+/// use v4l::{Format, FourCC};
+///
+/// let format = Format::new(3, 2, FourCC::new(b"GREY"));
+/// let transform = Transform {
+///     rotation: Rotation::Deg90,
+///     ..Transform::default()
+/// };
+/// let mut dst = [0u8; 6];
+/// let rotated = transform.apply(&[1, 2, 3, 4, 5, 6], &format, &mut dst).unwrap();
+/// assert_eq!((rotated.width, rotated.height), (2, 3));
+/// assert_eq!(dst, [4, 1, 5, 2, 6, 3]);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Transform {
+    pub rotation: Rotation,
+    /// Mirror at the vertical axis
+    pub hflip: bool,
+    /// Mirror at the horizontal axis
+    pub vflip: bool,
+}
+
+impl Transform {
+    /// Returns whether the transformation leaves the image as is
+    pub fn is_identity(&self) -> bool {
+        *self == Transform::default()
+    }
+
+    /// Configures the device to transform the image where it supports it
+    ///
+    /// `V4L2_CID_HFLIP`, `V4L2_CID_VFLIP` and `V4L2_CID_ROTATE` are set as far as the driver
+    /// offers them. A rotation by 180 degrees is done by flipping in both directions if there
+    /// is no rotation control. Hardware never rotates unless it can do the flips as well, as
+    /// the order of the operations would be wrong otherwise.
+    ///
+    /// Returns the remaining transformation, which has to be applied to the frames in software.
+    ///
+    /// # Arguments
+    ///
+    /// * `dev` - Capture device
+    pub fn offload<D: VideoDevice>(&self, dev: &D) -> io::Result<Transform> {
+        let controls = dev.query_controls()?;
+        let writable = |id: u32| {
+            controls.iter().find(|desc| {
+                desc.id == id && !desc.flags.intersects(Flags::DISABLED | Flags::READ_ONLY)
+            })
+        };
+        let has_hflip = writable(V4L2_CID_HFLIP).is_some();
+        let has_vflip = writable(V4L2_CID_VFLIP).is_some();
+        let rotate = writable(V4L2_CID_ROTATE).filter(|desc| {
+            let degrees = self.rotation.degrees();
+            degrees >= desc.minimum
+                && degrees <= desc.maximum
+                && (desc.step == 0 || ((degrees - desc.minimum) as u64).is_multiple_of(desc.step))
+        });
+
+        let mut hardware = Transform::default();
+        let mut software = *self;
+        // flipping both ways is the same as rotating by 180 degrees
+        if rotate.is_none() && self.rotation == Rotation::Deg180 && has_hflip && has_vflip {
+            software.rotation = Rotation::Deg0;
+            software.hflip = !software.hflip;
+            software.vflip = !software.vflip;
+        }
+        if has_hflip {
+            hardware.hflip = software.hflip;
+            software.hflip = false;
+        }
+        if has_vflip {
+            hardware.vflip = software.vflip;
+            software.vflip = false;
+        }
+        if rotate.is_some() && !software.hflip && !software.vflip {
+            hardware.rotation = software.rotation;
+            software.rotation = Rotation::Deg0;
+        }
+
+        let mut ctrls = Vec::new();
+        if has_hflip {
+            ctrls.push(Control {
+                id: V4L2_CID_HFLIP,
+                value: Value::Boolean(hardware.hflip),
+            });
+        }
+        if has_vflip {
+            ctrls.push(Control {
+                id: V4L2_CID_VFLIP,
+                value: Value::Boolean(hardware.vflip),
+            });
+        }
+        if writable(V4L2_CID_ROTATE).is_some() {
+            ctrls.push(Control {
+                id: V4L2_CID_ROTATE,
+                value: Value::Integer(hardware.rotation.degrees()),
+            });
+        }
+        for ctrl in ctrls {
+            dev.set_control(ctrl)?;
+        }
+
+        Ok(software)
+    }
+
+    /// Returns the format of the transformed frames, which are packed without padding
+    ///
+    /// Packed formats without chroma subsampling and the planar YUV formats stored in a single
+    /// buffer are supported. Packed 4:2:2 formats such as YUYV and planar formats with unequal
+    /// horizontal and vertical subsampling can be flipped and rotated by 180 degrees only.
+    /// Other formats fail with [`io::ErrorKind::Unsupported`].
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - Format of the source frames
+    pub fn format(&self, format: &Format) -> io::Result<Format> {
+        let info = self.info(format)?;
+        let (width, height) = if self.rotation.transposes() {
+            (format.height, format.width)
+        } else {
+            (format.width, format.height)
+        };
+
+        let planes = info.planes(width, height);
+        let mut transformed = *format;
+        transformed.width = width;
+        transformed.height = height;
+        transformed.stride = planes[0].0;
+        transformed.size = planes[0].1;
+        Ok(transformed)
+    }
+
+    /// Transforms a frame
+    ///
+    /// Returns the format of the transformed frame, see [`Transform::format`].
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - Source frame
+    /// * `format` - Format of the source frame
+    /// * `dst` - Buffer for the transformed frame, at least `size` bytes of the returned format
+    pub fn apply(&self, src: &[u8], format: &Format, dst: &mut [u8]) -> io::Result<Format> {
+        let info = self.info(format)?;
+        let transformed = self.format(format)?;
+        if dst.len() < transformed.size as usize {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "destination buffer is smaller than the transformed frame",
+            ));
+        }
+
+        let (width, height) = (format.width as usize, format.height as usize);
+        let stride = match format.stride as usize {
+            0 => width * info.bpp[0] as usize,
+            stride => stride,
+        };
+        let (mut src_offset, mut dst_offset) = (0, 0);
+        for plane in 0..info.comp_planes {
+            let bpp = info.bpp[plane] as usize;
+            let (sub_x, sub_y) = match plane {
+                0 => (1, 1),
+                _ => (info.subsampling.0 as usize, info.subsampling.1 as usize),
+            };
+            let (plane_width, plane_height) = (width.div_ceil(sub_x), height.div_ceil(sub_y));
+            // chroma planes have a stride proportional to the one of the luma plane
+            let src_stride = stride * bpp / (info.bpp[0] as usize * sub_x);
+            let src_size = src_stride * plane_height;
+            if src.len() < src_offset + src_size {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "source buffer is smaller than the format",
+                ));
+            }
+
+            let mut geometry = Plane {
+                width: plane_width,
+                height: plane_height,
+                bpp,
+                src_stride,
+                swap: None,
+            };
+            // packed 4:2:2 is transformed in macropixels, the luma samples of each are swapped
+            // when mirroring
+            if plane == 0 && info.subsampling.0 == 2 && info.comp_planes == 1 {
+                geometry.width = width.div_ceil(2);
+                geometry.bpp = 4;
+                geometry.swap = match &format.fourcc.repr {
+                    b"UYVY" | b"VYUY" => Some((1, 3)),
+                    _ => Some((0, 2)),
+                };
+            }
+            let dst_size = self.plane(&geometry, &src[src_offset..], &mut dst[dst_offset..]);
+
+            src_offset += src_size;
+            dst_offset += dst_size;
+        }
+
+        Ok(transformed)
+    }
+
+    /// Returns the layout of a supported format
+    fn info(&self, format: &Format) -> io::Result<Info> {
+        let unsupported = || {
+            io::Error::new(
+                io::ErrorKind::Unsupported,
+                "no transformation for the pixel format",
+            )
+        };
+        let info = Info::new(format.fourcc)
+            .filter(|info| info.mem_planes == 1)
+            .ok_or_else(unsupported)?;
+
+        // transposing subsampled chroma would change the subsampling
+        let (sub_x, sub_y) = info.subsampling;
+        let packed_subsampled = info.comp_planes == 1 && sub_x > 1;
+        if self.rotation.transposes() && (sub_x != sub_y || packed_subsampled) {
+            return Err(unsupported());
+        }
+        Ok(info)
+    }
+
+    /// Transforms a single plane, returns the number of bytes written
+    fn plane(&self, plane: &Plane, src: &[u8], dst: &mut [u8]) -> usize {
+        let (width, height, bpp) = (plane.width, plane.height, plane.bpp);
+        let (dst_width, dst_height) = if self.rotation.transposes() {
+            (height, width)
+        } else {
+            (width, height)
+        };
+        // mirroring the macropixels of packed 4:2:2 is only complete with swapped luma samples
+        let mirrored = self.hflip != matches!(self.rotation, Rotation::Deg180);
+
+        let dst_stride = dst_width * bpp;
+        if dst_stride == 0 {
+            return 0;
+        }
+        for (y, line) in dst
+            .chunks_exact_mut(dst_stride)
+            .take(dst_height)
+            .enumerate()
+        {
+            for (x, px) in line.chunks_exact_mut(bpp).enumerate() {
+                // position in the flipped image
+                let (fx, fy) = match self.rotation {
+                    Rotation::Deg0 => (x, y),
+                    Rotation::Deg90 => (y, height - 1 - x),
+                    Rotation::Deg180 => (width - 1 - x, height - 1 - y),
+                    Rotation::Deg270 => (width - 1 - y, x),
+                };
+                let sx = if self.hflip { width - 1 - fx } else { fx };
+                let sy = if self.vflip { height - 1 - fy } else { fy };
+
+                px.copy_from_slice(&src[sy * plane.src_stride + sx * bpp..][..bpp]);
+                if let (Some((a, b)), true) = (plane.swap, mirrored) {
+                    px.swap(a, b);
+                }
+            }
+        }
+        dst_stride * dst_height
+    }
+}
+
+/// Geometry of a plane
+struct Plane {
+    /// Width in elements of `bpp` bytes
+    width: usize,
+    height: usize,
+    bpp: usize,
+    src_stride: usize,
+    /// Bytes to swap within mirrored elements
+    swap: Option<(usize, usize)>,
+}
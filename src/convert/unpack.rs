@@ -0,0 +1,430 @@
+//! Unpacking of high bit depth formats into 16 bit samples
+
+use std::io;
+
+use super::bayer::Bayer;
+use crate::format::Format;
+
+/// Storage of the samples within a line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Packing {
+    /// One byte per sample
+    Bits8,
+    /// Two bytes per sample (little endian), of which the lower `depth` bits are used
+    Lsb16 { depth: u32 },
+    /// Two bytes per sample (little endian), of which the upper `depth` bits are used
+    Msb16 { depth: u32 },
+    /// MIPI CSI-2 packing, four 10 bit samples in five bytes
+    Mipi10,
+    /// MIPI CSI-2 packing, two 12 bit samples in three bytes
+    Mipi12,
+    /// Continuous stream of 10 bit samples, most significant bit first
+    Bits10Be,
+}
+
+impl Packing {
+    /// Returns the number of significant bits per sample
+    pub fn depth(&self) -> u32 {
+        match *self {
+            Packing::Bits8 => 8,
+            Packing::Lsb16 { depth } | Packing::Msb16 { depth } => depth,
+            Packing::Mipi10 | Packing::Bits10Be => 10,
+            Packing::Mipi12 => 12,
+        }
+    }
+
+    /// Returns the number of bytes of a line without padding
+    pub fn line_size(&self, width: usize) -> usize {
+        match self {
+            Packing::Bits8 => width,
+            Packing::Lsb16 { .. } | Packing::Msb16 { .. } => width * 2,
+            Packing::Mipi10 => width.div_ceil(4) * 5,
+            Packing::Mipi12 => width.div_ceil(2) * 3,
+            Packing::Bits10Be => (width * 10).div_ceil(8),
+        }
+    }
+
+    /// Unpacks a line, the samples keep their bit depth
+    pub fn unpack_line(&self, src: &[u8], dst: &mut [u16]) {
+        match *self {
+            Packing::Bits8 => {
+                for (sample, &byte) in dst.iter_mut().zip(src) {
+                    *sample = byte as u16;
+                }
+            }
+            Packing::Lsb16 { depth } => {
+                let mask = ((1u32 << depth) - 1) as u16;
+                for (sample, bytes) in dst.iter_mut().zip(src.chunks_exact(2)) {
+                    *sample = u16::from_le_bytes([bytes[0], bytes[1]]) & mask;
+                }
+            }
+            Packing::Msb16 { depth } => {
+                for (sample, bytes) in dst.iter_mut().zip(src.chunks_exact(2)) {
+                    *sample = u16::from_le_bytes([bytes[0], bytes[1]]) >> (16 - depth);
+                }
+            }
+            // the first bytes of each group hold the most significant bits of the samples, the
+            // last one the remaining bits of all of them
+            Packing::Mipi10 => {
+                for (samples, bytes) in dst.chunks_mut(4).zip(src.chunks(5)) {
+                    for (i, sample) in samples.iter_mut().enumerate() {
+                        *sample = (bytes[i] as u16) << 2 | (bytes[4] as u16 >> (i * 2)) & 0x3;
+                    }
+                }
+            }
+            Packing::Mipi12 => {
+                for (samples, bytes) in dst.chunks_mut(2).zip(src.chunks(3)) {
+                    for (i, sample) in samples.iter_mut().enumerate() {
+                        *sample = (bytes[i] as u16) << 4 | (bytes[2] as u16 >> (i * 4)) & 0xf;
+                    }
+                }
+            }
+            Packing::Bits10Be => {
+                for (i, sample) in dst.iter_mut().enumerate() {
+                    let (byte, shift) = (i * 10 / 8, i * 10 % 8);
+                    let next = src.get(byte + 1).copied().unwrap_or(0);
+                    let bits = u16::from_be_bytes([src[byte], next]);
+                    *sample = (bits << shift) >> 6;
+                }
+            }
+        }
+    }
+
+    /// Packs a line, bits above the depth of the samples are dropped
+    pub fn pack_line(&self, src: &[u16], dst: &mut [u8]) {
+        let mask = ((1u32 << self.depth()) - 1) as u16;
+        match *self {
+            Packing::Bits8 => {
+                for (byte, &sample) in dst.iter_mut().zip(src) {
+                    *byte = sample as u8;
+                }
+            }
+            Packing::Lsb16 { .. } => {
+                for (bytes, &sample) in dst.chunks_exact_mut(2).zip(src) {
+                    bytes.copy_from_slice(&(sample & mask).to_le_bytes());
+                }
+            }
+            Packing::Msb16 { depth } => {
+                for (bytes, &sample) in dst.chunks_exact_mut(2).zip(src) {
+                    bytes.copy_from_slice(&((sample & mask) << (16 - depth)).to_le_bytes());
+                }
+            }
+            Packing::Mipi10 => {
+                for (bytes, samples) in dst.chunks_mut(5).zip(src.chunks(4)) {
+                    bytes[4] = 0;
+                    for (i, &sample) in samples.iter().enumerate() {
+                        bytes[i] = (sample >> 2) as u8;
+                        bytes[4] |= ((sample & 0x3) << (i * 2)) as u8;
+                    }
+                }
+            }
+            Packing::Mipi12 => {
+                for (bytes, samples) in dst.chunks_mut(3).zip(src.chunks(2)) {
+                    bytes[2] = 0;
+                    for (i, &sample) in samples.iter().enumerate() {
+                        bytes[i] = (sample >> 4) as u8;
+                        bytes[2] |= ((sample & 0xf) << (i * 4)) as u8;
+                    }
+                }
+            }
+            Packing::Bits10Be => {
+                dst.iter_mut().for_each(|byte| *byte = 0);
+                for (i, &sample) in src.iter().enumerate() {
+                    let (byte, shift) = (i * 10 / 8, i * 10 % 8);
+                    let bits = ((sample & mask) << 6 >> shift).to_be_bytes();
+                    dst[byte] |= bits[0];
+                    if let Some(next) = dst.get_mut(byte + 1) {
+                        *next |= bits[1];
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Converter between high bit depth formats and planes of 16 bit samples
+///
+/// Monochrome (`Y10 `, `Y12 `, `Y14 `, `Y16 `, `Y10P`, `Y12P`, `Y10B`), semi-planar YUV
+/// (`P010`, `P012`) and raw Bayer formats are supported. The unpacked samples keep the bit
+/// depth of the format, i.e. range from 0 to 1023 for 10 bit formats, no matter whether the
+/// format stores them in the lower or the upper bits.
+///
+/// Semi-planar formats are unpacked into the luma plane followed by the plane of interleaved
+/// chroma samples, just like they are stored in the frame.
+///
+/// # Example
+///
+/// ```
+/// use v4l::convert::Unpacker;
+/// use v4l::{Format, FourCC};
+///
+/// let format = Format::new(4, 1, FourCC::new(b"Y10P"));
+/// let unpacker = Unpacker::new(&format).unwrap();
+/// let mut samples = vec![0u16; unpacker.samples()];
+/// unpacker.unpack(&[0xff, 0x00, 0x80, 0x00, 0b00_00_01_11], &mut samples).unwrap();
+/// assert_eq!(samples, [1023, 1, 512, 0]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Unpacker {
+    packing: Packing,
+    width: usize,
+    /// Number of lines of all planes
+    lines: usize,
+    stride: usize,
+}
+
+impl Unpacker {
+    /// Returns an unpacker for frames of the given format
+    ///
+    /// Formats which are not listed in [`Unpacker`] fail with [`io::ErrorKind::Unsupported`].
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - Format of the frames to unpack
+    pub fn new(format: &Format) -> io::Result<Self> {
+        let height = format.height as usize;
+        let (packing, lines) = match &format.fourcc.repr {
+            b"Y10 " => (Packing::Lsb16 { depth: 10 }, height),
+            b"Y12 " => (Packing::Lsb16 { depth: 12 }, height),
+            b"Y14 " => (Packing::Lsb16 { depth: 14 }, height),
+            b"Y16 " => (Packing::Lsb16 { depth: 16 }, height),
+            b"Y10P" => (Packing::Mipi10, height),
+            b"Y12P" => (Packing::Mipi12, height),
+            b"Y10B" => (Packing::Bits10Be, height),
+            // the chroma plane holds a line for every second luma line
+            b"P010" => (Packing::Msb16 { depth: 10 }, height + height.div_ceil(2)),
+            b"P012" => (Packing::Msb16 { depth: 12 }, height + height.div_ceil(2)),
+            fourcc => match Bayer::new(fourcc) {
+                Some(bayer) => (bayer.packing(), height),
+                None => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::Unsupported,
+                        "no unpacking for the pixel format",
+                    ))
+                }
+            },
+        };
+
+        let width = format.width as usize;
+        let stride = match format.stride as usize {
+            0 => packing.line_size(width),
+            stride => stride,
+        };
+
+        Ok(Unpacker {
+            packing,
+            width,
+            lines,
+            stride,
+        })
+    }
+
+    /// Returns the number of significant bits per sample
+    pub fn depth(&self) -> u32 {
+        self.packing.depth()
+    }
+
+    /// Returns the number of samples of an unpacked frame
+    pub fn samples(&self) -> usize {
+        self.width * self.lines
+    }
+
+    /// Returns the minimum size of a packed frame, the last line may lack its padding
+    pub fn packed_size(&self) -> usize {
+        if self.lines == 0 {
+            return 0;
+        }
+        self.stride * (self.lines - 1) + self.packing.line_size(self.width)
+    }
+
+    /// Unpacks a frame
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - Frame in the packed format, at least [`Unpacker::packed_size`] bytes
+    /// * `dst` - Buffer for the samples, at least [`Unpacker::samples`] values
+    pub fn unpack(&self, src: &[u8], dst: &mut [u16]) -> io::Result<()> {
+        self.check(src.len(), dst.len())?;
+        if self.width == 0 {
+            return Ok(());
+        }
+
+        let line_size = self.packing.line_size(self.width);
+        for (row, samples) in dst
+            .chunks_exact_mut(self.width)
+            .take(self.lines)
+            .enumerate()
+        {
+            self.packing
+                .unpack_line(&src[row * self.stride..][..line_size], samples);
+        }
+        Ok(())
+    }
+
+    /// Packs a frame, the padding at the end of the lines is left untouched
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - Samples, at least [`Unpacker::samples`] values
+    /// * `dst` - Buffer for the frame, at least [`Unpacker::packed_size`] bytes
+    pub fn pack(&self, src: &[u16], dst: &mut [u8]) -> io::Result<()> {
+        self.check(dst.len(), src.len())?;
+        if self.width == 0 {
+            return Ok(());
+        }
+
+        let line_size = self.packing.line_size(self.width);
+        for (row, samples) in src.chunks_exact(self.width).take(self.lines).enumerate() {
+            self.packing
+                .pack_line(samples, &mut dst[row * self.stride..][..line_size]);
+        }
+        Ok(())
+    }
+
+    /// Verifies that the buffers are large enough for a frame
+    fn check(&self, packed: usize, samples: usize) -> io::Result<()> {
+        if packed < self.packed_size() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "packed buffer is smaller than the format",
+            ));
+        }
+        if samples < self.samples() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "sample buffer is smaller than the frame",
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FourCC;
+
+    /// Unpacks a single line and packs it again, which must restore the input
+    fn round_trip(packing: Packing, packed: &[u8], samples: &[u16]) {
+        assert_eq!(packing.line_size(samples.len()), packed.len());
+        let mut unpacked = vec![0; samples.len()];
+        packing.unpack_line(packed, &mut unpacked);
+        assert_eq!(unpacked, samples, "{:?}", packing);
+
+        let mut repacked = vec![0; packed.len()];
+        packing.pack_line(samples, &mut repacked);
+        assert_eq!(repacked, packed, "{:?}", packing);
+    }
+
+    #[test]
+    fn unpacks_little_endian_samples() {
+        let lsb = Packing::Lsb16 { depth: 10 };
+        round_trip(lsb, &[0xff, 0x03, 0x01, 0x00], &[1023, 1]);
+        let msb = Packing::Msb16 { depth: 10 };
+        round_trip(msb, &[0xc0, 0xff, 0x40, 0x00], &[1023, 1]);
+
+        // bits outside of the depth are ignored
+        let mut samples = [0; 2];
+        lsb.unpack_line(&[0xff, 0xff, 0x00, 0xfc], &mut samples);
+        assert_eq!(samples, [1023, 0]);
+        msb.unpack_line(&[0x3f, 0xff, 0x3f, 0x00], &mut samples);
+        assert_eq!(samples, [1020, 0]);
+    }
+
+    #[test]
+    fn unpacks_mipi_groups() {
+        round_trip(
+            Packing::Mipi10,
+            &[0xff, 0x00, 0x80, 0x00, 0b00_00_01_11],
+            &[1023, 1, 512, 0],
+        );
+        // the last group of an odd width is incomplete
+        round_trip(
+            Packing::Mipi10,
+            &[0xff, 0x00, 0x80, 0x00, 0b00_00_01_11, 0xaf, 0, 0, 0, 0b01],
+            &[1023, 1, 512, 0, 701],
+        );
+        round_trip(
+            Packing::Mipi12,
+            &[0xab, 0x12, 0x3c, 0xff, 0x00, 0x0f],
+            &[0xabc, 0x123, 0xfff],
+        );
+    }
+
+    #[test]
+    fn unpacks_bit_streams() {
+        round_trip(
+            Packing::Bits10Be,
+            &[0xff, 0xc0, 0x08, 0x00, 0x01],
+            &[1023, 0, 512, 1],
+        );
+        // lines of an odd width end within a byte
+        round_trip(
+            Packing::Bits10Be,
+            &[0xff, 0xc0, 0x08, 0x00],
+            &[1023, 0, 512],
+        );
+        round_trip(Packing::Bits10Be, &[0x80, 0x40], &[513]);
+    }
+
+    #[test]
+    fn skips_line_padding() {
+        let mut format = Format::new(2, 2, FourCC::new(b"Y10 "));
+        format.stride = 6;
+        let unpacker = Unpacker::new(&format).unwrap();
+        assert_eq!(unpacker.depth(), 10);
+        assert_eq!(unpacker.samples(), 4);
+        assert_eq!(unpacker.packed_size(), 10);
+
+        let src = [0x01, 0x00, 0x02, 0x00, 0xee, 0xee, 0x03, 0x00, 0x04, 0x00];
+        let mut samples = [0; 4];
+        unpacker.unpack(&src, &mut samples).unwrap();
+        assert_eq!(samples, [1, 2, 3, 4]);
+
+        let mut dst = [0xee; 10];
+        unpacker.pack(&samples, &mut dst).unwrap();
+        assert_eq!(dst, src);
+    }
+
+    #[test]
+    fn unpacks_both_planes_of_semi_planar_formats() {
+        // an odd number of lines still has a chroma line for the last one
+        let format = Format::new(2, 3, FourCC::new(b"P010"));
+        let unpacker = Unpacker::new(&format).unwrap();
+        assert_eq!(unpacker.samples(), 2 * 5);
+        assert_eq!(unpacker.packed_size(), 4 * 5);
+
+        let src: Vec<u8> = (1..=10u16).flat_map(|s| (s << 6).to_le_bytes()).collect();
+        let mut samples = [0; 10];
+        unpacker.unpack(&src, &mut samples).unwrap();
+        assert_eq!(samples, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn checks_buffer_sizes() {
+        let format = Format::new(4, 1, FourCC::new(b"Y10P"));
+        let unpacker = Unpacker::new(&format).unwrap();
+        let err = unpacker.unpack(&[0; 4], &mut [0; 4]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        let err = unpacker.unpack(&[0; 5], &mut [0; 3]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        let err = unpacker.pack(&[0; 4], &mut [0; 4]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+        let empty = Unpacker::new(&Format::new(0, 0, FourCC::new(b"Y10P"))).unwrap();
+        assert_eq!(empty.packed_size(), 0);
+        empty.unpack(&[], &mut []).unwrap();
+    }
+
+    #[test]
+    fn rejects_other_formats() {
+        let err = Unpacker::new(&Format::new(4, 4, FourCC::new(b"YUYV"))).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+        assert_eq!(
+            Unpacker::new(&Format::new(4, 4, FourCC::new(b"pRAA")))
+                .unwrap()
+                .depth(),
+            10
+        );
+    }
+}
@@ -1,16 +1,32 @@
+use std::collections::HashMap;
 use std::convert::TryFrom;
-use std::path::Path;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use std::{
-    io, mem,
-    os::fd::{AsRawFd, RawFd},
+    fmt, io, mem,
+    os::fd::{AsFd, AsRawFd, BorrowedFd, IntoRawFd, OwnedFd, RawFd},
     sync::Arc,
 };
 
+use bitflags::bitflags;
 use libc;
 
+use crate::backend::{DeviceBackend, System};
+use crate::buffer::{self, Type};
+use crate::capability;
+use crate::context::{self, DeviceInfo};
 use crate::control;
-use crate::v4l2;
+use crate::event::{self, Event};
+use crate::frame::Frame;
+use crate::io::traits::BoxedCaptureStream;
+use crate::io::{mmap, read, userptr};
+use crate::media::Request;
+use crate::memory::Memory;
+use crate::reconnect::{is_disconnected, Disconnected};
+use crate::v4l2::{self, Library};
 use crate::v4l2::videodev::v4l2_ext_controls;
+use crate::v4l2::vidioc;
 use crate::v4l_sys::*;
 use crate::{capability::Capabilities, control::Control};
 
@@ -20,65 +36,1153 @@ pub const PLANES_MANY: bool = true;
 pub type Device = PlanarDevice<PLANES_ONE>;
 pub type MultiPlaneDevice = PlanarDevice<PLANES_MANY>;
 
+/// Identity of a physical device
+///
+/// Multi-camera setups usually assign roles to cameras (e.g. left and right). Device indices
+/// are not suitable to remember these assignments, as they depend on the probe order.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeviceId {
+    /// Card name
+    pub card: String,
+    /// Bus info, identifies the port the device is connected to
+    pub bus: String,
+    /// Serial number, if the device has one
+    pub serial: Option<String>,
+}
+
+impl fmt::Display for DeviceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.serial {
+            Some(serial) => write!(f, "{} ({})", self.card, serial),
+            None => write!(f, "{} ({})", self.card, self.bus),
+        }
+    }
+}
+
 /// Linux capture device abstraction
 pub struct PlanarDevice<const M: bool> {
     /// Raw handle
     handle: Arc<Handle>,
 }
 
-impl<const M: bool> PlanarDevice<M> {
-    /// Returns a capture device by index
+impl<const M: bool> PlanarDevice<M> {
+    /// Returns a capture device by index
+    ///
+    /// Devices are usually enumerated by the system.
+    /// An index of zero thus represents the first device the system got to know about.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index (0: first, 1: second, ..)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::device::Device;
+    /// let dev = Device::new(0);
+    /// ```
+    pub fn new(index: usize) -> io::Result<Self> {
+        Ok(Self {
+            handle: Arc::new(Handle::open(format!("{}{}", "/dev/video", index))?),
+        })
+    }
+
+    /// Returns a capture device by path
+    ///
+    /// Linux device nodes are usually found in /dev/videoX or /sys/class/video4linux/videoX.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path (e.g. "/dev/video0")
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::device::Device;
+    /// let dev = Device::with_path("/dev/video0");
+    /// ```
+    pub fn with_path<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Self {
+            handle: Arc::new(Handle::open(path)?),
+        })
+    }
+
+    /// Returns a capture device by path, accessed through the given library
+    ///
+    /// With both the `libv4l` and `v4l2` features enabled, this allows to use the format
+    /// emulation of libv4l for some devices only.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path (e.g. "/dev/video0")
+    /// * `library` - Library the device calls are routed through
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::device::Device;
+    /// use v4l::v4l2::Library;
+    ///
+    /// let dev = Device::with_library("/dev/video0", Library::default());
+    /// ```
+    pub fn with_library<P: AsRef<Path>>(path: P, library: Library) -> io::Result<Self> {
+        Ok(Self {
+            handle: Arc::new(Handle::open_with(path, library)?),
+        })
+    }
+
+    /// Returns a capture device wrapping an already opened file descriptor
+    ///
+    /// This is useful for sandboxed applications which cannot open device nodes themselves and
+    /// receive the file descriptor from a privileged broker instead (e.g. the camera portal or
+    /// systemd socket activation).
+    ///
+    /// # Arguments
+    ///
+    /// * `fd` - File descriptor of an opened video device node
+    pub fn with_fd(fd: OwnedFd) -> io::Result<Self> {
+        Ok(Self {
+            handle: Arc::new(Handle::from_fd(fd)?),
+        })
+    }
+
+    /// Returns a capture device whose I/O is dispatched through a custom backend
+    ///
+    /// # Arguments
+    ///
+    /// * `fd` - File descriptor the backend operates on
+    /// * `backend` - Backend performing the actual device access (see [`crate::backend`])
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::fs::File;
+    /// use std::sync::Arc;
+    /// use v4l::backend::System;
+    /// use v4l::device::Device;
+    ///
+    /// if let Ok(file) = File::open("/dev/video0") {
+    ///     let dev = Device::with_backend(file.into(), Arc::new(System));
+    /// }
+    /// ```
+    pub fn with_backend(fd: OwnedFd, backend: Arc<dyn DeviceBackend>) -> Self {
+        Self {
+            handle: Arc::new(unsafe { Handle::with_backend(fd.into_raw_fd(), backend) }),
+        }
+    }
+
+    /// Returns the first video device whose card name matches
+    ///
+    /// Device indices depend on the order in which devices were probed, so they may change
+    /// across reboots or when a USB device is plugged into another port. Only nodes which
+    /// support video I/O with the plane layout of `Self` are considered, e.g. the metadata node
+    /// of a UVC camera is skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `card` - Card name as reported by [`PlanarDevice::query_caps`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::device::Device;
+    /// let dev = Device::open_by_name("HD Pro Webcam C920");
+    /// ```
+    pub fn open_by_name(card: &str) -> io::Result<Self> {
+        Self::open_matching(|_, caps| caps.card == card)
+    }
+
+    /// Returns the first video device whose bus info matches
+    ///
+    /// The bus info stays the same as long as the device is connected to the same port, which
+    /// makes it suitable to tell identical cameras apart.
+    ///
+    /// # Arguments
+    ///
+    /// * `bus` - Bus info as reported by [`PlanarDevice::query_caps`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::device::Device;
+    /// let dev = Device::open_by_bus_info("usb-0000:00:14.0-2");
+    /// ```
+    pub fn open_by_bus_info(bus: &str) -> io::Result<Self> {
+        Self::open_matching(|_, caps| caps.bus == bus)
+    }
+
+    /// Returns the video device with the given identity
+    ///
+    /// Devices with a serial number are found regardless of the port they are connected to,
+    /// others have to stay connected to the same port.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identity as returned by [`PlanarDevice::id`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::device::Device;
+    ///
+    /// if let Ok(dev) = Device::new(0) {
+    ///     let id = dev.id().unwrap();
+    ///     drop(dev);
+    ///     let dev = Device::open_by_id(&id);
+    /// }
+    /// ```
+    pub fn open_by_id(id: &DeviceId) -> io::Result<Self> {
+        Self::open_matching(|dev, caps| {
+            caps.card == id.card
+                && match &id.serial {
+                    Some(serial) => dev.serial().as_ref() == Some(serial),
+                    None => caps.bus == id.bus,
+                }
+        })
+    }
+
+    fn open_matching<F: Fn(&Self, &Capabilities) -> bool>(matches: F) -> io::Result<Self> {
+        let video = if M {
+            capability::Flags::VIDEO_CAPTURE_MPLANE
+                | capability::Flags::VIDEO_OUTPUT_MPLANE
+                | capability::Flags::VIDEO_M2M_MPLANE
+        } else {
+            capability::Flags::VIDEO_CAPTURE
+                | capability::Flags::VIDEO_OUTPUT
+                | capability::Flags::VIDEO_M2M
+        };
+
+        let mut nodes = context::enum_devices();
+        nodes.sort_by_key(|node| node.index());
+        for node in nodes {
+            // nodes may be in use or vanish while scanning
+            let dev = match Self::with_path(node.path()) {
+                Ok(dev) => dev,
+                Err(_) => continue,
+            };
+            match dev.query_caps() {
+                Ok(caps) if caps.capabilities.intersects(video) && matches(&dev, &caps) => {
+                    return Ok(dev)
+                }
+                _ => continue,
+            }
+        }
+
+        Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "no matching video device",
+        ))
+    }
+
+    /// Returns the raw device handle
+    pub fn handle(&self) -> Arc<Handle> {
+        self.handle.clone()
+    }
+
+    /// Returns another device on the same node, with a file handle of its own
+    ///
+    /// Drivers which allow it (e.g. uvcvideo for its video and metadata queues, or any driver
+    /// for control access while streaming) can be used through several file handles at once.
+    /// The file handles are independent of each other, see [`Handle::reopen`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::buffer::Type;
+    /// use v4l::prelude::*;
+    ///
+    /// if let Ok(dev) = Device::new(0) {
+    ///     let stream = MmapStream::new(&dev, Type::VideoCapture);
+    ///     // watch events without interfering with the streaming file handle
+    ///     let monitor = dev.reopen().unwrap();
+    /// }
+    /// ```
+    pub fn reopen(&self) -> io::Result<Self> {
+        Ok(Self {
+            handle: Arc::new(self.handle.reopen()?),
+        })
+    }
+
+    /// Returns video4linux framework defined information such as card, driver, etc.
+    pub fn query_caps(&self) -> io::Result<Capabilities> {
+        self.handle.query_caps()
+    }
+
+    /// Returns the identity of the physical device
+    ///
+    /// The identity can be stored (e.g. in a configuration file) to open the same device again
+    /// later on through [`PlanarDevice::open_by_id`], even if its index changed in the meantime.
+    pub fn id(&self) -> io::Result<DeviceId> {
+        let caps = self.query_caps()?;
+        Ok(DeviceId {
+            card: caps.card,
+            bus: caps.bus,
+            serial: self.serial(),
+        })
+    }
+
+    /// Returns the properties of the device found in sysfs, e.g. USB vendor and product ID
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::device::Device;
+    ///
+    /// if let Ok(dev) = Device::new(0) {
+    ///     if let Ok(info) = dev.info() {
+    ///         println!("{:04x}:{:04x}", info.vendor_id.unwrap_or(0), info.product_id.unwrap_or(0));
+    ///     }
+    /// }
+    /// ```
+    pub fn info(&self) -> io::Result<DeviceInfo> {
+        DeviceInfo::from_sysfs(&self.handle.sysfs_path()?)
+    }
+
+    /// Returns the serial number of the USB device the node belongs to
+    fn serial(&self) -> Option<String> {
+        self.info().ok()?.serial
+    }
+
+    /// Issues an ioctl which is not (yet) wrapped by this crate
+    ///
+    /// The size of `T` is checked against the argument size encoded in `request`, so passing a
+    /// mismatching argument type is reported as [`io::ErrorKind::InvalidInput`] instead of
+    /// letting the driver write out of bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - IO control code (see [`v4l2::vidioc`])
+    /// * `arg` - Argument exchanged with the driver
+    ///
+    /// # Safety
+    ///
+    /// `T` must have the memory layout the driver expects for `request`. Any pointers embedded in
+    /// `arg` must be valid for the accesses the driver performs through them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::mem;
+    /// use v4l::device::Device;
+    /// use v4l::v4l_sys::v4l2_capability;
+    /// use v4l::vidioc;
+    ///
+    /// if let Ok(dev) = Device::new(0) {
+    ///     let mut caps: v4l2_capability = unsafe { mem::zeroed() };
+    ///     let res = unsafe { dev.ioctl_raw(vidioc::VIDIOC_QUERYCAP, &mut caps) };
+    /// }
+    /// ```
+    pub unsafe fn ioctl_raw<T>(&self, request: vidioc::_IOC_TYPE, arg: &mut T) -> io::Result<()> {
+        let size = vidioc::size(request);
+        if size != 0 && size != mem::size_of::<T>() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "argument size does not match the ioctl request",
+            ));
+        }
+
+        self.handle()
+            .ioctl(request, arg as *mut T as *mut std::os::raw::c_void)
+    }
+}
+
+impl<const M: bool> PlanarDevice<M> {
+    /// Returns the supported controls for a device such as gain, focus, white balance, etc.
+    pub fn query_controls(&self) -> io::Result<Vec<control::Description>> {
+        self.handle.query_controls()
+    }
+
+    /// Returns the control value for an ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Control identifier
+    pub fn control(&self, id: u32) -> io::Result<Control> {
+        self.handle.control(id)
+    }
+
+    /// Returns the control value for a name
+    ///
+    /// Names are matched case-insensitively and regardless of punctuation, so both the name
+    /// reported by the driver ("Exposure, Absolute") and the one used by v4l2-ctl
+    /// ("exposure_absolute") work. The controls are enumerated on the first call only.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Control name
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::Device;
+    ///
+    /// if let Ok(dev) = Device::new(0) {
+    ///     if let Ok(ctrl) = dev.control_by_name("brightness") {
+    ///         println!("{}: {:?}", ctrl.id, ctrl.value);
+    ///     }
+    /// }
+    /// ```
+    pub fn control_by_name(&self, name: &str) -> io::Result<Control> {
+        self.handle.control(self.handle.control_id(name)?)
+    }
+
+    /// Returns the identifier of the control with the given name
+    ///
+    /// Names are matched like in [`PlanarDevice::control_by_name`]. This allows setting
+    /// controls which cannot be read, such as buttons.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Control name
+    pub fn control_id(&self, name: &str) -> io::Result<u32> {
+        self.handle.control_id(name)
+    }
+
+    /// Returns the values of several controls with a single ioctl
+    ///
+    /// This is cheaper than calling [`PlanarDevice::control`] for each control, e.g. when
+    /// sampling exposure and gain for every frame. The values are in the order of `ids`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - Control identifiers
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::v4l_sys::{V4L2_CID_EXPOSURE_ABSOLUTE, V4L2_CID_GAIN};
+    /// use v4l::Device;
+    ///
+    /// if let Ok(dev) = Device::new(0) {
+    ///     if let Ok(values) = dev.get_controls(&[V4L2_CID_EXPOSURE_ABSOLUTE, V4L2_CID_GAIN]) {
+    ///         println!("exposure: {:?}, gain: {:?}", values[0], values[1]);
+    ///     }
+    /// }
+    /// ```
+    pub fn get_controls(&self, ids: &[u32]) -> io::Result<Vec<control::Value>> {
+        self.handle.get_controls(ids)
+    }
+
+    /// Modifies the control value
+    ///
+    /// Controls locked by another process streaming from the device fail with a
+    /// [`control::Grabbed`] error, controls refused because of an enabled automatic mode with a
+    /// [`control::Inactive`] error.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctrl` - Control to be set
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::control::{self, Control, Value};
+    /// use v4l::v4l_sys::V4L2_CID_EXPOSURE_ABSOLUTE;
+    /// use v4l::Device;
+    ///
+    /// if let Ok(dev) = Device::new(0) {
+    ///     let ctrl = Control {
+    ///         id: V4L2_CID_EXPOSURE_ABSOLUTE,
+    ///         value: Value::Integer(100),
+    ///     };
+    ///     if let Err(e) = dev.set_control(ctrl) {
+    ///         if e.get_ref().is_some_and(|e| e.is::<control::Inactive>()) {
+    ///             println!("disable auto exposure first");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn set_control(&self, ctrl: Control) -> io::Result<()> {
+        self.handle.set_controls(vec![ctrl], None)
+    }
+
+    /// Modifies the control values atomically
+    ///
+    /// Errors are reported like in [`PlanarDevice::set_control`].
+    ///
+    /// # Arguments
+    ///
+    /// * `ctrls` - Vec of the controls to be set
+    pub fn set_controls(&self, ctrls: Vec<Control>) -> io::Result<()> {
+        self.handle.set_controls(ctrls, None)
+    }
+
+    /// Returns the state of a buffer, e.g. whether it is queued and where it can be mapped
+    ///
+    /// Streams offer the same through their `query_buf` method. This one is meant for buffers
+    /// requested through raw ioctls, e.g. to implement custom mapping strategies.
+    ///
+    /// # Arguments
+    ///
+    /// * `buf_type` - Type of the buffer
+    /// * `memory` - Memory type the buffers were requested with
+    /// * `index` - Index of the buffer
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::buffer::Type;
+    /// use v4l::memory::Memory;
+    /// use v4l::prelude::*;
+    ///
+    /// if let Ok(dev) = Device::new(0) {
+    ///     if let Ok(info) = dev.query_buf(Type::VideoCapture, Memory::Mmap, 0) {
+    ///         println!("queued: {}, done: {}", info.is_queued(), info.is_done());
+    ///     }
+    /// }
+    /// ```
+    pub fn query_buf(
+        &self,
+        buf_type: Type,
+        memory: Memory,
+        index: u32,
+    ) -> io::Result<buffer::Info> {
+        self.handle.query_buf(buf_type, memory, index)
+    }
+
+    /// Subscribes to an event
+    ///
+    /// Pending events are signalled by `POLLPRI` on the device file descriptor.
+    ///
+    /// # Arguments
+    ///
+    /// * `typ` - Event type
+    /// * `id` - Object to watch, e.g. a control ID (zero for most event types)
+    pub fn subscribe_event(&self, typ: event::Type, id: u32) -> io::Result<()> {
+        self.handle.subscribe_event(typ, id)
+    }
+
+    /// Unsubscribes from an event
+    ///
+    /// # Arguments
+    ///
+    /// * `typ` - Event type, [`event::Type::All`] unsubscribes from all events
+    /// * `id` - Object which was watched
+    pub fn unsubscribe_event(&self, typ: event::Type, id: u32) -> io::Result<()> {
+        self.handle.unsubscribe_event(typ, id)
+    }
+
+    /// Removes the oldest pending event from the queue
+    ///
+    /// Fails with [`io::ErrorKind::WouldBlock`] if no event is pending.
+    pub fn dequeue_event(&self) -> io::Result<Event> {
+        self.handle.dequeue_event()
+    }
+
+    /// Binds control values to a media request
+    ///
+    /// The values are applied by the driver once the request is queued, along with the buffers
+    /// bound to the same request.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctrls` - Vec of the controls to be set
+    /// * `request` - Request to bind the values to
+    pub fn set_request_controls(&self, ctrls: Vec<Control>, request: &Request) -> io::Result<()> {
+        self.handle.set_controls(ctrls, Some(request))
+    }
+}
+
+/// Operations shared by all kinds of video devices
+///
+/// [`Device`] and [`MultiPlaneDevice`] expose the same handle, capabilities and controls. Helper
+/// functions which only need those can be written once against this trait instead of being
+/// duplicated per device kind. The provided methods operate on the [`Handle`], so implementors
+/// only need to supply it.
+///
+/// # Example
+///
+/// ```
+/// use std::io;
+/// use v4l::device::{Device, VideoDevice};
+///
+/// fn card<D: VideoDevice>(dev: &D) -> io::Result<String> {
+///     Ok(dev.query_caps()?.card)
+/// }
+///
+/// if let Ok(dev) = Device::new(0) {
+///     println!("{}", card(&dev).unwrap());
+/// }
+/// ```
+pub trait VideoDevice {
+    /// Returns the raw device handle
+    fn handle(&self) -> Arc<Handle>;
+
+    /// Returns video4linux framework defined information such as card, driver, etc.
+    fn query_caps(&self) -> io::Result<Capabilities> {
+        self.handle().query_caps()
+    }
+
+    /// Returns the supported controls for a device such as gain, focus, white balance, etc.
+    fn query_controls(&self) -> io::Result<Vec<control::Description>> {
+        self.handle().query_controls()
+    }
+
+    /// Returns the control value for an ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Control identifier
+    fn control(&self, id: u32) -> io::Result<Control> {
+        self.handle().control(id)
+    }
+
+    /// Returns the control value for a name, see [`PlanarDevice::control_by_name`]
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Control name
+    fn control_by_name(&self, name: &str) -> io::Result<Control> {
+        let handle = self.handle();
+        handle.control(handle.control_id(name)?)
+    }
+
+    /// Returns the values of several controls with a single ioctl
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - Control identifiers
+    fn get_controls(&self, ids: &[u32]) -> io::Result<Vec<control::Value>> {
+        self.handle().get_controls(ids)
+    }
+
+    /// Modifies the control value
+    ///
+    /// # Arguments
+    ///
+    /// * `ctrl` - Control to be set
+    fn set_control(&self, ctrl: Control) -> io::Result<()> {
+        self.handle().set_controls(vec![ctrl], None)
+    }
+
+    /// Modifies the control values atomically
+    ///
+    /// # Arguments
+    ///
+    /// * `ctrls` - Vec of the controls to be set
+    fn set_controls(&self, ctrls: Vec<Control>) -> io::Result<()> {
+        self.handle().set_controls(ctrls, None)
+    }
+}
+
+impl<const M: bool> VideoDevice for PlanarDevice<M> {
+    fn handle(&self) -> Arc<Handle> {
+        self.handle.clone()
+    }
+}
+
+impl<D: VideoDevice + ?Sized> VideoDevice for &D {
+    fn handle(&self) -> Arc<Handle> {
+        (**self).handle()
+    }
+}
+
+impl Device {
+    /// Returns a capture stream using the most efficient I/O method the driver supports
+    ///
+    /// Memory mapped buffers are preferred over user pointers, read() I/O is the last resort.
+    /// Drivers report the supported buffer memory types since Linux 4.20, older ones are probed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::device::Device;
+    /// use v4l::io::traits::CaptureStream;
+    ///
+    /// if let Ok(dev) = Device::new(0) {
+    ///     if let Ok(mut stream) = dev.best_capture_stream() {
+    ///         let (buf, meta, _) = stream.next().unwrap();
+    ///         println!("{} of {} bytes used", meta.bytesused, buf.len());
+    ///     }
+    /// }
+    /// ```
+    pub fn best_capture_stream(&self) -> io::Result<BoxedCaptureStream> {
+        let caps = self.query_caps()?.capabilities;
+        if !caps.contains(capability::Flags::VIDEO_CAPTURE) {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "device does not support single-planar capture",
+            ));
+        }
+
+        if caps.contains(capability::Flags::STREAMING) {
+            let (mmap, userptr) = match self.buffer_caps(Memory::Mmap) {
+                Ok(0) => (true, self.buffer_caps(Memory::UserPtr).is_ok()),
+                Ok(buf_caps) => (
+                    buf_caps & V4L2_BUF_CAP_SUPPORTS_MMAP != 0,
+                    buf_caps & V4L2_BUF_CAP_SUPPORTS_USERPTR != 0,
+                ),
+                Err(_) => (false, self.buffer_caps(Memory::UserPtr).is_ok()),
+            };
+
+            // allocation may still fail, e.g. if the driver is out of memory for mapped buffers
+            if mmap {
+                if let Ok(stream) = mmap::Stream::new(self, Type::VideoCapture) {
+                    return Ok(Box::new(mmap::stream::SinglePlane(stream)));
+                }
+            }
+            if userptr {
+                if let Ok(stream) = userptr::Stream::new(self, Type::VideoCapture) {
+                    return Ok(Box::new(stream));
+                }
+            }
+        }
+
+        if caps.contains(capability::Flags::READ_WRITE) {
+            return Ok(Box::new(read::Stream::new(self)?));
+        }
+
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "device supports neither streaming nor read() I/O",
+        ))
+    }
+
+    /// Captures a single frame
+    ///
+    /// A stream is started, the first frames are discarded to give auto exposure and white
+    /// balance time to converge, and the stream is stopped again once the frame was copied.
+    /// This is convenient for taking photos or benchmarks, but too slow for continuous capture.
+    ///
+    /// # Arguments
+    ///
+    /// * `warmup_frames` - Number of frames to discard before the one which is returned
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::device::Device;
+    ///
+    /// if let Ok(dev) = Device::new(0) {
+    ///     if let Ok(frame) = dev.snapshot(10) {
+    ///         println!("captured {} bytes", frame.planes[0].len());
+    ///     }
+    /// }
+    /// ```
+    pub fn snapshot(&self, warmup_frames: u32) -> io::Result<Frame> {
+        let mut stream = self.best_capture_stream()?;
+        for _ in 0..warmup_frames {
+            stream.next()?;
+        }
+
+        let frame = {
+            let (buf, meta, planes) = stream.next()?;
+            Frame::copy_from(buf, meta, planes)
+        };
+        stream.stop()?;
+        Ok(frame)
+    }
+
+    /// Returns the buffer capabilities (`V4L2_BUF_CAP_*`) reported by the driver
+    ///
+    /// No buffers are allocated. Fails if the driver does not support the memory type.
+    ///
+    /// # Arguments
+    ///
+    /// * `memory` - Memory type to probe
+    fn buffer_caps(&self, memory: Memory) -> io::Result<u32> {
+        let mut v4l2_reqbufs = v4l2_requestbuffers {
+            type_: Type::VideoCapture as u32,
+            memory: memory as u32,
+            count: 0,
+            ..unsafe { mem::zeroed() }
+        };
+        unsafe {
+            self.handle.ioctl(
+                v4l2::vidioc::VIDIOC_REQBUFS,
+                &mut v4l2_reqbufs as *mut _ as *mut std::os::raw::c_void,
+            )?;
+        }
+
+        Ok(v4l2_reqbufs.capabilities)
+    }
+}
+
+impl<const M: bool> AsFd for PlanarDevice<M> {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.handle.as_fd()
+    }
+}
+
+impl<const M: bool> AsRawFd for PlanarDevice<M> {
+    fn as_raw_fd(&self) -> RawFd {
+        self.handle.as_raw_fd()
+    }
+}
+
+impl io::Read for Device {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        unsafe {
+            let ret = libc::read(
+                self.handle().as_raw_fd(),
+                buf.as_mut_ptr() as *mut std::os::raw::c_void,
+                buf.len(),
+            );
+            match ret {
+                -1 => Err(io::Error::last_os_error()),
+                ret => Ok(ret as usize),
+            }
+        }
+    }
+}
+
+impl io::Write for Device {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        unsafe {
+            let ret = libc::write(
+                self.handle().as_raw_fd(),
+                buf.as_ptr() as *const std::os::raw::c_void,
+                buf.len(),
+            );
+
+            match ret {
+                -1 => Err(io::Error::last_os_error()),
+                ret => Ok(ret as usize),
+            }
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        // write doesn't use a buffer, so it effectively flushes with each call
+        // therefore, we don't have anything to flush later
+        Ok(())
+    }
+}
+
+bitflags! {
+    /// Poll events, both the ones waited for and the ones reported
+    ///
+    /// Errors and hangups are always reported, even if they were not asked for. Drivers signal
+    /// `ERR` when no buffers can ever become ready (e.g. the stream is off) and `ERR | HUP`
+    /// once the device got disconnected.
+    pub struct PollFlags: i16 {
+        /// A buffer can be dequeued from a capture queue
+        const IN = libc::POLLIN;
+        /// An event can be dequeued with VIDIOC_DQEVENT
+        const PRI = libc::POLLPRI;
+        /// A buffer can be dequeued from an output queue
+        const OUT = libc::POLLOUT;
+        /// Error condition
+        const ERR = libc::POLLERR;
+        /// The device hung up, i.e. it is gone
+        const HUP = libc::POLLHUP;
+        /// The file descriptor is not open
+        const NVAL = libc::POLLNVAL;
+    }
+}
+
+impl fmt::Display for PollFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Open file descriptor of a device node, closed once the last handle using it is dropped
+struct Descriptor {
+    fd: RawFd,
+    backend: Arc<dyn DeviceBackend>,
+}
+
+impl Drop for Descriptor {
+    fn drop(&mut self) {
+        let _ = self.backend.close(self.fd);
+    }
+}
+
+/// Device handle for low-level access.
+///
+/// Acquiring a handle facilitates (possibly mutating) interactions with the device.
+///
+/// Clones of a handle share its file descriptor, which stays open until the last one is
+/// dropped. The kernel keeps the state of a file handle (its priority, event subscriptions,
+/// the buffer queues it owns) per descriptor, so clones share that state as well. Independent
+/// file handles on the same node are opened through [`Handle::reopen`].
+///
+/// Handles are `Send` and `Sync`. Ioctls may be issued from several threads at once, drivers
+/// serialize them per device.
+#[derive(Clone)]
+pub struct Handle {
+    descriptor: Arc<Descriptor>,
+    /// Types of the controls read so far, they never change for a device
+    control_types: Arc<Mutex<HashMap<u32, control::Type>>>,
+    /// Control identifiers by normalized name, filled on the first lookup
+    control_ids: Arc<Mutex<HashMap<String, u32>>>,
+}
+
+impl Handle {
+    /// Wraps an existing file descriptor
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `fd` is a valid, open file descriptor for a V4L device.
+    pub unsafe fn new(fd: RawFd) -> Self {
+        Self::with_backend(fd, Arc::new(System))
+    }
+
+    /// Wraps an existing file descriptor whose I/O is dispatched through a custom backend
+    ///
+    /// The descriptor is closed through [`DeviceBackend::close`] once the handle is dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `fd` - File descriptor
+    /// * `backend` - Backend performing the actual device access
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `fd` is an open file descriptor the backend knows how to deal
+    /// with and that it is not owned by anything else.
+    pub unsafe fn with_backend(fd: RawFd, backend: Arc<dyn DeviceBackend>) -> Self {
+        Self {
+            descriptor: Arc::new(Descriptor { fd, backend }),
+            control_types: Arc::new(Mutex::new(HashMap::new())),
+            control_ids: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Opens a path and returns a handle to the device
+    ///
+    /// Linux device nodes are usually found in /dev/videoX or /sys/class/video4linux/videoX.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path (e.g. "/dev/video0")
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let fd = v4l2::open(&path, libc::O_RDWR | libc::O_NONBLOCK)?;
+        if fd == -1 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(unsafe { Handle::new(fd) })
+    }
+
+    /// Opens a path and returns a handle to the device, accessed through the given library
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path (e.g. "/dev/video0")
+    /// * `library` - Library the device calls are routed through
+    pub fn open_with<P: AsRef<Path>>(path: P, library: Library) -> io::Result<Self> {
+        let fd = library.open(&path, libc::O_RDWR | libc::O_NONBLOCK)?;
+        Ok(unsafe { Handle::with_backend(fd, Arc::new(library)) })
+    }
+
+    /// Takes ownership of an opened file descriptor and returns a handle to the device
+    ///
+    /// The descriptor is switched to non-blocking mode, just like the ones opened by
+    /// [`Handle::open`].
+    ///
+    /// # Arguments
+    ///
+    /// * `fd` - File descriptor of an opened device node
+    pub fn from_fd(fd: OwnedFd) -> io::Result<Self> {
+        let fd = fd.into_raw_fd();
+
+        let flags = unsafe { libc::fcntl(fd, libc::F_GETFL) };
+        if flags == -1 || unsafe { libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK) } == -1
+        {
+            let err = io::Error::last_os_error();
+            unsafe {
+                libc::close(fd);
+            }
+            return Err(err);
+        }
+
+        match v4l2::fd_open(fd, 0) {
+            Ok(fd) => Ok(unsafe { Handle::new(fd) }),
+            Err(e) => {
+                unsafe {
+                    libc::close(fd);
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Opens another file handle on the same device node
     ///
-    /// Devices are usually enumerated by the system.
-    /// An index of zero thus represents the first device the system got to know about.
+    /// The kernel keeps some state per file handle:
+    ///
+    /// * The priority, see [`crate::priority`]
+    /// * Event subscriptions and the pending events
+    /// * Ownership of the buffer queues: only the file handle which allocated the buffers of a
+    ///   queue may queue, dequeue or stream them, others get EBUSY. Streams stop and release
+    ///   their buffers through the handle they were created from, so dropping a stream does
+    ///   not affect streams of other file handles.
+    ///
+    /// Unlike clones, which share the file handle, the returned handle starts out with none of
+    /// that state. The node is reopened through /proc, so this works for descriptors passed in
+    /// from elsewhere as well. The new handle uses the default library, custom backends are not
+    /// reopened.
+    pub fn reopen(&self) -> io::Result<Self> {
+        Handle::open(format!("/proc/self/fd/{}", self.descriptor.fd))
+    }
+
+    /// Polls the file descriptor for I/O events
+    ///
+    /// Returns the events which occurred, empty in case of a timeout. Waiting for `IN | PRI`
+    /// allows interleaving buffer and event dequeues on a single thread.
+    ///
+    /// Polling is restarted if it gets interrupted by a signal (EINTR), taking the time which
+    /// already elapsed into account.
     ///
     /// # Arguments
     ///
-    /// * `index` - Index (0: first, 1: second, ..)
+    /// * `events` - The events you are interested in (e.g. `PollFlags::IN`)
+    /// * `timeout` - Timeout in milliseconds. A value of zero returns immediately, even if the fd
+    ///   is not ready. A negative value means infinite timeout (blocking).
     ///
     /// # Example
     ///
     /// ```
-    /// use v4l::device::Device;
-    /// let dev = Device::new(0);
+    /// use v4l::device::PollFlags;
+    /// use v4l::prelude::*;
+    ///
+    /// if let Ok(dev) = Device::new(0) {
+    ///     let ready = dev.handle().poll(PollFlags::IN | PollFlags::PRI, 1000).unwrap();
+    ///     if ready.contains(PollFlags::HUP) {
+    ///         println!("device disconnected");
+    ///     } else if ready.contains(PollFlags::PRI) {
+    ///         println!("event pending");
+    ///     }
+    /// }
     /// ```
-    pub fn new(index: usize) -> io::Result<Self> {
-        Ok(Self {
-            handle: Arc::new(Handle::open(format!("{}{}", "/dev/video", index))?),
-        })
+    pub fn poll(&self, events: PollFlags, timeout: i32) -> io::Result<PollFlags> {
+        let revents = self
+            .descriptor
+            .backend
+            .poll(self.descriptor.fd, events.bits(), timeout)?;
+        Ok(PollFlags::from_bits_truncate(revents))
     }
 
-    /// Returns a capture device by path
+    /// Waits for buffers to become ready
     ///
-    /// Linux device nodes are usually found in /dev/videoX or /sys/class/video4linux/videoX.
+    /// Returns false in case of a timeout. A disconnected device is reported as [`Disconnected`]
+    /// error right away instead of leaving it to the following ioctl.
     ///
     /// # Arguments
     ///
-    /// * `path` - Path (e.g. "/dev/video0")
+    /// * `events`  - The events you are interested in
+    /// * `timeout` - Timeout in milliseconds, negative values block indefinitely
+    pub(crate) fn wait_ready(&self, events: PollFlags, timeout: i32) -> io::Result<bool> {
+        let ready = self.poll(events, timeout)?;
+        if ready.contains(PollFlags::HUP) {
+            return Err(Disconnected::error(io::Error::from_raw_os_error(
+                libc::ENODEV,
+            )));
+        }
+        Ok(!ready.is_empty())
+    }
+
+    /// Performs an ioctl on the device
     ///
-    /// # Example
+    /// # Arguments
     ///
-    /// ```
-    /// use v4l::device::Device;
-    /// let dev = Device::with_path("/dev/video0");
-    /// ```
-    pub fn with_path<P: AsRef<Path>>(path: P) -> io::Result<Self> {
-        Ok(Self {
-            handle: Arc::new(Handle::open(path)?),
-        })
+    /// * `request` - IO control code (see [`vidioc`])
+    /// * `argp` - Pointer to the argument exchanged with the device
+    ///
+    /// # Safety
+    ///
+    /// `argp` must point to valid memory with the layout expected for `request`.
+    pub unsafe fn ioctl(
+        &self,
+        request: vidioc::_IOC_TYPE,
+        argp: *mut std::os::raw::c_void,
+    ) -> io::Result<()> {
+        self.descriptor
+            .backend
+            .ioctl(self.descriptor.fd, request, argp)
+            .map_err(|e| self.classify(e))
     }
 
-    /// Returns the raw device handle
-    pub fn handle(&self) -> Arc<Handle> {
-        self.handle.clone()
+    /// Turns errors caused by the device going away into [`Disconnected`] errors
+    fn classify(&self, e: io::Error) -> io::Error {
+        match e.raw_os_error() {
+            Some(libc::ENODEV) => Disconnected::error(e),
+            // Some drivers report EIO instead, which is also used for transient errors. Only a
+            // hangup makes it final.
+            Some(libc::EIO)
+                if self
+                    .poll(PollFlags::empty(), 0)
+                    .is_ok_and(|ready| ready.contains(PollFlags::HUP)) =>
+            {
+                Disconnected::error(e)
+            }
+            _ => e,
+        }
+    }
+
+    /// Maps device memory into the address space of the process
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Starting address hint
+    /// * `length` - Length of the mapping
+    /// * `prot` - Memory protection flags (e.g. PROT_READ)
+    /// * `flags` - Mapping flags (e.g. MAP_SHARED)
+    /// * `offset` - Offset of the buffer as reported by the device
+    ///
+    /// # Safety
+    ///
+    /// The returned memory must only be accessed for as long as the mapping is alive.
+    pub unsafe fn mmap(
+        &self,
+        start: *mut std::os::raw::c_void,
+        length: usize,
+        prot: std::os::raw::c_int,
+        flags: std::os::raw::c_int,
+        offset: libc::off_t,
+    ) -> io::Result<*mut std::os::raw::c_void> {
+        self.descriptor
+            .backend
+            .mmap(start, length, prot, flags, self.descriptor.fd, offset)
+    }
+
+    /// Unmaps memory which was previously mapped by [`Handle::mmap`]
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Address of the mapping
+    /// * `length` - Length of the mapping
+    ///
+    /// # Safety
+    ///
+    /// The memory must not be accessed anymore afterwards.
+    pub unsafe fn munmap(&self, start: *mut std::os::raw::c_void, length: usize) -> io::Result<()> {
+        self.descriptor.backend.munmap(start, length)
+    }
+
+    /// Returns the sysfs directory of the device node (e.g. /sys/devices/../video4linux/video0)
+    ///
+    /// The directory is looked up by device number, so it is found for nodes with custom names
+    /// and for file descriptors passed in from elsewhere, too.
+    pub fn sysfs_path(&self) -> io::Result<PathBuf> {
+        let mut stat: libc::stat = unsafe { mem::zeroed() };
+        if unsafe { libc::fstat(self.descriptor.fd, &mut stat) } == -1 {
+            return Err(io::Error::last_os_error());
+        }
+        if stat.st_mode & libc::S_IFMT != libc::S_IFCHR {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "not a character device",
+            ));
+        }
+
+        fs::canonicalize(format!(
+            "/sys/dev/char/{}:{}",
+            libc::major(stat.st_rdev),
+            libc::minor(stat.st_rdev)
+        ))
     }
+}
 
+// The control ioctls are shared by video device and sub-device nodes
+impl Handle {
     /// Returns video4linux framework defined information such as card, driver, etc.
-    pub fn query_caps(&self) -> io::Result<Capabilities> {
+    pub(crate) fn query_caps(&self) -> io::Result<Capabilities> {
         unsafe {
             let mut v4l2_caps: v4l2_capability = mem::zeroed();
-            v4l2::ioctl(
-                self.handle().as_raw_fd(),
+            self.ioctl(
                 v4l2::vidioc::VIDIOC_QUERYCAP,
                 &mut v4l2_caps as *mut _ as *mut std::os::raw::c_void,
             )?;
@@ -86,11 +1190,9 @@ impl<const M: bool> PlanarDevice<M> {
             Ok(Capabilities::from(v4l2_caps))
         }
     }
-}
 
-impl Device {
     /// Returns the supported controls for a device such as gain, focus, white balance, etc.
-    pub fn query_controls(&self) -> io::Result<Vec<control::Description>> {
+    pub(crate) fn query_controls(&self) -> io::Result<Vec<control::Description>> {
         let mut controls = Vec::new();
         unsafe {
             let mut v4l2_ctrl: v4l2_query_ext_ctrl = mem::zeroed();
@@ -98,8 +1200,7 @@ impl Device {
             loop {
                 v4l2_ctrl.id |= V4L2_CTRL_FLAG_NEXT_CTRL;
                 v4l2_ctrl.id |= V4L2_CTRL_FLAG_NEXT_COMPOUND;
-                match v4l2::ioctl(
-                    self.handle().as_raw_fd(),
+                match self.ioctl(
                     v4l2::vidioc::VIDIOC_QUERY_EXT_CTRL,
                     &mut v4l2_ctrl as *mut _ as *mut std::os::raw::c_void,
                 ) {
@@ -121,8 +1222,7 @@ impl Device {
                                     index: i as u32,
                                     ..mem::zeroed()
                                 };
-                                let res = v4l2::ioctl(
-                                    self.handle().as_raw_fd(),
+                                let res = self.ioctl(
                                     v4l2::vidioc::VIDIOC_QUERYMENU,
                                     &mut v4l2_menu as *mut _ as *mut std::os::raw::c_void,
                                 );
@@ -165,71 +1265,130 @@ impl Device {
         Ok(controls)
     }
 
-    /// Returns the control value for an ID
+    /// Returns the type of a control, it is only queried from the driver once
     ///
     /// # Arguments
     ///
     /// * `id` - Control identifier
-    pub fn control(&self, id: u32) -> io::Result<Control> {
+    fn control_type(&self, id: u32) -> io::Result<control::Type> {
+        if let Some(typ) = self.control_types.lock().unwrap().get(&id) {
+            return Ok(*typ);
+        }
+
+        let typ = self.query_control(id)?.typ;
+        self.control_types.lock().unwrap().insert(id, typ);
+        Ok(typ)
+    }
+
+    /// Returns the current flags of a control
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Control identifier
+    fn control_flags(&self, id: u32) -> io::Result<control::Flags> {
+        Ok(self.query_control(id)?.flags)
+    }
+
+    /// Returns the description of a single control, without menu items
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Control identifier
+    fn query_control(&self, id: u32) -> io::Result<control::Description> {
+        let mut queryctrl = v4l2_query_ext_ctrl {
+            id,
+            ..unsafe { mem::zeroed() }
+        };
         unsafe {
-            let mut queryctrl = v4l2_query_ext_ctrl {
-                id,
-                ..mem::zeroed()
-            };
-            v4l2::ioctl(
-                self.handle().as_raw_fd(),
+            self.ioctl(
                 v4l2::vidioc::VIDIOC_QUERY_EXT_CTRL,
                 &mut queryctrl as *mut _ as *mut std::os::raw::c_void,
             )?;
+        }
 
-            // determine the control type
-            let description = control::Description::from(queryctrl);
+        Ok(control::Description::from(queryctrl))
+    }
 
-            // query the actual control value
-            let mut v4l2_ctrl = v4l2_ext_control {
-                id,
-                ..mem::zeroed()
-            };
-            let mut v4l2_ctrls = v4l2_ext_controls {
-                count: 1,
-                controls: &mut v4l2_ctrl,
-                ..mem::zeroed()
-            };
-            v4l2::ioctl(
-                self.handle().as_raw_fd(),
-                v4l2::vidioc::VIDIOC_G_EXT_CTRLS,
-                &mut v4l2_ctrls as *mut _ as *mut std::os::raw::c_void,
-            )?;
+    /// Returns the identifier of a control, the controls are only enumerated once
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Control name
+    pub(crate) fn control_id(&self, name: &str) -> io::Result<u32> {
+        let mut ids = self.control_ids.lock().unwrap();
+        if ids.is_empty() {
+            for desc in self.query_controls()? {
+                ids.insert(control_key(&desc.name), desc.id);
+            }
+        }
 
-            let value = match description.typ {
-                control::Type::Integer64 => {
-                    control::Value::Integer(v4l2_ctrl.__bindgen_anon_1.value64)
-                }
-                control::Type::Integer | control::Type::Menu => {
-                    control::Value::Integer(v4l2_ctrl.__bindgen_anon_1.value as i64)
-                }
-                control::Type::Boolean => {
-                    control::Value::Boolean(v4l2_ctrl.__bindgen_anon_1.value == 1)
-                }
-                _ => {
-                    return Err(io::Error::new(
-                        io::ErrorKind::Other,
-                        "cannot handle control type",
-                    ))
-                }
-            };
+        ids.get(&control_key(name)).copied().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, "no control with the given name")
+        })
+    }
 
-            Ok(Control { id, value })
-        }
+    /// Returns the control value for an ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Control identifier
+    pub(crate) fn control(&self, id: u32) -> io::Result<Control> {
+        let value = self.get_controls(&[id])?.remove(0);
+        Ok(Control { id, value })
     }
 
-    /// Modifies the control value
+    /// Returns the values of several controls, read at once
     ///
     /// # Arguments
     ///
-    /// * `ctrl` - Control to be set
-    pub fn set_control(&self, ctrl: Control) -> io::Result<()> {
-        self.set_controls(vec![ctrl])
+    /// * `ids` - Control identifiers
+    pub(crate) fn get_controls(&self, ids: &[u32]) -> io::Result<Vec<control::Value>> {
+        let types = ids
+            .iter()
+            .map(|id| self.control_type(*id))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let mut v4l2_ctrls: Vec<v4l2_ext_control> = ids
+            .iter()
+            .map(|id| v4l2_ext_control {
+                id: *id,
+                ..unsafe { mem::zeroed() }
+            })
+            .collect();
+        // V4L2_CTRL_WHICH_CUR_VAL (zero) allows mixing controls of different classes
+        let mut controls = v4l2_ext_controls {
+            count: v4l2_ctrls.len() as u32,
+            controls: v4l2_ctrls.as_mut_ptr(),
+            ..unsafe { mem::zeroed() }
+        };
+        unsafe {
+            self.ioctl(
+                v4l2::vidioc::VIDIOC_G_EXT_CTRLS,
+                &mut controls as *mut _ as *mut std::os::raw::c_void,
+            )?;
+        }
+
+        types
+            .iter()
+            .zip(&v4l2_ctrls)
+            .map(|(typ, ctrl)| unsafe {
+                match typ {
+                    control::Type::Integer64 => {
+                        Ok(control::Value::Integer(ctrl.__bindgen_anon_1.value64))
+                    }
+                    control::Type::Integer | control::Type::Menu => {
+                        Ok(control::Value::Integer(ctrl.__bindgen_anon_1.value as i64))
+                    }
+                    control::Type::Bitmask => Ok(control::Value::Integer(
+                        ctrl.__bindgen_anon_1.value as u32 as i64,
+                    )),
+                    control::Type::Boolean => {
+                        Ok(control::Value::Boolean(ctrl.__bindgen_anon_1.value == 1))
+                    }
+                    _ => Err(io::Error::other("cannot handle control type")),
+                }
+            })
+            .collect()
     }
 
     /// Modifies the control values atomically
@@ -237,7 +1396,11 @@ impl Device {
     /// # Arguments
     ///
     /// * `ctrls` - Vec of the controls to be set
-    pub fn set_controls(&self, ctrls: Vec<Control>) -> io::Result<()> {
+    pub(crate) fn set_controls(
+        &self,
+        ctrls: Vec<Control>,
+        request: Option<&Request>,
+    ) -> io::Result<()> {
         unsafe {
             let mut control_list: Vec<v4l2_ext_control> = vec![];
             let mut class: Option<u32> = None;
@@ -249,7 +1412,7 @@ impl Device {
                 ));
             }
 
-            for ref ctrl in ctrls {
+            for ctrl in &ctrls {
                 let mut control = v4l2_ext_control {
                     id: ctrl.id,
                     ..mem::zeroed()
@@ -317,126 +1480,324 @@ impl Device {
                 which: class,
                 ..mem::zeroed()
             };
+            if let Some(request) = request {
+                // the values are applied once the request is queued
+                controls.which = V4L2_CTRL_WHICH_REQUEST_VAL;
+                controls.request_fd = request.as_raw_fd();
+            }
 
-            v4l2::ioctl(
-                self.handle().as_raw_fd(),
+            self.ioctl(
                 v4l2::vidioc::VIDIOC_S_EXT_CTRLS,
                 &mut controls as *mut _ as *mut std::os::raw::c_void,
             )
+            .map_err(|e| {
+                // error_idx equals count if the failing control could not be determined
+                let ids: Vec<u32> = match ctrls.get(controls.error_idx as usize) {
+                    Some(ctrl) => vec![ctrl.id],
+                    None => ctrls.iter().map(|ctrl| ctrl.id).collect(),
+                };
+                self.classify_control_error(&ids, e)
+            })
         }
     }
-}
 
-impl io::Read for Device {
-    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
-        unsafe {
-            let ret = libc::read(
-                self.handle().as_raw_fd(),
-                buf.as_mut_ptr() as *mut std::os::raw::c_void,
-                buf.len(),
-            );
-            match ret {
-                -1 => Err(io::Error::last_os_error()),
-                ret => Ok(ret as usize),
-            }
+    /// Turns errors of setting grabbed or inactive controls into [`control::Grabbed`] and
+    /// [`control::Inactive`] errors
+    ///
+    /// The flags are queried after the fact, they may change at any time.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - Identifiers of the controls which may have caused the error
+    /// * `e` - Error reported by the driver
+    fn classify_control_error(&self, ids: &[u32], e: io::Error) -> io::Error {
+        if is_disconnected(&e) {
+            return e;
         }
-    }
-}
 
-impl io::Write for Device {
-    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        unsafe {
-            let ret = libc::write(
-                self.handle().as_raw_fd(),
-                buf.as_ptr() as *const std::os::raw::c_void,
-                buf.len(),
-            );
+        let flags = ids
+            .iter()
+            .filter_map(|id| Some((*id, self.control_flags(*id).ok()?)))
+            .collect::<Vec<_>>();
+        let flagged = |flag| {
+            flags
+                .iter()
+                .find(|(_, flags)| flags.contains(flag))
+                .map(|(id, _)| *id)
+        };
 
-            match ret {
-                -1 => Err(io::Error::last_os_error()),
-                ret => Ok(ret as usize),
-            }
+        if let Some(id) = flagged(control::Flags::GRABBED) {
+            return control::Grabbed::error(id, e);
         }
-    }
-
-    fn flush(&mut self) -> io::Result<()> {
-        // write doesn't use a buffer, so it effectively flushes with each call
-        // therefore, we don't have anything to flush later
-        Ok(())
+        if let ([id], Some(libc::EBUSY)) = (ids, e.raw_os_error()) {
+            // not every driver sets the flag, the control framework reports EBUSY regardless
+            return control::Grabbed::error(*id, e);
+        }
+        if let Some(id) = flagged(control::Flags::INACTIVE) {
+            return control::Inactive::error(id, e);
+        }
+        e
     }
 }
 
-/// Device handle for low-level access.
-///
-/// Acquiring a handle facilitates (possibly mutating) interactions with the device.
-#[derive(Debug, Clone)]
-pub struct Handle(RawFd);
-
+// The event ioctls are shared by video device and sub-device nodes as well
 impl Handle {
-    /// Wraps an existing file descriptor
+    /// Subscribes to an event
     ///
-    /// The caller must ensure that `fd` is a valid, open file descriptor for a V4L device.
-    pub unsafe fn new(fd: RawFd) -> Self {
-        Self(fd)
+    /// # Arguments
+    ///
+    /// * `typ` - Event type
+    /// * `id` - Object to watch, e.g. a control ID (zero for most event types)
+    pub(crate) fn subscribe_event(&self, typ: event::Type, id: u32) -> io::Result<()> {
+        let mut sub = event::subscription(typ, id, 0);
+        unsafe {
+            self.ioctl(
+                v4l2::vidioc::VIDIOC_SUBSCRIBE_EVENT,
+                &mut sub as *mut _ as *mut std::os::raw::c_void,
+            )
+        }
     }
 
-    /// Opens a path and returns a handle to the device
-    ///
-    /// Linux device nodes are usually found in /dev/videoX or /sys/class/video4linux/videoX.
+    /// Unsubscribes from an event
     ///
     /// # Arguments
     ///
-    /// * `path` - Path (e.g. "/dev/video0")
-    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
-        let fd = v4l2::open(&path, libc::O_RDWR | libc::O_NONBLOCK)?;
-        if fd == -1 {
-            return Err(io::Error::last_os_error());
+    /// * `typ` - Event type, [`event::Type::All`] unsubscribes from all events
+    /// * `id` - Object which was watched
+    pub(crate) fn unsubscribe_event(&self, typ: event::Type, id: u32) -> io::Result<()> {
+        let mut sub = event::subscription(typ, id, 0);
+        unsafe {
+            self.ioctl(
+                v4l2::vidioc::VIDIOC_UNSUBSCRIBE_EVENT,
+                &mut sub as *mut _ as *mut std::os::raw::c_void,
+            )
         }
+    }
 
-        Ok(Handle(fd))
+    /// Removes the oldest pending event from the queue
+    pub(crate) fn dequeue_event(&self) -> io::Result<Event> {
+        unsafe {
+            let mut v4l2_event: v4l2_event = mem::zeroed();
+            self.ioctl(
+                v4l2::vidioc::VIDIOC_DQEVENT,
+                &mut v4l2_event as *mut _ as *mut std::os::raw::c_void,
+            )?;
+
+            Ok(Event::from(v4l2_event))
+        }
     }
+}
 
-    /// Polls the file descriptor for I/O events
+// Buffer queries are used by streams as well as by applications managing buffers themselves
+impl Handle {
+    /// Returns the state of a buffer
     ///
     /// # Arguments
     ///
-    /// * `events`  - The events you are interested in (e.g. POLLIN)
-    ///
-    /// * `timeout` - Timeout in milliseconds
-    ///               A value of zero returns immedately, even if the fd is not ready.
-    ///               A negative value means infinite timeout (blocking).
-    pub fn poll(&self, events: i16, timeout: i32) -> io::Result<i32> {
-        match unsafe {
-            libc::poll(
-                [libc::pollfd {
-                    fd: self.0,
-                    events,
-                    revents: 0,
-                }]
-                .as_mut_ptr(),
-                1,
-                timeout,
-            )
-        } {
-            -1 => Err(io::Error::last_os_error()),
-            ret => {
-                // A return value of zero means that we timed out. A positive value signifies the
-                // number of fds with non-zero revents fields (aka I/O activity).
-                assert!(ret == 0 || ret == 1);
-                Ok(ret)
-            }
+    /// * `buf_type` - Type of the buffer
+    /// * `memory` - Memory type the buffers were requested with
+    /// * `index` - Index of the buffer
+    pub(crate) fn query_buf(
+        &self,
+        buf_type: Type,
+        memory: Memory,
+        index: u32,
+    ) -> io::Result<buffer::Info> {
+        let mut v4l2_planes: [v4l2_plane; VIDEO_MAX_PLANES as usize] = unsafe { mem::zeroed() };
+        let mut v4l2_buf = v4l2_buffer {
+            index,
+            type_: buf_type as u32,
+            memory: memory as u32,
+            ..unsafe { mem::zeroed() }
+        };
+        if buf_type.planar() {
+            v4l2_buf.length = VIDEO_MAX_PLANES;
+            v4l2_buf.m.planes = v4l2_planes.as_mut_ptr();
+        }
+        unsafe {
+            self.ioctl(
+                v4l2::vidioc::VIDIOC_QUERYBUF,
+                &mut v4l2_buf as *mut _ as *mut std::os::raw::c_void,
+            )?;
         }
+
+        let planes = if buf_type.planar() {
+            v4l2_planes[..v4l2_buf.length as usize]
+                .iter()
+                .map(buffer::Plane::from)
+                .collect()
+        } else {
+            vec![buffer::Plane {
+                length: v4l2_buf.length,
+                bytesused: v4l2_buf.bytesused,
+                data_offset: 0,
+                mem_offset: unsafe { v4l2_buf.m.offset },
+            }]
+        };
+        Ok(buffer::Info {
+            meta: buffer::Metadata::from(v4l2_buf),
+            planes,
+        })
     }
 }
 
-impl Drop for Handle {
-    fn drop(&mut self) {
-        let _ = v4l2::close(self.0);
+/// Returns the name of a control as used by v4l2-ctl, e.g. "exposure_absolute"
+///
+/// Letters are lowercased, everything else is collapsed into single underscores.
+fn control_key(name: &str) -> String {
+    let mut key = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c.is_alphanumeric() {
+            key.extend(c.to_lowercase());
+        } else if !key.is_empty() && !key.ends_with('_') {
+            key.push('_');
+        }
+    }
+    if key.ends_with('_') {
+        key.pop();
+    }
+    key
+}
+
+impl fmt::Debug for Handle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Handle").field(&self.descriptor.fd).finish()
     }
 }
 
 impl AsRawFd for Handle {
     fn as_raw_fd(&self) -> RawFd {
-        self.0
+        self.descriptor.fd
+    }
+}
+
+impl AsFd for Handle {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        // The descriptor stays open for as long as any clone of the handle is alive.
+        unsafe { BorrowedFd::borrow_raw(self.descriptor.fd) }
+    }
+}
+
+impl TryFrom<OwnedFd> for Handle {
+    type Error = io::Error;
+
+    fn try_from(fd: OwnedFd) -> Result<Self, Self::Error> {
+        Handle::from_fd(fd)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Mock;
+    use crate::control::Value;
+
+    fn integer(id: u32, value: i64) -> Control {
+        Control {
+            id,
+            value: Value::Integer(value),
+        }
+    }
+
+    #[test]
+    fn enumerates_controls() {
+        let dev: Device = Mock::new().device().unwrap();
+        let controls = VideoDevice::query_controls(&dev).unwrap();
+
+        let names: Vec<_> = controls.iter().map(|desc| desc.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "Brightness",
+                "Contrast",
+                "Horizontal Flip",
+                "Exposure Time, Absolute"
+            ]
+        );
+        assert_eq!(controls[0].id, V4L2_CID_BRIGHTNESS);
+        assert_eq!(controls[0].typ, control::Type::Integer);
+        assert_eq!((controls[0].minimum, controls[0].maximum), (0, 255));
+        assert_eq!(controls[0].default, 128);
+        assert_eq!(controls[2].typ, control::Type::Boolean);
+    }
+
+    #[test]
+    fn reads_back_written_controls() {
+        let dev: Device = Mock::new().device().unwrap();
+        let value = |id| VideoDevice::control(&dev, id).unwrap().value;
+        assert_eq!(value(V4L2_CID_BRIGHTNESS), Value::Integer(128));
+        assert_eq!(value(V4L2_CID_HFLIP), Value::Boolean(false));
+
+        VideoDevice::set_control(&dev, integer(V4L2_CID_BRIGHTNESS, 200)).unwrap();
+        let hflip = Control {
+            id: V4L2_CID_HFLIP,
+            value: Value::Boolean(true),
+        };
+        VideoDevice::set_control(&dev, hflip).unwrap();
+        assert_eq!(value(V4L2_CID_BRIGHTNESS), Value::Integer(200));
+        assert_eq!(value(V4L2_CID_HFLIP), Value::Boolean(true));
+
+        // like the kernel, the driver clamps integers to their range
+        VideoDevice::set_control(&dev, integer(V4L2_CID_BRIGHTNESS, 1000)).unwrap();
+        assert_eq!(value(V4L2_CID_BRIGHTNESS), Value::Integer(255));
+    }
+
+    #[test]
+    fn looks_up_controls_by_name() {
+        let dev: Device = Mock::new().device().unwrap();
+
+        let ctrl = VideoDevice::control_by_name(&dev, "exposure time absolute").unwrap();
+        assert_eq!(ctrl.id, V4L2_CID_EXPOSURE_ABSOLUTE);
+        assert_eq!(ctrl.value, Value::Integer(156));
+
+        let err = VideoDevice::control_by_name(&dev, "zoom").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn reads_controls_of_several_classes_at_once() {
+        let dev: Device = Mock::new().device().unwrap();
+        let values =
+            VideoDevice::get_controls(&dev, &[V4L2_CID_BRIGHTNESS, V4L2_CID_EXPOSURE_ABSOLUTE])
+                .unwrap();
+        assert_eq!(values, [Value::Integer(128), Value::Integer(156)]);
+
+        let err = VideoDevice::control(&dev, V4L2_CID_BRIGHTNESS + 0x100).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn writes_controls_of_one_class_at_once() {
+        let dev: Device = Mock::new().device().unwrap();
+
+        let mixed = vec![
+            integer(V4L2_CID_BRIGHTNESS, 10),
+            integer(V4L2_CID_EXPOSURE_ABSOLUTE, 20),
+        ];
+        let err = VideoDevice::set_controls(&dev, mixed).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+        let user = vec![
+            integer(V4L2_CID_BRIGHTNESS, 10),
+            integer(V4L2_CID_CONTRAST, 20),
+        ];
+        VideoDevice::set_controls(&dev, user).unwrap();
+        let values =
+            VideoDevice::get_controls(&dev, &[V4L2_CID_BRIGHTNESS, V4L2_CID_CONTRAST]).unwrap();
+        assert_eq!(values, [Value::Integer(10), Value::Integer(20)]);
+    }
+
+    #[test]
+    fn forwards_through_references() {
+        fn brightness<D: VideoDevice>(dev: D) -> io::Result<Control> {
+            dev.control(V4L2_CID_BRIGHTNESS)
+        }
+
+        let dev: Device = Mock::new().device().unwrap();
+        assert_eq!(brightness(&dev).unwrap().value, Value::Integer(128));
+        assert_eq!(
+            brightness::<&&Device>(&&dev).unwrap().value,
+            Value::Integer(128)
+        );
     }
 }
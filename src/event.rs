@@ -0,0 +1,211 @@
+//! Device events
+//!
+//! Drivers signal state changes which are not tied to a buffer through events: the start of a
+//! frame exposure, a change of the input source, the end of a stream, .. Events have to be
+//! subscribed to before they are queued by the driver. Pending events are signalled by
+//! `POLLPRI` on the device file descriptor.
+
+use std::collections::VecDeque;
+use std::{fmt, mem, time::Duration};
+
+use crate::control;
+use crate::v4l_sys::*;
+
+/// Event type
+#[allow(clippy::unreadable_literal)]
+#[rustfmt::skip]
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Type {
+    /// All events, only valid for unsubscribing
+    All             = 0,
+    Vsync           = 1,
+    Eos             = 2,
+    Ctrl            = 3,
+    FrameSync       = 4,
+    SourceChange    = 5,
+    MotionDet       = 6,
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Type::All => write!(f, "all"),
+            Type::Vsync => write!(f, "vertical sync"),
+            Type::Eos => write!(f, "end of stream"),
+            Type::Ctrl => write!(f, "control change"),
+            Type::FrameSync => write!(f, "frame sync"),
+            Type::SourceChange => write!(f, "source change"),
+            Type::MotionDet => write!(f, "motion detection"),
+        }
+    }
+}
+
+/// Event specific data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// Last buffer of the stream was dequeued
+    Eos,
+    /// Exposure of a frame started
+    ///
+    /// The frame sequence matches the sequence number of the buffer the frame ends up in.
+    FrameSync { frame_sequence: u32 },
+    /// Input source properties (e.g. the resolution) changed, `changes` is a bitmask of
+    /// `V4L2_EVENT_SRC_CH_*` flags
+    SourceChange { changes: u32 },
+    /// Value or flags of a control changed, `changes` is a bitmask of `V4L2_EVENT_CTRL_CH_*`
+    /// flags. The value is only valid for integer (and boolean, menu, ..) controls.
+    Ctrl {
+        changes: u32,
+        value: i64,
+        flags: control::Flags,
+    },
+    /// Any other event, identified by its raw type
+    Other(u32),
+}
+
+/// Dequeued event
+#[derive(Debug, Clone, Copy)]
+pub struct Event {
+    /// Event specific data
+    pub kind: Kind,
+    /// Object the event refers to, e.g. the control or pad ID
+    pub id: u32,
+    /// Sequence number, counting the events of the file handle
+    pub sequence: u32,
+    /// Number of events still pending
+    pub pending: u32,
+    /// Time the event was queued (CLOCK_MONOTONIC)
+    pub timestamp: Duration,
+}
+
+impl From<v4l2_event> for Event {
+    fn from(ev: v4l2_event) -> Self {
+        let kind = unsafe {
+            match ev.type_ {
+                V4L2_EVENT_EOS => Kind::Eos,
+                V4L2_EVENT_FRAME_SYNC => Kind::FrameSync {
+                    frame_sequence: ev.u.frame_sync.frame_sequence,
+                },
+                V4L2_EVENT_SOURCE_CHANGE => Kind::SourceChange {
+                    changes: ev.u.src_change.changes,
+                },
+                V4L2_EVENT_CTRL => Kind::Ctrl {
+                    changes: ev.u.ctrl.changes,
+                    value: if ev.u.ctrl.type_ == control::Type::Integer64 as u32 {
+                        ev.u.ctrl.__bindgen_anon_1.value64
+                    } else {
+                        ev.u.ctrl.__bindgen_anon_1.value as i64
+                    },
+                    flags: control::Flags::from(ev.u.ctrl.flags),
+                },
+                typ => Kind::Other(typ),
+            }
+        };
+
+        Event {
+            kind,
+            id: ev.id,
+            sequence: ev.sequence,
+            pending: ev.pending,
+            timestamp: Duration::new(ev.timestamp.tv_sec as u64, ev.timestamp.tv_nsec as u32),
+        }
+    }
+}
+
+pub(crate) fn subscription(typ: Type, id: u32, flags: u32) -> v4l2_event_subscription {
+    v4l2_event_subscription {
+        type_: typ as u32,
+        id,
+        flags,
+        ..unsafe { mem::zeroed() }
+    }
+}
+
+/// Correlates frame sync events with dequeued buffers
+///
+/// Frame sync events are queued when the exposure of a frame starts, long before its buffer is
+/// dequeued. The tracker keeps the start times of the most recent frames, so they can be looked
+/// up by buffer sequence number, e.g. to fire a strobe or to timestamp data of external sensors
+/// relative to the exposure.
+///
+/// # Example
+///
+/// ```
+/// use v4l::buffer::Type;
+/// use v4l::event::{self, FrameSync};
+/// use v4l::io::mmap::Stream;
+/// use v4l::io::traits::CaptureStream;
+/// use v4l::Device;
+///
+/// if let Ok(dev) = Device::new(0) {
+///     let mut sync = FrameSync::new(8);
+///     if dev.subscribe_event(event::Type::FrameSync, 0).is_ok() {
+///         let mut stream = Stream::new(&dev, Type::VideoCapture).unwrap();
+///         let (_, meta, _) = stream.next().unwrap();
+///         while let Ok(ev) = dev.dequeue_event() {
+///             sync.push(&ev);
+///         }
+///         println!("exposure started at {:?}", sync.take(meta.sequence));
+///     }
+/// }
+/// ```
+pub struct FrameSync {
+    starts: VecDeque<(u32, Duration)>,
+    capacity: usize,
+}
+
+impl FrameSync {
+    /// Returns a tracker
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Number of frames to remember, usually the number of buffers
+    pub fn new(capacity: usize) -> Self {
+        FrameSync {
+            starts: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Records an event, returns whether it was a frame sync event
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - Dequeued event
+    pub fn push(&mut self, event: &Event) -> bool {
+        match event.kind {
+            Kind::FrameSync { frame_sequence } => {
+                if self.starts.len() == self.capacity {
+                    self.starts.pop_front();
+                }
+                self.starts.push_back((frame_sequence, event.timestamp));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the exposure start time of a frame
+    ///
+    /// Entries of the frame and all frames before it are discarded.
+    ///
+    /// # Arguments
+    ///
+    /// * `sequence` - Sequence number of the dequeued buffer
+    pub fn take(&mut self, sequence: u32) -> Option<Duration> {
+        let mut start = None;
+        // sequence numbers wrap around, compare their distance instead
+        while let Some(&(frame_sequence, timestamp)) = self.starts.front() {
+            if sequence.wrapping_sub(frame_sequence) as i32 >= 0 {
+                self.starts.pop_front();
+                if frame_sequence == sequence {
+                    start = Some(timestamp);
+                }
+            } else {
+                break;
+            }
+        }
+        start
+    }
+}
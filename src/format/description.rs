@@ -7,10 +7,30 @@ use crate::v4l_sys::*;
 bitflags! {
     #[allow(clippy::unreadable_literal)]
     pub struct Flags : u32 {
-        const COMPRESSED            = 0x0001;
-        const EMULATED              = 0x0002;
-        const CONTINUOUS_BITSTREAM  = 0x0004;
-        const DYN_RESOLUTION        = 0x0008;
+        /// Compressed format, e.g. MJPEG or H.264
+        const COMPRESSED                = 0x0001;
+        /// Format is converted in software by libv4l, which costs CPU time
+        const EMULATED                  = 0x0002;
+        /// Compressed bitstream which does not need to be split into frames by the application
+        const CONTINUOUS_BYTESTREAM     = 0x0004;
+        /// Alias of [`Flags::CONTINUOUS_BYTESTREAM`]
+        const CONTINUOUS_BITSTREAM      = 0x0004;
+        /// Decoder detects resolution changes of the bitstream
+        const DYN_RESOLUTION            = 0x0008;
+        /// Encoder supports setting the frame interval independent of the frame rate
+        const ENC_CAP_FRAME_INTERVAL    = 0x0010;
+        /// Colorspace can be requested on capture queues
+        const CSC_COLORSPACE            = 0x0020;
+        /// Transfer function can be requested on capture queues
+        const CSC_XFER_FUNC             = 0x0040;
+        /// Y'CbCr encoding can be requested on capture queues
+        const CSC_YCBCR_ENC             = 0x0080;
+        /// HSV encoding can be requested on capture queues, shares the bit with Y'CbCr
+        const CSC_HSV_ENC               = 0x0080;
+        /// Quantization can be requested on capture queues
+        const CSC_QUANTIZATION          = 0x0100;
+        /// Metadata format is line based, like image data
+        const META_LINE_BASED           = 0x0200;
     }
 }
 
@@ -42,6 +62,16 @@ pub struct Description {
     pub fourcc: FourCC,
 }
 
+impl Description {
+    /// Returns whether the format is converted by libv4l rather than provided by the driver
+    ///
+    /// Emulated formats are convenient, but converting each frame in software costs CPU time.
+    /// Performance sensitive applications should prefer native formats.
+    pub fn is_emulated(&self) -> bool {
+        self.flags.contains(Flags::EMULATED)
+    }
+}
+
 impl fmt::Display for Description {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "index       : {}", self.index)?;
@@ -0,0 +1,72 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+#[derive(Debug, Copy, Clone)]
+#[repr(u32)]
+/// Y'CbCr (or HSV) encoding for the colorspace.
+///
+/// The driver decides this for capture streams unless a conversion is requested, and the user
+/// sets it for output streams.
+pub enum Encoding {
+    /// default for the colorspace
+    Default = 0,
+    /// ITU-R BT.601 encoding
+    BT601 = 1,
+    /// Rec. 709 encoding
+    Rec709 = 2,
+    /// extended gamut xvYCC BT.601 encoding
+    XV601 = 3,
+    /// extended gamut xvYCC Rec. 709 encoding
+    XV709 = 4,
+    /// sYCC encoding, identical to BT.601
+    SYCC = 5,
+    /// BT.2020 non-constant luminance encoding
+    BT2020 = 6,
+    /// BT.2020 constant luminance encoding
+    BT2020ConstLum = 7,
+    /// SMPTE 240M encoding
+    SMPTE240M = 8,
+    /// HSV with the hue mapped to 0-179
+    HSV180 = 128,
+    /// HSV with the hue mapped to 0-255
+    HSV256 = 129,
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Default => write!(f, "default"),
+            Self::BT601 => write!(f, "BT.601"),
+            Self::Rec709 => write!(f, "Rec. 709"),
+            Self::XV601 => write!(f, "xvYCC 601"),
+            Self::XV709 => write!(f, "xvYCC 709"),
+            Self::SYCC => write!(f, "sYCC"),
+            Self::BT2020 => write!(f, "BT.2020"),
+            Self::BT2020ConstLum => write!(f, "BT.2020 constant luminance"),
+            Self::SMPTE240M => write!(f, "SMPTE 240M"),
+            Self::HSV180 => write!(f, "HSV 180"),
+            Self::HSV256 => write!(f, "HSV 256"),
+        }
+    }
+}
+
+impl TryFrom<u32> for Encoding {
+    type Error = ();
+
+    fn try_from(code: u32) -> Result<Self, Self::Error> {
+        match code {
+            0 => Ok(Self::Default),
+            1 => Ok(Self::BT601),
+            2 => Ok(Self::Rec709),
+            3 => Ok(Self::XV601),
+            4 => Ok(Self::XV709),
+            5 => Ok(Self::SYCC),
+            6 => Ok(Self::BT2020),
+            7 => Ok(Self::BT2020ConstLum),
+            8 => Ok(Self::SMPTE240M),
+            128 => Ok(Self::HSV180),
+            129 => Ok(Self::HSV256),
+            _ => Err(()),
+        }
+    }
+}
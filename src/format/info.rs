@@ -0,0 +1,100 @@
+use crate::format::FourCC;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// Memory layout of an uncompressed pixel format
+///
+/// This mirrors the format info table of the kernel (`v4l2_format_info`) and allows computing
+/// the stride and size of each plane without asking the driver.
+pub struct Info {
+    /// Pixelformat code
+    pub fourcc: FourCC,
+    /// Number of memory planes, i.e. buffers the image is stored in
+    pub mem_planes: usize,
+    /// Number of component planes, e.g. 2 for Y and interleaved CbCr
+    pub comp_planes: usize,
+    /// Bytes per pixel of each component plane
+    pub bpp: [u32; 3],
+    /// Horizontal and vertical chroma subsampling of the planes following the first one
+    pub subsampling: (u32, u32),
+}
+
+impl Info {
+    /// Returns the memory layout of a pixel format
+    ///
+    /// Compressed and vendor specific formats are not known, `None` is returned for those.
+    ///
+    /// # Arguments
+    ///
+    /// * `fourcc` - Four character code (pixelformat)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::format::{FourCC, Info};
+    /// let info = Info::new(FourCC::new(b"NM12")).unwrap();
+    /// assert_eq!(info.mem_planes, 2);
+    /// ```
+    pub fn new(fourcc: FourCC) -> Option<Self> {
+        let info = |mem_planes, comp_planes, bpp, subsampling| {
+            Some(Info {
+                fourcc,
+                mem_planes,
+                comp_planes,
+                bpp,
+                subsampling,
+            })
+        };
+
+        match &fourcc.repr {
+            // packed formats
+            b"GREY" => info(1, 1, [1, 0, 0], (1, 1)),
+            b"Y16 " => info(1, 1, [2, 0, 0], (1, 1)),
+            b"YUYV" | b"YVYU" | b"UYVY" | b"VYUY" => info(1, 1, [2, 0, 0], (2, 1)),
+            b"RGBP" | b"RGBR" | b"RGBO" | b"RGBQ" => info(1, 1, [2, 0, 0], (1, 1)),
+            b"RGB3" | b"BGR3" => info(1, 1, [3, 0, 0], (1, 1)),
+            b"RGB4" | b"BGR4" | b"AR24" | b"XR24" | b"AB24" | b"XB24" | b"RA24" | b"RX24"
+            | b"BA24" | b"BX24" => info(1, 1, [4, 0, 0], (1, 1)),
+            // planar formats stored in a single memory plane
+            b"NV12" | b"NV21" => info(1, 2, [1, 2, 0], (2, 2)),
+            b"NV16" | b"NV61" => info(1, 2, [1, 2, 0], (2, 1)),
+            b"NV24" | b"NV42" => info(1, 2, [1, 2, 0], (1, 1)),
+            b"YU12" | b"YV12" => info(1, 3, [1, 1, 1], (2, 2)),
+            b"422P" => info(1, 3, [1, 1, 1], (2, 1)),
+            // planar formats stored in separate memory planes
+            b"NM12" | b"NM21" => info(2, 2, [1, 2, 0], (2, 2)),
+            b"NM16" | b"NM61" => info(2, 2, [1, 2, 0], (2, 1)),
+            b"YM12" | b"YM21" => info(3, 3, [1, 1, 1], (2, 2)),
+            b"YM16" | b"YM61" => info(3, 3, [1, 1, 1], (2, 1)),
+            b"YM24" | b"YM42" => info(3, 3, [1, 1, 1], (1, 1)),
+            _ => None,
+        }
+    }
+
+    /// Returns the stride and size of each memory plane
+    ///
+    /// Planes are packed without any padding, drivers with alignment requirements may report
+    /// larger values when the format is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Width in pixels
+    /// * `height` - Height in pixels
+    pub fn planes(&self, width: u32, height: u32) -> Vec<(u32, u32)> {
+        let (sub_x, sub_y) = self.subsampling;
+        let comp_plane = |plane: usize| {
+            // packed formats subsample within the pixel, not across planes
+            let (sub_x, sub_y) = if plane == 0 { (1, 1) } else { (sub_x, sub_y) };
+            let stride = width.div_ceil(sub_x) * self.bpp[plane];
+            (stride, stride * height.div_ceil(sub_y))
+        };
+
+        if self.mem_planes == self.comp_planes {
+            (0..self.comp_planes).map(comp_plane).collect()
+        } else {
+            // all component planes follow each other, the stride refers to the first one
+            let stride = comp_plane(0).0;
+            let size = (0..self.comp_planes).map(|plane| comp_plane(plane).1).sum();
+            vec![(stride, size)]
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use bitflags::bitflags;
-use std::{convert::TryFrom, fmt, mem};
+use std::{convert::TryFrom, fmt, io, mem};
 
 use crate::v4l_sys::*;
 
@@ -9,12 +9,18 @@ pub use colorspace::Colorspace;
 pub mod description;
 pub use description::Description;
 
+pub mod encoding;
+pub use encoding::Encoding;
+
 pub mod field;
 pub use field::FieldOrder;
 
 pub mod fourcc;
 pub use fourcc::FourCC;
 
+pub mod info;
+pub use info::Info;
+
 pub mod quantization;
 pub use quantization::Quantization;
 
@@ -25,6 +31,9 @@ bitflags! {
     #[allow(clippy::unreadable_literal)]
     pub struct Flags : u32 {
         const PREMUL_ALPHA  = 0x00000001;
+        /// Request the driver to convert to the colorimetry given in the format, see
+        /// [`PlanarFormat::request_csc`]
+        const SET_CSC       = 0x00000002;
     }
 }
 
@@ -68,12 +77,57 @@ pub struct PlanarFormat<P> {
 
     /// supplements the pixelformat (fourcc) information
     pub colorspace: Colorspace,
+    /// the Y'CbCr (or HSV) encoding
+    pub encoding: Encoding,
     /// the way colors are mapped
     pub quantization: Quantization,
     /// the transfer function for the colorspace
     pub transfer: TransferFunction,
 }
 
+impl<P> PlanarFormat<P> {
+    /// Requests a colorspace conversion to the given colorimetry
+    ///
+    /// Capture drivers which are able to convert (e.g. HDMI receivers) advertise it with the
+    /// `CSC_*` flags of the format [`Description`]. The fields a driver cannot convert are
+    /// replaced by the colorimetry it delivers, so check the format returned by `set_format`.
+    ///
+    /// # Arguments
+    ///
+    /// * `colorspace` - Desired colorspace
+    /// * `encoding` - Desired Y'CbCr (or HSV) encoding
+    /// * `quantization` - Desired quantization
+    /// * `transfer` - Desired transfer function
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::format::{Colorspace, Encoding, Quantization, TransferFunction};
+    /// use v4l::{Format, FourCC};
+    ///
+    /// let fmt = Format::new(1920, 1080, FourCC::new(b"YUYV")).request_csc(
+    ///     Colorspace::Rec709,
+    ///     Encoding::Rec709,
+    ///     Quantization::LimitedRange,
+    ///     TransferFunction::Rec709,
+    /// );
+    /// ```
+    pub fn request_csc(
+        mut self,
+        colorspace: Colorspace,
+        encoding: Encoding,
+        quantization: Quantization,
+        transfer: TransferFunction,
+    ) -> Self {
+        self.colorspace = colorspace;
+        self.encoding = encoding;
+        self.quantization = quantization;
+        self.transfer = transfer;
+        self.flags |= Flags::SET_CSC;
+        self
+    }
+}
+
 pub type Format = PlanarFormat<u32>;
 impl Copy for Format {}
 
@@ -102,6 +156,7 @@ impl Format {
             size: 0,
             flags: Flags::empty(),
             colorspace: Colorspace::Default,
+            encoding: Encoding::Default,
             quantization: Quantization::Default,
             transfer: TransferFunction::Default,
         }
@@ -117,6 +172,7 @@ impl fmt::Display for Format {
         writeln!(f, "stride         : {}", self.stride)?;
         writeln!(f, "size           : {}", self.size)?;
         writeln!(f, "colorspace     : {}", self.colorspace)?;
+        writeln!(f, "encoding       : {}", self.encoding)?;
         writeln!(f, "quantization   : {}", self.quantization)?;
         writeln!(f, "transfer       : {}", self.transfer)?;
         Ok(())
@@ -134,6 +190,9 @@ impl From<v4l2_pix_format> for Format {
             size: fmt.sizeimage,
             flags: Flags::from(fmt.flags),
             colorspace: Colorspace::try_from(fmt.colorspace).expect("Invalid colorspace"),
+            // the Y'CbCr and HSV encodings share the field
+            encoding: Encoding::try_from(unsafe { fmt.__bindgen_anon_1.ycbcr_enc })
+                .expect("Invalid encoding"),
             quantization: Quantization::try_from(fmt.quantization).expect("Invalid quantization"),
             transfer: TransferFunction::try_from(fmt.xfer_func).expect("Invalid transfer function"),
         }
@@ -151,6 +210,9 @@ impl From<Format> for v4l2_pix_format {
             sizeimage: format.size,
             colorspace: format.colorspace as u32,
             flags: format.flags.into(),
+            __bindgen_anon_1: v4l2_pix_format__bindgen_ty_1 {
+                ycbcr_enc: format.encoding as u32,
+            },
             quantization: format.quantization as u32,
             xfer_func: format.transfer as u32,
             ..unsafe { mem::zeroed() }
@@ -171,10 +233,98 @@ impl MultiPlaneFormat {
             size: vec![0],
             flags: Flags::empty(),
             colorspace: Colorspace::Default,
+            encoding: Encoding::Default,
             quantization: Quantization::Default,
             transfer: TransferFunction::Default,
         }
     }
+
+    /// Returns a builder which computes the plane layout from the pixel format
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Width in pixels
+    /// * `height` - Height in pixels
+    /// * `fourcc` - Four character code (pixelformat)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::format::{FourCC, MultiPlaneFormat};
+    /// let fmt = MultiPlaneFormat::builder(640, 480, FourCC::new(b"NM12"))
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(fmt.size, vec![640 * 480, 640 * 240]);
+    /// ```
+    pub fn builder(width: u32, height: u32, fourcc: FourCC) -> MultiPlaneFormatBuilder {
+        MultiPlaneFormatBuilder {
+            format: Self::single_plane(width, height, fourcc),
+        }
+    }
+}
+
+/// Multi-planar format builder
+///
+/// The stride and size of each plane are filled in from the pixel format [`Info`], so the
+/// format can be handed to the driver or used to allocate buffers right away.
+pub struct MultiPlaneFormatBuilder {
+    format: MultiPlaneFormat,
+}
+
+impl MultiPlaneFormatBuilder {
+    /// Sets the field order, defaults to [`FieldOrder::Any`]
+    pub fn field_order(mut self, field_order: FieldOrder) -> Self {
+        self.format.field_order = field_order;
+        self
+    }
+
+    /// Sets the format flags
+    pub fn flags(mut self, flags: Flags) -> Self {
+        self.format.flags = flags;
+        self
+    }
+
+    /// Sets the colorspace, defaults to [`Colorspace::Default`]
+    pub fn colorspace(mut self, colorspace: Colorspace) -> Self {
+        self.format.colorspace = colorspace;
+        self
+    }
+
+    /// Sets the Y'CbCr (or HSV) encoding, defaults to [`Encoding::Default`]
+    pub fn encoding(mut self, encoding: Encoding) -> Self {
+        self.format.encoding = encoding;
+        self
+    }
+
+    /// Sets the quantization, defaults to [`Quantization::Default`]
+    pub fn quantization(mut self, quantization: Quantization) -> Self {
+        self.format.quantization = quantization;
+        self
+    }
+
+    /// Sets the transfer function, defaults to [`TransferFunction::Default`]
+    pub fn transfer(mut self, transfer: TransferFunction) -> Self {
+        self.format.transfer = transfer;
+        self
+    }
+
+    /// Returns the format with the stride and size of every memory plane filled in
+    ///
+    /// Fails if the memory layout of the pixel format is not known, e.g. for compressed formats.
+    pub fn build(self) -> io::Result<MultiPlaneFormat> {
+        let mut format = self.format;
+        let info = Info::new(format.fourcc).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::Unsupported,
+                "memory layout of the pixel format is unknown",
+            )
+        })?;
+
+        let (stride, size) = info.planes(format.width, format.height).into_iter().unzip();
+        format.stride = stride;
+        format.size = size;
+        Ok(format)
+    }
 }
 
 impl From<v4l2_pix_format_mplane> for MultiPlaneFormat {
@@ -189,6 +339,8 @@ impl From<v4l2_pix_format_mplane> for MultiPlaneFormat {
             size: planes.iter().map(|p| p.sizeimage).collect(),
             flags: Flags::from(fmt.flags as u32),
             colorspace: Colorspace::try_from(fmt.colorspace).expect("Invalid colorspace"),
+            encoding: Encoding::try_from(unsafe { fmt.__bindgen_anon_1.ycbcr_enc } as u32)
+                .expect("Invalid encoding"),
             quantization: Quantization::try_from(fmt.quantization as u32)
                 .expect("Invalid quantization"),
             transfer: TransferFunction::try_from(fmt.xfer_func as u32)
@@ -206,6 +358,7 @@ impl fmt::Display for MultiPlaneFormat {
         writeln!(f, "stride         : {:?}", self.stride)?;
         writeln!(f, "size           : {:?}", self.size)?;
         writeln!(f, "colorspace     : {}",   self.colorspace)?;
+        writeln!(f, "encoding       : {}",   self.encoding)?;
         writeln!(f, "quantization   : {}",   self.quantization)?;
         writeln!(f, "transfer       : {}",   self.transfer)?;
         Ok(())
@@ -228,6 +381,9 @@ impl From<MultiPlaneFormat> for v4l2_pix_format_mplane {
             num_planes: format.size.len() as u8,
             colorspace: format.colorspace as u32,
             flags: Into::<u32>::into(format.flags) as u8,
+            __bindgen_anon_1: v4l2_pix_format_mplane__bindgen_ty_1 {
+                ycbcr_enc: format.encoding as u8,
+            },
             quantization: format.quantization as u8,
             xfer_func: format.transfer as u8,
             ..unsafe { mem::zeroed() }
@@ -240,3 +396,138 @@ impl From<MultiPlaneFormat> for v4l2_pix_format_mplane {
     }
 }
 
+impl From<Format> for MultiPlaneFormat {
+    fn from(format: Format) -> Self {
+        // single-planar formats keep all image planes in one memory plane
+        Self {
+            width: format.width,
+            height: format.height,
+            fourcc: format.fourcc,
+            field_order: format.field_order,
+            stride: vec![format.stride],
+            size: vec![format.size],
+            flags: format.flags,
+            colorspace: format.colorspace,
+            encoding: format.encoding,
+            quantization: format.quantization,
+            transfer: format.transfer,
+        }
+    }
+}
+
+impl TryFrom<MultiPlaneFormat> for Format {
+    type Error = ();
+
+    /// Fails if the format is stored in more than one memory plane (e.g. NM12)
+    fn try_from(format: MultiPlaneFormat) -> Result<Self, Self::Error> {
+        match (format.stride.as_slice(), format.size.as_slice()) {
+            ([stride], [size]) => Ok(Self {
+                width: format.width,
+                height: format.height,
+                fourcc: format.fourcc,
+                field_order: format.field_order,
+                stride: *stride,
+                size: *size,
+                flags: format.flags,
+                colorspace: format.colorspace,
+                encoding: format.encoding,
+                quantization: format.quantization,
+                transfer: format.transfer,
+            }),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Streaming format of either API flavor
+///
+/// Some drivers only implement the multi-planar API, even for formats which are stored in a
+/// single memory plane. Code which handles both kinds of devices can pass this type around and
+/// convert it into the flavor a device expects.
+pub enum AnyFormat {
+    /// Single-planar format
+    Single(Format),
+    /// Multi-planar format
+    Multi(MultiPlaneFormat),
+}
+
+impl AnyFormat {
+    /// Returns the width in pixels
+    pub fn width(&self) -> u32 {
+        match self {
+            AnyFormat::Single(format) => format.width,
+            AnyFormat::Multi(format) => format.width,
+        }
+    }
+
+    /// Returns the height in pixels
+    pub fn height(&self) -> u32 {
+        match self {
+            AnyFormat::Single(format) => format.height,
+            AnyFormat::Multi(format) => format.height,
+        }
+    }
+
+    /// Returns the pixelformat code
+    pub fn fourcc(&self) -> FourCC {
+        match self {
+            AnyFormat::Single(format) => format.fourcc,
+            AnyFormat::Multi(format) => format.fourcc,
+        }
+    }
+
+    /// Returns the stride and size of every memory plane
+    pub fn planes(&self) -> Vec<(u32, u32)> {
+        match self {
+            AnyFormat::Single(format) => vec![(format.stride, format.size)],
+            AnyFormat::Multi(format) => format
+                .stride
+                .iter()
+                .copied()
+                .zip(format.size.iter().copied())
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for AnyFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnyFormat::Single(format) => format.fmt(f),
+            AnyFormat::Multi(format) => format.fmt(f),
+        }
+    }
+}
+
+impl From<Format> for AnyFormat {
+    fn from(format: Format) -> Self {
+        AnyFormat::Single(format)
+    }
+}
+
+impl From<MultiPlaneFormat> for AnyFormat {
+    fn from(format: MultiPlaneFormat) -> Self {
+        AnyFormat::Multi(format)
+    }
+}
+
+impl From<AnyFormat> for MultiPlaneFormat {
+    fn from(format: AnyFormat) -> Self {
+        match format {
+            AnyFormat::Single(format) => format.into(),
+            AnyFormat::Multi(format) => format,
+        }
+    }
+}
+
+impl TryFrom<AnyFormat> for Format {
+    type Error = ();
+
+    fn try_from(format: AnyFormat) -> Result<Self, Self::Error> {
+        match format {
+            AnyFormat::Single(format) => Ok(format),
+            AnyFormat::Multi(format) => Format::try_from(format),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use std::io;
+
+use crate::buffer::Metadata;
+use crate::io::traits::CaptureStream;
+use crate::v4l_sys::v4l2_plane;
+
+/// Stream buffers which consist of one or more image planes
+pub trait Planes {
+    /// Returns the memory of all planes
+    fn planes(&self) -> Vec<&[u8]>;
+}
+
+impl Planes for [u8] {
+    fn planes(&self) -> Vec<&[u8]> {
+        vec![self]
+    }
+}
+
+impl Planes for Vec<&mut [u8]> {
+    fn planes(&self) -> Vec<&[u8]> {
+        self.iter().map(|plane| &**plane).collect()
+    }
+}
+
+/// Captured frame owning its data
+///
+/// Stream buffers are only borrowed until the next buffer is dequeued. Copying them into a frame
+/// allows to keep the data around for longer, e.g. to hand it over to other threads.
+#[derive(Clone)]
+pub struct Frame {
+    /// Image planes, single-planar buffers have exactly one
+    pub planes: Vec<Vec<u8>>,
+    /// Buffer metadata
+    pub meta: Metadata,
+}
+
+impl Frame {
+    /// Returns a frame
+    ///
+    /// # Arguments
+    ///
+    /// * `planes` - Image planes
+    /// * `meta` - Buffer metadata
+    pub fn new(planes: Vec<Vec<u8>>, meta: Metadata) -> Self {
+        Frame { planes, meta }
+    }
+
+    /// Copies the payload of a dequeued buffer
+    ///
+    /// Only the bytes which are actually used by the payload are copied, which matters for
+    /// compressed formats such as MJPEG.
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - Buffer memory
+    /// * `meta` - Buffer metadata
+    /// * `planes` - Plane descriptions of multi-planar buffers
+    pub fn copy_from<T: Planes + ?Sized>(buf: &T, meta: &Metadata, planes: &[v4l2_plane]) -> Self {
+        let data = buf
+            .planes()
+            .into_iter()
+            .enumerate()
+            .map(|(i, plane)| {
+                // single-planar buffers carry the payload size in the metadata only
+                let used = planes
+                    .get(i)
+                    .map(|plane| plane.bytesused)
+                    .filter(|&used| used > 0)
+                    .unwrap_or(meta.bytesused) as usize;
+
+                // drivers may not report the payload size, fall back to the whole buffer then
+                match used {
+                    0 => plane.to_vec(),
+                    used => plane[..used.min(plane.len())].to_vec(),
+                }
+            })
+            .collect();
+
+        Frame::new(data, *meta)
+    }
+
+    /// Captures the next frame of a stream
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - Capture stream
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::buffer::Type;
+    /// use v4l::frame::Frame;
+    /// use v4l::prelude::*;
+    ///
+    /// if let Ok(dev) = Device::new(0) {
+    ///     let mut stream = MmapStream::new(&dev, Type::VideoCapture).unwrap();
+    ///     let frame = Frame::capture(&mut stream);
+    /// }
+    /// ```
+    pub fn capture<S>(stream: &mut S) -> io::Result<Self>
+    where
+        S: for<'b> CaptureStream<'b>,
+        S::Item: Planes,
+    {
+        let (buf, meta, planes) = stream.next()?;
+        Ok(Frame::copy_from(buf, meta, planes))
+    }
+
+    /// Returns the data of the first plane
+    pub fn data(&self) -> &[u8] {
+        self.planes.first().map(|plane| &plane[..]).unwrap_or(&[])
+    }
+
+    /// Returns the total number of bytes of all planes
+    pub fn len(&self) -> usize {
+        self.planes.iter().map(|plane| plane.len()).sum()
+    }
+
+    /// Returns whether the frame contains no data at all
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -0,0 +1,176 @@
+//! EGL dma-buf import
+//!
+//! `EGL_EXT_image_dma_buf_import` creates an `EGLImage` from DMA buffers, which can then be
+//! bound to an OpenGL (ES) texture. This avoids copying frames for previews. The extension is
+//! configured through a list of attributes describing the DRM fourcc and the memory layout of
+//! each image plane, see [`import_attributes`].
+
+use std::io;
+use std::os::fd::AsRawFd;
+
+use crate::format::{Colorspace, FourCC, MultiPlaneFormat, Quantization};
+use crate::interop::Layout;
+use crate::io::dmabuf::DmaBuf;
+
+pub const EGL_NONE: i32 = 0x3038;
+pub const EGL_WIDTH: i32 = 0x3057;
+pub const EGL_HEIGHT: i32 = 0x3056;
+pub const EGL_LINUX_DRM_FOURCC_EXT: i32 = 0x3271;
+pub const EGL_DMA_BUF_PLANE0_FD_EXT: i32 = 0x3272;
+pub const EGL_DMA_BUF_PLANE0_OFFSET_EXT: i32 = 0x3273;
+pub const EGL_DMA_BUF_PLANE0_PITCH_EXT: i32 = 0x3274;
+pub const EGL_DMA_BUF_PLANE1_FD_EXT: i32 = 0x3275;
+pub const EGL_DMA_BUF_PLANE1_OFFSET_EXT: i32 = 0x3276;
+pub const EGL_DMA_BUF_PLANE1_PITCH_EXT: i32 = 0x3277;
+pub const EGL_DMA_BUF_PLANE2_FD_EXT: i32 = 0x3278;
+pub const EGL_DMA_BUF_PLANE2_OFFSET_EXT: i32 = 0x3279;
+pub const EGL_DMA_BUF_PLANE2_PITCH_EXT: i32 = 0x327a;
+pub const EGL_YUV_COLOR_SPACE_HINT_EXT: i32 = 0x327b;
+pub const EGL_SAMPLE_RANGE_HINT_EXT: i32 = 0x327c;
+pub const EGL_ITU_REC601_EXT: i32 = 0x327f;
+pub const EGL_ITU_REC709_EXT: i32 = 0x3280;
+pub const EGL_ITU_REC2020_EXT: i32 = 0x3281;
+pub const EGL_YUV_FULL_RANGE_EXT: i32 = 0x3282;
+pub const EGL_YUV_NARROW_RANGE_EXT: i32 = 0x3283;
+pub const EGL_DMA_BUF_PLANE0_MODIFIER_LO_EXT: i32 = 0x3443;
+pub const EGL_DMA_BUF_PLANE0_MODIFIER_HI_EXT: i32 = 0x3444;
+pub const EGL_DMA_BUF_PLANE1_MODIFIER_LO_EXT: i32 = 0x3445;
+pub const EGL_DMA_BUF_PLANE1_MODIFIER_HI_EXT: i32 = 0x3446;
+pub const EGL_DMA_BUF_PLANE2_MODIFIER_LO_EXT: i32 = 0x3447;
+pub const EGL_DMA_BUF_PLANE2_MODIFIER_HI_EXT: i32 = 0x3448;
+
+/// Linear (untiled) layout, the only layout V4L2 buffers are guaranteed to have
+pub const DRM_FORMAT_MOD_LINEAR: u64 = 0;
+
+/// FD, offset, pitch and modifier attributes of the image planes
+const PLANE_ATTRIBUTES: [[i32; 5]; 3] = [
+    [
+        EGL_DMA_BUF_PLANE0_FD_EXT,
+        EGL_DMA_BUF_PLANE0_OFFSET_EXT,
+        EGL_DMA_BUF_PLANE0_PITCH_EXT,
+        EGL_DMA_BUF_PLANE0_MODIFIER_LO_EXT,
+        EGL_DMA_BUF_PLANE0_MODIFIER_HI_EXT,
+    ],
+    [
+        EGL_DMA_BUF_PLANE1_FD_EXT,
+        EGL_DMA_BUF_PLANE1_OFFSET_EXT,
+        EGL_DMA_BUF_PLANE1_PITCH_EXT,
+        EGL_DMA_BUF_PLANE1_MODIFIER_LO_EXT,
+        EGL_DMA_BUF_PLANE1_MODIFIER_HI_EXT,
+    ],
+    [
+        EGL_DMA_BUF_PLANE2_FD_EXT,
+        EGL_DMA_BUF_PLANE2_OFFSET_EXT,
+        EGL_DMA_BUF_PLANE2_PITCH_EXT,
+        EGL_DMA_BUF_PLANE2_MODIFIER_LO_EXT,
+        EGL_DMA_BUF_PLANE2_MODIFIER_HI_EXT,
+    ],
+];
+
+/// Returns the DRM fourcc code of a V4L2 pixel format, if it can be imported
+///
+/// # Arguments
+///
+/// * `fourcc` - V4L2 pixel format
+pub fn drm_fourcc(fourcc: FourCC) -> Option<FourCC> {
+    Layout::new(fourcc).map(|layout| FourCC::new(layout.drm))
+}
+
+/// Returns the attribute list for `eglCreateImageKHR` with the `EGL_LINUX_DMA_BUF_EXT` target
+///
+/// The list is terminated by `EGL_NONE`. The file descriptors are borrowed from `buf`, which has
+/// to be kept alive until the image was created. Passing a modifier requires the
+/// `EGL_EXT_image_dma_buf_import_modifiers` extension, for V4L2 buffers that is usually
+/// [`DRM_FORMAT_MOD_LINEAR`]. For YUV formats, colorspace and quantization of the format are
+/// passed as hints.
+///
+/// # Arguments
+///
+/// * `buf` - Exported buffer
+/// * `format` - Format of the buffer
+/// * `modifier` - Format modifier, if any
+///
+/// # Example
+///
+/// ```
+/// use v4l::buffer::Type;
+/// use v4l::device::MultiPlaneDevice;
+/// use v4l::interop::egl;
+/// use v4l::io::mmap::Stream;
+/// use v4l::video::Capture;
+///
+/// if let Ok(dev) = MultiPlaneDevice::new(0) {
+///     let format = dev.format().unwrap();
+///     let stream = Stream::new(&dev, Type::VideoCaptureMplane).unwrap();
+///     let buf = stream.export(0).unwrap();
+///     let attribs = egl::import_attributes(&buf, &format, None).unwrap();
+///     // eglCreateImageKHR(display, EGL_NO_CONTEXT, EGL_LINUX_DMA_BUF_EXT, null, attribs)
+/// }
+/// ```
+pub fn import_attributes(
+    buf: &DmaBuf,
+    format: &MultiPlaneFormat,
+    modifier: Option<u64>,
+) -> io::Result<Vec<i32>> {
+    let layout = Layout::new(format.fourcc).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::Unsupported,
+            "pixel format has no DRM equivalent",
+        )
+    })?;
+
+    let mut attribs = vec![
+        EGL_WIDTH,
+        format.width as i32,
+        EGL_HEIGHT,
+        format.height as i32,
+        EGL_LINUX_DRM_FOURCC_EXT,
+        u32::from(FourCC::new(layout.drm)) as i32,
+    ];
+
+    for (plane, attrs) in layout.planes(buf, format)?.iter().zip(&PLANE_ATTRIBUTES) {
+        let [fd_attr, offset_attr, pitch_attr, lo_attr, hi_attr] = *attrs;
+        attribs.extend_from_slice(&[
+            fd_attr,
+            plane.memory.fd.as_raw_fd(),
+            offset_attr,
+            plane.offset as i32,
+            pitch_attr,
+            plane.pitch as i32,
+        ]);
+        if let Some(modifier) = modifier {
+            attribs.extend_from_slice(&[
+                lo_attr,
+                (modifier & 0xffff_ffff) as i32,
+                hi_attr,
+                (modifier >> 32) as i32,
+            ]);
+        }
+    }
+
+    if layout.yuv() {
+        let color_space = match format.colorspace {
+            Colorspace::Rec709 => EGL_ITU_REC709_EXT,
+            Colorspace::Rec2020 => EGL_ITU_REC2020_EXT,
+            _ => EGL_ITU_REC601_EXT,
+        };
+        let full_range = match format.quantization {
+            Quantization::FullRange => true,
+            Quantization::LimitedRange => false,
+            Quantization::Default => matches!(format.colorspace, Colorspace::JPEG),
+        };
+        attribs.extend_from_slice(&[
+            EGL_YUV_COLOR_SPACE_HINT_EXT,
+            color_space,
+            EGL_SAMPLE_RANGE_HINT_EXT,
+            if full_range {
+                EGL_YUV_FULL_RANGE_EXT
+            } else {
+                EGL_YUV_NARROW_RANGE_EXT
+            },
+        ]);
+    }
+
+    attribs.push(EGL_NONE);
+    Ok(attribs)
+}
@@ -0,0 +1,148 @@
+//! Glue for handing frames to other graphics and media APIs
+//!
+//! The helpers in here translate buffers and formats into whatever the other side expects,
+//! without depending on the respective libraries themselves.
+
+use std::io;
+
+use crate::format::{FourCC, MultiPlaneFormat};
+use crate::io::dmabuf::{DmaBuf, Plane};
+
+pub mod egl;
+#[cfg(feature = "ndarray")]
+pub mod ndarray;
+#[cfg(feature = "opencv")]
+pub mod opencv;
+#[cfg(feature = "pipewire")]
+pub mod pipewire;
+#[cfg(feature = "vulkan")]
+pub mod vulkan;
+
+/// Memory layout of a pixel format as seen by DRM
+pub(crate) struct Layout {
+    /// DRM fourcc code
+    pub drm: &'static [u8; 4],
+    /// Horizontal and vertical chroma subsampling of the planes following the first one
+    pub subsampling: (u32, u32),
+    /// Number of image planes
+    pub planes: usize,
+    /// Whether the image planes are stored in separate memory planes
+    pub separate: bool,
+}
+
+impl Layout {
+    pub fn new(fourcc: FourCC) -> Option<Self> {
+        let layout = |drm, subsampling, planes, separate| {
+            Some(Layout {
+                drm,
+                subsampling,
+                planes,
+                separate,
+            })
+        };
+
+        match &fourcc.repr {
+            b"YUYV" => layout(b"YUYV", (1, 1), 1, false),
+            b"YVYU" => layout(b"YVYU", (1, 1), 1, false),
+            b"UYVY" => layout(b"UYVY", (1, 1), 1, false),
+            b"VYUY" => layout(b"VYUY", (1, 1), 1, false),
+            b"GREY" => layout(b"R8  ", (1, 1), 1, false),
+            // V4L2 names RGB formats by byte order, DRM by little-endian word order
+            b"RGB3" => layout(b"BG24", (1, 1), 1, false),
+            b"BGR3" => layout(b"RG24", (1, 1), 1, false),
+            b"AR24" => layout(b"AR24", (1, 1), 1, false),
+            b"XR24" => layout(b"XR24", (1, 1), 1, false),
+            b"AB24" => layout(b"AB24", (1, 1), 1, false),
+            b"XB24" => layout(b"XB24", (1, 1), 1, false),
+            b"RGBP" => layout(b"RG16", (1, 1), 1, false),
+            b"NV12" => layout(b"NV12", (2, 2), 2, false),
+            b"NV21" => layout(b"NV21", (2, 2), 2, false),
+            b"NV16" => layout(b"NV16", (2, 1), 2, false),
+            b"NV61" => layout(b"NV61", (2, 1), 2, false),
+            b"NV24" => layout(b"NV24", (1, 1), 2, false),
+            b"YU12" => layout(b"YU12", (2, 2), 3, false),
+            b"YV12" => layout(b"YV12", (2, 2), 3, false),
+            b"422P" => layout(b"YU16", (2, 1), 3, false),
+            b"NM12" => layout(b"NV12", (2, 2), 2, true),
+            b"NM21" => layout(b"NV21", (2, 2), 2, true),
+            b"NM16" => layout(b"NV16", (2, 1), 2, true),
+            b"NM61" => layout(b"NV61", (2, 1), 2, true),
+            b"YM12" => layout(b"YU12", (2, 2), 3, true),
+            b"YM21" => layout(b"YV12", (2, 2), 3, true),
+            b"YM16" => layout(b"YU16", (2, 1), 3, true),
+            _ => None,
+        }
+    }
+
+    pub fn yuv(&self) -> bool {
+        !matches!(
+            self.drm,
+            b"R8  " | b"BG24" | b"RG24" | b"AR24" | b"XR24" | b"AB24" | b"XB24" | b"RG16"
+        )
+    }
+
+    /// Returns the position of the image planes within an exported buffer
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - Exported buffer
+    /// * `format` - Format of the buffer
+    pub fn planes<'a>(
+        &self,
+        buf: &'a DmaBuf,
+        format: &MultiPlaneFormat,
+    ) -> io::Result<Vec<ImagePlane<'a>>> {
+        let memory_planes = if self.separate { self.planes } else { 1 };
+        if buf.planes.len() < memory_planes || format.stride.len() < memory_planes {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "buffer does not match the format",
+            ));
+        }
+
+        let (sub_x, sub_y) = self.subsampling;
+        let mut planes = Vec::new();
+        let mut offset = buf.planes[0].offset;
+        for plane in 0..self.planes {
+            if self.separate {
+                let pitch = format.stride[plane];
+                planes.push(ImagePlane {
+                    memory: &buf.planes[plane],
+                    offset: buf.planes[plane].offset,
+                    pitch,
+                });
+            } else {
+                // contiguous planes follow each other, chroma planes are subsampled
+                let stride = format.stride[0];
+                let pitch = match plane {
+                    0 => stride,
+                    // semi-planar formats interleave both chroma components
+                    _ if self.planes == 2 => stride * 2 / sub_x,
+                    _ => stride / sub_x,
+                };
+                let height = if plane == 0 {
+                    format.height
+                } else {
+                    format.height / sub_y
+                };
+                planes.push(ImagePlane {
+                    memory: &buf.planes[0],
+                    offset,
+                    pitch,
+                });
+                offset += pitch * height;
+            }
+        }
+        Ok(planes)
+    }
+}
+
+/// Image plane within an exported buffer
+pub(crate) struct ImagePlane<'a> {
+    /// Memory plane holding the image plane
+    pub memory: &'a Plane,
+    /// Offset within the memory plane
+    pub offset: u32,
+    /// Bytes per line
+    pub pitch: u32,
+}
@@ -0,0 +1,143 @@
+//! ndarray views
+//!
+//! Scientific and machine learning code often works on `ndarray` arrays. The views returned
+//! here borrow the frame data, padding at the end of each line is skipped through the row
+//! stride, so no copies are needed. Every view has the shape `(rows, columns, channels)`.
+
+use std::io;
+
+use ndarray::{ArrayView3, ShapeBuilder};
+
+use crate::format::{Format, FourCC};
+
+/// Channels per pixel of a packed format
+fn packed_channels(fourcc: FourCC) -> Option<usize> {
+    match &fourcc.repr {
+        b"GREY" => Some(1),
+        // luma and alternating chroma samples
+        b"YUYV" | b"YVYU" | b"UYVY" | b"VYUY" => Some(2),
+        b"RGB3" | b"BGR3" | b"YUV3" => Some(3),
+        b"AR24" | b"XR24" | b"AB24" | b"XB24" | b"RA24" | b"BA24" => Some(4),
+        _ => None,
+    }
+}
+
+/// Horizontal and vertical chroma subsampling and chroma plane count of a planar format
+fn planar_layout(fourcc: FourCC) -> Option<((usize, usize), usize)> {
+    match &fourcc.repr {
+        b"NV12" | b"NV21" => Some(((2, 2), 1)),
+        b"NV16" | b"NV61" => Some(((2, 1), 1)),
+        b"NV24" | b"NV42" => Some(((1, 1), 1)),
+        b"YU12" | b"YV12" => Some(((2, 2), 2)),
+        b"422P" => Some(((2, 1), 2)),
+        _ => None,
+    }
+}
+
+fn plane_view<'a>(
+    data: &'a [u8],
+    rows: usize,
+    cols: usize,
+    channels: usize,
+    stride: usize,
+) -> io::Result<ArrayView3<'a, u8>> {
+    let shape = (rows, cols, channels).strides((stride, channels, 1));
+    ArrayView3::from_shape(shape, data).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "frame is smaller than its format",
+        )
+    })
+}
+
+/// Returns a view of a frame in a packed format
+///
+/// # Arguments
+///
+/// * `data` - Frame data
+/// * `format` - Format of the frame
+///
+/// # Example
+///
+/// ```
+/// use v4l::buffer::Type;
+/// use v4l::interop::ndarray;
+/// use v4l::io::mmap::Stream;
+/// use v4l::io::traits::CaptureStream;
+/// use v4l::video::Capture;
+/// use v4l::Device;
+///
+/// if let Ok(dev) = Device::new(0) {
+///     let format = dev.format().unwrap();
+///     let mut stream = Stream::new(&dev, Type::VideoCapture).unwrap();
+///     let (buf, _, _) = stream.next().unwrap();
+///     if let Ok(view) = ndarray::view(&buf[0], &format) {
+///         println!("shape: {:?}", view.shape());
+///     }
+/// }
+/// ```
+pub fn view<'a>(data: &'a [u8], format: &Format) -> io::Result<ArrayView3<'a, u8>> {
+    let channels = packed_channels(format.fourcc)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::Unsupported, "pixel format is not packed"))?;
+    let stride = match format.stride as usize {
+        0 => format.width as usize * channels,
+        stride => stride,
+    };
+    plane_view(
+        data,
+        format.height as usize,
+        format.width as usize,
+        channels,
+        stride,
+    )
+}
+
+/// Returns one view per image plane of a frame
+///
+/// Packed formats have a single plane. For planar formats, the first view is the luma plane
+/// with one channel. Interleaved chroma (e.g. NV12) makes up a second view with two channels,
+/// separate chroma planes (e.g. YU12) make up two views with one channel each. Chroma planes
+/// have the subsampled dimensions.
+///
+/// # Arguments
+///
+/// * `data` - Frame data
+/// * `format` - Format of the frame
+pub fn planes<'a>(data: &'a [u8], format: &Format) -> io::Result<Vec<ArrayView3<'a, u8>>> {
+    if packed_channels(format.fourcc).is_some() {
+        return Ok(vec![view(data, format)?]);
+    }
+    let ((sub_x, sub_y), chroma_planes) = planar_layout(format.fourcc).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::Unsupported,
+            "pixel format has no known layout",
+        )
+    })?;
+
+    let (width, height) = (format.width as usize, format.height as usize);
+    let stride = match format.stride as usize {
+        0 => width,
+        stride => stride,
+    };
+    let (chroma_width, chroma_height) = (width / sub_x, height / sub_y);
+    // interleaved chroma holds two samples per subsampled pixel
+    let (chroma_channels, chroma_stride) = match chroma_planes {
+        1 => (2, stride * 2 / sub_x),
+        _ => (1, stride / sub_x),
+    };
+
+    let mut offset = stride * height;
+    let mut views = vec![plane_view(data, height, width, 1, stride)?];
+    for _ in 0..chroma_planes {
+        let plane = data.get(offset..).unwrap_or_default();
+        views.push(plane_view(
+            plane,
+            chroma_height,
+            chroma_width,
+            chroma_channels,
+            chroma_stride,
+        )?);
+        offset += chroma_stride * chroma_height;
+    }
+    Ok(views)
+}
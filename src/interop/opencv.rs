@@ -0,0 +1,104 @@
+//! OpenCV matrices
+//!
+//! Most OpenCV algorithms expect 8 bit BGR images. [`to_mat`] wraps a dequeued frame in a
+//! matrix honoring the line stride of the format and converts it to BGR, so captured frames can
+//! be fed into OpenCV pipelines directly.
+
+use std::io;
+use std::os::raw::c_void;
+
+use opencv::core::{Mat, CV_8UC1, CV_8UC2, CV_8UC3, CV_8UC4};
+use opencv::imgproc;
+use opencv::prelude::*;
+
+use crate::format::Format;
+
+fn cv_error(e: opencv::Error) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, e.to_string())
+}
+
+/// Returns the frame as an 8 bit BGR matrix
+///
+/// Packed YUV, semi-planar and planar 4:2:0, greyscale and 24/32 bit RGB formats are
+/// supported. The returned matrix owns its data, so the buffer can be requeued right away.
+///
+/// # Arguments
+///
+/// * `data` - Frame data (the first plane of a dequeued buffer)
+/// * `format` - Format of the frame
+///
+/// # Example
+///
+/// ```
+/// use v4l::buffer::Type;
+/// use v4l::interop::opencv;
+/// use v4l::io::mmap::Stream;
+/// use v4l::io::traits::CaptureStream;
+/// use v4l::video::Capture;
+/// use v4l::Device;
+///
+/// if let Ok(dev) = Device::new(0) {
+///     let format = dev.format().unwrap();
+///     let mut stream = Stream::new(&dev, Type::VideoCapture).unwrap();
+///     let (buf, _, _) = stream.next().unwrap();
+///     let mat = opencv::to_mat(&buf[0], &format).unwrap();
+/// }
+/// ```
+pub fn to_mat(data: &[u8], format: &Format) -> io::Result<Mat> {
+    // element type, bytes per pixel and number of matrix rows of the unconverted image
+    let height = format.height as usize;
+    // the chroma planes follow the luma plane, OpenCV treats them as extra rows
+    let planar = height * 3 / 2;
+    let (typ, bpp, rows, code) = match &format.fourcc.repr {
+        b"YUYV" => (CV_8UC2, 2, height, Some(imgproc::COLOR_YUV2BGR_YUYV)),
+        b"UYVY" => (CV_8UC2, 2, height, Some(imgproc::COLOR_YUV2BGR_UYVY)),
+        b"NV12" => (CV_8UC1, 1, planar, Some(imgproc::COLOR_YUV2BGR_NV12)),
+        b"NV21" => (CV_8UC1, 1, planar, Some(imgproc::COLOR_YUV2BGR_NV21)),
+        b"YU12" => (CV_8UC1, 1, planar, Some(imgproc::COLOR_YUV2BGR_I420)),
+        b"YV12" => (CV_8UC1, 1, planar, Some(imgproc::COLOR_YUV2BGR_YV12)),
+        b"GREY" => (CV_8UC1, 1, height, Some(imgproc::COLOR_GRAY2BGR)),
+        b"RGB3" => (CV_8UC3, 3, height, Some(imgproc::COLOR_RGB2BGR)),
+        b"BGR3" => (CV_8UC3, 3, height, None),
+        b"AR24" | b"XR24" => (CV_8UC4, 4, height, Some(imgproc::COLOR_BGRA2BGR)),
+        b"AB24" | b"XB24" => (CV_8UC4, 4, height, Some(imgproc::COLOR_RGBA2BGR)),
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "pixel format cannot be converted to BGR",
+            ))
+        }
+    };
+
+    // some drivers do not report a stride for packed formats
+    let stride = match format.stride as usize {
+        0 => format.width as usize * bpp,
+        stride => stride,
+    };
+    if data.len() < stride * rows {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "frame is smaller than its format",
+        ));
+    }
+
+    // SAFETY: the view does not outlive `data` and is only ever read from
+    let view = unsafe {
+        Mat::new_rows_cols_with_data_unsafe(
+            rows as i32,
+            format.width as i32,
+            typ,
+            data.as_ptr() as *mut c_void,
+            stride,
+        )
+    }
+    .map_err(cv_error)?;
+
+    match code {
+        Some(code) => {
+            let mut mat = Mat::default();
+            imgproc::cvt_color_def(&view, &mut mat, code).map_err(cv_error)?;
+            Ok(mat)
+        }
+        None => view.try_clone().map_err(cv_error),
+    }
+}
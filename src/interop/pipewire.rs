@@ -0,0 +1,266 @@
+//! PipeWire camera source
+//!
+//! Desktop sharing (browsers, video conferencing, OBS, ..) increasingly discovers cameras
+//! through PipeWire instead of opening V4L2 nodes directly. [`Source`] publishes a capture
+//! stream as a `Video/Source` node, so applications built on this crate can feed processed
+//! or synthesized frames into that ecosystem.
+
+use std::cell::RefCell;
+use std::io;
+use std::io::Cursor;
+use std::os::fd::AsRawFd;
+use std::rc::Rc;
+
+use pipewire as pw;
+use pw::spa;
+use pw::spa::param::format::{FormatProperties, MediaSubtype, MediaType};
+use pw::spa::param::video::VideoFormat;
+use pw::spa::param::ParamType;
+use pw::spa::pod::serialize::PodSerializer;
+use pw::spa::pod::Pod;
+use pw::spa::support::system::IoFlags;
+use pw::spa::utils::{Direction, Fraction as SpaFraction, Rectangle, SpaTypes};
+
+use crate::device::Device;
+use crate::format::{Format, FourCC};
+use crate::fraction::Fraction;
+use crate::frame::Planes;
+use crate::io::traits::CaptureStream;
+
+fn pw_error(e: pw::Error) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, e.to_string())
+}
+
+/// Returns the SPA video format of a V4L2 pixel format, if PipeWire knows about it
+///
+/// # Arguments
+///
+/// * `fourcc` - V4L2 pixel format
+pub fn video_format(fourcc: FourCC) -> Option<VideoFormat> {
+    // both sides name RGB formats by byte order
+    match &fourcc.repr {
+        b"YUYV" => Some(VideoFormat::YUY2),
+        b"YVYU" => Some(VideoFormat::YVYU),
+        b"UYVY" => Some(VideoFormat::UYVY),
+        b"NV12" => Some(VideoFormat::NV12),
+        b"NV21" => Some(VideoFormat::NV21),
+        b"NV16" => Some(VideoFormat::NV16),
+        b"YU12" => Some(VideoFormat::I420),
+        b"YV12" => Some(VideoFormat::YV12),
+        b"GREY" => Some(VideoFormat::GRAY8),
+        b"RGB3" => Some(VideoFormat::RGB),
+        b"BGR3" => Some(VideoFormat::BGR),
+        b"AR24" => Some(VideoFormat::BGRA),
+        b"XR24" => Some(VideoFormat::BGRx),
+        b"AB24" => Some(VideoFormat::RGBA),
+        b"XB24" => Some(VideoFormat::RGBx),
+        _ => None,
+    }
+}
+
+/// PipeWire video source node
+///
+/// The node offers exactly the format of the capture stream, PipeWire consumers have to
+/// accept it as is. Frames are copied into the buffers of the PipeWire stream as soon as the
+/// device delivers them, frames are dropped while no consumer is connected.
+///
+/// # Example
+///
+/// ```no_run
+/// use v4l::buffer::Type;
+/// use v4l::interop::pipewire::Source;
+/// use v4l::io::mmap::Stream;
+/// use v4l::video::Capture;
+/// use v4l::Device;
+///
+/// let dev = Device::new(0).unwrap();
+/// let format = dev.format().unwrap();
+/// let stream = Stream::new(&dev, Type::VideoCapture).unwrap();
+/// Source::new("Camera", &format).run(&dev, stream).unwrap();
+/// ```
+pub struct Source {
+    name: String,
+    format: Format,
+    interval: Option<Fraction>,
+}
+
+impl Source {
+    /// Returns a source
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Node name shown to the user
+    /// * `format` - Format of the capture stream
+    pub fn new(name: &str, format: &Format) -> Self {
+        Source {
+            name: name.to_string(),
+            format: format.clone(),
+            interval: None,
+        }
+    }
+
+    /// Sets the frame interval announced to consumers
+    ///
+    /// By default, a variable frame rate is announced.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - Time between two frames, e.g. from the capture parameters
+    pub fn interval(mut self, interval: Fraction) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    fn format_param(&self) -> io::Result<Vec<u8>> {
+        let video_format = video_format(self.format.fourcc).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::Unsupported,
+                "pixel format has no PipeWire equivalent",
+            )
+        })?;
+        // PipeWire expects a frame rate, the inverse of the frame interval
+        let framerate = match self.interval {
+            Some(interval) => SpaFraction {
+                num: interval.denominator,
+                denom: interval.numerator,
+            },
+            None => SpaFraction { num: 0, denom: 1 },
+        };
+
+        let obj = spa::pod::object!(
+            SpaTypes::ObjectParamFormat,
+            ParamType::EnumFormat,
+            spa::pod::property!(FormatProperties::MediaType, Id, MediaType::Video),
+            spa::pod::property!(FormatProperties::MediaSubtype, Id, MediaSubtype::Raw),
+            spa::pod::property!(FormatProperties::VideoFormat, Id, video_format),
+            spa::pod::property!(
+                FormatProperties::VideoSize,
+                Rectangle,
+                Rectangle {
+                    width: self.format.width,
+                    height: self.format.height,
+                }
+            ),
+            spa::pod::property!(FormatProperties::VideoFramerate, Fraction, framerate),
+        );
+
+        let (cursor, _) =
+            PodSerializer::serialize(Cursor::new(Vec::new()), &spa::pod::Value::Object(obj))
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{:?}", e)))?;
+        Ok(cursor.into_inner())
+    }
+
+    /// Publishes the capture stream until an error occurs
+    ///
+    /// The PipeWire main loop runs on the calling thread. The stream is started if it is not
+    /// running yet, the first frame is dropped in that case.
+    ///
+    /// # Arguments
+    ///
+    /// * `dev` - Device the stream belongs to, its file descriptor is watched for new frames
+    /// * `stream` - Capture stream
+    pub fn run<S>(&self, dev: &Device, mut stream: S) -> io::Result<()>
+    where
+        S: for<'a> CaptureStream<'a> + 'static,
+        S::Item: Planes,
+    {
+        let param = self.format_param()?;
+        // the stream is only started once a buffer is requested for the first time
+        stream.next()?;
+
+        pw::init();
+        let mainloop = pw::main_loop::MainLoop::new(None).map_err(pw_error)?;
+        let context = pw::context::Context::new(&mainloop).map_err(pw_error)?;
+        let core = context.connect(None).map_err(pw_error)?;
+
+        let pw_stream = pw::stream::Stream::new(
+            &core,
+            &self.name,
+            pw::properties::properties! {
+                *pw::keys::MEDIA_TYPE => "Video",
+                *pw::keys::MEDIA_CATEGORY => "Capture",
+                *pw::keys::MEDIA_ROLE => "Camera",
+                *pw::keys::MEDIA_CLASS => "Video/Source",
+                *pw::keys::NODE_NAME => self.name.as_str(),
+            },
+        )
+        .map_err(pw_error)?;
+        let _listener = pw_stream
+            .add_local_listener_with_user_data(())
+            .state_changed({
+                let mainloop = mainloop.clone();
+                move |_, _, _, state| {
+                    if let pw::stream::StreamState::Error(_) = state {
+                        mainloop.quit();
+                    }
+                }
+            })
+            .register()
+            .map_err(pw_error)?;
+
+        let mut params = [Pod::from_bytes(&param).unwrap()];
+        pw_stream
+            .connect(
+                Direction::Output,
+                None,
+                pw::stream::StreamFlags::MAP_BUFFERS,
+                &mut params,
+            )
+            .map_err(pw_error)?;
+
+        let stride = self.format.stride;
+        let stream = Rc::new(RefCell::new(stream));
+        let error = Rc::new(RefCell::new(None));
+        let _io = mainloop.loop_().add_io(dev.as_raw_fd(), IoFlags::IN, {
+            let mainloop = mainloop.clone();
+            let error = error.clone();
+            move |_| {
+                let mut stream = stream.borrow_mut();
+                let (buf, meta, _) = match stream.next() {
+                    Ok(frame) => frame,
+                    Err(e) => {
+                        *error.borrow_mut() = Some(e);
+                        mainloop.quit();
+                        return;
+                    }
+                };
+
+                // no consumer (or all buffers in use), drop the frame
+                let mut pw_buf = match pw_stream.dequeue_buffer() {
+                    Some(pw_buf) => pw_buf,
+                    None => return,
+                };
+                let data = &mut pw_buf.datas_mut()[0];
+                let len = match data.data() {
+                    Some(dst) => {
+                        let mut len = 0;
+                        for plane in buf.planes() {
+                            let used = match meta.bytesused as usize {
+                                0 => plane.len(),
+                                used => used.min(plane.len()),
+                            };
+                            let n = used.min(dst.len() - len);
+                            dst[len..len + n].copy_from_slice(&plane[..n]);
+                            len += n;
+                        }
+                        len
+                    }
+                    None => 0,
+                };
+
+                let chunk = data.chunk_mut();
+                *chunk.offset_mut() = 0;
+                *chunk.stride_mut() = stride as i32;
+                *chunk.size_mut() = len as u32;
+            }
+        });
+
+        mainloop.run();
+
+        let res = error.borrow_mut().take();
+        match res {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
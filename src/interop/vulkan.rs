@@ -0,0 +1,162 @@
+//! Vulkan external memory import
+//!
+//! Exported buffers are imported into Vulkan through `VK_EXT_external_memory_dma_buf` and
+//! `VK_EXT_image_drm_format_modifier`: the image is created with an explicit DRM format modifier
+//! and one `VkSubresourceLayout` per plane, its memory is imported from the DMA buffer file
+//! descriptor. [`import_info`] collects everything needed to fill those structures, so the
+//! frames can be sampled by compute or inference pipelines without copies. The values match the
+//! Vulkan headers and can be passed to `ash`, `vulkano` or `wgpu-hal` as is.
+
+use std::io;
+use std::os::fd::{AsRawFd, RawFd};
+
+use crate::format::{FourCC, MultiPlaneFormat};
+use crate::interop::egl::DRM_FORMAT_MOD_LINEAR;
+use crate::interop::Layout;
+use crate::io::dmabuf::DmaBuf;
+
+pub const VK_FORMAT_R5G6B5_UNORM_PACK16: i32 = 4;
+pub const VK_FORMAT_R8_UNORM: i32 = 9;
+pub const VK_FORMAT_R8G8B8_UNORM: i32 = 23;
+pub const VK_FORMAT_B8G8R8_UNORM: i32 = 30;
+pub const VK_FORMAT_R8G8B8A8_UNORM: i32 = 37;
+pub const VK_FORMAT_B8G8R8A8_UNORM: i32 = 44;
+pub const VK_FORMAT_G8B8G8R8_422_UNORM: i32 = 1_000_156_000;
+pub const VK_FORMAT_B8G8R8G8_422_UNORM: i32 = 1_000_156_001;
+pub const VK_FORMAT_G8_B8_R8_3PLANE_420_UNORM: i32 = 1_000_156_002;
+pub const VK_FORMAT_G8_B8R8_2PLANE_420_UNORM: i32 = 1_000_156_003;
+pub const VK_FORMAT_G8_B8_R8_3PLANE_422_UNORM: i32 = 1_000_156_004;
+pub const VK_FORMAT_G8_B8R8_2PLANE_422_UNORM: i32 = 1_000_156_005;
+pub const VK_FORMAT_G8_B8R8_2PLANE_444_UNORM: i32 = 1_000_330_001;
+
+/// `VK_EXTERNAL_MEMORY_HANDLE_TYPE_DMA_BUF_BIT_EXT`
+pub const VK_EXTERNAL_MEMORY_HANDLE_TYPE_DMA_BUF_BIT_EXT: u32 = 0x0000_0200;
+/// `VK_IMAGE_TILING_DRM_FORMAT_MODIFIER_EXT`
+pub const VK_IMAGE_TILING_DRM_FORMAT_MODIFIER_EXT: i32 = 1_000_158_000;
+
+/// Layout of one plane, maps to `VkSubresourceLayout`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PlaneLayout {
+    /// DMA buffer holding the plane
+    pub fd: RawFd,
+    /// Offset of the plane within the DMA buffer
+    pub offset: u64,
+    /// Size of the plane in bytes
+    pub size: u64,
+    /// Bytes per line
+    pub row_pitch: u64,
+}
+
+/// Parameters to create and import a Vulkan image from an exported buffer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportInfo {
+    /// `VkFormat` of the image
+    pub format: i32,
+    /// Width and height of the image
+    pub extent: (u32, u32),
+    /// Image tiling, always `VK_IMAGE_TILING_DRM_FORMAT_MODIFIER_EXT`
+    pub tiling: i32,
+    /// DRM format modifier of the memory layout
+    pub drm_format_modifier: u64,
+    /// External memory handle type to import the file descriptors as
+    pub handle_type: u32,
+    /// Whether the planes live in separate DMA buffers (`VK_IMAGE_CREATE_DISJOINT_BIT`)
+    pub disjoint: bool,
+    /// Plane layouts, one per image plane
+    pub planes: Vec<PlaneLayout>,
+}
+
+/// Returns the `VkFormat` of a V4L2 pixel format, if it can be imported
+///
+/// Formats with swapped chroma planes (e.g. NV21, YV12) have no Vulkan equivalent.
+///
+/// # Arguments
+///
+/// * `fourcc` - V4L2 pixel format
+pub fn vk_format(fourcc: FourCC) -> Option<i32> {
+    match &fourcc.repr {
+        b"YUYV" => Some(VK_FORMAT_G8B8G8R8_422_UNORM),
+        b"UYVY" => Some(VK_FORMAT_B8G8R8G8_422_UNORM),
+        b"GREY" => Some(VK_FORMAT_R8_UNORM),
+        b"RGB3" => Some(VK_FORMAT_R8G8B8_UNORM),
+        b"BGR3" => Some(VK_FORMAT_B8G8R8_UNORM),
+        b"AR24" | b"XR24" => Some(VK_FORMAT_B8G8R8A8_UNORM),
+        b"AB24" | b"XB24" => Some(VK_FORMAT_R8G8B8A8_UNORM),
+        b"RGBP" => Some(VK_FORMAT_R5G6B5_UNORM_PACK16),
+        b"NV12" | b"NM12" => Some(VK_FORMAT_G8_B8R8_2PLANE_420_UNORM),
+        b"NV16" | b"NM16" => Some(VK_FORMAT_G8_B8R8_2PLANE_422_UNORM),
+        b"NV24" => Some(VK_FORMAT_G8_B8R8_2PLANE_444_UNORM),
+        b"YU12" | b"YM12" => Some(VK_FORMAT_G8_B8_R8_3PLANE_420_UNORM),
+        b"422P" | b"YM16" => Some(VK_FORMAT_G8_B8_R8_3PLANE_422_UNORM),
+        _ => None,
+    }
+}
+
+/// Returns the import parameters of an exported buffer
+///
+/// V4L2 buffers are linear, so the DRM format modifier is [`DRM_FORMAT_MOD_LINEAR`]. The file
+/// descriptors are borrowed from `buf`. Vulkan takes ownership of a file descriptor on import,
+/// so pass a duplicate (`dup()`) if the buffer is to be imported more than once.
+///
+/// # Arguments
+///
+/// * `buf` - Exported buffer
+/// * `format` - Format of the buffer
+///
+/// # Example
+///
+/// ```
+/// use v4l::buffer::Type;
+/// use v4l::device::MultiPlaneDevice;
+/// use v4l::interop::vulkan;
+/// use v4l::io::mmap::Stream;
+/// use v4l::video::Capture;
+///
+/// if let Ok(dev) = MultiPlaneDevice::new(0) {
+///     let format = dev.format().unwrap();
+///     let stream = Stream::new(&dev, Type::VideoCaptureMplane).unwrap();
+///     let buf = stream.export(0).unwrap();
+///     let info = vulkan::import_info(&buf, &format).unwrap();
+///     println!("VkFormat {} with {} plane(s)", info.format, info.planes.len());
+/// }
+/// ```
+pub fn import_info(buf: &DmaBuf, format: &MultiPlaneFormat) -> io::Result<ImportInfo> {
+    let unsupported = || {
+        io::Error::new(
+            io::ErrorKind::Unsupported,
+            "pixel format has no Vulkan equivalent",
+        )
+    };
+    let vk_format = vk_format(format.fourcc).ok_or_else(unsupported)?;
+    let layout = Layout::new(format.fourcc).ok_or_else(unsupported)?;
+
+    let (_, sub_y) = layout.subsampling;
+    let planes = layout
+        .planes(buf, format)?
+        .iter()
+        .enumerate()
+        .map(|(i, plane)| {
+            let height = if i == 0 {
+                format.height
+            } else {
+                format.height / sub_y
+            };
+            PlaneLayout {
+                fd: plane.memory.fd.as_raw_fd(),
+                offset: u64::from(plane.offset),
+                size: u64::from(plane.pitch) * u64::from(height),
+                row_pitch: u64::from(plane.pitch),
+            }
+        })
+        .collect();
+
+    Ok(ImportInfo {
+        format: vk_format,
+        extent: (format.width, format.height),
+        tiling: VK_IMAGE_TILING_DRM_FORMAT_MODIFIER_EXT,
+        drm_format_modifier: DRM_FORMAT_MOD_LINEAR,
+        handle_type: VK_EXTERNAL_MEMORY_HANDLE_TYPE_DMA_BUF_BIT_EXT,
+        disjoint: layout.separate && layout.planes > 1,
+        planes,
+    })
+}
@@ -0,0 +1,148 @@
+use std::{collections::VecDeque, io};
+
+use crate::buffer::Metadata;
+use crate::timestamp::Timestamp;
+
+/// Application data attached to queued output buffers
+///
+/// Encoders, decoders and other memory-to-memory devices copy the timestamp of an OUTPUT buffer
+/// to the CAPTURE buffer produced from it, possibly out of order (e.g. for B-frames) and with a
+/// delay of several frames. The annotations keep a payload per timestamp, so whatever produced
+/// an input (a frame number, the original presentation time, a tracing span, ..) can be looked
+/// up once the result is dequeued. Devices which do not produce anything, e.g. displays, are
+/// covered as well: payloads can be taken by the index of the dequeued OUTPUT buffer instead.
+///
+/// The timestamps of the annotated buffers must be unique.
+///
+/// # Example
+///
+/// ```
+/// use v4l::buffer::Type;
+/// use v4l::io::annotation::Annotations;
+/// use v4l::io::traits::{CaptureStream, OutputStream};
+/// use v4l::prelude::*;
+/// use v4l::timestamp::Timestamp;
+///
+/// if let Ok(dev) = Device::new(0) {
+///     let mut input = MmapStream::new(&dev, Type::VideoOutput).unwrap();
+///     let mut output = MmapStream::new(&dev, Type::VideoCapture).unwrap();
+///     let mut frames = Annotations::new();
+///
+///     let (_, meta) = OutputStream::next(&mut input).unwrap();
+///     meta.timestamp = Timestamp::new(0, 40_000);
+///     frames.attach(meta, "frame 1").unwrap();
+///
+///     let (_, meta, _) = CaptureStream::next(&mut output).unwrap();
+///     println!("packet of {:?}", frames.take(meta));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Annotations<T> {
+    /// Payloads along with the timestamp and index of their buffer, oldest first
+    pending: VecDeque<(Timestamp, u32, T)>,
+}
+
+impl<T> Annotations<T> {
+    /// Returns an empty set of annotations
+    pub fn new() -> Self {
+        Annotations {
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Attaches a payload to a buffer which is about to be queued
+    ///
+    /// Fails with [`io::ErrorKind::AlreadyExists`] if the timestamp of the buffer is annotated
+    /// already.
+    ///
+    /// # Arguments
+    ///
+    /// * `meta` - Metadata of the buffer, the timestamp has to be set already
+    /// * `payload` - Data to attach
+    pub fn attach(&mut self, meta: &Metadata, payload: T) -> io::Result<()> {
+        if self.position(meta.timestamp).is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                "timestamp is annotated already",
+            ));
+        }
+
+        self.pending
+            .push_back((meta.timestamp, meta.index, payload));
+        Ok(())
+    }
+
+    /// Returns the payload of a dequeued buffer without removing it
+    ///
+    /// # Arguments
+    ///
+    /// * `meta` - Metadata of the dequeued buffer, matched by its timestamp
+    pub fn get(&self, meta: &Metadata) -> Option<&T> {
+        self.position(meta.timestamp)
+            .map(|pos| &self.pending[pos].2)
+    }
+
+    /// Removes and returns the payload of a dequeued buffer
+    ///
+    /// # Arguments
+    ///
+    /// * `meta` - Metadata of the dequeued buffer, matched by its timestamp
+    pub fn take(&mut self, meta: &Metadata) -> Option<T> {
+        let pos = self.position(meta.timestamp)?;
+        self.pending.remove(pos).map(|(_, _, payload)| payload)
+    }
+
+    /// Removes and returns the oldest payload attached to an OUTPUT buffer
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the dequeued OUTPUT buffer
+    pub fn take_index(&mut self, index: u32) -> Option<T> {
+        let pos = self.pending.iter().position(|(_, i, _)| *i == index)?;
+        self.pending.remove(pos).map(|(_, _, payload)| payload)
+    }
+
+    /// Removes all payloads attached before the one of a dequeued buffer
+    ///
+    /// Devices may drop inputs without producing anything for them. Without reordering, the
+    /// payloads of older inputs will never be taken once a newer one came out.
+    ///
+    /// # Arguments
+    ///
+    /// * `meta` - Metadata of the dequeued buffer, matched by its timestamp
+    pub fn discard_older(&mut self, meta: &Metadata) -> Vec<T> {
+        match self.position(meta.timestamp) {
+            Some(pos) => self
+                .pending
+                .drain(..pos)
+                .map(|(_, _, payload)| payload)
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns the number of payloads which were not taken yet
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns whether all payloads were taken
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Removes all payloads, e.g. after the device was flushed
+    pub fn clear(&mut self) {
+        self.pending.clear()
+    }
+
+    fn position(&self, timestamp: Timestamp) -> Option<usize> {
+        self.pending.iter().position(|(ts, _, _)| *ts == timestamp)
+    }
+}
+
+impl<T> Default for Annotations<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
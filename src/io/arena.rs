@@ -0,0 +1,690 @@
+//! Streams of buffers from any arena
+//!
+//! The memory mapped and user pointer streams come with their own arenas. Other kinds of memory,
+//! e.g. DMA buffers allocated from a DMA heap, can be streamed by implementing [`Arena`] for
+//! them and handing the arena to a [`Stream`].
+
+use std::convert::TryInto;
+use std::time::{Duration, Instant};
+use std::{io, mem, mem::ManuallyDrop, os::fd::AsRawFd, sync::Arc};
+
+use crate::buffer::{Info, Metadata, Type};
+use crate::device::{Handle, PollFlags, VideoDevice};
+use crate::io::dmabuf::Fence;
+use crate::io::traits::{Arena, CaptureStream, OutputStream, Stream as StreamTrait};
+use crate::io::{
+    check_granted, request_count, stream_off, Abandoned, DropPolicy, Inactive, WouldBlock,
+    STOP_TIMEOUT,
+};
+use crate::media::Request;
+use crate::v4l2;
+use crate::v4l_sys::*;
+
+/// Stream of the buffers of an arena
+///
+/// The arena provides the memory, the stream exchanges the buffers with the driver. Multi-planar
+/// buffer types are supported if the arena reports the planes of its buffers.
+pub struct Stream<A: Arena> {
+    handle: Arc<Handle>,
+    /// Dropped explicitly, unless the stream was abandoned
+    arena: ManuallyDrop<A>,
+    arena_index: usize,
+    buf_type: Type,
+    buf_meta: Vec<Metadata>,
+    /// Planes of each buffer, as last exchanged with the driver
+    buf_planes: Vec<Vec<v4l2_plane>>,
+    /// Fences to wait for before queueing each buffer
+    fences: Vec<Option<Fence>>,
+    timeout: Option<i32>,
+    would_block: WouldBlock,
+    drop_policy: DropPolicy,
+    stop_timeout: Option<Duration>,
+    /// Whether the driver did not stop streaming in time, the buffers are leaked then
+    abandoned: bool,
+
+    active: bool,
+    /// Whether the buffer at `arena_index` was dequeued and has to be queued again
+    held: bool,
+}
+
+impl<A: Arena> Stream<A> {
+    /// Returns a stream of the buffers of an arena
+    ///
+    /// # Arguments
+    ///
+    /// * `dev` - Device (single- or multi-planar) to get its file descriptor
+    /// * `buf_type` - Type of the buffers
+    /// * `arena` - Arena which did not allocate any buffers yet
+    /// * `buf_count` - Number of buffers to request, raised to the minimum the driver needs
+    pub fn with_arena<D: VideoDevice>(
+        dev: &D,
+        buf_type: Type,
+        mut arena: A,
+        buf_count: u32,
+    ) -> io::Result<Self> {
+        let requested = request_count(&dev.handle(), buf_type, buf_count);
+        let count = arena.allocate(requested)?;
+        check_granted(requested, count)?;
+        let count = count as usize;
+        let buf_meta = vec![Metadata::with_memory(arena.memory()); count];
+        let buf_planes = (0..count)
+            .map(|index| arena.planes(index).to_vec())
+            .collect();
+
+        Ok(Stream {
+            handle: dev.handle(),
+            arena: ManuallyDrop::new(arena),
+            arena_index: 0,
+            buf_type,
+            buf_meta,
+            buf_planes,
+            fences: (0..count).map(|_| None).collect(),
+            active: false,
+            held: false,
+            timeout: None,
+            would_block: WouldBlock::default(),
+            drop_policy: DropPolicy::default(),
+            stop_timeout: Some(STOP_TIMEOUT),
+            abandoned: false,
+        })
+    }
+
+    /// Splits the stream into its buffers and driver state, leaving the device untouched
+    ///
+    /// Streams are `Send`, so they can be moved to another thread as they are. The parts
+    /// carry the buffers and the state of the queue only, without the settings of the stream
+    /// (timeout and would-block policy) except for the drop policy and the stop timeout, which
+    /// apply to dropping the parts as well. This allows handing a running stream to a thread or
+    /// component which applies its own settings: the stream is neither stopped nor are its
+    /// buffers released in between.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::thread;
+    /// use std::time::Duration;
+    /// use v4l::buffer::Type;
+    /// use v4l::io::mmap::Stream;
+    /// use v4l::io::traits::CaptureStream;
+    /// use v4l::prelude::*;
+    ///
+    /// if let Ok(dev) = Device::new(0) {
+    ///     let mut stream = Stream::new(&dev, Type::VideoCapture).unwrap();
+    ///     stream.prime().unwrap();
+    ///
+    ///     let parts = stream.into_parts();
+    ///     let capture = thread::spawn(move || {
+    ///         let mut stream = Stream::from_parts(parts);
+    ///         stream.set_timeout(Duration::from_millis(500));
+    ///         stream.next().map(|(buf, _, _)| buf[0].len())
+    ///     });
+    ///     println!("{:?}", capture.join().unwrap());
+    /// }
+    /// ```
+    pub fn into_parts(mut self) -> Parts<A> {
+        // the parts own the queue and the arena now, dropping the remains must neither stop the
+        // stream nor release the buffers
+        let abandoned = mem::replace(&mut self.abandoned, true);
+        Parts {
+            handle: self.handle.clone(),
+            arena: ManuallyDrop::new(unsafe { ManuallyDrop::take(&mut self.arena) }),
+            buf_type: self.buf_type,
+            buf_meta: mem::take(&mut self.buf_meta),
+            buf_planes: mem::take(&mut self.buf_planes),
+            fences: mem::take(&mut self.fences),
+            active: mem::replace(&mut self.active, false),
+            held: self.held.then_some(self.arena_index),
+            drop_policy: self.drop_policy.clone(),
+            stop_timeout: self.stop_timeout,
+            abandoned,
+        }
+    }
+
+    /// Returns a stream assembled from the parts of another one
+    ///
+    /// The drop policy and the stop timeout are taken over, all other settings are reset to
+    /// their defaults.
+    ///
+    /// # Arguments
+    ///
+    /// * `parts` - Parts returned by [`Stream::into_parts`]
+    pub fn from_parts(mut parts: Parts<A>) -> Self {
+        // see into_parts(), the remains of the parts are left behind the same way
+        let abandoned = mem::replace(&mut parts.abandoned, true);
+        Stream {
+            handle: parts.handle.clone(),
+            arena: ManuallyDrop::new(unsafe { ManuallyDrop::take(&mut parts.arena) }),
+            arena_index: parts.held.unwrap_or(0),
+            buf_type: parts.buf_type,
+            buf_meta: mem::take(&mut parts.buf_meta),
+            buf_planes: mem::take(&mut parts.buf_planes),
+            fences: mem::take(&mut parts.fences),
+            active: mem::replace(&mut parts.active, false),
+            held: parts.held.is_some(),
+            timeout: None,
+            would_block: WouldBlock::default(),
+            drop_policy: parts.drop_policy.clone(),
+            stop_timeout: parts.stop_timeout,
+            abandoned,
+        }
+    }
+
+    /// Returns the raw device handle
+    pub fn handle(&self) -> Arc<Handle> {
+        self.handle.clone()
+    }
+
+    /// Returns the arena providing the buffers
+    pub fn arena(&self) -> &A {
+        &self.arena
+    }
+
+    /// Returns the number of buffers allocated by the driver
+    ///
+    /// This may be more than requested if the driver needs more buffers to operate, or fewer
+    /// if memory is short.
+    pub fn buffer_count(&self) -> usize {
+        self.arena.len()
+    }
+
+    /// Returns the size of a buffer in bytes, summed up over all planes
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the buffer, panics if it is out of range
+    pub fn buffer_len(&self, index: usize) -> usize {
+        self.arena.buffer_len(index)
+    }
+
+    /// Returns the state of a buffer as reported by the driver, e.g. whether it is queued
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the buffer
+    pub fn query_buf(&self, index: usize) -> io::Result<Info> {
+        self.handle.query_buf(self.buf_type, self.arena.memory(), index as u32)
+    }
+
+    /// Returns an iterator over all buffers, ordered by index
+    ///
+    /// The contents are only meaningful for buffers which are not queued.
+    pub fn buffers(&self) -> impl Iterator<Item = &A::Buffer> {
+        (0..self.arena.len()).map(move |index| self.arena.buffer_at(index))
+    }
+
+    /// Sets a timeout of the v4l file handle.
+    pub fn set_timeout(&mut self, duration: Duration) {
+        self.timeout = Some(duration.as_millis().try_into().unwrap());
+    }
+
+    /// Clears the timeout of the v4l file handle.
+    pub fn clear_timeout(&mut self) {
+        self.timeout = None;
+    }
+
+    /// Sets the behavior of dequeue operations when no buffer is ready yet.
+    ///
+    /// By default, [`io::ErrorKind::WouldBlock`] is returned to the caller.
+    pub fn set_would_block(&mut self, policy: WouldBlock) {
+        self.would_block = policy;
+    }
+
+    /// Sets the behavior when stopping the stream fails as it is dropped.
+    ///
+    /// By default, errors are logged and ignored.
+    pub fn set_drop_policy(&mut self, policy: DropPolicy) {
+        self.drop_policy = policy;
+    }
+
+    /// Sets the time stopping the stream waits for the driver
+    ///
+    /// Stopping a stream on a wedged driver would block forever, e.g. while the application
+    /// shuts down. Once the timeout expires, `stop()` fails with [`io::ErrorKind::TimedOut`]
+    /// and the stream is given up: the driver keeps its queue locked, so further operations
+    /// fail with [`Abandoned`] and the arena is leaked when the stream is dropped.
+    ///
+    /// By default, the stream waits for [`STOP_TIMEOUT`] on a helper thread. With `None`, it
+    /// stops on the calling thread instead and waits indefinitely.
+    pub fn set_stop_timeout(&mut self, timeout: Option<Duration>) {
+        self.stop_timeout = timeout;
+    }
+
+    /// Queues all buffers and starts streaming, without waiting for a frame
+    ///
+    /// This starts the sensor early, e.g. to let auto exposure settle while the application is
+    /// still busy setting up. Frames are consumed later on through `next()` as usual. Priming an
+    /// active stream does nothing.
+    ///
+    /// Only capture streams can be primed, output buffers have to be filled first.
+    pub fn prime(&mut self) -> io::Result<()> {
+        if self.abandoned {
+            return Err(Abandoned.into());
+        }
+        if self.active {
+            return Ok(());
+        }
+
+        for index in 0..self.arena.len() {
+            self.qbuf(index, None, false)?;
+        }
+        self.start()?;
+        self.held = false;
+        Ok(())
+    }
+
+    /// Removes the newest buffer from the drivers' outgoing queue, skipping older ones
+    ///
+    /// Waits for a buffer like [`CaptureStream::dequeue`] does, then drains all buffers which
+    /// are ready as well. The stale ones are queued again right away, so applications rendering
+    /// slower than the capture rate always show the latest frame instead of lagging behind.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::buffer::Type;
+    /// use v4l::io::traits::CaptureStream;
+    /// use v4l::prelude::*;
+    ///
+    /// if let Ok(dev) = Device::new(0) {
+    ///     let mut stream = MmapStream::new(&dev, Type::VideoCapture).unwrap();
+    ///     stream.prime().unwrap();
+    ///     for _ in 0..100 {
+    ///         let index = stream.dequeue_latest().unwrap();
+    ///         let (_, meta, _) = CaptureStream::get(&stream, index).unwrap();
+    ///         println!("rendering frame {}", meta.sequence);
+    ///         CaptureStream::queue(&mut stream, index).unwrap();
+    ///     }
+    /// }
+    /// ```
+    pub fn dequeue_latest(&mut self) -> io::Result<usize> {
+        if self.would_block.polls_before_dequeue() && !CaptureStream::poll(self)? {
+            return Err(io::Error::new(io::ErrorKind::TimedOut, "VIDIOC_DQBUF"));
+        }
+
+        let mut index = CaptureStream::dequeue(self)?;
+        while self
+            .handle
+            .poll(PollFlags::IN, 0)?
+            .contains(PollFlags::IN)
+        {
+            let newer = CaptureStream::dequeue(self)?;
+            CaptureStream::queue(self, index)?;
+            index = newer;
+        }
+        Ok(index)
+    }
+
+    /// Attaches a fence to a buffer, which is only queued once the fence is signaled
+    ///
+    /// This allows handing buffers to the stream while a producer such as a GPU is still
+    /// rendering into them. The index of a buffer is part of its metadata. Waiting for the
+    /// fence is subject to the stream timeout.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the buffer
+    /// * `fence` - Fence of the producer
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::os::fd::OwnedFd;
+    /// use v4l::io::arena::Stream;
+    /// use v4l::io::dmabuf::Fence;
+    /// use v4l::io::traits::{Arena, OutputStream};
+    ///
+    /// fn render<A: Arena>(stream: &mut Stream<A>, submit: impl Fn(&mut A::Buffer) -> OwnedFd) {
+    ///     let (buf, meta) = stream.next().unwrap();
+    ///     let index = meta.index as usize;
+    ///     let sync_file = submit(buf);
+    ///     stream.set_fence(index, Fence::from(sync_file));
+    /// }
+    /// ```
+    pub fn set_fence(&mut self, index: usize, fence: Fence) {
+        self.fences[index] = Some(fence);
+    }
+
+    fn buffer_desc(&self, index: usize) -> v4l2_buffer {
+        v4l2_buffer {
+            index: index as u32,
+            type_: self.buf_type as u32,
+            memory: self.arena.memory() as u32,
+            ..unsafe { mem::zeroed() }
+        }
+    }
+
+    /// Inserts a buffer into the drivers' incoming queue, regardless of the stream state
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the buffer
+    /// * `request` - Media request to bind the buffer to
+    /// * `output` - Whether the payload described by the metadata is passed to the driver
+    fn qbuf(&mut self, index: usize, request: Option<&Request>, output: bool) -> io::Result<()> {
+        if let Some(fence) = &self.fences[index] {
+            let timeout = self.timeout.map(|ms| Duration::from_millis(ms as u64));
+            if !fence.wait(timeout)? {
+                return Err(io::Error::new(io::ErrorKind::TimedOut, "fence"));
+            }
+            self.fences[index] = None;
+        }
+
+        let mut v4l2_buf = self.buffer_desc(index);
+        let mut planes = self.buf_planes[index].clone();
+        self.arena.prepare(index, &mut v4l2_buf, &mut planes);
+        if self.buf_type.planar() {
+            v4l2_buf.length = planes.len() as u32;
+            v4l2_buf.m.planes = planes.as_mut_ptr();
+        }
+        if let Some(request) = request {
+            v4l2_buf.flags |= V4L2_BUF_FLAG_REQUEST_FD;
+            v4l2_buf.__bindgen_anon_1.request_fd = request.as_raw_fd();
+        }
+        if output {
+            let meta = &self.buf_meta[index];
+            v4l2_buf.bytesused = meta.bytesused;
+            v4l2_buf.field = meta.field;
+            // memory-to-memory devices copy the timestamp to the matching capture buffer
+            v4l2_buf.timestamp = meta.timestamp.into();
+        }
+
+        unsafe {
+            self.handle.ioctl(
+                v4l2::vidioc::VIDIOC_QBUF,
+                &mut v4l2_buf as *mut _ as *mut std::os::raw::c_void,
+            )
+        }
+    }
+
+    /// Removes a buffer from the drivers' outgoing queue, honoring the would-block policy
+    ///
+    /// # Arguments
+    ///
+    /// * `events` - Events to wait for when no buffer is ready yet (e.g. `PollFlags::IN`)
+    fn dqbuf(&mut self, events: PollFlags) -> io::Result<usize> {
+        if self.abandoned {
+            return Err(Abandoned.into());
+        }
+
+        let mut v4l2_buf = self.buffer_desc(0);
+        // all buffers have the same number of planes
+        let mut planes = self.buf_planes.first().cloned().unwrap_or_default();
+        if self.buf_type.planar() {
+            v4l2_buf.length = planes.len() as u32;
+            v4l2_buf.m.planes = planes.as_mut_ptr();
+        }
+
+        let since = Instant::now();
+        loop {
+            let res = unsafe {
+                self.handle.ioctl(
+                    v4l2::vidioc::VIDIOC_DQBUF,
+                    &mut v4l2_buf as *mut _ as *mut std::os::raw::c_void,
+                )
+            };
+
+            match res {
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    let timeout = self.timeout.unwrap_or(-1);
+                    if !self
+                        .would_block
+                        .wait(&self.handle, events, timeout, since)?
+                    {
+                        return Err(e);
+                    }
+                }
+                res => break res?,
+            }
+        }
+        self.arena_index = v4l2_buf.index as usize;
+        self.held = true;
+        self.buf_meta[self.arena_index] = Metadata::from(v4l2_buf);
+        if self.buf_type.planar() {
+            self.buf_planes[self.arena_index] = planes;
+        }
+
+        Ok(self.arena_index)
+    }
+}
+
+/// Buffers and queue state of a stream, see [`Stream::into_parts`]
+///
+/// Dropping the parts stops the stream and releases the buffers, just like dropping the stream
+/// does.
+pub struct Parts<A: Arena> {
+    handle: Arc<Handle>,
+    /// Dropped explicitly, unless the stream was abandoned
+    arena: ManuallyDrop<A>,
+    buf_type: Type,
+    buf_meta: Vec<Metadata>,
+    buf_planes: Vec<Vec<v4l2_plane>>,
+    fences: Vec<Option<Fence>>,
+    active: bool,
+    /// Buffer dequeued by the last call to `next()`, which queues it again on the following one
+    held: Option<usize>,
+    drop_policy: DropPolicy,
+    stop_timeout: Option<Duration>,
+    /// Whether the driver did not stop streaming in time, the buffers are leaked then
+    abandoned: bool,
+}
+
+impl<A: Arena> Parts<A> {
+    /// Returns the raw device handle
+    pub fn handle(&self) -> Arc<Handle> {
+        self.handle.clone()
+    }
+
+    /// Returns the type of the buffers
+    pub fn buf_type(&self) -> Type {
+        self.buf_type
+    }
+
+    /// Returns the number of buffers
+    pub fn buffer_count(&self) -> usize {
+        self.arena.len()
+    }
+
+    /// Returns whether the stream is running
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+}
+
+impl<A: Arena> Drop for Parts<A> {
+    fn drop(&mut self) {
+        if self.active {
+            if let Err(e) = stream_off(&self.handle, self.buf_type, self.stop_timeout) {
+                if e.kind() == io::ErrorKind::TimedOut {
+                    self.abandoned = true;
+                }
+                self.drop_policy.handle(e);
+            }
+        }
+
+        // releasing the buffers of an abandoned stream would block on the locked queue as well
+        if !self.abandoned {
+            // the arena is not accessed anymore
+            unsafe { ManuallyDrop::drop(&mut self.arena) }
+        }
+    }
+}
+
+impl<A: Arena> Drop for Stream<A> {
+    fn drop(&mut self) {
+        if let Err(e) = self.stop() {
+            self.drop_policy.handle(e);
+        }
+
+        // releasing the buffers of an abandoned stream would block on the locked queue as well
+        if !self.abandoned {
+            // the arena is not accessed anymore
+            unsafe { ManuallyDrop::drop(&mut self.arena) }
+        }
+    }
+}
+
+impl<A: Arena> StreamTrait for Stream<A> {
+    type Item = A::Buffer;
+
+    fn start(&mut self) -> io::Result<()> {
+        if self.abandoned {
+            return Err(Abandoned.into());
+        }
+        if self.active {
+            return Ok(());
+        }
+
+        unsafe {
+            let mut typ = self.buf_type as u32;
+            self.handle.ioctl(
+                v4l2::vidioc::VIDIOC_STREAMON,
+                &mut typ as *mut _ as *mut std::os::raw::c_void,
+            )?;
+        }
+        debug!(handle = ?self.handle, buf_type = ?self.buf_type, "stream on");
+
+        self.active = true;
+        Ok(())
+    }
+
+    fn stop(&mut self) -> io::Result<()> {
+        if !self.active {
+            return Ok(());
+        }
+
+        if let Err(e) = stream_off(&self.handle, self.buf_type, self.stop_timeout) {
+            if e.kind() == io::ErrorKind::TimedOut {
+                self.active = false;
+                self.abandoned = true;
+            }
+            return Err(e);
+        }
+        debug!(handle = ?self.handle, buf_type = ?self.buf_type, "stream off");
+
+        self.active = false;
+        Ok(())
+    }
+
+    fn is_active(&self) -> bool {
+        self.active
+    }
+}
+
+impl<'a, A: Arena> CaptureStream<'a> for Stream<A> {
+    fn poll(&self) -> io::Result<bool> {
+        // Nothing being ready can only happen if there was a timeout. A timeout is only
+        // possible if the `timeout` value is non-zero, meaning we should propagate it to the
+        // caller.
+        self.handle
+            .wait_ready(PollFlags::IN, self.timeout.unwrap_or(-1))
+    }
+
+    fn queue(&mut self, index: usize) -> io::Result<()> {
+        CaptureStream::queue_request(self, index, None)
+    }
+
+    fn queue_request(&mut self, index: usize, request: Option<&Request>) -> io::Result<()> {
+        if self.abandoned {
+            return Err(Abandoned.into());
+        }
+        if !self.active {
+            return Err(Inactive.into());
+        }
+        self.qbuf(index, request, false)?;
+
+        // the buffer is back in the driver, next() must not queue it a second time
+        if index == self.arena_index {
+            self.held = false;
+        }
+        Ok(())
+    }
+
+    fn dequeue(&mut self) -> io::Result<usize> {
+        self.dqbuf(PollFlags::IN)
+    }
+
+    fn get(&self, index: usize) -> io::Result<(&Self::Item, &Metadata, &[v4l2_plane])> {
+        Ok((
+            self.arena.buffer_at(index),
+            &self.buf_meta[index],
+            &self.buf_planes[index],
+        ))
+    }
+
+    fn next(&'a mut self) -> io::Result<(&'a Self::Item, &'a Metadata, &'a [v4l2_plane])> {
+        if !self.active {
+            // Enqueue all buffers once on stream start
+            self.prime()?;
+        } else if self.held {
+            CaptureStream::queue(self, self.arena_index)?;
+        }
+
+        if self.would_block.polls_before_dequeue() && !CaptureStream::poll(self)? {
+            return Err(io::Error::new(io::ErrorKind::TimedOut, "VIDIOC_DQBUF"));
+        }
+
+        let index = CaptureStream::dequeue(self)?;
+        CaptureStream::get(self, index)
+    }
+}
+
+impl<'a, A: Arena> OutputStream<'a> for Stream<A> {
+    fn poll(&self) -> io::Result<bool> {
+        // Nothing being ready can only happen if there was a timeout. A timeout is only
+        // possible if the `timeout` value is non-zero, meaning we should propagate it to the
+        // caller.
+        self.handle
+            .wait_ready(PollFlags::OUT, self.timeout.unwrap_or(-1))
+    }
+
+    fn queue(&mut self, index: usize) -> io::Result<()> {
+        OutputStream::queue_request(self, index, None)
+    }
+
+    fn queue_request(&mut self, index: usize, request: Option<&Request>) -> io::Result<()> {
+        if self.abandoned {
+            return Err(Abandoned.into());
+        }
+        if !self.active {
+            return Err(Inactive.into());
+        }
+        self.qbuf(index, request, true)
+    }
+
+    fn dequeue(&mut self) -> io::Result<usize> {
+        self.dqbuf(PollFlags::OUT)
+    }
+
+    fn get(
+        &mut self,
+        index: usize,
+    ) -> io::Result<(&mut Self::Item, &mut Metadata, &mut [v4l2_plane])> {
+        Ok((
+            self.arena.buffer_at_mut(index),
+            &mut self.buf_meta[index],
+            &mut self.buf_planes[index],
+        ))
+    }
+
+    fn next(&'a mut self) -> io::Result<(&'a mut Self::Item, &'a mut Metadata)> {
+        let init = !self.active;
+        if !self.active {
+            self.start()?;
+        }
+
+        // Only queue and dequeue once the buffer has been filled at the call site. The initial
+        // call to this function from the call site will happen just after the buffers have been
+        // allocated, meaning we need to return the empty buffer initially so it can be filled.
+        if !init {
+            OutputStream::queue(self, self.arena_index)?;
+            if self.would_block.polls_before_dequeue() && !OutputStream::poll(self)? {
+                return Err(io::Error::new(io::ErrorKind::TimedOut, "VIDIOC_QBUF"));
+            }
+            self.arena_index = OutputStream::dequeue(self)?;
+        }
+
+        let bytes = self.arena.buffer_at_mut(self.arena_index);
+        let meta = &mut self.buf_meta[self.arena_index];
+        Ok((bytes, meta))
+    }
+}
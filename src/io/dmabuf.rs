@@ -0,0 +1,107 @@
+use std::io;
+use std::os::fd::{AsFd, AsRawFd, BorrowedFd, OwnedFd};
+use std::time::Duration;
+
+/// Memory plane of an exported buffer
+#[derive(Debug)]
+pub struct Plane {
+    /// DMA buffer file descriptor
+    pub fd: OwnedFd,
+    /// Offset of the payload within the DMA buffer
+    ///
+    /// Exported buffers start at offset zero. Multi-planar drivers may report a data offset
+    /// for dequeued buffers (`v4l2_plane::data_offset`) which callers can apply here.
+    pub offset: u32,
+    /// Size of the plane in bytes
+    pub len: u32,
+}
+
+impl AsFd for Plane {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
+
+/// Stream buffer exported as DMA buffer(s)
+///
+/// Exported buffers can be imported by other devices and APIs (GPU, display, encoder, ..)
+/// without copying the image data. The file descriptors keep the memory alive even when the
+/// stream which allocated it is gone.
+///
+/// Single-planar buffers consist of exactly one plane.
+#[derive(Debug)]
+pub struct DmaBuf {
+    /// Memory planes
+    pub planes: Vec<Plane>,
+}
+
+/// Sync file fence, signaled once the producer of a buffer (e.g. a GPU) is done with it
+///
+/// The fence proposal for V4L2 (`V4L2_BUF_FLAG_IN_FENCE`) never made it into mainline kernels,
+/// so streams wait for attached fences themselves before handing a buffer to the driver. The
+/// API stays the same should the kernel learn to wait for fences.
+#[derive(Debug)]
+pub struct Fence {
+    fd: OwnedFd,
+}
+
+impl Fence {
+    /// Returns whether the fence is signaled, waiting for it at most `timeout`
+    ///
+    /// Fails if the producer signaled an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Maximum time to wait, `None` waits indefinitely
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::os::fd::OwnedFd;
+    /// use std::time::Duration;
+    /// use v4l::io::dmabuf::Fence;
+    ///
+    /// fn wait_for_gpu(sync_file: OwnedFd) -> bool {
+    ///     let fence = Fence::from(sync_file);
+    ///     fence.wait(Some(Duration::from_millis(100))).unwrap_or(false)
+    /// }
+    /// ```
+    pub fn wait(&self, timeout: Option<Duration>) -> io::Result<bool> {
+        let timeout = match timeout {
+            Some(timeout) => timeout.as_millis().min(i32::MAX as u128) as i32,
+            None => -1,
+        };
+        let mut pollfd = libc::pollfd {
+            fd: self.fd.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+
+        loop {
+            let ret = unsafe { libc::poll(&mut pollfd, 1, timeout) };
+            if ret == -1 {
+                let e = io::Error::last_os_error();
+                if e.kind() == io::ErrorKind::Interrupted {
+                    continue;
+                }
+                return Err(e);
+            }
+            if pollfd.revents & (libc::POLLERR | libc::POLLNVAL) != 0 {
+                return Err(io::Error::other("fence signaled an error"));
+            }
+            return Ok(ret > 0);
+        }
+    }
+}
+
+impl From<OwnedFd> for Fence {
+    fn from(fd: OwnedFd) -> Self {
+        Fence { fd }
+    }
+}
+
+impl AsFd for Fence {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
@@ -0,0 +1,378 @@
+//! DMA buffers from a DMA heap or udmabuf
+//!
+//! Buffers allocated by the application and imported by the driver (`V4L2_MEMORY_DMABUF`) can be
+//! shared with other devices without copying, just like exported buffers. Systems without a GPU
+//! allocator can obtain them from the kernel's DMA heaps (`/dev/dma_heap/*`) or create them
+//! from memfds with `/dev/udmabuf`. The [`Arena`] in here does either and plugs into the
+//! generic [`crate::io::arena::Stream`].
+//!
+//! This module is only available with the `dmaheap` feature.
+
+use std::fs::OpenOptions;
+use std::ops::{Deref, DerefMut};
+use std::os::fd::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd};
+use std::path::PathBuf;
+use std::{io, mem, ptr, slice, sync::Arc};
+
+use crate::buffer;
+use crate::device::Handle;
+use crate::frame::Planes;
+use crate::io::dmabuf::{DmaBuf, Plane};
+use crate::io::traits::Arena as ArenaTrait;
+use crate::io::DropPolicy;
+use crate::memory::Memory;
+use crate::v4l2::platform;
+use crate::v4l2::vidioc::{self, _IOC_TYPE};
+use crate::v4l_sys::*;
+
+/// `struct dma_heap_allocation_data` of linux/dma-heap.h
+#[repr(C)]
+struct DmaHeapAllocationData {
+    len: u64,
+    fd: u32,
+    fd_flags: u32,
+    heap_flags: u64,
+}
+
+/// `struct udmabuf_create` of linux/udmabuf.h
+#[repr(C)]
+struct UdmabufCreate {
+    memfd: u32,
+    flags: u32,
+    offset: u64,
+    size: u64,
+}
+
+const DMA_HEAP_IOCTL_ALLOC: _IOC_TYPE = vidioc::iowr::<DmaHeapAllocationData>(b'H', 0);
+const UDMABUF_CREATE: _IOC_TYPE = vidioc::iow::<UdmabufCreate>(b'u', 0x42);
+const UDMABUF_FLAGS_CLOEXEC: u32 = 0x01;
+
+/// Provider of the buffer memory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// DMA heap, e.g. `/dev/dma_heap/system` or a CMA heap for devices which need physically
+    /// contiguous memory
+    Heap(PathBuf),
+    /// Memfd backed memory turned into DMA buffers by `/dev/udmabuf`, always scattered
+    Udmabuf,
+}
+
+impl Default for Source {
+    fn default() -> Self {
+        Source::Heap(PathBuf::from("/dev/dma_heap/system"))
+    }
+}
+
+impl Source {
+    /// Returns a DMA buffer of `len` bytes
+    fn allocate(&self, len: usize) -> io::Result<OwnedFd> {
+        match self {
+            Source::Heap(path) => {
+                let heap = OpenOptions::new().read(true).write(true).open(path)?;
+                let mut data = DmaHeapAllocationData {
+                    len: len as u64,
+                    fd: 0,
+                    fd_flags: (libc::O_RDWR | libc::O_CLOEXEC) as u32,
+                    heap_flags: 0,
+                };
+                unsafe {
+                    if platform::ioctl(
+                        heap.as_raw_fd(),
+                        DMA_HEAP_IOCTL_ALLOC,
+                        &mut data as *mut _ as *mut _,
+                    ) == -1
+                    {
+                        return Err(io::Error::last_os_error());
+                    }
+                    Ok(OwnedFd::from_raw_fd(data.fd as i32))
+                }
+            }
+            Source::Udmabuf => unsafe {
+                // udmabuf wants whole pages and a memfd which cannot shrink underneath it
+                let page = libc::sysconf(libc::_SC_PAGESIZE) as usize;
+                let size = len.div_ceil(page) * page;
+                let memfd = libc::memfd_create(
+                    b"v4l-udmabuf\0".as_ptr() as *const libc::c_char,
+                    libc::MFD_CLOEXEC | libc::MFD_ALLOW_SEALING,
+                );
+                if memfd == -1 {
+                    return Err(io::Error::last_os_error());
+                }
+                let memfd = OwnedFd::from_raw_fd(memfd);
+                if libc::ftruncate(memfd.as_raw_fd(), size as libc::off_t) == -1
+                    || libc::fcntl(memfd.as_raw_fd(), libc::F_ADD_SEALS, libc::F_SEAL_SHRINK) == -1
+                {
+                    return Err(io::Error::last_os_error());
+                }
+
+                let dev = OpenOptions::new()
+                    .read(true)
+                    .write(true)
+                    .open("/dev/udmabuf")?;
+                let mut create = UdmabufCreate {
+                    memfd: memfd.as_raw_fd() as u32,
+                    flags: UDMABUF_FLAGS_CLOEXEC,
+                    offset: 0,
+                    size: size as u64,
+                };
+                let fd = platform::ioctl(
+                    dev.as_raw_fd(),
+                    UDMABUF_CREATE,
+                    &mut create as *mut _ as *mut _,
+                );
+                if fd == -1 {
+                    return Err(io::Error::last_os_error());
+                }
+                Ok(OwnedFd::from_raw_fd(fd))
+            },
+        }
+    }
+}
+
+/// DMA buffer mapped for CPU access
+///
+/// Heaps with cached memory may need cache maintenance (`DMA_BUF_IOCTL_SYNC`) on platforms
+/// without coherent DMA before the CPU reads what a device wrote.
+pub struct Buffer {
+    fd: OwnedFd,
+    ptr: *mut u8,
+    len: usize,
+}
+
+// The buffer exclusively owns its mapping, just like a Vec would.
+unsafe impl Send for Buffer {}
+unsafe impl Sync for Buffer {}
+
+impl Buffer {
+    fn new(source: &Source, len: usize) -> io::Result<Self> {
+        let fd = source.allocate(len)?;
+        let ptr = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                len,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_SHARED,
+                fd.as_raw_fd(),
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(Buffer {
+            fd,
+            ptr: ptr as *mut u8,
+            len,
+        })
+    }
+
+    /// Returns the buffer for sharing with other devices and APIs
+    ///
+    /// The file descriptor is duplicated, so the memory outlives the stream.
+    pub fn export(&self) -> io::Result<DmaBuf> {
+        Ok(DmaBuf {
+            planes: vec![Plane {
+                fd: self.fd.try_clone()?,
+                offset: 0,
+                len: self.len as u32,
+            }],
+        })
+    }
+}
+
+impl Deref for Buffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl DerefMut for Buffer {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+}
+
+impl AsFd for Buffer {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
+
+impl Planes for Buffer {
+    fn planes(&self) -> Vec<&[u8]> {
+        vec![&self[..]]
+    }
+}
+
+impl Drop for Buffer {
+    fn drop(&mut self) {
+        unsafe {
+            libc::munmap(self.ptr as *mut libc::c_void, self.len);
+        }
+    }
+}
+
+/// Manage DMA buffers imported by the driver
+///
+/// Only single-planar buffer types are supported. All buffers are released in the Drop impl.
+///
+/// # Example
+///
+/// ```
+/// use v4l::buffer::Type;
+/// use v4l::io::arena::Stream;
+/// use v4l::io::heap::{Arena, Source};
+/// use v4l::io::traits::CaptureStream;
+/// use v4l::prelude::*;
+///
+/// if let Ok(dev) = Device::new(0) {
+///     let arena = Arena::new(dev.handle(), Type::VideoCapture, Source::Udmabuf);
+///     if let Ok(mut stream) = Stream::with_arena(&dev, Type::VideoCapture, arena, 4) {
+///         let (buf, _, _) = stream.next().unwrap();
+///         // hand the frame to another device without copying
+///         let dmabuf = buf.export().unwrap();
+///     }
+/// }
+/// ```
+pub struct Arena {
+    handle: Arc<Handle>,
+    bufs: Vec<Buffer>,
+    buf_type: buffer::Type,
+    source: Source,
+}
+
+impl Arena {
+    /// Returns a new buffer manager instance
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - Device handle to get its file descriptor
+    /// * `buf_type` - Type of the buffers
+    /// * `source` - Provider of the buffer memory
+    pub fn new(handle: Arc<Handle>, buf_type: buffer::Type, source: Source) -> Self {
+        Arena {
+            handle,
+            bufs: Vec::new(),
+            buf_type,
+            source,
+        }
+    }
+
+    fn requestbuffers_desc(&self) -> v4l2_requestbuffers {
+        v4l2_requestbuffers {
+            type_: self.buf_type as u32,
+            memory: Memory::DmaBuf as u32,
+            ..unsafe { mem::zeroed() }
+        }
+    }
+}
+
+impl ArenaTrait for Arena {
+    type Buffer = Buffer;
+
+    fn memory(&self) -> Memory {
+        Memory::DmaBuf
+    }
+
+    fn allocate(&mut self, count: u32) -> io::Result<u32> {
+        if self.buf_type.planar() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "multi-planar DMA buffer import is not supported",
+            ));
+        }
+
+        // we need to get the maximum buffer size from the format first
+        let mut v4l2_fmt = v4l2_format {
+            type_: self.buf_type as u32,
+            ..unsafe { mem::zeroed() }
+        };
+        unsafe {
+            self.handle.ioctl(
+                vidioc::VIDIOC_G_FMT,
+                &mut v4l2_fmt as *mut _ as *mut std::os::raw::c_void,
+            )?;
+        }
+
+        let mut v4l2_reqbufs = v4l2_requestbuffers {
+            count,
+            ..self.requestbuffers_desc()
+        };
+        unsafe {
+            self.handle.ioctl(
+                vidioc::VIDIOC_REQBUFS,
+                &mut v4l2_reqbufs as *mut _ as *mut std::os::raw::c_void,
+            )?;
+        }
+        debug!(
+            handle = ?self.handle,
+            memory = "dmabuf",
+            requested = count,
+            granted = v4l2_reqbufs.count,
+            "allocated buffers"
+        );
+
+        let len = unsafe { v4l2_fmt.fmt.pix.sizeimage as usize };
+        self.bufs = (0..v4l2_reqbufs.count)
+            .map(|_| Buffer::new(&self.source, len))
+            .collect::<io::Result<_>>()?;
+
+        Ok(v4l2_reqbufs.count)
+    }
+
+    fn release(&mut self) -> io::Result<()> {
+        // free all buffers by requesting 0
+        let mut v4l2_reqbufs = v4l2_requestbuffers {
+            count: 0,
+            ..self.requestbuffers_desc()
+        };
+        unsafe {
+            self.handle.ioctl(
+                vidioc::VIDIOC_REQBUFS,
+                &mut v4l2_reqbufs as *mut _ as *mut std::os::raw::c_void,
+            )?;
+        }
+        debug!(handle = ?self.handle, memory = "dmabuf", "released buffers");
+
+        self.bufs.clear();
+        Ok(())
+    }
+
+    fn len(&self) -> usize {
+        self.bufs.len()
+    }
+
+    fn buffer_len(&self, index: usize) -> usize {
+        self.bufs[index].len
+    }
+
+    fn buffer_at(&self, index: usize) -> &Self::Buffer {
+        &self.bufs[index]
+    }
+
+    fn buffer_at_mut(&mut self, index: usize) -> &mut Self::Buffer {
+        &mut self.bufs[index]
+    }
+
+    fn prepare(&self, index: usize, buf: &mut v4l2_buffer, _planes: &mut [v4l2_plane]) {
+        let mem = &self.bufs[index];
+        buf.m.fd = mem.fd.as_raw_fd();
+        buf.length = mem.len as u32;
+    }
+}
+
+impl Drop for Arena {
+    fn drop(&mut self) {
+        if self.bufs.is_empty() {
+            // nothing to do
+            return;
+        }
+
+        if let Err(e) = self.release() {
+            DropPolicy::default().handle(e);
+        }
+    }
+}
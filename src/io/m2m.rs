@@ -0,0 +1,436 @@
+use std::collections::VecDeque;
+use std::{io, mem};
+
+use crate::buffer::{Flags, Metadata, Type};
+use crate::capability;
+use crate::device::VideoDevice;
+use crate::io::mmap;
+use crate::io::traits::{CaptureStream, OutputStream, Stream as StreamTrait};
+use crate::memory::Memory;
+use crate::timestamp::Timestamp;
+use crate::v4l2;
+use crate::v4l_sys::*;
+
+/// State of a memory-to-memory stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    /// Inputs are processed as they are queued
+    Running,
+    /// The device was told to stop, results of the pending inputs are still coming in
+    Draining,
+    /// All results were dequeued, the stream has to be restarted to take inputs again
+    Drained,
+}
+
+/// Command interface of the device, decoders and encoders have their own
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Decoder,
+    Encoder,
+}
+
+/// Stream of a memory-to-memory device, e.g. a scaler, an encoder or a decoder
+///
+/// Memory-to-memory devices process the buffers queued on their OUTPUT queue and return the
+/// results on their CAPTURE queue. This stream drives both queues with mapped buffers: input
+/// buffers are filled and queued through [`Stream::input`] and [`Stream::queue_input`],
+/// results are picked up through [`Stream::output`].
+///
+/// Results carry the timestamp of the input they were produced from. Drivers announce this
+/// through `V4L2_BUF_FLAG_TIMESTAMP_COPY`, see [`Stream::timestamp_copy`]. For drivers which
+/// stamp their results themselves, the timestamps of the inputs are handed out in queueing
+/// order instead, which matches devices producing exactly one result per input.
+///
+/// # Example
+///
+/// ```
+/// use v4l::io::m2m::Stream;
+/// use v4l::timestamp::Timestamp;
+/// use v4l::Device;
+///
+/// if let Ok(dev) = Device::new(0) {
+///     let mut stream = Stream::new(&dev).unwrap();
+///     for frame in 0..10 {
+///         let (planes, meta) = stream.input().unwrap();
+///         planes[0].fill(0x80);
+///         meta.bytesused = planes[0].len() as u32;
+///         meta.timestamp = Timestamp::new(0, frame * 40_000);
+///         stream.queue_input().unwrap();
+///
+///         let (planes, meta) = stream.output().unwrap();
+///         println!("{} bytes at {}", planes[0].len(), meta.timestamp);
+///     }
+/// }
+/// ```
+pub struct Stream<'a> {
+    input: mmap::Stream<'a>,
+    output: mmap::Stream<'a>,
+    /// Input buffers owned by the application, the first one is handed out next
+    free: VecDeque<usize>,
+    /// Output buffer handed out last, it is queued again by the next call to `output()`
+    held: Option<usize>,
+    /// Whether the driver copies the input timestamps to the results
+    copy: bool,
+    /// Timestamps of the queued inputs, oldest first, unless the driver copies them
+    timestamps: VecDeque<Timestamp>,
+    /// Metadata of the result handed out last
+    meta: Metadata,
+    state: State,
+    /// Command interface used to stop the device, detected by the first drain
+    command: Option<Command>,
+}
+
+impl<'a> Stream<'a> {
+    /// Returns a stream with four buffers per queue
+    ///
+    /// Multi-planar devices are detected through their capabilities.
+    ///
+    /// # Arguments
+    ///
+    /// * `dev` - Device (single- or multi-planar)
+    pub fn new<D: VideoDevice>(dev: &D) -> io::Result<Self> {
+        Self::with_buffers(dev, 4, 4)
+    }
+
+    /// Returns a stream with a given number of buffers per queue
+    ///
+    /// # Arguments
+    ///
+    /// * `dev` - Device (single- or multi-planar)
+    /// * `inputs` - Number of OUTPUT buffers to request
+    /// * `outputs` - Number of CAPTURE buffers to request
+    pub fn with_buffers<D: VideoDevice>(dev: &D, inputs: u32, outputs: u32) -> io::Result<Self> {
+        let caps = dev.handle().query_caps()?;
+        let (input_type, output_type) = if caps
+            .capabilities
+            .contains(capability::Flags::VIDEO_M2M_MPLANE)
+        {
+            (Type::VideoOutputMplane, Type::VideoCaptureMplane)
+        } else {
+            (Type::VideoOutput, Type::VideoCapture)
+        };
+
+        let input = mmap::Stream::with_buffers(dev, input_type, inputs)?;
+        let output = mmap::Stream::with_buffers(dev, output_type, outputs)?;
+        let copy = output.query_buf(0)?.meta.flags & Flags::TIMESTAMP_MASK == Flags::TIMESTAMP_COPY;
+
+        Ok(Stream {
+            free: (0..input.buffer_count()).collect(),
+            input,
+            output,
+            held: None,
+            copy,
+            timestamps: VecDeque::new(),
+            meta: Metadata::with_memory(Memory::Mmap),
+            state: State::Running,
+            command: None,
+        })
+    }
+
+    /// Returns whether the driver copies the input timestamps to the results
+    ///
+    /// If it does not, the results are stamped with the input timestamps in queueing order.
+    pub fn timestamp_copy(&self) -> bool {
+        self.copy
+    }
+
+    /// Returns the input buffer to fill next, waiting for the device to release one if needed
+    ///
+    /// Set the number of bytes used and the timestamp in the metadata, then queue the buffer
+    /// through [`Stream::queue_input`]. Until then, the same buffer is returned again.
+    pub fn input(&mut self) -> io::Result<(&mut [&'a mut [u8]], &mut Metadata)> {
+        let index = match self.free.front() {
+            Some(index) => *index,
+            None => {
+                if !OutputStream::poll(&self.input)? {
+                    return Err(io::Error::new(io::ErrorKind::TimedOut, "VIDIOC_DQBUF"));
+                }
+                let index = OutputStream::dequeue(&mut self.input)?;
+                self.free.push_back(index);
+                index
+            }
+        };
+
+        let (planes, meta, _) = OutputStream::get(&mut self.input, index)?;
+        Ok((planes, meta))
+    }
+
+    /// Returns whether the stream takes inputs or is being drained
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    /// Queues the input buffer returned by [`Stream::input`] for processing
+    ///
+    /// Both queues are started by the first input. Drained streams have to be restarted first.
+    pub fn queue_input(&mut self) -> io::Result<()> {
+        if self.state != State::Running {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "stream is drained, restart it first",
+            ));
+        }
+
+        let index = match self.free.front() {
+            Some(index) => *index,
+            None => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "no input buffer to queue",
+                ))
+            }
+        };
+
+        if !self.input.is_active() {
+            self.input.start()?;
+            self.output.prime()?;
+        }
+
+        let (_, meta, planes) = OutputStream::get(&mut self.input, index)?;
+        let timestamp = meta.timestamp;
+        // multi-planar drivers take the size from the plane
+        if let [plane] = planes {
+            plane.bytesused = meta.bytesused;
+        }
+        OutputStream::queue(&mut self.input, index)?;
+        self.free.pop_front();
+        if !self.copy {
+            self.timestamps.push_back(timestamp);
+        }
+        Ok(())
+    }
+
+    /// Returns the next result, waiting for the device to produce one
+    ///
+    /// The result handed out before is released back to the device. Once the last result of a
+    /// drain was handed out, this fails with [`io::ErrorKind::BrokenPipe`] until the stream is
+    /// restarted.
+    pub fn output(&mut self) -> io::Result<(&[&'a mut [u8]], &Metadata)> {
+        if self.state == State::Drained {
+            return Err(io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                "stream is drained",
+            ));
+        }
+
+        if let Some(index) = self.held.take() {
+            CaptureStream::queue(&mut self.output, index)?;
+        }
+
+        if !CaptureStream::poll(&self.output)? {
+            return Err(io::Error::new(io::ErrorKind::TimedOut, "VIDIOC_DQBUF"));
+        }
+        let index = CaptureStream::dequeue(&mut self.output)?;
+        self.held = Some(index);
+
+        let (planes, meta, _) = CaptureStream::get(&self.output, index)?;
+        self.meta = *meta;
+        if self.meta.flags.contains(Flags::LAST) {
+            self.state = State::Drained;
+        }
+        if !self.copy {
+            if let Some(timestamp) = self.timestamps.pop_front() {
+                self.meta.timestamp = timestamp;
+            }
+        }
+        Ok((planes, &self.meta))
+    }
+
+    /// Tells the device to finish the pending inputs and hands out their results
+    ///
+    /// Decoders and encoders are sent their STOP command. The results are dequeued until the
+    /// driver marks the last one with [`Flags::LAST`], which is passed to `f` as well and may
+    /// be empty. Afterwards, the stream is [`State::Drained`] and takes no more inputs until it
+    /// is restarted. Draining a drained stream does nothing.
+    ///
+    /// Devices without a command interface, e.g. scalers, fail with
+    /// [`io::ErrorKind::Unsupported`]. They produce a result for every input, so they are
+    /// drained by calling [`Stream::output`] once per pending input instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Called with every remaining result
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::io::m2m::Stream;
+    /// use v4l::Device;
+    ///
+    /// if let Ok(dev) = Device::new(0) {
+    ///     let mut stream = Stream::new(&dev).unwrap();
+    ///     // ... queue the inputs
+    ///     stream
+    ///         .drain(|planes, meta| println!("{} planes at {}", planes.len(), meta.timestamp))
+    ///         .unwrap();
+    ///     stream.restart().unwrap();
+    /// }
+    /// ```
+    pub fn drain<F>(&mut self, mut f: F) -> io::Result<()>
+    where
+        F: FnMut(&[&'a mut [u8]], &Metadata),
+    {
+        match self.state {
+            State::Drained => return Ok(()),
+            // nothing was queued, so there is nothing to wait for
+            State::Running if !self.input.is_active() => {
+                self.state = State::Drained;
+                return Ok(());
+            }
+            State::Running => {
+                let command = match self.command {
+                    Some(command) => command,
+                    None => self.detect_command()?,
+                };
+                self.command = Some(command);
+                self.ioctl(command, false, V4L2_DEC_CMD_STOP)?;
+                self.state = State::Draining;
+            }
+            State::Draining => {}
+        }
+
+        loop {
+            let (planes, meta) = self.output()?;
+            f(planes, meta);
+            if self.state == State::Drained {
+                break;
+            }
+        }
+        self.timestamps.clear();
+        Ok(())
+    }
+
+    /// Lets a drained stream take inputs again
+    ///
+    /// Decoders and encoders are sent their START command. Restarting a running stream does
+    /// nothing.
+    pub fn restart(&mut self) -> io::Result<()> {
+        if self.state == State::Running {
+            return Ok(());
+        }
+
+        if let Some(command) = self.command {
+            self.ioctl(command, false, V4L2_DEC_CMD_START)?;
+        }
+        self.state = State::Running;
+        Ok(())
+    }
+
+    /// Returns whether the device takes decoder or encoder commands
+    fn detect_command(&self) -> io::Result<Command> {
+        for command in [Command::Decoder, Command::Encoder] {
+            match self.ioctl(command, true, V4L2_DEC_CMD_STOP) {
+                Ok(()) => return Ok(command),
+                // the ioctls of the other device kind are not implemented
+                Err(e) if e.raw_os_error() == Some(libc::ENOTTY) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "device takes neither decoder nor encoder commands",
+        ))
+    }
+
+    /// Sends or tries a command, the decoder and encoder ones share their values
+    fn ioctl(&self, command: Command, try_: bool, cmd: u32) -> io::Result<()> {
+        let handle = self.input.handle();
+        unsafe {
+            match command {
+                Command::Decoder => {
+                    let mut v4l2_cmd = v4l2_decoder_cmd {
+                        cmd,
+                        ..mem::zeroed()
+                    };
+                    let request = if try_ {
+                        v4l2::vidioc::VIDIOC_TRY_DECODER_CMD
+                    } else {
+                        v4l2::vidioc::VIDIOC_DECODER_CMD
+                    };
+                    handle.ioctl(
+                        request,
+                        &mut v4l2_cmd as *mut _ as *mut std::os::raw::c_void,
+                    )
+                }
+                Command::Encoder => {
+                    let mut v4l2_cmd = v4l2_encoder_cmd {
+                        cmd,
+                        ..mem::zeroed()
+                    };
+                    let request = if try_ {
+                        v4l2::vidioc::VIDIOC_TRY_ENCODER_CMD
+                    } else {
+                        v4l2::vidioc::VIDIOC_ENCODER_CMD
+                    };
+                    handle.ioctl(
+                        request,
+                        &mut v4l2_cmd as *mut _ as *mut std::os::raw::c_void,
+                    )
+                }
+            }
+        }
+    }
+
+    /// Returns the stream of the OUTPUT queue, which takes the inputs
+    pub fn input_stream(&mut self) -> &mut mmap::Stream<'a> {
+        &mut self.input
+    }
+
+    /// Returns the stream of the CAPTURE queue, which returns the results
+    pub fn output_stream(&mut self) -> &mut mmap::Stream<'a> {
+        &mut self.output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Mock;
+    use crate::device::Device;
+
+    #[test]
+    fn copies_inputs_with_their_timestamps() {
+        let dev: Device = Mock::mem2mem().device().unwrap();
+        let mut stream = Stream::with_buffers(&dev, 2, 2).unwrap();
+        assert!(stream.timestamp_copy());
+        assert_eq!(stream.state(), State::Running);
+
+        for frame in 0..5u8 {
+            let (planes, meta) = stream.input().unwrap();
+            planes[0][..3].copy_from_slice(&[frame, frame + 1, frame + 2]);
+            meta.bytesused = 3;
+            meta.timestamp = Timestamp::new(1, i64::from(frame) * 1000);
+            stream.queue_input().unwrap();
+
+            let (planes, meta) = stream.output().unwrap();
+            assert_eq!(meta.bytesused, 3);
+            assert_eq!(&planes[0][..3], &[frame, frame + 1, frame + 2]);
+            assert_eq!(meta.timestamp, Timestamp::new(1, i64::from(frame) * 1000));
+            assert_eq!(meta.memory, Memory::Mmap);
+        }
+    }
+
+    #[test]
+    fn drain_needs_a_command_interface() {
+        let dev: Device = Mock::mem2mem().device().unwrap();
+        let mut stream = Stream::new(&dev).unwrap();
+
+        // nothing was queued yet
+        stream.drain(|_, _| panic!("no results expected")).unwrap();
+        assert_eq!(stream.state(), State::Drained);
+        assert_eq!(
+            stream.queue_input().unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+        stream.restart().unwrap();
+
+        let (_, meta) = stream.input().unwrap();
+        meta.bytesused = 1;
+        stream.queue_input().unwrap();
+        assert_eq!(
+            stream.drain(|_, _| {}).unwrap_err().kind(),
+            io::ErrorKind::Unsupported
+        );
+    }
+}
@@ -1,20 +1,39 @@
-use std::{io, mem, os::fd::AsRawFd, ptr, slice, sync::Arc};
+use std::{io, mem, ptr, slice, sync::Arc};
 
 use crate::buffer;
 use crate::device::Handle;
+use crate::io::traits::Arena as ArenaTrait;
+use crate::io::DropPolicy;
 use crate::memory::Memory;
 use crate::v4l2;
 use crate::v4l_sys::*;
 
+/// Options applied when mapping buffers into memory
+///
+/// By default, pages are faulted in lazily on first access, which can cause latency spikes for
+/// the first frames in real-time capture.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MapOptions {
+    /// Pre-fault the pages of the mappings (`MAP_POPULATE`)
+    pub populate: bool,
+    /// Advise the kernel that the mappings will be accessed soon (`MADV_WILLNEED`)
+    pub will_need: bool,
+    /// Lock the mappings in memory (`mlock()`), subject to `RLIMIT_MEMLOCK`
+    pub lock: bool,
+}
+
 /// Manage mapped buffers
 ///
 /// All buffers are unmapped in the Drop impl.
-/// In case of errors during unmapping, we panic because there is memory corruption going on.
+/// Errors while releasing them are logged and ignored like [`DropPolicy::Ignore`] does.
 pub struct Arena<'a> {
     handle: Arc<Handle>,
     pub bufs: Vec<Vec<&'a mut [u8]>>,
     pub buf_type: buffer::Type,
     pub planes: Vec<Vec<v4l2_plane>>,
+    pub options: MapOptions,
+    /// Address and length of every mapping, planes may share one
+    mappings: Vec<(usize, usize)>,
 }
 
 impl<'a> Arena<'a> {
@@ -33,6 +52,8 @@ impl<'a> Arena<'a> {
             bufs: Vec::new(),
             buf_type,
             planes: Vec::new(),
+            options: MapOptions::default(),
+            mappings: Vec::new(),
         }
     }
 
@@ -52,7 +73,96 @@ impl<'a> Arena<'a> {
         }
     }
 
-    pub fn allocate(&mut self, count: u32) -> io::Result<u32> {
+    /// Maps a range of device memory, honoring the mapping options
+    ///
+    /// # Arguments
+    ///
+    /// * `length` - Length of the range
+    /// * `offset` - Memory offset reported by the driver
+    unsafe fn map(&mut self, length: usize, offset: usize) -> io::Result<*mut u8> {
+        let mut flags = libc::MAP_SHARED;
+        if self.options.populate {
+            flags |= libc::MAP_POPULATE;
+        }
+        let ptr = self.handle.mmap(
+            ptr::null_mut(),
+            length,
+            libc::PROT_READ | libc::PROT_WRITE,
+            flags,
+            offset as libc::off_t,
+        )?;
+
+        // the advice is merely a hint, failing to follow it is not an error
+        if self.options.will_need {
+            libc::madvise(ptr, length, libc::MADV_WILLNEED);
+        }
+        if self.options.lock && libc::mlock(ptr, length) == -1 {
+            let e = io::Error::last_os_error();
+            self.handle.munmap(ptr, length)?;
+            return Err(e);
+        }
+
+        self.mappings.push((ptr as usize, length));
+        Ok(ptr as *mut u8)
+    }
+
+    /// Maps all planes of a multi-planar buffer at once if they share one allocation
+    ///
+    /// Some drivers allocate all planes of a buffer in one piece and report the same memory offset
+    /// for each of them, the planes then follow each other within the allocation. Mapping it once
+    /// saves mappings and TLB entries for high resolution capture. Returns `None` if the planes
+    /// have distinct memory offsets (as with vb2 based drivers) or the allocation is too small to
+    /// hold the planes back to back, in which case each plane has to be mapped on its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `planes` - Planes of the buffer, as returned by VIDIOC_QUERYBUF
+    unsafe fn map_shared(
+        &mut self,
+        planes: &[v4l2_plane],
+    ) -> io::Result<Option<Vec<&'a mut [u8]>>> {
+        if !self.buf_type.planar() || planes.len() < 2 {
+            return Ok(None);
+        }
+
+        // the data offsets are only known once a buffer was dequeued, the memory offsets are not
+        let offset = planes[0].m.mem_offset;
+        if planes.iter().any(|plane| plane.m.mem_offset != offset) {
+            return Ok(None);
+        }
+
+        let length = planes.iter().map(|plane| plane.length as usize).sum();
+        let ptr = match self.map(length, offset as usize) {
+            Ok(ptr) => ptr,
+            // each plane spans the whole allocation instead
+            Err(e) if e.raw_os_error() == Some(libc::EINVAL) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        trace!(handle = ?self.handle, planes = planes.len(), length, "mapped planes at once");
+
+        let mut start = 0;
+        Ok(Some(
+            planes
+                .iter()
+                .map(|plane| {
+                    let len = plane.length as usize;
+                    let slice = slice::from_raw_parts_mut(ptr.add(start), len);
+                    start += len;
+                    slice
+                })
+                .collect(),
+        ))
+    }
+}
+
+impl<'a> ArenaTrait for Arena<'a> {
+    type Buffer = Vec<&'a mut [u8]>;
+
+    fn memory(&self) -> Memory {
+        Memory::Mmap
+    }
+
+    fn allocate(&mut self, count: u32) -> io::Result<u32> {
         let num_planes = if !self.buf_type.planar() {
             1
         } else {
@@ -61,8 +171,7 @@ impl<'a> Arena<'a> {
             unsafe {
                 v4l2_fmt = mem::zeroed();
                 v4l2_fmt.type_ = self.buf_type as u32;
-                v4l2::ioctl(
-                    self.handle.as_raw_fd(),
+                self.handle.ioctl(
                     v4l2::vidioc::VIDIOC_G_FMT,
                     &mut v4l2_fmt as *mut _ as *mut std::os::raw::c_void,
                 )?;
@@ -76,12 +185,18 @@ impl<'a> Arena<'a> {
             ..self.requestbuffers_desc()
         };
         unsafe {
-            v4l2::ioctl(
-                self.handle.as_raw_fd(),
+            self.handle.ioctl(
                 v4l2::vidioc::VIDIOC_REQBUFS,
                 &mut v4l2_reqbufs as *mut _ as *mut std::os::raw::c_void,
             )?;
         }
+        debug!(
+            handle = ?self.handle,
+            memory = "mmap",
+            requested = count,
+            granted = v4l2_reqbufs.count,
+            "allocated buffers"
+        );
 
         for index in 0..v4l2_reqbufs.count {
             let mut v4l2_planes: Vec<v4l2_plane> = Vec::new();
@@ -97,28 +212,29 @@ impl<'a> Arena<'a> {
                 v4l2_buf.m.planes = v4l2_planes.as_mut_ptr();
             }
             unsafe {
-                v4l2::ioctl(
-                    self.handle.as_raw_fd(),
+                self.handle.ioctl(
                     v4l2::vidioc::VIDIOC_QUERYBUF,
                     &mut v4l2_buf as *mut _ as *mut std::os::raw::c_void,
                 )?;
 
+                if let Some(planes) = self.map_shared(&v4l2_planes)? {
+                    self.bufs.push(planes);
+                    self.planes.push(v4l2_planes);
+                    continue;
+                }
+
                 // each plane has to be mapped separately
                 let mut planes = Vec::new();
                 for plane in &v4l2_planes {
                     let length = if !self.buf_type.planar() { v4l2_buf.length as usize } else { plane.length as usize };
-                    let ptr = v4l2::mmap(
-                        ptr::null_mut(),
-                        length,
-                        libc::PROT_READ | libc::PROT_WRITE,
-                        libc::MAP_SHARED,
-                        self.handle.as_raw_fd(),
-                        plane.m.mem_offset as libc::off_t,
-                    )?;
-
-                    planes.push(slice::from_raw_parts_mut::<u8>(
-                        ptr as *mut u8, length
-                    ));
+                    let offset = if !self.buf_type.planar() {
+                        v4l2_buf.m.offset
+                    } else {
+                        plane.m.mem_offset
+                    };
+                    let ptr = self.map(length, offset as usize)?;
+
+                    planes.push(slice::from_raw_parts_mut::<u8>(ptr, length));
                 }
 
                 // finally, add the buffer (with all its planes) to the set
@@ -130,12 +246,11 @@ impl<'a> Arena<'a> {
         Ok(v4l2_reqbufs.count)
     }
 
-    pub fn release(&mut self) -> io::Result<()> {
-        for buf in &self.bufs {
-            for plane in buf {
-                unsafe {
-                    v4l2::munmap(plane.as_ptr() as *mut core::ffi::c_void, buf.len())?;
-                }
+    fn release(&mut self) -> io::Result<()> {
+        // planes sharing a mapping do not necessarily start at a page boundary
+        for (addr, length) in self.mappings.drain(..) {
+            unsafe {
+                self.handle.munmap(addr as *mut core::ffi::c_void, length)?;
             }
         }
 
@@ -145,37 +260,49 @@ impl<'a> Arena<'a> {
             ..self.requestbuffers_desc()
         };
         unsafe {
-            v4l2::ioctl(
-                self.handle.as_raw_fd(),
+            self.handle.ioctl(
                 v4l2::vidioc::VIDIOC_REQBUFS,
                 &mut v4l2_reqbufs as *mut _ as *mut std::os::raw::c_void,
             )?;
         }
+        debug!(handle = ?self.handle, memory = "mmap", "released buffers");
 
         self.bufs.clear();
+        self.planes.clear();
         Ok(())
     }
+
+    fn len(&self) -> usize {
+        self.bufs.len()
+    }
+
+    fn buffer_len(&self, index: usize) -> usize {
+        self.bufs[index].iter().map(|plane| plane.len()).sum()
+    }
+
+    fn buffer_at(&self, index: usize) -> &Self::Buffer {
+        &self.bufs[index]
+    }
+
+    fn buffer_at_mut(&mut self, index: usize) -> &mut Self::Buffer {
+        &mut self.bufs[index]
+    }
+
+    fn planes(&self, index: usize) -> &[v4l2_plane] {
+        &self.planes[index]
+    }
 }
 
 impl<'a> Drop for Arena<'a> {
     fn drop(&mut self) {
-        if self.bufs.is_empty() {
+        // allocate() may have failed after mapping some planes, before adding their buffer
+        if self.bufs.is_empty() && self.mappings.is_empty() {
             // nothing to do
             return;
         }
 
         if let Err(e) = self.release() {
-            if let Some(code) = e.raw_os_error() {
-                // ENODEV means the file descriptor wrapped in the handle became invalid, most
-                // likely because the device was unplugged or the connection (USB, PCI, ..)
-                // broke down. Handle this case gracefully by ignoring it.
-                if code == 19 {
-                    /* ignore */
-                    return;
-                }
-            }
-
-            panic!("{:?}", e)
+            DropPolicy::default().handle(e);
         }
     }
 }
@@ -1,4 +1,5 @@
 pub(crate) mod arena;
 
 pub mod stream;
-pub use stream::Stream;
+pub use arena::{Arena, MapOptions};
+pub use stream::{Parts, Stream};
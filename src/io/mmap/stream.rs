@@ -1,299 +1,333 @@
-use std::convert::TryInto;
-use std::time::Duration;
-use std::{io, mem, os::fd::AsRawFd, sync::Arc};
+use std::{io, mem, os::fd::{FromRawFd, OwnedFd}};
 
 use crate::buffer::{Metadata, Type};
-use crate::device::{PlanarDevice, Handle};
-use crate::io::mmap::arena::Arena;
-use crate::io::traits::{CaptureStream, OutputStream, Stream as StreamTrait};
-use crate::memory::Memory;
+use crate::device::VideoDevice;
+use crate::io::arena;
+use crate::io::dmabuf::{DmaBuf, Plane};
+use crate::io::mmap::arena::{Arena, MapOptions};
+use crate::io::traits::{CaptureStream, Stream as StreamTrait};
+use crate::io::Profile;
+use crate::media::Request;
 use crate::v4l2;
 use crate::v4l_sys::*;
 
 /// Stream of mapped buffers
 ///
 /// An arena instance is used internally for buffer handling.
-pub struct Stream<'a> {
-    handle: Arc<Handle>,
-    arena: Arena<'a>,
-    arena_index: usize,
-    buf_type: Type,
-    buf_meta: Vec<Metadata>,
-    timeout: Option<i32>,
-
-    active: bool,
-}
+pub type Stream<'a> = arena::Stream<Arena<'a>>;
+
+/// Buffers and queue state of a mapped buffer stream, see [`Stream::into_parts`]
+pub type Parts<'a> = arena::Parts<Arena<'a>>;
 
-impl<'a> Stream<'a> {
+impl<'a> arena::Stream<Arena<'a>> {
     /// Returns a stream for frame capturing
     ///
     /// # Arguments
     ///
-    /// * `dev` - Capture device ref to get its file descriptor
+    /// * `dev` - Device (single- or multi-planar) to get its file descriptor
     /// * `buf_type` - Type of the buffers
     ///
     /// # Example
     ///
     /// ```
     /// use v4l::buffer::Type;
-    /// use v4l::device::Device;
+    /// use v4l::device::{Device, MultiPlaneDevice};
     /// use v4l::io::mmap::Stream;
     ///
     /// let dev = Device::new(0);
     /// if let Ok(dev) = dev {
     ///     let stream = Stream::new(&dev, Type::VideoCapture);
     /// }
+    ///
+    /// // multi-planar devices work the same way
+    /// if let Ok(dev) = MultiPlaneDevice::new(1) {
+    ///     let stream = Stream::new(&dev, Type::VideoCaptureMplane);
+    /// }
     /// ```
-    pub fn new<const M: bool>(
-        dev: &PlanarDevice<M>, buf_type: Type
+    pub fn new<D: VideoDevice>(
+        dev: &D, buf_type: Type
     ) -> io::Result<Self> {
         Stream::with_buffers(dev, buf_type, 4)
     }
 
-    pub fn with_buffers<const M: bool>(
-        dev: &PlanarDevice<M>, buf_type: Type, buf_count: u32
+    /// Returns a stream with a given number of buffers
+    ///
+    /// The count is raised to the minimum the driver needs. The driver may grant fewer buffers
+    /// if memory is short, see [`Stream::buffer_count`].
+    ///
+    /// # Arguments
+    ///
+    /// * `dev` - Device (single- or multi-planar) to get its file descriptor
+    /// * `buf_type` - Type of the buffers
+    /// * `buf_count` - Number of buffers to request
+    pub fn with_buffers<D: VideoDevice>(
+        dev: &D, buf_type: Type, buf_count: u32
     ) -> io::Result<Self> {
-        let mut arena = Arena::new(dev.handle(), buf_type);
-        let count = arena.allocate(buf_count)?;
-        let mut buf_meta = Vec::new();
-        buf_meta.resize(count as usize, Metadata::with_memory(Memory::Mmap));
-
-        Ok(Stream {
-            handle: dev.handle(),
-            arena,
-            arena_index: 0,
-            buf_type,
-            buf_meta,
-            active: false,
-            timeout: None,
-        })
-    }
-
-    /// Returns the raw device handle
-    pub fn handle(&self) -> Arc<Handle> {
-        self.handle.clone()
-    }
-
-    /// Sets a timeout of the v4l file handle.
-    pub fn set_timeout(&mut self, duration: Duration) {
-        self.timeout = Some(duration.as_millis().try_into().unwrap());
+        Stream::with_options(dev, buf_type, buf_count, MapOptions::default())
     }
 
-    /// Clears the timeout of the v4l file handle.
-    pub fn clear_timeout(&mut self) {
-        self.timeout = None;
+    /// Returns a stream with the number of buffers suitable for a use case
+    ///
+    /// # Arguments
+    ///
+    /// * `dev` - Device (single- or multi-planar) to get its file descriptor
+    /// * `buf_type` - Type of the buffers
+    /// * `profile` - Use case, e.g. [`Profile::LowLatency`] for previews
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::buffer::Type;
+    /// use v4l::device::Device;
+    /// use v4l::io::mmap::Stream;
+    /// use v4l::io::Profile;
+    ///
+    /// if let Ok(dev) = Device::new(0) {
+    ///     if let Ok(stream) = Stream::with_profile(&dev, Type::VideoCapture, Profile::Recording) {
+    ///         println!("got {} buffers", stream.buffer_count());
+    ///     }
+    /// }
+    /// ```
+    pub fn with_profile<D: VideoDevice>(
+        dev: &D,
+        buf_type: Type,
+        profile: Profile,
+    ) -> io::Result<Self> {
+        Stream::with_buffers(dev, buf_type, profile.buffers())
     }
 
-    fn buffer_desc(&mut self, index: usize) -> v4l2_buffer {
-        let mut v4l2_buf = v4l2_buffer {
-            index: index as u32,
-            type_: self.buf_type as u32,
-            memory: Memory::Mmap as u32,
-            ..unsafe { mem::zeroed() }
-        };
-        if self.buf_type.planar() {
-            v4l2_buf.length = self.arena.planes[index].len() as u32;
-            v4l2_buf.m.planes = self.arena.planes[index].as_mut_ptr();
-        }
-        v4l2_buf
+    /// Returns a stream whose buffers are mapped with the given options
+    ///
+    /// # Arguments
+    ///
+    /// * `dev` - Device (single- or multi-planar) to get its file descriptor
+    /// * `buf_type` - Type of the buffers
+    /// * `buf_count` - Number of buffers to request
+    /// * `options` - Mapping options, e.g. to pre-fault the buffers
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::buffer::Type;
+    /// use v4l::device::Device;
+    /// use v4l::io::mmap::{MapOptions, Stream};
+    ///
+    /// let options = MapOptions {
+    ///     populate: true,
+    ///     lock: true,
+    ///     ..MapOptions::default()
+    /// };
+    /// if let Ok(dev) = Device::new(0) {
+    ///     let stream = Stream::with_options(&dev, Type::VideoCapture, 4, options);
+    /// }
+    /// ```
+    pub fn with_options<D: VideoDevice>(
+        dev: &D, buf_type: Type, buf_count: u32, options: MapOptions
+    ) -> io::Result<Self> {
+        let mut arena = Arena::new(dev.handle(), buf_type);
+        arena.options = options;
+        Stream::with_arena(dev, buf_type, arena, buf_count)
     }
-}
 
-impl<'a> Drop for Stream<'a> {
-    fn drop(&mut self) {
-        if let Err(e) = self.stop() {
-            if let Some(code) = e.raw_os_error() {
-                // ENODEV means the file descriptor wrapped in the handle became invalid, most
-                // likely because the device was unplugged or the connection (USB, PCI, ..)
-                // broke down. Handle this case gracefully by ignoring it.
-                if code == 19 {
-                    /* ignore */
-                    return;
-                }
+    /// Exports a buffer as DMA buffer, one file descriptor per memory plane
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the buffer
+    pub fn export(&self, index: usize) -> io::Result<DmaBuf> {
+        let bufs = self.arena().bufs.get(index).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "buffer index out of range")
+        })?;
+
+        let mut planes = Vec::new();
+        for (plane, buf) in bufs.iter().enumerate() {
+            let mut v4l2_expbuf = v4l2_exportbuffer {
+                type_: self.arena().buf_type as u32,
+                index: index as u32,
+                plane: plane as u32,
+                flags: libc::O_CLOEXEC as u32,
+                ..unsafe { mem::zeroed() }
+            };
+            unsafe {
+                self.handle().ioctl(
+                    v4l2::vidioc::VIDIOC_EXPBUF,
+                    &mut v4l2_expbuf as *mut _ as *mut std::os::raw::c_void,
+                )?;
             }
 
-            panic!("{:?}", e)
+            planes.push(Plane {
+                fd: unsafe { OwnedFd::from_raw_fd(v4l2_expbuf.fd) },
+                offset: 0,
+                len: buf.len() as u32,
+            });
         }
+
+        Ok(DmaBuf { planes })
     }
 }
 
-impl<'a> StreamTrait for Stream<'a> {
-    type Item = Vec<&'a mut [u8]>;
+/// Capture stream of single-planar buffers, exposing the only plane as plain byte slice
+///
+/// This makes mapped buffers interchangeable with the other single-planar I/O methods.
+pub(crate) struct SinglePlane<'a>(pub Stream<'a>);
 
-    fn start(&mut self) -> io::Result<()> {
-        unsafe {
-            let mut typ = self.buf_type as u32;
-            v4l2::ioctl(
-                self.handle.as_raw_fd(),
-                v4l2::vidioc::VIDIOC_STREAMON,
-                &mut typ as *mut _ as *mut std::os::raw::c_void,
-            )?;
-        }
+impl<'a> StreamTrait for SinglePlane<'a> {
+    type Item = [u8];
 
-        self.active = true;
-        Ok(())
+    fn start(&mut self) -> io::Result<()> {
+        self.0.start()
     }
 
     fn stop(&mut self) -> io::Result<()> {
-        unsafe {
-            let mut typ = self.buf_type as u32;
-            v4l2::ioctl(
-                self.handle.as_raw_fd(),
-                v4l2::vidioc::VIDIOC_STREAMOFF,
-                &mut typ as *mut _ as *mut std::os::raw::c_void,
-            )?;
-        }
+        self.0.stop()
+    }
 
-        self.active = false;
-        Ok(())
+    fn is_active(&self) -> bool {
+        self.0.is_active()
     }
 }
 
-impl<'a, 'b> CaptureStream<'b> for Stream<'a> {
+impl<'a, 'b> CaptureStream<'b> for SinglePlane<'a> {
     fn poll(&self) -> io::Result<bool> {
-        if self.handle.poll(libc::POLLIN, self.timeout.unwrap_or(-1))? == 0 {
-            // This condition can only happen if there was a timeout.
-            // A timeout is only possible if the `timeout` value is non-zero, meaning we should
-            // propagate it to the caller.
-            return Ok(false);
-        }
-        Ok(true)
+        CaptureStream::poll(&self.0)
     }
 
     fn queue(&mut self, index: usize) -> io::Result<()> {
-        let mut v4l2_buf = v4l2_buffer {
-            ..self.buffer_desc(index)
-        };
-
-        unsafe {
-            v4l2::ioctl(
-                self.handle.as_raw_fd(),
-                v4l2::vidioc::VIDIOC_QBUF,
-                &mut v4l2_buf as *mut _ as *mut std::os::raw::c_void,
-            )?;
-        }
+        CaptureStream::queue(&mut self.0, index)
+    }
 
-        Ok(())
+    fn queue_request(&mut self, index: usize, request: Option<&Request>) -> io::Result<()> {
+        CaptureStream::queue_request(&mut self.0, index, request)
     }
 
     fn dequeue(&mut self) -> io::Result<usize> {
-        let mut v4l2_buf = self.buffer_desc(0);
-
-        unsafe {
-            v4l2::ioctl(
-                self.handle.as_raw_fd(),
-                v4l2::vidioc::VIDIOC_DQBUF,
-                &mut v4l2_buf as *mut _ as *mut std::os::raw::c_void,
-            )?;
-        }
-        self.arena_index = v4l2_buf.index as usize;
-        self.buf_meta[self.arena_index] = Metadata::from(v4l2_buf);
-
-        Ok(self.arena_index)
+        CaptureStream::dequeue(&mut self.0)
     }
 
     fn get(&self, index: usize) -> io::Result<(&Self::Item, &Metadata, &[v4l2_plane])> {
-        Ok((&self.arena.bufs[index], &self.buf_meta[index], &self.arena.planes[index]))
+        let (bufs, meta, planes) = CaptureStream::get(&self.0, index)?;
+        Ok((bufs[0], meta, planes))
     }
 
-    fn next(&'b mut self) -> io::Result<(&Self::Item, &Metadata, &[v4l2_plane])> {
-        if !self.active {
-            // Enqueue all buffers once on stream start
-            for index in 0..self.arena.bufs.len() {
-                CaptureStream::queue(self, index)?;
-            }
-
-            self.start()?;
-        } else {
-            CaptureStream::queue(self, self.arena_index)?;
-        }
-
-        if !OutputStream::poll(self)? {
-            return Err(io::Error::new(io::ErrorKind::TimedOut, "VIDIOC_QBUF"));
-        }
-
-        let index = CaptureStream::dequeue(self)?;
-        CaptureStream::get(self, index)
+    fn next(&'b mut self) -> io::Result<(&'b Self::Item, &'b Metadata, &'b [v4l2_plane])> {
+        let (bufs, meta, planes) = CaptureStream::next(&mut self.0)?;
+        Ok((bufs[0], meta, planes))
     }
 }
 
-impl<'a, 'b> OutputStream<'b> for Stream<'a> {
-    fn poll(&self) -> io::Result<bool> {
-        if self
-            .handle
-            .poll(libc::POLLOUT, self.timeout.unwrap_or(-1))?
-            == 0
-        {
-            // This condition can only happen if there was a timeout.
-            // A timeout is only possible if the `timeout` value is non-zero, meaning we should
-            // propagate it to the caller.
-            return Ok(false);
-        }
-        Ok(true)
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+    use crate::backend::Mock;
+    use crate::device::Device;
+    use crate::io::Inactive;
+    use crate::memory::Memory;
+    use crate::timestamp::Timestamp;
+    use crate::video::Capture;
+
+    fn mock() -> (Mock, Device) {
+        let mock = Mock::new();
+        mock.set_generator(|buf, _, sequence| buf.fill(sequence as u8));
+        let dev = mock.device().unwrap();
+        (mock, dev)
     }
 
-    fn queue(&mut self, index: usize) -> io::Result<()> {
-        let mut v4l2_buf = v4l2_buffer {
-            ..self.buffer_desc(index)
-        };
-        unsafe {
-            // output settings
-            //
-            // MetaData.bytesused is initialized to 0. For an output device, when bytesused is
-            // set to 0 v4l2 will set it to the size of the plane:
-            // https://www.kernel.org/doc/html/v4.15/media/uapi/v4l/buffer.html#struct-v4l2-plane
-            v4l2_buf.bytesused = self.buf_meta[index].bytesused;
-            v4l2_buf.field = self.buf_meta[index].field;
-
-            v4l2::ioctl(
-                self.handle.as_raw_fd(),
-                v4l2::vidioc::VIDIOC_QBUF,
-                &mut v4l2_buf as *mut _ as *mut std::os::raw::c_void,
-            )
+    #[test]
+    fn captures_generated_frames() {
+        let (mock, dev) = mock();
+        let size = dev.format().unwrap().size;
+        let mut stream = Stream::with_buffers(&dev, Type::VideoCapture, 4).unwrap();
+        assert_eq!(stream.buffer_count(), 4);
+
+        // more frames than buffers, so each buffer is queued again
+        for frame in 0..10u32 {
+            let (planes, meta, _) = stream.next().unwrap();
+            assert_eq!(planes.len(), 1);
+            assert_eq!(planes[0].len(), size as usize);
+            assert!(planes[0].iter().all(|&byte| byte == frame as u8));
+            assert_eq!(meta.sequence, frame);
+            assert_eq!(meta.bytesused, size);
+            assert_eq!(meta.memory, Memory::Mmap);
+            assert_eq!(
+                meta.timestamp,
+                Timestamp::new(0, i64::from(frame) * 1_000_000 / 30)
+            );
         }
+        assert_eq!(mock.frame_count(), 10);
     }
 
-    fn dequeue(&mut self) -> io::Result<usize> {
-        let mut v4l2_buf = self.buffer_desc(0);
-
-        unsafe {
-            v4l2::ioctl(
-                self.handle.as_raw_fd(),
-                v4l2::vidioc::VIDIOC_DQBUF,
-                &mut v4l2_buf as *mut _ as *mut std::os::raw::c_void,
-            )?;
+    #[test]
+    fn stops_when_dropped() {
+        for stop_timeout in [None, Some(crate::io::STOP_TIMEOUT)] {
+            let (mock, dev) = mock();
+            let mut stream = Stream::new(&dev, Type::VideoCapture).unwrap();
+            stream.set_stop_timeout(stop_timeout);
+            stream.next().unwrap();
+            assert!(mock.is_streaming());
+
+            drop(stream);
+            assert!(!mock.is_streaming());
         }
-        self.arena_index = v4l2_buf.index as usize;
-        self.buf_meta[self.arena_index] = Metadata::from(v4l2_buf);
-
-        Ok(self.arena_index)
     }
 
-    fn get(&mut self, index: usize) -> io::Result<(&mut Self::Item, &mut Metadata, &mut [v4l2_plane])> {
-        Ok((&mut self.arena.bufs[index], &mut self.buf_meta[index], &mut self.arena.planes[index]))
+    #[test]
+    fn queues_buffers_of_active_streams_only() {
+        let (_, dev) = mock();
+        let mut stream = Stream::new(&dev, Type::VideoCapture).unwrap();
+        let err = CaptureStream::queue(&mut stream, 0).unwrap_err();
+        assert!(err.get_ref().is_some_and(|e| e.is::<Inactive>()));
+
+        stream.prime().unwrap();
+        let index = CaptureStream::dequeue(&mut stream).unwrap();
+        assert!(!stream.query_buf(index).unwrap().is_queued());
+        CaptureStream::queue(&mut stream, index).unwrap();
+        assert!(stream.query_buf(index).unwrap().is_queued());
+
+        // the buffer was queued manually, next() must not queue it a second time
+        let (_, meta, _) = stream.next().unwrap();
+        assert_eq!(meta.sequence, 1);
+        let (_, meta, _) = stream.next().unwrap();
+        assert_eq!(meta.sequence, 2);
     }
 
-    fn next(&'b mut self) -> io::Result<(&mut Self::Item, &mut Metadata)> {
-        let init = !self.active;
-        if !self.active {
-            self.start()?;
-        }
+    #[test]
+    fn reports_dequeue_errors() {
+        let (mock, dev) = mock();
+        let mut stream = Stream::new(&dev, Type::VideoCapture).unwrap();
+        stream.prime().unwrap();
 
-        // Only queue and dequeue once the buffer has been filled at the call site. The initial
-        // call to this function from the call site will happen just after the buffers have been
-        // allocated, meaning we need to return the empty buffer initially so it can be filled.
-        if !init {
-            OutputStream::queue(self, self.arena_index)?;
-            if !OutputStream::poll(self)? {
-                return Err(io::Error::new(io::ErrorKind::TimedOut, "VIDIOC_QBUF"));
-            }
-            self.arena_index = OutputStream::dequeue(self)?;
-        }
+        mock.fail_next(v4l2::vidioc::VIDIOC_DQBUF, libc::EIO);
+        let err = stream.next().err().unwrap();
+        assert_eq!(err.raw_os_error(), Some(libc::EIO));
 
-        // The index used to access the buffer elements is given to us by v4l2, so we assume it
-        // will always be valid.
-        let bytes = &mut self.arena.bufs[self.arena_index];
-        let meta = &mut self.buf_meta[self.arena_index];
-        Ok((bytes, meta))
+        let (_, meta, _) = stream.next().unwrap();
+        assert_eq!(meta.sequence, 0);
+    }
+
+    #[test]
+    fn continues_from_parts_on_another_thread() {
+        let (mock, dev) = mock();
+        let mut stream = Stream::new(&dev, Type::VideoCapture).unwrap();
+        let (_, meta, _) = stream.next().unwrap();
+        assert_eq!(meta.sequence, 0);
+
+        let parts = stream.into_parts();
+        assert!(parts.is_active());
+        assert_eq!(parts.buffer_count(), 4);
+        assert!(mock.is_streaming());
+
+        let sequences = thread::spawn(move || {
+            let mut stream = Stream::from_parts(parts);
+            (0..5)
+                .map(|_| stream.next().map(|(_, meta, _)| meta.sequence))
+                .collect::<io::Result<Vec<_>>>()
+        })
+        .join()
+        .unwrap()
+        .unwrap();
+        assert_eq!(sequences, [1, 2, 3, 4, 5]);
+        assert!(!mock.is_streaming());
     }
 }
@@ -1,25 +1,332 @@
 use std::{
-    io,
+    error, fmt, hint, io,
     marker::PhantomData,
     mem,
     ops::{Index, IndexMut},
     ptr, slice,
-    sync::Arc,
+    sync::{mpsc, Arc},
+    thread,
+    time::{Duration, Instant},
 };
-use std::os::fd::AsRawFd;
 
 use crate::{
     buffer::{Metadata, Type},
-    device::Handle,
+    control::Value,
+    device::{Handle, PollFlags},
     memory::{Memory, Mmap, UserPtr},
+    reconnect::is_disconnected,
     v4l2,
     v4l_sys::*,
 };
 
 pub mod traits;
 
+pub mod annotation;
+pub mod arena;
+pub mod dmabuf;
+#[cfg(feature = "dmaheap")]
+pub mod heap;
+pub mod m2m;
 pub mod mmap;
+pub mod pacing;
+pub mod read;
 pub mod userptr;
+pub mod watchdog;
+
+/// Behavior of stream operations when the driver has no buffer ready (EAGAIN)
+///
+/// Device handles are opened in non-blocking mode, so dequeueing a buffer before the driver
+/// is done with it fails with [`io::ErrorKind::WouldBlock`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum WouldBlock {
+    /// Report [`io::ErrorKind::WouldBlock`] to the caller
+    #[default]
+    Return,
+    /// Poll the device (honoring the stream timeout) and retry the operation
+    Poll,
+    /// Retry the operation in a busy loop for up to the given duration, then poll like
+    /// [`WouldBlock::Poll`]
+    ///
+    /// Spinning trades CPU time for latency: the buffer is picked up as soon as the driver is
+    /// done with it instead of waiting for the scheduler to wake up the thread.
+    Spin(Duration),
+}
+
+impl WouldBlock {
+    /// Returns whether streams poll the device before dequeueing a buffer
+    ///
+    /// When spinning, waiting is left to the dequeue operation, which retries until the budget is
+    /// used up.
+    pub(crate) fn polls_before_dequeue(&self) -> bool {
+        !matches!(self, WouldBlock::Spin(_))
+    }
+
+    /// Waits until an operation which would have blocked may be retried
+    ///
+    /// Returns `false` if the policy is to report [`io::ErrorKind::WouldBlock`] instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - Device handle to poll
+    /// * `events` - Events to wait for (e.g. `PollFlags::IN`)
+    /// * `timeout` - Poll timeout in milliseconds, negative values block indefinitely
+    /// * `since` - Time of the first attempt, bounds the spinning
+    pub(crate) fn wait(
+        &self,
+        handle: &Handle,
+        events: PollFlags,
+        timeout: i32,
+        since: Instant,
+    ) -> io::Result<bool> {
+        match *self {
+            WouldBlock::Return => Ok(false),
+            WouldBlock::Spin(budget) if since.elapsed() < budget => {
+                hint::spin_loop();
+                Ok(true)
+            }
+            WouldBlock::Poll | WouldBlock::Spin(_) => {
+                if !handle.wait_ready(events, timeout)? {
+                    return Err(io::Error::new(io::ErrorKind::TimedOut, "VIDIOC_DQBUF"));
+                }
+                Ok(true)
+            }
+        }
+    }
+}
+
+/// Number of buffers suitable for common use cases
+///
+/// Fewer buffers mean less latency between capturing and handing out a frame, more buffers
+/// give the application more time to process frames before the driver runs out of buffers and
+/// starts dropping frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    /// Two buffers, e.g. for previews and computer vision which only need the latest frame
+    LowLatency,
+    /// Six buffers, e.g. for displaying video which must not stutter
+    Smooth,
+    /// Eight buffers, e.g. for encoding or writing frames to disk with varying delays
+    Recording,
+}
+
+impl Profile {
+    /// Returns the number of buffers to request
+    pub fn buffers(self) -> u32 {
+        match self {
+            Profile::LowLatency => 2,
+            Profile::Smooth => 6,
+            Profile::Recording => 8,
+        }
+    }
+}
+
+/// Returns the number of buffers to request, raised to the minimum the driver needs
+///
+/// Drivers announce their minimum through `V4L2_CID_MIN_BUFFERS_FOR_CAPTURE` (or its output
+/// counterpart), e.g. decoders which have to hold reference frames. Drivers without the control
+/// are assumed to cope with any count.
+///
+/// # Arguments
+///
+/// * `handle` - Device handle
+/// * `buf_type` - Type of the buffers
+/// * `count` - Number of buffers the application asked for
+pub(crate) fn request_count(handle: &Handle, buf_type: Type, count: u32) -> u32 {
+    let output = matches!(
+        buf_type,
+        Type::VideoOutput
+            | Type::VideoOutputMplane
+            | Type::VbiOutput
+            | Type::SlicedVbiOutput
+            | Type::VideoOutputOverlay
+            | Type::SdrOutput
+            | Type::MetaOutput
+    );
+    let id = if output {
+        V4L2_CID_MIN_BUFFERS_FOR_OUTPUT
+    } else {
+        V4L2_CID_MIN_BUFFERS_FOR_CAPTURE
+    };
+
+    match handle.control(id).map(|ctrl| ctrl.value) {
+        Ok(Value::Integer(min)) if min > count as i64 => {
+            debug!(requested = count, min, "raising buffer count to the driver minimum");
+            min as u32
+        }
+        _ => count,
+    }
+}
+
+/// Checks the number of buffers granted by the driver
+///
+/// Drivers may grant fewer buffers than requested if memory is short, which is logged. Not
+/// getting any buffer at all is an error.
+///
+/// # Arguments
+///
+/// * `requested` - Number of buffers requested
+/// * `granted` - Number of buffers granted by `VIDIOC_REQBUFS`
+pub(crate) fn check_granted(requested: u32, granted: u32) -> io::Result<()> {
+    if granted == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::OutOfMemory,
+            "driver did not grant any buffers",
+        ));
+    }
+    if granted < requested {
+        debug!(requested, granted, "driver granted fewer buffers than requested");
+    }
+    Ok(())
+}
+
+/// Time streams wait for the driver to stop streaming by default
+///
+/// Stopping waits on a helper thread, so applications do not hang on wedged drivers. Streams
+/// can stop on the calling thread without a bound instead, see e.g.
+/// [`mmap::Stream::set_stop_timeout`].
+pub const STOP_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Stops streaming, waiting for the driver at most `timeout`
+///
+/// `VIDIOC_STREAMOFF` waits for the driver to stop the hardware and to hand back all buffers,
+/// which never completes on a wedged driver. With a timeout, the ioctl is issued on a helper
+/// thread which is left behind if it does not return in time, and the call fails with
+/// [`io::ErrorKind::TimedOut`]. The queue must not be touched afterwards: the driver holds its
+/// lock, so any further ioctl on it (e.g. releasing the buffers) would block as well.
+///
+/// # Arguments
+///
+/// * `handle` - Device handle
+/// * `buf_type` - Type of the buffers
+/// * `timeout` - Maximum time to wait, `None` issues the ioctl on the calling thread
+pub(crate) fn stream_off(
+    handle: &Arc<Handle>,
+    buf_type: Type,
+    timeout: Option<Duration>,
+) -> io::Result<()> {
+    let off = move |handle: &Handle| unsafe {
+        let mut typ = buf_type as u32;
+        handle.ioctl(
+            v4l2::vidioc::VIDIOC_STREAMOFF,
+            &mut typ as *mut _ as *mut std::os::raw::c_void,
+        )
+    };
+
+    let timeout = match timeout {
+        Some(timeout) => timeout,
+        None => return off(handle),
+    };
+
+    let (tx, rx) = mpsc::channel();
+    let handle = handle.clone();
+    thread::Builder::new()
+        .name("v4l-streamoff".into())
+        .spawn(move || {
+            // the receiver is gone if the caller gave up already
+            let _ = tx.send(off(&handle));
+        })?;
+
+    match rx.recv_timeout(timeout) {
+        Ok(res) => res,
+        Err(_) => {
+            debug!(?buf_type, ?timeout, "driver did not stop streaming, giving up");
+            Err(io::Error::new(io::ErrorKind::TimedOut, "VIDIOC_STREAMOFF"))
+        }
+    }
+}
+
+/// Error payload of buffer operations on a stream which is not active
+///
+/// Streams are started explicitly through [`traits::Stream::start`] or implicitly by the first
+/// call to `next()`, which queues all buffers for capture streams. The errors are of kind
+/// [`io::ErrorKind::Other`] and can be recognized with `e.get_ref()` and `is::<Inactive>()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Inactive;
+
+impl fmt::Display for Inactive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "stream is not active")
+    }
+}
+
+impl error::Error for Inactive {}
+
+impl From<Inactive> for io::Error {
+    fn from(inactive: Inactive) -> Self {
+        io::Error::other(inactive)
+    }
+}
+
+/// Error payload of operations on a stream which was given up
+///
+/// Streams are given up if the driver does not stop streaming within their stop timeout (see
+/// e.g. [`mmap::Stream::set_stop_timeout`]). The driver keeps the queue locked, so the stream
+/// cannot be started or used anymore and has to be dropped. The errors are of kind
+/// [`io::ErrorKind::Other`] and can be recognized with `e.get_ref()` and `is::<Abandoned>()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Abandoned;
+
+impl fmt::Display for Abandoned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "stream was abandoned after stopping it timed out")
+    }
+}
+
+impl error::Error for Abandoned {}
+
+impl From<Abandoned> for io::Error {
+    fn from(abandoned: Abandoned) -> Self {
+        io::Error::other(abandoned)
+    }
+}
+
+/// Behavior of streams when stopping them fails in their Drop impl
+///
+/// Panicking in Drop while the thread is already unwinding aborts the whole process, so errors
+/// are logged and ignored by default. Errors caused by the device going away are always
+/// ignored, there is nothing left to stop. Buffer arenas failing to release their buffers
+/// afterwards log and ignore the error as well.
+#[derive(Clone, Default)]
+pub enum DropPolicy {
+    /// Log the error (with the `tracing` feature) and carry on
+    #[default]
+    Ignore,
+    /// Panic, unless the thread is unwinding already
+    Panic,
+    /// Hand the error to a callback, e.g. to count it or to schedule a device reset
+    Callback(Arc<dyn Fn(&io::Error) + Send + Sync>),
+}
+
+impl fmt::Debug for DropPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DropPolicy::Ignore => write!(f, "Ignore"),
+            DropPolicy::Panic => write!(f, "Panic"),
+            DropPolicy::Callback(_) => write!(f, "Callback(..)"),
+        }
+    }
+}
+
+impl DropPolicy {
+    /// Deals with an error which occurred while dropping a stream or arena
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Error returned by the teardown operation
+    pub(crate) fn handle(&self, e: io::Error) {
+        if is_disconnected(&e) {
+            return;
+        }
+
+        match self {
+            DropPolicy::Panic if !thread::panicking() => panic!("{:?}", e),
+            DropPolicy::Callback(callback) => callback(&e),
+            _ => {
+                debug!(error = %e, "ignoring error on drop");
+            }
+        }
+    }
+}
 
 /// Manage mapped buffers
 ///
@@ -48,6 +355,11 @@ impl<B, S> Queue<B, S> {
         self.bufs.len()
     }
 
+    /// Returns whether the queue holds no elements
+    pub fn is_empty(&self) -> bool {
+        self.bufs.is_empty()
+    }
+
     /// Request a number of buffers be allocated in the drivers' queue
     ///
     /// Returns the number of actual buffers. Some drivers will require a certain minimum number
@@ -65,12 +377,18 @@ impl<B, S> Queue<B, S> {
         };
 
         unsafe {
-            v4l2::ioctl(
-                self.handle.as_raw_fd(),
+            self.handle.ioctl(
                 v4l2::vidioc::VIDIOC_REQBUFS,
                 &mut v4l2_reqbufs as *mut _ as *mut std::os::raw::c_void,
             )?;
         }
+        debug!(
+            handle = ?self.handle,
+            memory = %memory,
+            requested = count,
+            granted = v4l2_reqbufs.count,
+            "allocated buffers"
+        );
 
         Ok(v4l2_reqbufs.count)
     }
@@ -88,8 +406,7 @@ impl<B, S> Queue<B, S> {
         };
 
         unsafe {
-            v4l2::ioctl(
-                self.handle.as_raw_fd(),
+            self.handle.ioctl(
                 v4l2::vidioc::VIDIOC_QUERYBUF,
                 &mut buf as *mut _ as *mut std::os::raw::c_void,
             )?;
@@ -101,8 +418,7 @@ impl<B, S> Queue<B, S> {
     /// Start the stream
     fn streamon(&mut self) -> io::Result<()> {
         unsafe {
-            v4l2::ioctl(
-                self.handle.as_raw_fd(),
+            self.handle.ioctl(
                 v4l2::vidioc::VIDIOC_STREAMON,
                 &mut self.buf_type as *mut _ as *mut std::os::raw::c_void,
             )
@@ -112,8 +428,7 @@ impl<B, S> Queue<B, S> {
     /// Stop the stream
     fn streamoff(&mut self) -> io::Result<()> {
         unsafe {
-            v4l2::ioctl(
-                self.handle.as_raw_fd(),
+            self.handle.ioctl(
                 v4l2::vidioc::VIDIOC_STREAMOFF,
                 &mut self.buf_type as *mut _ as *mut std::os::raw::c_void,
             )
@@ -125,8 +440,7 @@ impl<B, S> Queue<B, S> {
         buf.type_ = self.buf_type as u32;
 
         unsafe {
-            v4l2::ioctl(
-                self.handle.as_raw_fd(),
+            self.handle.ioctl(
                 v4l2::vidioc::VIDIOC_QBUF,
                 buf as *mut _ as *mut std::os::raw::c_void,
             )
@@ -138,8 +452,7 @@ impl<B, S> Queue<B, S> {
         buf.type_ = self.buf_type as u32;
 
         unsafe {
-            v4l2::ioctl(
-                self.handle.as_raw_fd(),
+            self.handle.ioctl(
                 v4l2::vidioc::VIDIOC_DQBUF,
                 buf as *mut _ as *mut std::os::raw::c_void,
             )
@@ -165,6 +478,7 @@ impl<B> Queue<B, queue::Idle> {
     /// Start the stream
     pub fn start_stream(mut self) -> io::Result<Queue<B, queue::Streaming>> {
         self.streamon()?;
+        debug!(handle = ?self.handle, buf_type = ?self.buf_type, "stream on");
 
         Ok(Queue {
             handle: self.handle,
@@ -179,6 +493,7 @@ impl<B> Queue<B, queue::Streaming> {
     /// Stop the stream
     pub fn stop_stream(mut self) -> io::Result<Queue<B, queue::Idle>> {
         self.streamoff()?;
+        debug!(handle = ?self.handle, buf_type = ?self.buf_type, "stream off");
 
         Ok(Queue {
             handle: self.handle,
@@ -244,18 +559,16 @@ impl Queue<Mmap<'_>, queue::Idle> {
             };
 
             let mapping = unsafe {
-                v4l2::ioctl(
-                    queue.handle.as_raw_fd(),
+                queue.handle.ioctl(
                     v4l2::vidioc::VIDIOC_QUERYBUF,
                     &mut v4l2_buf as *mut _ as *mut std::os::raw::c_void,
                 )?;
 
-                let ptr = v4l2::mmap(
+                let ptr = queue.handle.mmap(
                     ptr::null_mut(),
                     v4l2_buf.length as usize,
                     libc::PROT_READ | libc::PROT_WRITE,
                     libc::MAP_SHARED,
-                    queue.handle.as_raw_fd(),
                     v4l2_buf.m.offset as libc::off_t,
                 )?;
 
@@ -322,8 +635,7 @@ impl Queue<UserPtr, queue::Idle> {
             ..unsafe { mem::zeroed() }
         };
         unsafe {
-            v4l2::ioctl(
-                queue.handle.as_raw_fd(),
+            queue.handle.ioctl(
                 v4l2::vidioc::VIDIOC_G_FMT,
                 &mut v4l2_fmt as *mut _ as *mut std::os::raw::c_void,
             )?;
@@ -0,0 +1,231 @@
+use std::{
+    io, mem,
+    os::fd::{AsRawFd, FromRawFd, OwnedFd},
+};
+
+use crate::buffer::Metadata;
+use crate::fraction::Fraction;
+use crate::io::traits::OutputStream;
+use crate::video::output::Parameters;
+
+const NSEC_PER_SEC: u128 = 1_000_000_000;
+
+/// Output stream wrapper which hands out buffers at a steady frame rate
+///
+/// Output devices such as virtual cameras (e.g. v4l2loopback) or displays consume frames as
+/// fast as they are queued. This wrapper delays [`Paced::next`] until the slot of the next frame
+/// has come, using a timerfd on the monotonic clock. Frame deadlines are derived from the time
+/// the first frame was requested, so the frame rate does not drift over time. If the producer
+/// falls behind by more than one frame interval, the schedule is restarted instead of emitting
+/// a burst of frames to catch up.
+///
+/// # Example
+///
+/// ```
+/// use v4l::buffer::Type;
+/// use v4l::io::pacing::Paced;
+/// use v4l::prelude::*;
+/// use v4l::video::Output;
+///
+/// if let Ok(dev) = Device::new(0) {
+///     let params = dev.params().unwrap();
+///     let stream = MmapStream::new(&dev, Type::VideoOutput).unwrap();
+///     let mut stream = Paced::with_params(stream, &params).unwrap();
+///
+///     loop {
+///         let (buf, meta) = stream.next().unwrap();
+///         // ... fill the buffer
+///         meta.bytesused = buf[0].len() as u32;
+///     }
+/// }
+/// ```
+pub struct Paced<S> {
+    stream: S,
+    timer: OwnedFd,
+    interval: Fraction,
+    /// Start of the current schedule in nanoseconds
+    base: Option<u128>,
+    /// Number of frames scheduled since the start
+    frame: u128,
+    /// Number of times the schedule had to be restarted
+    late: u64,
+}
+
+impl<S> Paced<S> {
+    /// Returns a stream which emits frames at the given interval
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - Output stream
+    /// * `interval` - Frame interval in seconds
+    pub fn new(stream: S, interval: Fraction) -> io::Result<Self> {
+        if interval.numerator == 0 || interval.denominator == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "frame interval must not be zero",
+            ));
+        }
+
+        let fd = unsafe { libc::timerfd_create(libc::CLOCK_MONOTONIC, libc::TFD_CLOEXEC) };
+        if fd == -1 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(Paced {
+            stream,
+            timer: unsafe { OwnedFd::from_raw_fd(fd) },
+            interval,
+            base: None,
+            frame: 0,
+            late: 0,
+        })
+    }
+
+    /// Returns a stream which emits frames at the interval of the output parameters
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - Output stream
+    /// * `params` - Output parameters of the device
+    pub fn with_params(stream: S, params: &Parameters) -> io::Result<Self> {
+        Self::new(stream, params.interval)
+    }
+
+    /// Returns the frame interval
+    pub fn interval(&self) -> Fraction {
+        self.interval
+    }
+
+    /// Changes the frame interval, restarting the schedule
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - Frame interval in seconds
+    pub fn set_interval(&mut self, interval: Fraction) -> io::Result<()> {
+        if interval.numerator == 0 || interval.denominator == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "frame interval must not be zero",
+            ));
+        }
+
+        self.interval = interval;
+        self.base = None;
+        Ok(())
+    }
+
+    /// Returns how often the producer fell behind by more than one frame interval
+    pub fn late(&self) -> u64 {
+        self.late
+    }
+
+    /// Returns a reference to the wrapped stream
+    pub fn get_ref(&self) -> &S {
+        &self.stream
+    }
+
+    /// Returns a mutable reference to the wrapped stream
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.stream
+    }
+
+    /// Returns the wrapped stream
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+
+    /// Blocks until the slot of the next frame has come
+    pub fn wait(&mut self) -> io::Result<()> {
+        let now = monotonic()?;
+        let base = *self.base.get_or_insert(now);
+        let period = self.offset(1);
+        let mut deadline = base + self.offset(self.frame);
+
+        if now > deadline + period {
+            self.late += 1;
+            self.base = Some(now);
+            self.frame = 0;
+            deadline = now;
+        }
+        self.frame += 1;
+
+        if deadline > now {
+            self.sleep_until(deadline)?;
+        }
+        Ok(())
+    }
+
+    /// Waits for the next frame slot, then fetches the next buffer to fill
+    ///
+    /// The buffer filled after the previous call is queued once its slot has come.
+    pub fn next<'b>(&'b mut self) -> io::Result<(&'b mut S::Item, &'b mut Metadata)>
+    where
+        S: OutputStream<'b>,
+    {
+        self.wait()?;
+        self.stream.next()
+    }
+
+    /// Returns the time offset of a frame relative to the start of the schedule in nanoseconds
+    fn offset(&self, frame: u128) -> u128 {
+        frame * u128::from(self.interval.numerator) * NSEC_PER_SEC
+            / u128::from(self.interval.denominator)
+    }
+
+    fn sleep_until(&self, deadline: u128) -> io::Result<()> {
+        let spec = libc::itimerspec {
+            it_interval: libc::timespec {
+                tv_sec: 0,
+                tv_nsec: 0,
+            },
+            it_value: libc::timespec {
+                tv_sec: (deadline / NSEC_PER_SEC) as libc::time_t,
+                tv_nsec: (deadline % NSEC_PER_SEC) as libc::c_long,
+            },
+        };
+        let ret = unsafe {
+            libc::timerfd_settime(
+                self.timer.as_raw_fd(),
+                libc::TFD_TIMER_ABSTIME,
+                &spec,
+                std::ptr::null_mut(),
+            )
+        };
+        if ret == -1 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // reading blocks until the timer expired
+        let mut expirations = 0u64;
+        loop {
+            let ret = unsafe {
+                libc::read(
+                    self.timer.as_raw_fd(),
+                    &mut expirations as *mut _ as *mut libc::c_void,
+                    mem::size_of::<u64>(),
+                )
+            };
+            if ret != -1 {
+                return Ok(());
+            }
+
+            let err = io::Error::last_os_error();
+            if err.kind() != io::ErrorKind::Interrupted {
+                return Err(err);
+            }
+        }
+    }
+}
+
+/// Returns the current time of the monotonic clock in nanoseconds
+fn monotonic() -> io::Result<u128> {
+    let mut ts = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    if unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC, &mut ts) } == -1 {
+        return Err(io::Error::last_os_error());
+    }
+
+    Ok(ts.tv_sec as u128 * NSEC_PER_SEC + ts.tv_nsec as u128)
+}
@@ -0,0 +1,163 @@
+use std::convert::TryInto;
+use std::time::Duration;
+use std::{io, mem, os::fd::AsRawFd, sync::Arc};
+
+use crate::buffer::{Metadata, Type};
+use crate::device::{Handle, PollFlags, VideoDevice};
+use crate::io::traits::{CaptureStream, Stream as StreamTrait};
+use crate::memory::Memory;
+use crate::timestamp::Timestamp;
+use crate::v4l2;
+use crate::v4l_sys::*;
+
+/// Stream of frames read through the read() system call
+///
+/// Some drivers (e.g. for older TV cards or simple sensors) only implement read/write I/O. Each
+/// frame is copied into a single buffer owned by the stream. The driver does not provide
+/// timestamps in this mode, frames are stamped with the monotonic clock once they were read.
+pub struct Stream {
+    handle: Arc<Handle>,
+    buf: Vec<u8>,
+    buf_meta: Metadata,
+    sequence: u32,
+    timeout: Option<i32>,
+}
+
+impl Stream {
+    /// Returns a stream for frame capturing
+    ///
+    /// The buffer is sized according to the current format of the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `dev` - Device (single- or multi-planar) to get its file descriptor
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::device::Device;
+    /// use v4l::io::read::Stream;
+    ///
+    /// let dev = Device::new(0);
+    /// if let Ok(dev) = dev {
+    ///     let stream = Stream::new(&dev);
+    /// }
+    /// ```
+    pub fn new<D: VideoDevice>(dev: &D) -> io::Result<Self> {
+        let handle = dev.handle();
+
+        let mut v4l2_fmt = v4l2_format {
+            type_: Type::VideoCapture as u32,
+            ..unsafe { mem::zeroed() }
+        };
+        unsafe {
+            handle.ioctl(
+                v4l2::vidioc::VIDIOC_G_FMT,
+                &mut v4l2_fmt as *mut _ as *mut std::os::raw::c_void,
+            )?;
+        }
+        let buf_len = unsafe { v4l2_fmt.fmt.pix.sizeimage };
+
+        let mut buf_meta = Metadata::with_memory(Memory::UserPtr);
+        buf_meta.type_ = Type::VideoCapture as u32;
+        buf_meta.length = buf_len;
+
+        Ok(Stream {
+            handle,
+            buf: vec![0u8; buf_len as usize],
+            buf_meta,
+            sequence: 0,
+            timeout: None,
+        })
+    }
+
+    /// Returns the raw device handle
+    pub fn handle(&self) -> Arc<Handle> {
+        self.handle.clone()
+    }
+
+    /// Sets a timeout of the v4l file handle.
+    pub fn set_timeout(&mut self, duration: Duration) {
+        self.timeout = Some(duration.as_millis().try_into().unwrap());
+    }
+
+    /// Clears the timeout of the v4l file handle.
+    pub fn clear_timeout(&mut self) {
+        self.timeout = None;
+    }
+}
+
+impl StreamTrait for Stream {
+    type Item = [u8];
+
+    /// The driver starts capturing on the first read, there is nothing to do
+    fn start(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// The driver stops capturing once the file handle is closed, there is nothing to do
+    fn stop(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Frames can be read at any time, so the stream is always active
+    fn is_active(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> CaptureStream<'a> for Stream {
+    fn poll(&self) -> io::Result<bool> {
+        self.handle
+            .wait_ready(PollFlags::IN, self.timeout.unwrap_or(-1))
+    }
+
+    /// There is only one buffer, which is filled by [`CaptureStream::dequeue`]
+    fn queue(&mut self, _index: usize) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn dequeue(&mut self) -> io::Result<usize> {
+        let ret = unsafe {
+            libc::read(
+                self.handle.as_raw_fd(),
+                self.buf.as_mut_ptr() as *mut std::os::raw::c_void,
+                self.buf.len(),
+            )
+        };
+        if ret == -1 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let mut ts: libc::timespec = unsafe { mem::zeroed() };
+        unsafe {
+            libc::clock_gettime(libc::CLOCK_MONOTONIC, &mut ts);
+        }
+        self.buf_meta.bytesused = ret as u32;
+        self.buf_meta.timestamp =
+            Timestamp::from(Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32));
+        self.buf_meta.sequence = self.sequence;
+        self.sequence = self.sequence.wrapping_add(1);
+
+        Ok(0)
+    }
+
+    fn get(&self, index: usize) -> io::Result<(&Self::Item, &Metadata, &[v4l2_plane])> {
+        if index != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "buffer index out of range",
+            ));
+        }
+        Ok((&self.buf, &self.buf_meta, &[]))
+    }
+
+    fn next(&'a mut self) -> io::Result<(&'a Self::Item, &'a Metadata, &'a [v4l2_plane])> {
+        if !CaptureStream::poll(self)? {
+            return Err(io::Error::new(io::ErrorKind::TimedOut, "read"));
+        }
+
+        let index = CaptureStream::dequeue(self)?;
+        CaptureStream::get(self, index)
+    }
+}
@@ -1,25 +1,113 @@
 use std::io;
 
 use crate::buffer::Metadata;
+use crate::media::Request;
+use crate::memory::Memory;
 use crate::v4l_sys::*;
 
+/// Memory backing the buffers of a stream
+///
+/// An arena requests buffers from the driver and provides the memory for them. Implementing
+/// this trait for a new kind of memory (e.g. DMA buffers from a DMA heap or udmabuf) makes it
+/// usable with [`crate::io::arena::Stream`], the streaming logic does not depend on the memory.
+///
+/// Arenas release their buffers when they are dropped.
+pub trait Arena {
+    /// Buffer as handed out by streams, e.g. a byte slice
+    type Buffer: ?Sized;
+
+    /// Returns the memory type, as passed to `VIDIOC_REQBUFS`
+    fn memory(&self) -> Memory;
+
+    /// Requests buffers from the driver and provides the memory for them
+    ///
+    /// Returns the number of buffers granted by the driver, which may differ from the request.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of buffers to request
+    fn allocate(&mut self, count: u32) -> io::Result<u32>;
+
+    /// Frees all buffers
+    fn release(&mut self) -> io::Result<()>;
+
+    /// Returns the number of buffers
+    fn len(&self) -> usize;
+
+    /// Returns whether there are no buffers
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the size of the buffer at an index in bytes, summed up over all planes
+    fn buffer_len(&self, index: usize) -> usize;
+
+    /// Returns the buffer at an index, panics if the index is out of range
+    fn buffer_at(&self, index: usize) -> &Self::Buffer;
+
+    /// Returns the buffer at an index for writing, panics if the index is out of range
+    fn buffer_at_mut(&mut self, index: usize) -> &mut Self::Buffer;
+
+    /// Returns the planes of a multi-planar buffer as reported by the driver
+    ///
+    /// Single-planar arenas have no planes.
+    fn planes(&self, _index: usize) -> &[v4l2_plane] {
+        &[]
+    }
+
+    /// Fills in the memory specific fields of a buffer which is about to be queued
+    ///
+    /// USERPTR and DMABUF buffers are passed to the driver this way, e.g. as `m.userptr` and
+    /// `length` or as `m.userptr` of each plane for multi-planar buffers.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the buffer
+    /// * `buf` - Buffer to be queued
+    /// * `planes` - Planes of the buffer, empty for single-planar buffers
+    fn prepare(&self, _index: usize, _buf: &mut v4l2_buffer, _planes: &mut [v4l2_plane]) {}
+}
+
 /// Streaming I/O
 pub trait Stream {
     type Item: ?Sized;
 
     /// Start streaming, takes exclusive ownership of a device
+    ///
+    /// Starting a stream which is active already does nothing.
     fn start(&mut self) -> io::Result<()>;
 
     /// Stop streaming, frees all buffers
+    ///
+    /// Stopping a stream which is not active does nothing.
     fn stop(&mut self) -> io::Result<()>;
+
+    /// Returns whether the stream has been started and not stopped since
+    fn is_active(&self) -> bool;
 }
 
 pub trait CaptureStream<'a>: Stream {
     fn poll(&self) -> io::Result<bool>;
 
     /// Insert a buffer into the drivers' incoming queue
+    ///
+    /// Fails with [`crate::io::Inactive`] unless the stream is active.
     fn queue(&mut self, index: usize) -> io::Result<()>;
 
+    /// Insert a buffer into the drivers' incoming queue, optionally bound to a media request
+    ///
+    /// Buffers bound to a request are only handed to the driver once the request is queued.
+    /// Streams which do not support requests fail with [`io::ErrorKind::Unsupported`].
+    fn queue_request(&mut self, index: usize, request: Option<&Request>) -> io::Result<()> {
+        match request {
+            None => self.queue(index),
+            Some(_) => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "stream does not support requests",
+            )),
+        }
+    }
+
     /// Remove a buffer from the drivers' outgoing queue
     fn dequeue(&mut self) -> io::Result<usize>;
 
@@ -28,15 +116,36 @@ pub trait CaptureStream<'a>: Stream {
 
     /// Fetch a new frame by first queueing and then dequeueing.
     /// First time initialization is performed if necessary.
-    fn next(&'a mut self) -> io::Result<(&Self::Item, &Metadata, &[v4l2_plane])>;
+    fn next(&'a mut self) -> io::Result<(&'a Self::Item, &'a Metadata, &'a [v4l2_plane])>;
 }
 
+/// Capture stream of single-planar buffers whose I/O method is chosen at runtime
+///
+/// The stream is `Send`, so it can be moved to a capture thread.
+pub type BoxedCaptureStream = Box<dyn for<'a> CaptureStream<'a, Item = [u8]> + Send>;
+
 pub trait OutputStream<'a>: Stream {
     fn poll(&self) -> io::Result<bool>;
 
     /// Insert a buffer into the drivers' incoming queue
+    ///
+    /// Fails with [`crate::io::Inactive`] unless the stream is active.
     fn queue(&mut self, index: usize) -> io::Result<()>;
 
+    /// Insert a buffer into the drivers' incoming queue, optionally bound to a media request
+    ///
+    /// Buffers bound to a request are only handed to the driver once the request is queued.
+    /// Streams which do not support requests fail with [`io::ErrorKind::Unsupported`].
+    fn queue_request(&mut self, index: usize, request: Option<&Request>) -> io::Result<()> {
+        match request {
+            None => self.queue(index),
+            Some(_) => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "stream does not support requests",
+            )),
+        }
+    }
+
     /// Remove a buffer from the drivers' outgoing queue
     fn dequeue(&mut self) -> io::Result<usize>;
 
@@ -45,5 +154,5 @@ pub trait OutputStream<'a>: Stream {
 
     /// Dump a new frame by first queueing and then dequeueing.
     /// First time initialization is performed if necessary.
-    fn next(&'a mut self) -> io::Result<(&mut Self::Item, &mut Metadata)>;
+    fn next(&'a mut self) -> io::Result<(&'a mut Self::Item, &'a mut Metadata)>;
 }
@@ -1,18 +1,164 @@
-use std::{io, mem, os::fd::AsRawFd, sync::Arc};
+use std::ops::{Deref, DerefMut};
+use std::{fs, io, mem, ptr, slice, sync::Arc};
 
 use crate::buffer;
 use crate::device::Handle;
+use crate::io::traits::Arena as ArenaTrait;
+use crate::io::DropPolicy;
 use crate::memory::Memory;
 use crate::v4l2;
 use crate::v4l_sys::*;
 
+/// Allocator of user buffers
+///
+/// Hugepages reduce the number of IOMMU and TLB entries needed to cover a buffer, which
+/// matters for 4K (and larger) capture on embedded platforms. Hugepage buffers are aligned to the
+/// hugepage size and their size is rounded up to a multiple of it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Allocator {
+    /// Regular heap memory
+    #[default]
+    Heap,
+    /// Heap memory with transparent hugepages requested (`MADV_HUGEPAGE`)
+    ///
+    /// The kernel falls back to regular pages if transparent hugepages are disabled or no
+    /// hugepage is available.
+    TransparentHugepages,
+    /// Explicit hugepages from the hugetlbfs pool (`MAP_HUGETLB`)
+    ///
+    /// Allocation fails if the pool (`/proc/sys/vm/nr_hugepages`) is too small. Buffers are rounded
+    /// up to the default hugetlbfs page size (`Hugepagesize` in `/proc/meminfo`), which may be
+    /// larger than a transparent hugepage.
+    Hugetlb,
+}
+
+/// Returns the size of a (PMD sized) hugepage
+fn hugepage_size() -> usize {
+    fs::read_to_string("/sys/kernel/mm/transparent_hugepage/hpage_pmd_size")
+        .ok()
+        .and_then(|size| size.trim().parse().ok())
+        .unwrap_or(2 << 20)
+}
+
+/// Returns the default size of hugetlbfs pages, which `MAP_HUGETLB` allocates
+///
+/// It may differ from the size of transparent hugepages, e.g. 1G pages or 512M pages on systems
+/// with 64K base pages.
+fn hugetlb_page_size() -> usize {
+    fs::read_to_string("/proc/meminfo")
+        .ok()
+        .and_then(|info| {
+            info.lines()
+                .find_map(|line| line.strip_prefix("Hugepagesize:"))
+                .and_then(|size| size.trim().strip_suffix("kB"))
+                .and_then(|size| size.trim().parse::<usize>().ok())
+        })
+        .map(|kb| kb << 10)
+        .unwrap_or_else(hugepage_size)
+}
+
+/// Memory of a user buffer
+pub(crate) enum Buffer {
+    Heap(Vec<u8>),
+    /// Memory allocated by `posix_memalign()`
+    Aligned(*mut u8, usize),
+    /// Anonymous mapping of hugepages: pointer, buffer length and mapping length
+    Mapped(*mut u8, usize, usize),
+}
+
+// The buffer exclusively owns its memory, just like a Vec would.
+unsafe impl Send for Buffer {}
+unsafe impl Sync for Buffer {}
+
+impl Buffer {
+    fn new(allocator: Allocator, len: usize) -> io::Result<Self> {
+        if allocator == Allocator::Heap {
+            return Ok(Buffer::Heap(vec![0u8; len]));
+        }
+
+        // munmap() of hugetlb mappings fails unless the length is a multiple of their page size
+        let huge = match allocator {
+            Allocator::Hugetlb => hugetlb_page_size(),
+            _ => hugepage_size(),
+        };
+        let size = len.div_ceil(huge) * huge;
+        unsafe {
+            match allocator {
+                Allocator::Heap => unreachable!(),
+                Allocator::TransparentHugepages => {
+                    let mut ptr = ptr::null_mut();
+                    let ret = libc::posix_memalign(&mut ptr, huge, size);
+                    if ret != 0 {
+                        return Err(io::Error::from_raw_os_error(ret));
+                    }
+                    // merely a hint, the kernel is free to ignore it
+                    libc::madvise(ptr, size, libc::MADV_HUGEPAGE);
+                    ptr::write_bytes(ptr as *mut u8, 0, size);
+                    Ok(Buffer::Aligned(ptr as *mut u8, len))
+                }
+                Allocator::Hugetlb => {
+                    let ptr = libc::mmap(
+                        ptr::null_mut(),
+                        size,
+                        libc::PROT_READ | libc::PROT_WRITE,
+                        libc::MAP_PRIVATE | libc::MAP_ANONYMOUS | libc::MAP_HUGETLB,
+                        -1,
+                        0,
+                    );
+                    if ptr == libc::MAP_FAILED {
+                        return Err(io::Error::last_os_error());
+                    }
+                    Ok(Buffer::Mapped(ptr as *mut u8, len, size))
+                }
+            }
+        }
+    }
+}
+
+impl Deref for Buffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Buffer::Heap(buf) => buf,
+            Buffer::Aligned(ptr, len) | Buffer::Mapped(ptr, len, _) => unsafe {
+                slice::from_raw_parts(*ptr, *len)
+            },
+        }
+    }
+}
+
+impl DerefMut for Buffer {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            Buffer::Heap(buf) => buf,
+            Buffer::Aligned(ptr, len) | Buffer::Mapped(ptr, len, _) => unsafe {
+                slice::from_raw_parts_mut(*ptr, *len)
+            },
+        }
+    }
+}
+
+impl Drop for Buffer {
+    fn drop(&mut self) {
+        match *self {
+            Buffer::Heap(_) => {}
+            Buffer::Aligned(ptr, _) => unsafe { libc::free(ptr as *mut libc::c_void) },
+            Buffer::Mapped(ptr, _, size) => unsafe {
+                libc::munmap(ptr as *mut libc::c_void, size);
+            },
+        }
+    }
+}
+
 /// Manage user allocated buffers
 ///
 /// All buffers are released in the Drop impl.
 pub struct Arena {
     handle: Arc<Handle>,
-    pub bufs: Vec<Vec<u8>>,
+    pub(crate) bufs: Vec<Buffer>,
     pub buf_type: buffer::Type,
+    pub allocator: Allocator,
 }
 
 impl Arena {
@@ -30,6 +176,7 @@ impl Arena {
             handle,
             bufs: Vec::new(),
             buf_type,
+            allocator: Allocator::default(),
         }
     }
 
@@ -40,16 +187,23 @@ impl Arena {
             ..unsafe { mem::zeroed() }
         }
     }
+}
+
+impl ArenaTrait for Arena {
+    type Buffer = [u8];
+
+    fn memory(&self) -> Memory {
+        Memory::UserPtr
+    }
 
-    pub fn allocate(&mut self, count: u32) -> io::Result<u32> {
+    fn allocate(&mut self, count: u32) -> io::Result<u32> {
         // we need to get the maximum buffer size from the format first
         let mut v4l2_fmt = v4l2_format {
             type_: self.buf_type as u32,
             ..unsafe { mem::zeroed() }
         };
         unsafe {
-            v4l2::ioctl(
-                self.handle.as_raw_fd(),
+            self.handle.ioctl(
                 v4l2::vidioc::VIDIOC_G_FMT,
                 &mut v4l2_fmt as *mut _ as *mut std::os::raw::c_void,
             )?;
@@ -67,38 +221,68 @@ impl Arena {
             ..self.requestbuffers_desc()
         };
         unsafe {
-            v4l2::ioctl(
-                self.handle.as_raw_fd(),
+            self.handle.ioctl(
                 v4l2::vidioc::VIDIOC_REQBUFS,
                 &mut v4l2_reqbufs as *mut _ as *mut std::os::raw::c_void,
             )?;
         }
+        debug!(
+            handle = ?self.handle,
+            memory = "userptr",
+            requested = count,
+            granted = v4l2_reqbufs.count,
+            "allocated buffers"
+        );
 
         // allocate the new user buffers
-        self.bufs.resize(v4l2_reqbufs.count as usize, Vec::new());
-        for i in 0..v4l2_reqbufs.count {
-            let buf = &mut self.bufs[i as usize];
-            unsafe {
-                buf.resize(v4l2_fmt.fmt.pix.sizeimage as usize, 0);
-            }
-        }
+        let len = unsafe { v4l2_fmt.fmt.pix.sizeimage as usize };
+        self.bufs = (0..v4l2_reqbufs.count)
+            .map(|_| Buffer::new(self.allocator, len))
+            .collect::<io::Result<_>>()?;
 
         Ok(v4l2_reqbufs.count)
     }
 
-    pub fn release(&mut self) -> io::Result<()> {
+    fn release(&mut self) -> io::Result<()> {
         // free all buffers by requesting 0
         let mut v4l2_reqbufs = v4l2_requestbuffers {
             count: 0,
             ..self.requestbuffers_desc()
         };
         unsafe {
-            v4l2::ioctl(
-                self.handle.as_raw_fd(),
+            self.handle.ioctl(
                 v4l2::vidioc::VIDIOC_REQBUFS,
                 &mut v4l2_reqbufs as *mut _ as *mut std::os::raw::c_void,
-            )
+            )?;
         }
+        debug!(
+            handle = ?self.handle,
+            memory = "userptr",
+            "released buffers"
+        );
+
+        Ok(())
+    }
+    fn len(&self) -> usize {
+        self.bufs.len()
+    }
+
+    fn buffer_len(&self, index: usize) -> usize {
+        self.bufs[index].len()
+    }
+
+    fn buffer_at(&self, index: usize) -> &Self::Buffer {
+        &self.bufs[index]
+    }
+
+    fn buffer_at_mut(&mut self, index: usize) -> &mut Self::Buffer {
+        &mut self.bufs[index]
+    }
+
+    fn prepare(&self, index: usize, buf: &mut v4l2_buffer, _planes: &mut [v4l2_plane]) {
+        let mem = &self.bufs[index];
+        buf.m.userptr = mem.as_ptr() as std::os::raw::c_ulong;
+        buf.length = mem.len() as u32;
     }
 }
 
@@ -110,17 +294,7 @@ impl Drop for Arena {
         }
 
         if let Err(e) = self.release() {
-            if let Some(code) = e.raw_os_error() {
-                // ENODEV means the file descriptor wrapped in the handle became invalid, most
-                // likely because the device was unplugged or the connection (USB, PCI, ..)
-                // broke down. Handle this case gracefully by ignoring it.
-                if code == 19 {
-                    /* ignore */
-                    return;
-                }
-            }
-
-            panic!("{:?}", e)
+            DropPolicy::default().handle(e);
         }
     }
 }
@@ -1,4 +1,5 @@
 pub(crate) mod arena;
 
 pub mod stream;
+pub use arena::{Allocator, Arena};
 pub use stream::Stream;
@@ -1,35 +1,22 @@
-use std::convert::TryInto;
-use std::time::Duration;
-use std::{io, mem, os::fd::AsRawFd, sync::Arc};
+use std::io;
 
-use crate::buffer::{Metadata, Type};
-use crate::device::{Device, Handle};
-use crate::io::traits::{CaptureStream, Stream as StreamTrait};
-use crate::io::userptr::arena::Arena;
-use crate::memory::Memory;
-use crate::v4l2;
-use crate::v4l_sys::*;
+use crate::buffer::Type;
+use crate::device::VideoDevice;
+use crate::io::arena;
+use crate::io::userptr::arena::{Allocator, Arena};
+use crate::io::Profile;
 
 /// Stream of user buffers
 ///
 /// An arena instance is used internally for buffer handling.
-pub struct Stream {
-    handle: Arc<Handle>,
-    arena: Arena,
-    arena_index: usize,
-    buf_type: Type,
-    buf_meta: Vec<Metadata>,
-    timeout: Option<i32>,
+pub type Stream = arena::Stream<Arena>;
 
-    active: bool,
-}
-
-impl Stream {
+impl arena::Stream<Arena> {
     /// Returns a stream for frame capturing
     ///
     /// # Arguments
     ///
-    /// * `dev` - Device ref to get its file descriptor
+    /// * `dev` - Device (single- or multi-planar) to get its file descriptor
     /// * `buf_type` - Type of the buffers
     ///
     /// # Example
@@ -44,170 +31,130 @@ impl Stream {
     ///     let stream = Stream::new(&dev, Type::VideoCapture);
     /// }
     /// ```
-    pub fn new(dev: &Device, buf_type: Type) -> io::Result<Self> {
+    pub fn new<D: VideoDevice>(dev: &D, buf_type: Type) -> io::Result<Self> {
         Stream::with_buffers(dev, buf_type, 4)
     }
 
-    pub fn with_buffers(dev: &Device, buf_type: Type, buf_count: u32) -> io::Result<Self> {
-        let mut arena = Arena::new(dev.handle(), buf_type);
-        let count = arena.allocate(buf_count)?;
-        let mut buf_meta = Vec::new();
-        buf_meta.resize(count as usize, Metadata::with_memory(Memory::UserPtr));
-
-        Ok(Stream {
-            handle: dev.handle(),
-            arena,
-            arena_index: 0,
-            buf_type,
-            buf_meta,
-            active: false,
-            timeout: None,
-        })
-    }
-
-    /// Returns the raw device handle
-    pub fn handle(&self) -> Arc<Handle> {
-        self.handle.clone()
-    }
-
-    /// Sets a timeout of the v4l file handle.
-    pub fn set_timeout(&mut self, duration: Duration) {
-        self.timeout = Some(duration.as_millis().try_into().unwrap());
-    }
-
-    /// Clears the timeout of the v4l file handle.
-    pub fn clear_timeout(&mut self) {
-        self.timeout = None;
-    }
-
-    fn buffer_desc(&self) -> v4l2_buffer {
-        v4l2_buffer {
-            type_: self.buf_type as u32,
-            memory: Memory::UserPtr as u32,
-            ..unsafe { mem::zeroed() }
-        }
-    }
-}
-
-impl Drop for Stream {
-    fn drop(&mut self) {
-        if let Err(e) = self.stop() {
-            if let Some(code) = e.raw_os_error() {
-                // ENODEV means the file descriptor wrapped in the handle became invalid, most
-                // likely because the device was unplugged or the connection (USB, PCI, ..)
-                // broke down. Handle this case gracefully by ignoring it.
-                if code == 19 {
-                    /* ignore */
-                    return;
-                }
-            }
-
-            panic!("{:?}", e)
-        }
+    /// Returns a stream with a given number of buffers
+    ///
+    /// The count is raised to the minimum the driver needs. The driver may grant fewer buffers
+    /// if memory is short, see [`Stream::buffer_count`].
+    ///
+    /// # Arguments
+    ///
+    /// * `dev` - Device (single- or multi-planar) to get its file descriptor
+    /// * `buf_type` - Type of the buffers
+    /// * `buf_count` - Number of buffers to request
+    pub fn with_buffers<D: VideoDevice>(
+        dev: &D,
+        buf_type: Type,
+        buf_count: u32,
+    ) -> io::Result<Self> {
+        Stream::with_allocator(dev, buf_type, buf_count, Allocator::default())
     }
-}
-
-impl StreamTrait for Stream {
-    type Item = [u8];
-
-    fn start(&mut self) -> io::Result<()> {
-        unsafe {
-            let mut typ = self.buf_type as u32;
-            v4l2::ioctl(
-                self.handle.as_raw_fd(),
-                v4l2::vidioc::VIDIOC_STREAMON,
-                &mut typ as *mut _ as *mut std::os::raw::c_void,
-            )?;
-        }
 
-        self.active = true;
-        Ok(())
+    /// Returns a stream with the number of buffers suitable for a use case
+    ///
+    /// # Arguments
+    ///
+    /// * `dev` - Device (single- or multi-planar) to get its file descriptor
+    /// * `buf_type` - Type of the buffers
+    /// * `profile` - Use case, e.g. [`Profile::LowLatency`] for previews
+    pub fn with_profile<D: VideoDevice>(
+        dev: &D,
+        buf_type: Type,
+        profile: Profile,
+    ) -> io::Result<Self> {
+        Stream::with_buffers(dev, buf_type, profile.buffers())
     }
 
-    fn stop(&mut self) -> io::Result<()> {
-        unsafe {
-            let mut typ = self.buf_type as u32;
-            v4l2::ioctl(
-                self.handle.as_raw_fd(),
-                v4l2::vidioc::VIDIOC_STREAMOFF,
-                &mut typ as *mut _ as *mut std::os::raw::c_void,
-            )?;
-        }
-
-        self.active = false;
-        Ok(())
+    /// Returns a stream whose buffers are allocated by the given allocator
+    ///
+    /// # Arguments
+    ///
+    /// * `dev` - Device (single- or multi-planar) to get its file descriptor
+    /// * `buf_type` - Type of the buffers
+    /// * `buf_count` - Number of buffers to request
+    /// * `allocator` - Allocator of the buffer memory, e.g. for hugepages
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::buffer::Type;
+    /// use v4l::device::Device;
+    /// use v4l::io::userptr::{Allocator, Stream};
+    ///
+    /// if let Ok(dev) = Device::new(0) {
+    ///     let stream =
+    ///         Stream::with_allocator(&dev, Type::VideoCapture, 4, Allocator::TransparentHugepages);
+    /// }
+    /// ```
+    pub fn with_allocator<D: VideoDevice>(
+        dev: &D,
+        buf_type: Type,
+        buf_count: u32,
+        allocator: Allocator,
+    ) -> io::Result<Self> {
+        let mut arena = Arena::new(dev.handle(), buf_type);
+        arena.allocator = allocator;
+        Stream::with_arena(dev, buf_type, arena, buf_count)
     }
 }
 
-impl<'a> CaptureStream<'a> for Stream {
-    fn poll(&self) -> io::Result<bool> {
-        if self.handle.poll(libc::POLLIN, self.timeout.unwrap_or(-1))? == 0 {
-            // This condition can only happen if there was a timeout.
-            // A timeout is only possible if the `timeout` value is non-zero, meaning we should
-            // propagate it to the caller.
-            return Ok(false);
-        }
-        Ok(true)
-    }
-
-    fn queue(&mut self, index: usize) -> io::Result<()> {
-        let buf = &mut self.arena.bufs[index];
-        let mut v4l2_buf = v4l2_buffer {
-            index: index as u32,
-            m: v4l2_buffer__bindgen_ty_1 {
-                userptr: buf.as_ptr() as std::os::raw::c_ulong,
-            },
-            length: buf.len() as u32,
-            ..self.buffer_desc()
-        };
-        unsafe {
-            v4l2::ioctl(
-                self.handle.as_raw_fd(),
-                v4l2::vidioc::VIDIOC_QBUF,
-                &mut v4l2_buf as *mut _ as *mut std::os::raw::c_void,
-            )?;
-        }
-
-        Ok(())
-    }
-
-    fn dequeue(&mut self) -> io::Result<usize> {
-        let mut v4l2_buf = self.buffer_desc();
-
-        unsafe {
-            v4l2::ioctl(
-                self.handle.as_raw_fd(),
-                v4l2::vidioc::VIDIOC_DQBUF,
-                &mut v4l2_buf as *mut _ as *mut std::os::raw::c_void,
-            )?;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Mock;
+    use crate::device::Device;
+    use crate::io::traits::CaptureStream;
+    use crate::memory::Memory;
+    use crate::video::Capture;
+
+    #[test]
+    fn captures_into_user_buffers() {
+        let mock = Mock::new();
+        mock.set_generator(|buf, _, sequence| buf.fill(sequence as u8 + 1));
+        let dev: Device = mock.device().unwrap();
+        let size = dev.format().unwrap().size;
+
+        let mut stream = Stream::with_buffers(&dev, Type::VideoCapture, 2).unwrap();
+        assert_eq!(stream.buffer_count(), 2);
+        assert!(stream.buffers().all(|buf| buf.len() == size as usize));
+
+        for frame in 0..5u32 {
+            let (buf, meta, planes) = stream.next().unwrap();
+            assert!(planes.is_empty());
+            assert_eq!(buf.len(), size as usize);
+            assert!(buf.iter().all(|&byte| byte == frame as u8 + 1));
+            assert_eq!(meta.sequence, frame);
+            assert_eq!(meta.memory, Memory::UserPtr);
         }
-        self.arena_index = v4l2_buf.index as usize;
-        self.buf_meta[self.arena_index] = Metadata::from(v4l2_buf);
 
-        Ok(self.arena_index)
+        drop(stream);
+        assert!(!mock.is_streaming());
     }
 
-    fn get(&self, index: usize) -> io::Result<(&Self::Item, &Metadata, &[v4l2_plane])> {
-        Ok((&self.arena.bufs[index], &self.buf_meta[index], &[]))
+    #[test]
+    fn sizes_buffers_after_the_format() {
+        let dev: Device = Mock::new().device().unwrap();
+        let mut fmt = dev.format().unwrap();
+        (fmt.width, fmt.height) = (1280, 720);
+        dev.set_format(&fmt).unwrap();
+
+        let mut stream = Stream::new(&dev, Type::VideoCapture).unwrap();
+        let (buf, meta, _) = stream.next().unwrap();
+        assert_eq!(buf.len(), 1280 * 720 * 2);
+        assert_eq!(meta.bytesused, 1280 * 720 * 2);
     }
 
-    fn next(&'a mut self) -> io::Result<(&Self::Item, &Metadata, &[v4l2_plane])> {
-        if !self.active {
-            // Enqueue all buffers once on stream start
-            for index in 0..self.arena.bufs.len() {
-                self.queue(index)?;
-            }
-
-            self.start()?;
-        } else {
-            self.queue(self.arena_index)?;
-        }
-
-        if !self.poll()? {
-            return Err(io::Error::new(io::ErrorKind::TimedOut, "VIDIOC_DQBUF"));
-        }
+    #[test]
+    fn reports_allocation_errors() {
+        let mock = Mock::new();
+        let dev: Device = mock.device().unwrap();
+        mock.fail_next(crate::v4l2::vidioc::VIDIOC_REQBUFS, libc::ENOMEM);
 
-        let index = self.dequeue()?;
-        self.get(index)
+        let err = Stream::new(&dev, Type::VideoCapture).err().unwrap();
+        assert_eq!(err.raw_os_error(), Some(libc::ENOMEM));
+        assert!(Stream::new(&dev, Type::VideoCapture).is_ok());
     }
 }
@@ -0,0 +1,194 @@
+use std::{
+    io,
+    time::{Duration, Instant},
+};
+
+use crate::buffer::Metadata;
+use crate::io::traits::{CaptureStream, Stream};
+use crate::media::Request;
+use crate::v4l_sys::*;
+
+/// Recovery action of a stall, e.g. resetting the device through a vendor control
+type Recovery<S> = Box<dyn FnMut(&mut S) -> io::Result<()> + Send>;
+
+/// Capture stream wrapper which recovers streams that stopped delivering frames
+///
+/// Some drivers and camera firmwares silently stop delivering frames after a while, e.g. after
+/// a USB bandwidth hiccup, and only come back once the stream is restarted. The watchdog keeps
+/// track of the time since the last successfully dequeued frame. Once it exceeds the threshold,
+/// the stream is stopped (and thus restarted by the next call to `next()`), or a custom
+/// recovery action is invoked instead.
+///
+/// Stalls are detected by `next()` failing, so the wrapped stream needs a timeout shorter than
+/// the threshold (e.g. set through `set_timeout`). The recovery runs at the beginning of the
+/// call following the one which exceeded the threshold. Buffers dequeued manually count as
+/// frames, but only `next()` recovers.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use v4l::buffer::Type;
+/// use v4l::io::traits::CaptureStream;
+/// use v4l::io::watchdog::Watchdog;
+/// use v4l::prelude::*;
+///
+/// if let Ok(dev) = Device::new(0) {
+///     let mut stream = MmapStream::new(&dev, Type::VideoCapture).unwrap();
+///     stream.set_timeout(Duration::from_millis(200));
+///     let mut stream = Watchdog::new(stream, Duration::from_secs(2));
+///
+///     loop {
+///         match stream.next() {
+///             Ok((buf, meta, _)) => println!("{} bytes, seq: {}", buf.len(), meta.sequence),
+///             Err(e) if e.kind() == std::io::ErrorKind::TimedOut => continue,
+///             Err(e) => panic!("{}", e),
+///         }
+///     }
+/// }
+/// ```
+pub struct Watchdog<S> {
+    stream: S,
+    threshold: Duration,
+    recovery: Option<Recovery<S>>,
+    /// Time of the last dequeued frame, or the first attempt to get one
+    last: Option<Instant>,
+    /// Number of stalls recovered from
+    stalls: u64,
+}
+
+impl<S> Watchdog<S> {
+    /// Returns a stream which is restarted once it stalls for `threshold`
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - Capture stream
+    /// * `threshold` - Maximum time without a frame
+    pub fn new(stream: S, threshold: Duration) -> Self {
+        Watchdog {
+            stream,
+            threshold,
+            recovery: None,
+            last: None,
+            stalls: 0,
+        }
+    }
+
+    /// Replaces restarting the stream with a custom recovery action
+    ///
+    /// The action gets the wrapped stream, e.g. to stop it and reset the device in between.
+    /// Errors it returns are passed on to the caller of `next()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `recovery` - Action to take once the stream stalled
+    pub fn on_stall<F>(mut self, recovery: F) -> Self
+    where
+        F: FnMut(&mut S) -> io::Result<()> + Send + 'static,
+    {
+        self.recovery = Some(Box::new(recovery));
+        self
+    }
+
+    /// Returns the maximum time without a frame
+    pub fn threshold(&self) -> Duration {
+        self.threshold
+    }
+
+    /// Returns how often the stream stalled
+    pub fn stalls(&self) -> u64 {
+        self.stalls
+    }
+
+    /// Returns a reference to the wrapped stream
+    pub fn get_ref(&self) -> &S {
+        &self.stream
+    }
+
+    /// Returns a mutable reference to the wrapped stream
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.stream
+    }
+
+    /// Returns the wrapped stream
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+
+    /// Returns whether the time since the last frame exceeds the threshold
+    pub fn is_stalled(&self) -> bool {
+        self.last
+            .is_some_and(|last| last.elapsed() >= self.threshold)
+    }
+}
+
+impl<S: Stream> Watchdog<S> {
+    /// Runs the recovery action and restarts the clock
+    fn recover(&mut self) -> io::Result<()> {
+        self.stalls += 1;
+        debug!(stalls = self.stalls, "stream stalled, recovering");
+        self.last = Some(Instant::now());
+        match &mut self.recovery {
+            Some(recovery) => recovery(&mut self.stream),
+            // the next call to next() queues the buffers and starts the stream again
+            None => self.stream.stop(),
+        }
+    }
+}
+
+impl<S: Stream> Stream for Watchdog<S> {
+    type Item = S::Item;
+
+    fn start(&mut self) -> io::Result<()> {
+        self.stream.start()
+    }
+
+    fn stop(&mut self) -> io::Result<()> {
+        // a stopped stream does not stall
+        self.last = None;
+        self.stream.stop()
+    }
+
+    fn is_active(&self) -> bool {
+        self.stream.is_active()
+    }
+}
+
+impl<'a, S: CaptureStream<'a>> CaptureStream<'a> for Watchdog<S> {
+    fn poll(&self) -> io::Result<bool> {
+        self.stream.poll()
+    }
+
+    fn queue(&mut self, index: usize) -> io::Result<()> {
+        self.stream.queue(index)
+    }
+
+    fn queue_request(&mut self, index: usize, request: Option<&Request>) -> io::Result<()> {
+        self.stream.queue_request(index, request)
+    }
+
+    fn dequeue(&mut self) -> io::Result<usize> {
+        let index = self.stream.dequeue()?;
+        self.last = Some(Instant::now());
+        Ok(index)
+    }
+
+    fn get(&self, index: usize) -> io::Result<(&Self::Item, &Metadata, &[v4l2_plane])> {
+        self.stream.get(index)
+    }
+
+    fn next(&'a mut self) -> io::Result<(&'a Self::Item, &'a Metadata, &'a [v4l2_plane])> {
+        if self.is_stalled() {
+            self.recover()?;
+        }
+        if self.last.is_none() {
+            self.last = Some(Instant::now());
+        }
+
+        let res = self.stream.next();
+        if res.is_ok() {
+            self.last = Some(Instant::now());
+        }
+        res
+    }
+}
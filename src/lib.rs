@@ -55,10 +55,10 @@
 //!     MmapStream::with_buffers(&mut dev, Type::VideoCapture, 4).expect("Failed to create buffer stream");
 //!
 //! loop {
-//!     let (buf, meta) = stream.next().unwrap();
+//!     let (bufs, meta, _) = stream.next().unwrap();
 //!     println!(
 //!         "Buffer size: {}, seq: {}, timestamp: {}",
-//!        buf.len(),
+//!        bufs[0].len(),
 //!        meta.sequence,
 //!        meta.timestamp
 //!    );
@@ -67,29 +67,64 @@
 //!
 //! Have a look at the examples to learn more about device and buffer management.
 
-#[cfg(feature = "v4l-sys")]
+#[cfg(all(feature = "v4l-sys", not(feature = "v4l2-sys")))]
 pub use v4l_sys;
 
+// the kernel structs are the same, whichever headers they were generated from
 #[cfg(feature = "v4l2-sys")]
 pub use v4l2_sys as v4l_sys;
 
+#[macro_use]
+mod log;
+
 pub mod v4l2;
 
+#[cfg(feature = "av")]
+pub mod av;
+pub mod backend;
+pub mod broadcast;
 pub mod buffer;
 pub mod capability;
+pub mod clock;
+pub mod codec;
+pub mod compliance;
 pub mod context;
 pub mod control;
+pub mod convert;
 pub mod device;
+pub mod event;
 pub mod format;
 pub mod fraction;
+pub mod frame;
 pub mod frameinterval;
 pub mod framesize;
+pub mod interop;
+pub mod media;
 pub mod memory;
+pub mod metrics;
+#[cfg(feature = "mux")]
+pub mod mux;
+#[cfg(feature = "net")]
+pub mod net;
 pub mod parameters;
+pub mod priority;
+pub mod privacy;
+pub mod ptz;
+pub mod radio;
+pub mod recorder;
+pub mod reconnect;
+pub mod rect;
+pub mod roi;
+pub mod sched;
+pub mod stats;
+pub mod still;
+pub mod subdev;
 pub mod timestamp;
+pub mod touch;
 pub mod video;
 
 pub mod io;
+pub mod testing;
 
 pub use {
     capability::Capabilities,
@@ -100,10 +135,31 @@ pub use {
     frameinterval::FrameInterval,
     framesize::FrameSize,
     memory::Memory,
+    rect::Rect,
     timestamp::Timestamp,
+    v4l2::vidioc,
 };
 
 pub mod prelude {
     pub use crate::device::Device;
     pub use crate::io::{mmap::Stream as MmapStream, userptr::Stream as UserptrStream};
 }
+
+// Devices and streams are commonly moved to (or shared with) capture threads, this fails to
+// compile once a field makes them lose their thread safety.
+#[allow(dead_code)]
+fn assert_thread_safe() {
+    fn send_sync<T: Send + Sync>() {}
+    fn send<T: Send>() {}
+
+    send_sync::<device::Handle>();
+    send_sync::<device::Device>();
+    send_sync::<device::MultiPlaneDevice>();
+    send_sync::<media::Request>();
+    send_sync::<io::mmap::Stream<'static>>();
+    send_sync::<io::mmap::Parts<'static>>();
+    send_sync::<io::userptr::Stream>();
+    send_sync::<io::read::Stream>();
+    send_sync::<io::m2m::Stream<'static>>();
+    send::<io::traits::BoxedCaptureStream>();
+}
@@ -0,0 +1,28 @@
+//! Internal logging macros
+//!
+//! They forward to the `tracing` crate when the `tracing` feature is enabled and expand to
+//! nothing otherwise, so call sites do not need to be feature gated.
+
+#[cfg(feature = "tracing")]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        tracing::trace!($($arg)*)
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! trace {
+    ($($arg:tt)*) => {};
+}
+
+#[cfg(feature = "tracing")]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        tracing::debug!($($arg)*)
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! debug {
+    ($($arg:tt)*) => {};
+}
@@ -0,0 +1,359 @@
+use std::path::{Path, PathBuf};
+use std::{
+    fmt, io, mem,
+    os::fd::{AsFd, AsRawFd, BorrowedFd, OwnedFd, RawFd},
+    str,
+    sync::Arc,
+};
+
+use crate::device::Handle;
+use crate::v4l2;
+use crate::v4l2::media::*;
+
+pub mod pipeline;
+pub use pipeline::Pipeline;
+
+pub mod request;
+pub use request::Request;
+
+pub mod topology;
+pub use topology::{Entity, Function, Pad, Topology};
+
+/// Media device information
+#[derive(Debug)]
+pub struct Info {
+    /// Driver name, e.g. rkisp1
+    pub driver: String,
+    /// Device model
+    pub model: String,
+    /// Serial number
+    pub serial: String,
+    /// Bus location
+    pub bus: String,
+    /// Media API version MAJOR.MINOR.PATCH
+    pub media_version: (u8, u8, u8),
+    /// Hardware revision, driver specific
+    pub hw_revision: u32,
+    /// Driver version MAJOR.MINOR.PATCH
+    pub driver_version: (u8, u8, u8),
+}
+
+fn version(version: u32) -> (u8, u8, u8) {
+    (
+        ((version >> 16) & 0xff) as u8,
+        ((version >> 8) & 0xff) as u8,
+        (version & 0xff) as u8,
+    )
+}
+
+fn string(bytes: &[u8]) -> String {
+    str::from_utf8(bytes)
+        .unwrap_or_default()
+        .trim_matches(char::from(0))
+        .to_string()
+}
+
+impl From<media_device_info> for Info {
+    fn from(info: media_device_info) -> Self {
+        Info {
+            driver: string(&info.driver),
+            model: string(&info.model),
+            serial: string(&info.serial),
+            bus: string(&info.bus_info),
+            media_version: version(info.media_version),
+            hw_revision: info.hw_revision,
+            driver_version: version(info.driver_version),
+        }
+    }
+}
+
+impl fmt::Display for Info {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Driver      : {}", self.driver)?;
+        writeln!(f, "Model       : {}", self.model)?;
+        writeln!(f, "Serial      : {}", self.serial)?;
+        writeln!(f, "Bus         : {}", self.bus)?;
+        writeln!(
+            f,
+            "Version     : {}.{}.{}",
+            self.driver_version.0, self.driver_version.1, self.driver_version.2
+        )?;
+        Ok(())
+    }
+}
+
+/// Linux media controller device abstraction
+///
+/// Media devices (/dev/mediaX) describe how the video nodes and sub-devices of a complex
+/// pipeline are connected. They are used to find the nodes which belong to a pipeline and to
+/// configure the links between them.
+pub struct MediaDevice {
+    /// Raw handle
+    handle: Arc<Handle>,
+}
+
+impl MediaDevice {
+    /// Returns a media device by index
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index (0: first, 1: second, ..)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::media::MediaDevice;
+    /// let media = MediaDevice::new(0);
+    /// ```
+    pub fn new(index: usize) -> io::Result<Self> {
+        Self::with_path(format!("{}{}", "/dev/media", index))
+    }
+
+    /// Returns a media device by path
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path (e.g. "/dev/media0")
+    pub fn with_path<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(MediaDevice {
+            handle: Arc::new(Handle::open(path)?),
+        })
+    }
+
+    /// Returns a media device wrapping an already opened file descriptor
+    ///
+    /// # Arguments
+    ///
+    /// * `fd` - File descriptor of an opened media device node
+    pub fn with_fd(fd: OwnedFd) -> io::Result<Self> {
+        Ok(MediaDevice {
+            handle: Arc::new(Handle::from_fd(fd)?),
+        })
+    }
+
+    /// Returns the raw device handle
+    pub fn handle(&self) -> Arc<Handle> {
+        self.handle.clone()
+    }
+
+    /// Returns the media device information
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::media::MediaDevice;
+    ///
+    /// if let Ok(media) = MediaDevice::new(0) {
+    ///     println!("{}", media.info().unwrap());
+    /// }
+    /// ```
+    pub fn info(&self) -> io::Result<Info> {
+        unsafe {
+            let mut info: media_device_info = mem::zeroed();
+            self.handle.ioctl(
+                v4l2::vidioc::MEDIA_IOC_DEVICE_INFO,
+                &mut info as *mut _ as *mut std::os::raw::c_void,
+            )?;
+
+            Ok(Info::from(info))
+        }
+    }
+
+    /// Returns a snapshot of the media graph
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::media::MediaDevice;
+    ///
+    /// if let Ok(media) = MediaDevice::new(0) {
+    ///     for entity in media.topology().unwrap().entities {
+    ///         println!("{}", entity);
+    ///     }
+    /// }
+    /// ```
+    pub fn topology(&self) -> io::Result<Topology> {
+        loop {
+            // the first call only returns the number of graph objects
+            let mut topology = media_v2_topology::default();
+            unsafe {
+                self.handle.ioctl(
+                    v4l2::vidioc::MEDIA_IOC_G_TOPOLOGY,
+                    &mut topology as *mut _ as *mut std::os::raw::c_void,
+                )?;
+            }
+            let version = topology.topology_version;
+
+            let mut entities: Vec<media_v2_entity> =
+                vec![unsafe { mem::zeroed() }; topology.num_entities as usize];
+            let mut interfaces: Vec<media_v2_interface> =
+                vec![unsafe { mem::zeroed() }; topology.num_interfaces as usize];
+            let mut pads: Vec<media_v2_pad> =
+                vec![unsafe { mem::zeroed() }; topology.num_pads as usize];
+            let mut links: Vec<media_v2_link> =
+                vec![unsafe { mem::zeroed() }; topology.num_links as usize];
+            topology.ptr_entities = entities.as_mut_ptr() as u64;
+            topology.ptr_interfaces = interfaces.as_mut_ptr() as u64;
+            topology.ptr_pads = pads.as_mut_ptr() as u64;
+            topology.ptr_links = links.as_mut_ptr() as u64;
+
+            // the driver fails with ENOSPC if the graph grew in the meantime
+            match unsafe {
+                self.handle.ioctl(
+                    v4l2::vidioc::MEDIA_IOC_G_TOPOLOGY,
+                    &mut topology as *mut _ as *mut std::os::raw::c_void,
+                )
+            } {
+                Ok(()) => {}
+                Err(e) if e.raw_os_error() == Some(libc::ENOSPC) => continue,
+                Err(e) => return Err(e),
+            }
+            if topology.topology_version != version {
+                continue;
+            }
+
+            entities.truncate(topology.num_entities as usize);
+            interfaces.truncate(topology.num_interfaces as usize);
+            pads.truncate(topology.num_pads as usize);
+            links.truncate(topology.num_links as usize);
+
+            return Ok(Topology {
+                version,
+                entities: entities.into_iter().map(Entity::from).collect(),
+                interfaces: interfaces.into_iter().map(From::from).collect(),
+                pads: pads.into_iter().map(From::from).collect(),
+                links: links.into_iter().map(From::from).collect(),
+            });
+        }
+    }
+
+    /// Returns the entity with the given name
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Entity name, e.g. "imx290 1-001a"
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::media::MediaDevice;
+    ///
+    /// if let Ok(media) = MediaDevice::new(0) {
+    ///     if let Some(entity) = media.entity_by_name("rkisp1_mainpath").unwrap() {
+    ///         println!("{:?}", media.devnode(&entity).unwrap());
+    ///     }
+    /// }
+    /// ```
+    pub fn entity_by_name(&self, name: &str) -> io::Result<Option<Entity>> {
+        Ok(self.topology()?.entity_by_name(name).cloned())
+    }
+
+    /// Returns all entities with the given function
+    ///
+    /// # Arguments
+    ///
+    /// * `function` - Entity function, e.g. `Function::CamSensor`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::media::{Function, MediaDevice};
+    ///
+    /// if let Ok(media) = MediaDevice::new(0) {
+    ///     for sensor in media.entity_by_function(Function::CamSensor).unwrap() {
+    ///         println!("{}", sensor);
+    ///     }
+    /// }
+    /// ```
+    pub fn entity_by_function(&self, function: Function) -> io::Result<Vec<Entity>> {
+        Ok(self
+            .topology()?
+            .entities_by_function(function)
+            .into_iter()
+            .cloned()
+            .collect())
+    }
+
+    /// Returns the path of the device node of an entity
+    ///
+    /// Video nodes resolve to /dev/videoX, sub-devices to /dev/v4l-subdevX. Entities without an
+    /// interface (e.g. sub-devices whose driver does not expose a node) resolve to `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - Entity
+    pub fn devnode(&self, entity: &Entity) -> io::Result<Option<PathBuf>> {
+        Ok(self.topology()?.devnode(entity.id))
+    }
+
+    /// Enables or disables the data link between two pads
+    ///
+    /// Immutable links cannot be modified. Most drivers refuse to modify links while the
+    /// pipeline is streaming, unless the link is flagged as dynamic.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Source pad of the link
+    /// * `sink` - Sink pad of the link
+    /// * `enabled` - Whether data should flow through the link
+    pub fn setup_link(&self, source: &Pad, sink: &Pad, enabled: bool) -> io::Result<()> {
+        let mut link = media_link_desc {
+            source: media_pad_desc {
+                entity: source.entity,
+                index: source.index as u16,
+                ..Default::default()
+            },
+            sink: media_pad_desc {
+                entity: sink.entity,
+                index: sink.index as u16,
+                ..Default::default()
+            },
+            flags: if enabled { MEDIA_LNK_FL_ENABLED } else { 0 },
+            ..Default::default()
+        };
+
+        unsafe {
+            self.handle.ioctl(
+                v4l2::vidioc::MEDIA_IOC_SETUP_LINK,
+                &mut link as *mut _ as *mut std::os::raw::c_void,
+            )
+        }
+    }
+
+    /// Allocates a new request
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::media::MediaDevice;
+    ///
+    /// if let Ok(media) = MediaDevice::new(0) {
+    ///     if let Ok(request) = media.alloc_request() {
+    ///         request.queue().unwrap();
+    ///     }
+    /// }
+    /// ```
+    pub fn alloc_request(&self) -> io::Result<Request> {
+        let mut fd: std::os::raw::c_int = -1;
+        unsafe {
+            self.handle.ioctl(
+                v4l2::vidioc::MEDIA_IOC_REQUEST_ALLOC,
+                &mut fd as *mut _ as *mut std::os::raw::c_void,
+            )?;
+        }
+
+        Ok(Request::new(fd))
+    }
+}
+
+impl AsFd for MediaDevice {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.handle.as_fd()
+    }
+}
+
+impl AsRawFd for MediaDevice {
+    fn as_raw_fd(&self) -> RawFd {
+        self.handle.as_raw_fd()
+    }
+}
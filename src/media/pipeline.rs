@@ -0,0 +1,185 @@
+use std::collections::{HashMap, VecDeque};
+use std::io;
+
+use crate::media::topology::{Entity, Link, LinkFlags, LinkType, Topology};
+use crate::media::MediaDevice;
+use crate::subdev::{Format, Subdevice, Which};
+
+/// Pipeline configurator
+///
+/// Connects a sensor to a video node, similar to what is usually scripted with `media-ctl`:
+/// the shortest data path through the media graph is searched, its links are enabled (and
+/// conflicting links into the same sink pads are disabled), and the media bus format is
+/// propagated from the sensor pad by pad towards the video node. Every sub-device along the
+/// path may adjust the format, e.g. an ISP converting raw Bayer data into YUV.
+///
+/// The format which arrives at the video node is returned, the video node itself has to be
+/// configured with a matching pixel format by the caller.
+///
+/// # Example
+///
+/// ```
+/// use v4l::media::{Function, MediaDevice, Pipeline};
+/// use v4l::subdev::Format;
+///
+/// if let Ok(media) = MediaDevice::new(0) {
+///     let sensors = media.entity_by_function(Function::CamSensor).unwrap();
+///     let video = media.entity_by_name("rkisp1_mainpath").unwrap();
+///     if let (Some(sensor), Some(video)) = (sensors.first(), video) {
+///         let fmt = Pipeline::new(&media, sensor, &video)
+///             .format(Format::new(1920, 1080, 0x300f))
+///             .configure()
+///             .unwrap();
+///         println!("{}", fmt);
+///     }
+/// }
+/// ```
+pub struct Pipeline<'a> {
+    media: &'a MediaDevice,
+    sensor: u32,
+    video: u32,
+    format: Option<Format>,
+}
+
+impl<'a> Pipeline<'a> {
+    /// Returns a pipeline configurator
+    ///
+    /// # Arguments
+    ///
+    /// * `media` - Media device both entities belong to
+    /// * `sensor` - Entity at the start of the pipeline, usually a camera sensor
+    /// * `video` - Entity at the end of the pipeline, usually a video node
+    pub fn new(media: &'a MediaDevice, sensor: &Entity, video: &Entity) -> Self {
+        Pipeline {
+            media,
+            sensor: sensor.id,
+            video: video.id,
+            format: None,
+        }
+    }
+
+    /// Sets the format of the sensor
+    ///
+    /// By default, the active format of the sensor is kept and propagated.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - Media bus format of the sensor source pad
+    pub fn format(mut self, format: Format) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Returns the links of the shortest data path from the sensor to the video node
+    pub fn links(&self) -> io::Result<Vec<Link>> {
+        self.path(&self.media.topology()?)
+    }
+
+    fn path(&self, topology: &Topology) -> io::Result<Vec<Link>> {
+        let entity = |pad: u32| topology.pad(pad).map(|pad| pad.entity);
+
+        // breadth-first search, remembering the link each entity was reached through
+        let mut reached: HashMap<u32, Link> = HashMap::new();
+        let mut queue = VecDeque::new();
+        queue.push_back(self.sensor);
+        while let Some(current) = queue.pop_front() {
+            if current == self.video {
+                break;
+            }
+
+            for link in &topology.links {
+                if link.typ != LinkType::Data || entity(link.source) != Some(current) {
+                    continue;
+                }
+
+                let next = match entity(link.sink) {
+                    Some(next) => next,
+                    None => continue,
+                };
+                if next != self.sensor && !reached.contains_key(&next) {
+                    reached.insert(next, *link);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let mut path = Vec::new();
+        let mut current = self.video;
+        while current != self.sensor {
+            let link = reached.get(&current).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    "no data path from the sensor to the video node",
+                )
+            })?;
+            path.push(*link);
+            current = entity(link.source).unwrap();
+        }
+        path.reverse();
+        Ok(path)
+    }
+
+    /// Enables the links and propagates the format along the pipeline
+    ///
+    /// Returns the media bus format at the last sub-device before the video node.
+    pub fn configure(&self) -> io::Result<Format> {
+        let topology = self.media.topology()?;
+        let path = self.path(&topology)?;
+        if path.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "sensor and video node are the same entity",
+            ));
+        }
+        let pad = |id: u32| topology.pad(id).unwrap();
+
+        for link in &path {
+            // a sink pad can only receive data from one source at a time
+            for other in &topology.links {
+                if other.typ == LinkType::Data
+                    && other.sink == link.sink
+                    && other.id != link.id
+                    && other.flags.contains(LinkFlags::ENABLED)
+                    && !other.flags.contains(LinkFlags::IMMUTABLE)
+                {
+                    self.media
+                        .setup_link(pad(other.source), pad(other.sink), false)?;
+                }
+            }
+
+            if !link.flags.contains(LinkFlags::ENABLED) {
+                self.media
+                    .setup_link(pad(link.source), pad(link.sink), true)?;
+            }
+        }
+
+        let subdev = |entity: u32| -> io::Result<Option<Subdevice>> {
+            topology
+                .devnode(entity)
+                .map(Subdevice::with_path)
+                .transpose()
+        };
+
+        let sensor = subdev(self.sensor)?.ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, "sensor has no sub-device node")
+        })?;
+        let source = pad(path[0].source).index;
+        let mut format = match self.format {
+            Some(format) => sensor.set_format(source, &format, Which::Active)?,
+            None => sensor.format(source, Which::Active)?,
+        };
+
+        for (link, next) in path.iter().zip(path.iter().skip(1)) {
+            // entities without a node are configured by their driver
+            let subdev = match subdev(pad(link.sink).entity)? {
+                Some(subdev) => subdev,
+                None => continue,
+            };
+
+            format = subdev.set_format(pad(link.sink).index, &format, Which::Active)?;
+            format = subdev.set_format(pad(next.source).index, &format, Which::Active)?;
+        }
+
+        Ok(format)
+    }
+}
@@ -0,0 +1,79 @@
+use std::convert::TryInto;
+use std::time::Duration;
+use std::{
+    io,
+    os::fd::{AsFd, AsRawFd, BorrowedFd, RawFd},
+};
+
+use crate::device::{Handle, PollFlags};
+use crate::v4l2;
+
+/// Media request
+///
+/// A request bundles buffers and controls, the driver applies them together once the request
+/// is queued. Stateless codecs rely on this to receive the parameters (SPS, PPS, slice
+/// parameters, ..) of each frame along with its bitstream buffer.
+///
+/// Requests are allocated by [`MediaDevice::alloc_request`](super::MediaDevice::alloc_request).
+/// Buffers are bound to a request through
+/// [`OutputStream::queue_request`](crate::io::traits::OutputStream::queue_request), controls
+/// through [`Device::set_request_controls`](crate::device::Device::set_request_controls).
+pub struct Request {
+    /// Raw handle
+    handle: Handle,
+}
+
+impl Request {
+    pub(crate) fn new(fd: RawFd) -> Self {
+        Request {
+            handle: unsafe { Handle::new(fd) },
+        }
+    }
+
+    /// Queues the request
+    ///
+    /// The buffers and controls bound to the request are handed to the driver. Once queued, the
+    /// request cannot be modified until it completed and was re-initialized.
+    pub fn queue(&self) -> io::Result<()> {
+        unsafe {
+            self.handle
+                .ioctl(v4l2::vidioc::MEDIA_REQUEST_IOC_QUEUE, std::ptr::null_mut())
+        }
+    }
+
+    /// Re-initializes a completed request so it can be used again
+    pub fn reinit(&self) -> io::Result<()> {
+        unsafe {
+            self.handle
+                .ioctl(v4l2::vidioc::MEDIA_REQUEST_IOC_REINIT, std::ptr::null_mut())
+        }
+    }
+
+    /// Waits for the request to complete
+    ///
+    /// Returns `false` if the timeout expired before the request completed.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Maximum time to wait, `None` waits indefinitely
+    pub fn wait(&self, timeout: Option<Duration>) -> io::Result<bool> {
+        let timeout = match timeout {
+            Some(duration) => duration.as_millis().try_into().unwrap_or(i32::MAX),
+            None => -1,
+        };
+
+        Ok(!self.handle.poll(PollFlags::PRI, timeout)?.is_empty())
+    }
+}
+
+impl AsFd for Request {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.handle.as_fd()
+    }
+}
+
+impl AsRawFd for Request {
+    fn as_raw_fd(&self) -> RawFd {
+        self.handle.as_raw_fd()
+    }
+}
@@ -0,0 +1,417 @@
+use bitflags::bitflags;
+use std::path::PathBuf;
+use std::{fmt, fs, str};
+
+use crate::v4l2::media::*;
+
+/// Main function of an entity
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Function {
+    /// Unknown function
+    Unknown,
+    /// Data streaming input and/or output (video device node)
+    IoV4l,
+    /// Camera video sensor
+    CamSensor,
+    /// Flash controller
+    Flash,
+    /// Lens controller
+    Lens,
+    /// Analog video decoder
+    AtvDecoder,
+    /// Video composer (blender)
+    ProcVideoComposer,
+    /// Video pixel formatter
+    ProcVideoPixelFormatter,
+    /// Video pixel encoding converter
+    ProcVideoPixelEncConv,
+    /// Video look-up table
+    ProcVideoLut,
+    /// Video scaler
+    ProcVideoScaler,
+    /// Video statistics
+    ProcVideoStatistics,
+    /// Video encoder
+    ProcVideoEncoder,
+    /// Video decoder
+    ProcVideoDecoder,
+    /// Image signal processor
+    ProcVideoIsp,
+    /// Video multiplexer
+    VidMux,
+    /// Video interface bridge, e.g. a CSI-2 receiver
+    VidIfBridge,
+    /// Digital video decoder
+    DvDecoder,
+    /// Digital video encoder
+    DvEncoder,
+    /// Any other function
+    Other(u32),
+}
+
+impl From<u32> for Function {
+    fn from(function: u32) -> Self {
+        match function {
+            0x0000_0000 => Function::Unknown,
+            0x0001_0001 => Function::IoV4l,
+            0x0002_0001 => Function::CamSensor,
+            0x0002_0002 => Function::Flash,
+            0x0002_0003 => Function::Lens,
+            0x0002_0004 => Function::AtvDecoder,
+            0x0000_4001 => Function::ProcVideoComposer,
+            0x0000_4002 => Function::ProcVideoPixelFormatter,
+            0x0000_4003 => Function::ProcVideoPixelEncConv,
+            0x0000_4004 => Function::ProcVideoLut,
+            0x0000_4005 => Function::ProcVideoScaler,
+            0x0000_4006 => Function::ProcVideoStatistics,
+            0x0000_4007 => Function::ProcVideoEncoder,
+            0x0000_4008 => Function::ProcVideoDecoder,
+            0x0000_4009 => Function::ProcVideoIsp,
+            0x0000_5001 => Function::VidMux,
+            0x0000_5002 => Function::VidIfBridge,
+            0x0000_6001 => Function::DvDecoder,
+            0x0000_6002 => Function::DvEncoder,
+            function => Function::Other(function),
+        }
+    }
+}
+
+impl From<Function> for u32 {
+    fn from(function: Function) -> Self {
+        match function {
+            Function::Unknown => 0x0000_0000,
+            Function::IoV4l => 0x0001_0001,
+            Function::CamSensor => 0x0002_0001,
+            Function::Flash => 0x0002_0002,
+            Function::Lens => 0x0002_0003,
+            Function::AtvDecoder => 0x0002_0004,
+            Function::ProcVideoComposer => 0x0000_4001,
+            Function::ProcVideoPixelFormatter => 0x0000_4002,
+            Function::ProcVideoPixelEncConv => 0x0000_4003,
+            Function::ProcVideoLut => 0x0000_4004,
+            Function::ProcVideoScaler => 0x0000_4005,
+            Function::ProcVideoStatistics => 0x0000_4006,
+            Function::ProcVideoEncoder => 0x0000_4007,
+            Function::ProcVideoDecoder => 0x0000_4008,
+            Function::ProcVideoIsp => 0x0000_4009,
+            Function::VidMux => 0x0000_5001,
+            Function::VidIfBridge => 0x0000_5002,
+            Function::DvDecoder => 0x0000_6001,
+            Function::DvEncoder => 0x0000_6002,
+            Function::Other(function) => function,
+        }
+    }
+}
+
+bitflags! {
+    pub struct EntityFlags: u32 {
+        const DEFAULT   = 0x00000001;
+        const CONNECTOR = 0x00000002;
+    }
+}
+
+bitflags! {
+    pub struct PadFlags: u32 {
+        const SINK         = 0x00000001;
+        const SOURCE       = 0x00000002;
+        const MUST_CONNECT = 0x00000004;
+    }
+}
+
+bitflags! {
+    pub struct LinkFlags: u32 {
+        const ENABLED   = 0x00000001;
+        const IMMUTABLE = 0x00000002;
+        const DYNAMIC   = 0x00000004;
+    }
+}
+
+impl fmt::Display for EntityFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl fmt::Display for PadFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl fmt::Display for LinkFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Type of a link
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LinkType {
+    /// Data flows between two pads
+    Data,
+    /// Associates an interface (device node) with an entity
+    Interface,
+    /// Associates two entities, e.g. a lens with its sensor
+    Ancillary,
+    /// Any other type
+    Other(u32),
+}
+
+/// Type of an interface
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum InterfaceType {
+    /// Video device node (/dev/videoX)
+    V4lVideo,
+    /// VBI device node (/dev/vbiX)
+    V4lVbi,
+    /// Radio device node (/dev/radioX)
+    V4lRadio,
+    /// Sub-device node (/dev/v4l-subdevX)
+    V4lSubdev,
+    /// Software defined radio device node (/dev/swradioX)
+    V4lSwradio,
+    /// Touch device node (/dev/v4l-touchX)
+    V4lTouch,
+    /// Any other type, e.g. DVB or ALSA interfaces
+    Other(u32),
+}
+
+impl From<u32> for InterfaceType {
+    fn from(typ: u32) -> Self {
+        match typ {
+            0x0200 => InterfaceType::V4lVideo,
+            0x0201 => InterfaceType::V4lVbi,
+            0x0202 => InterfaceType::V4lRadio,
+            0x0203 => InterfaceType::V4lSubdev,
+            0x0204 => InterfaceType::V4lSwradio,
+            0x0205 => InterfaceType::V4lTouch,
+            typ => InterfaceType::Other(typ),
+        }
+    }
+}
+
+/// Hardware or software component of the media graph, e.g. a sensor or a DMA engine
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entity {
+    /// Unique identifier
+    pub id: u32,
+    /// Name, unique within the media device
+    pub name: String,
+    /// Main function
+    pub function: Function,
+    /// Entity flags
+    pub flags: EntityFlags,
+}
+
+impl From<media_v2_entity> for Entity {
+    fn from(entity: media_v2_entity) -> Self {
+        Entity {
+            id: entity.id,
+            name: str::from_utf8(&entity.name)
+                .unwrap_or_default()
+                .trim_matches(char::from(0))
+                .to_string(),
+            function: Function::from(entity.function),
+            flags: EntityFlags::from_bits_truncate(entity.flags),
+        }
+    }
+}
+
+impl fmt::Display for Entity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}): {:?}", self.name, self.id, self.function)
+    }
+}
+
+/// Device node through which an entity can be controlled
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Interface {
+    /// Unique identifier
+    pub id: u32,
+    /// Interface type
+    pub typ: InterfaceType,
+    /// Major number of the device node
+    pub major: u32,
+    /// Minor number of the device node
+    pub minor: u32,
+}
+
+impl Interface {
+    /// Returns the path of the device node
+    ///
+    /// The path is resolved through sysfs, so it is correct even if udev rules use custom
+    /// names. Returns `None` if the device node cannot be found.
+    pub fn devnode(&self) -> Option<PathBuf> {
+        let uevent = fs::read_to_string(format!(
+            "/sys/dev/char/{}:{}/uevent",
+            self.major, self.minor
+        ))
+        .ok()?;
+        uevent
+            .lines()
+            .find_map(|line| line.strip_prefix("DEVNAME="))
+            .map(|name| PathBuf::from("/dev").join(name))
+    }
+}
+
+impl From<media_v2_interface> for Interface {
+    fn from(intf: media_v2_interface) -> Self {
+        let raw = intf.raw;
+        Interface {
+            id: intf.id,
+            typ: InterfaceType::from(intf.intf_type),
+            major: raw[0],
+            minor: raw[1],
+        }
+    }
+}
+
+/// Connection point of an entity
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Pad {
+    /// Unique identifier
+    pub id: u32,
+    /// Identifier of the entity the pad belongs to
+    pub entity: u32,
+    /// Index of the pad within its entity, as used by sub-device calls
+    pub index: u32,
+    /// Pad flags
+    pub flags: PadFlags,
+}
+
+impl From<media_v2_pad> for Pad {
+    fn from(pad: media_v2_pad) -> Self {
+        Pad {
+            id: pad.id,
+            entity: pad.entity_id,
+            index: pad.index,
+            flags: PadFlags::from_bits_truncate(pad.flags),
+        }
+    }
+}
+
+/// Connection between two pads, or between an interface and an entity
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Link {
+    /// Unique identifier
+    pub id: u32,
+    /// Identifier of the source pad (or interface for interface links)
+    pub source: u32,
+    /// Identifier of the sink pad (or entity for interface links)
+    pub sink: u32,
+    /// Link type
+    pub typ: LinkType,
+    /// Link flags
+    pub flags: LinkFlags,
+}
+
+impl From<media_v2_link> for Link {
+    fn from(link: media_v2_link) -> Self {
+        let typ = match link.flags & MEDIA_LNK_FL_LINK_TYPE {
+            MEDIA_LNK_FL_DATA_LINK => LinkType::Data,
+            MEDIA_LNK_FL_INTERFACE_LINK => LinkType::Interface,
+            MEDIA_LNK_FL_ANCILLARY_LINK => LinkType::Ancillary,
+            typ => LinkType::Other(typ >> 28),
+        };
+
+        Link {
+            id: link.id,
+            source: link.source_id,
+            sink: link.sink_id,
+            typ,
+            flags: LinkFlags::from_bits_truncate(link.flags & !MEDIA_LNK_FL_LINK_TYPE),
+        }
+    }
+}
+
+/// Snapshot of the media graph
+#[derive(Debug, Clone)]
+pub struct Topology {
+    /// Version of the graph, changes whenever the graph is modified
+    pub version: u64,
+    /// Entities
+    pub entities: Vec<Entity>,
+    /// Interfaces
+    pub interfaces: Vec<Interface>,
+    /// Pads
+    pub pads: Vec<Pad>,
+    /// Links
+    pub links: Vec<Link>,
+}
+
+impl Topology {
+    /// Returns the entity with the given ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Entity identifier
+    pub fn entity(&self, id: u32) -> Option<&Entity> {
+        self.entities.iter().find(|entity| entity.id == id)
+    }
+
+    /// Returns the entity with the given name, e.g. "imx290 1-001a"
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Entity name
+    pub fn entity_by_name(&self, name: &str) -> Option<&Entity> {
+        self.entities.iter().find(|entity| entity.name == name)
+    }
+
+    /// Returns all entities with the given function
+    ///
+    /// # Arguments
+    ///
+    /// * `function` - Entity function
+    pub fn entities_by_function(&self, function: Function) -> Vec<&Entity> {
+        self.entities
+            .iter()
+            .filter(|entity| entity.function == function)
+            .collect()
+    }
+
+    /// Returns the pads of an entity, ordered by their index
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - Entity identifier
+    pub fn pads(&self, entity: u32) -> Vec<&Pad> {
+        let mut pads: Vec<_> = self
+            .pads
+            .iter()
+            .filter(|pad| pad.entity == entity)
+            .collect();
+        pads.sort_by_key(|pad| pad.index);
+        pads
+    }
+
+    /// Returns the pad with the given ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Pad identifier
+    pub fn pad(&self, id: u32) -> Option<&Pad> {
+        self.pads.iter().find(|pad| pad.id == id)
+    }
+
+    /// Returns the interface (device node) of an entity
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - Entity identifier
+    pub fn interface(&self, entity: u32) -> Option<&Interface> {
+        self.links
+            .iter()
+            .filter(|link| link.typ == LinkType::Interface && link.sink == entity)
+            .find_map(|link| self.interfaces.iter().find(|intf| intf.id == link.source))
+    }
+
+    /// Returns the path of the device node of an entity
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - Entity identifier
+    pub fn devnode(&self, entity: u32) -> Option<PathBuf> {
+        self.interface(entity)?.devnode()
+    }
+}
@@ -10,7 +10,7 @@ use crate::v4l2;
 #[allow(clippy::unreadable_literal)]
 #[rustfmt::skip]
 #[repr(u32)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Memory {
     Mmap        = 1,
     UserPtr     = 2,
@@ -0,0 +1,326 @@
+//! Stream metrics
+//!
+//! Long-running capture applications want to know their frame rate, how many frames the driver
+//! dropped and how often operations failed, usually in whatever telemetry system they use
+//! already. Instead of depending on a metrics library, the crate reports these to an
+//! [`Observer`]: [`Observed`] wraps a capture stream and calls the observer for every frame and
+//! error. [`Counters`] is a ready-made observer which accumulates the values, e.g. to be
+//! exported periodically.
+
+use std::{
+    io,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::buffer::{Metadata, Type};
+use crate::device::{Handle, VideoDevice};
+use crate::io::traits::{CaptureStream, Stream};
+use crate::media::Request;
+use crate::v4l_sys::*;
+
+/// Metrics of a single dequeued frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sample {
+    /// Sequence number reported by the driver
+    pub sequence: u32,
+    /// Number of bytes used
+    pub bytesused: u32,
+    /// Frames the driver dropped since the previous frame, according to the sequence numbers
+    pub dropped: u32,
+    /// Time since the previous frame was dequeued, `None` for the first frame
+    pub interval: Option<Duration>,
+}
+
+impl Sample {
+    /// Returns the frame rate derived from the interval, `None` for the first frame
+    pub fn fps(&self) -> Option<f64> {
+        self.interval
+            .filter(|interval| !interval.is_zero())
+            .map(|interval| 1.0 / interval.as_secs_f64())
+    }
+}
+
+/// Receiver of stream metrics
+///
+/// All methods do nothing by default, observers implement the ones they are interested in.
+/// They are called on the capturing thread and should return quickly.
+pub trait Observer: Send + Sync {
+    /// Called for every dequeued frame
+    fn frame(&self, _sample: &Sample) {}
+
+    /// Called for every failed stream operation, including timeouts
+    fn error(&self, _e: &io::Error) {}
+
+    /// Called with the number of buffers queued in the driver after a frame was dequeued
+    ///
+    /// Only reported if the stream was wrapped with [`Observed::with_device`].
+    fn queue_depth(&self, _depth: usize) {}
+}
+
+impl<O: Observer + ?Sized> Observer for Arc<O> {
+    fn frame(&self, sample: &Sample) {
+        (**self).frame(sample)
+    }
+
+    fn error(&self, e: &io::Error) {
+        (**self).error(e)
+    }
+
+    fn queue_depth(&self, depth: usize) {
+        (**self).queue_depth(depth)
+    }
+}
+
+/// Observer accumulating the metrics in atomic counters
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+/// use v4l::buffer::Type;
+/// use v4l::io::traits::CaptureStream;
+/// use v4l::metrics::{Counters, Observed};
+/// use v4l::prelude::*;
+///
+/// if let Ok(dev) = Device::new(0) {
+///     let counters = Arc::new(Counters::new());
+///     let stream = MmapStream::new(&dev, Type::VideoCapture).unwrap();
+///     let mut stream = Observed::new(stream, counters.clone());
+///
+///     for _ in 0..30 {
+///         stream.next().unwrap();
+///     }
+///     println!("{} frames, {} dropped", counters.frames(), counters.dropped());
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct Counters {
+    frames: AtomicU64,
+    dropped: AtomicU64,
+    errors: AtomicU64,
+    bytes: AtomicU64,
+    /// Latest frame interval in nanoseconds
+    interval: AtomicU64,
+    queue_depth: AtomicUsize,
+}
+
+impl Counters {
+    /// Returns counters starting at zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of frames dequeued
+    pub fn frames(&self) -> u64 {
+        self.frames.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of frames dropped by the driver
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of failed operations
+    pub fn errors(&self) -> u64 {
+        self.errors.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of bytes captured
+    pub fn bytes(&self) -> u64 {
+        self.bytes.load(Ordering::Relaxed)
+    }
+
+    /// Returns the frame rate derived from the latest frame interval
+    pub fn fps(&self) -> Option<f64> {
+        match self.interval.load(Ordering::Relaxed) {
+            0 => None,
+            nanos => Some(1e9 / nanos as f64),
+        }
+    }
+
+    /// Returns the latest number of buffers queued in the driver
+    pub fn queue_depth(&self) -> usize {
+        self.queue_depth.load(Ordering::Relaxed)
+    }
+}
+
+impl Observer for Counters {
+    fn frame(&self, sample: &Sample) {
+        self.frames.fetch_add(1, Ordering::Relaxed);
+        self.dropped
+            .fetch_add(sample.dropped as u64, Ordering::Relaxed);
+        self.bytes
+            .fetch_add(sample.bytesused as u64, Ordering::Relaxed);
+        if let Some(interval) = sample.interval {
+            self.interval
+                .store(interval.as_nanos() as u64, Ordering::Relaxed);
+        }
+    }
+
+    fn error(&self, _e: &io::Error) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn queue_depth(&self, depth: usize) {
+        self.queue_depth.store(depth, Ordering::Relaxed);
+    }
+}
+
+/// Capture stream wrapper reporting metrics to an observer
+///
+/// Frames are reported by `next()`. Buffers dequeued manually only report errors, their
+/// metadata is not known until they are accessed.
+pub struct Observed<S, O> {
+    stream: S,
+    observer: O,
+    /// Device and buffer type to query the queue depth from
+    queue: Option<(Arc<Handle>, Type)>,
+    /// Sequence number and dequeue time of the previous frame
+    last: Option<(u32, Instant)>,
+}
+
+impl<S, O: Observer> Observed<S, O> {
+    /// Returns a stream reporting to the observer
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - Capture stream
+    /// * `observer` - Receiver of the metrics
+    pub fn new(stream: S, observer: O) -> Self {
+        Observed {
+            stream,
+            observer,
+            queue: None,
+            last: None,
+        }
+    }
+
+    /// Reports the queue depth as well
+    ///
+    /// The buffers are queried from the device after each frame, which costs an ioctl per
+    /// buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `dev` - Device the stream belongs to
+    /// * `buf_type` - Type of the buffers of the stream
+    pub fn with_device<D: VideoDevice>(mut self, dev: &D, buf_type: Type) -> Self {
+        self.queue = Some((dev.handle(), buf_type));
+        self
+    }
+
+    /// Returns the observer
+    pub fn observer(&self) -> &O {
+        &self.observer
+    }
+
+    /// Returns a reference to the wrapped stream
+    pub fn get_ref(&self) -> &S {
+        &self.stream
+    }
+
+    /// Returns a mutable reference to the wrapped stream
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.stream
+    }
+
+    /// Returns the wrapped stream
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+}
+
+/// Reports a dequeued frame, the stream itself is still borrowed by the frame
+fn report<O: Observer>(
+    observer: &O,
+    queue: &Option<(Arc<Handle>, Type)>,
+    last: &mut Option<(u32, Instant)>,
+    meta: &Metadata,
+) {
+    let now = Instant::now();
+    let (dropped, interval) = match *last {
+        Some((sequence, time)) => (
+            meta.sequence.wrapping_sub(sequence).saturating_sub(1),
+            Some(now - time),
+        ),
+        None => (0, None),
+    };
+    *last = Some((meta.sequence, now));
+
+    observer.frame(&Sample {
+        sequence: meta.sequence,
+        bytesused: meta.bytesused,
+        dropped,
+        interval,
+    });
+
+    if let Some((handle, buf_type)) = queue {
+        // the driver reports EINVAL for the first index past the last buffer
+        let depth = (0..)
+            .map_while(|index| handle.query_buf(*buf_type, meta.memory, index).ok())
+            .filter(|info| info.is_queued())
+            .count();
+        observer.queue_depth(depth);
+    }
+}
+
+impl<S: Stream, O> Stream for Observed<S, O> {
+    type Item = S::Item;
+
+    fn start(&mut self) -> io::Result<()> {
+        self.stream.start()
+    }
+
+    fn stop(&mut self) -> io::Result<()> {
+        // frames are not dropped while the stream is stopped
+        self.last = None;
+        self.stream.stop()
+    }
+
+    fn is_active(&self) -> bool {
+        self.stream.is_active()
+    }
+}
+
+impl<'a, S: CaptureStream<'a>, O: Observer> CaptureStream<'a> for Observed<S, O> {
+    fn poll(&self) -> io::Result<bool> {
+        self.stream.poll()
+    }
+
+    fn queue(&mut self, index: usize) -> io::Result<()> {
+        self.stream.queue(index)
+    }
+
+    fn queue_request(&mut self, index: usize, request: Option<&Request>) -> io::Result<()> {
+        self.stream.queue_request(index, request)
+    }
+
+    fn dequeue(&mut self) -> io::Result<usize> {
+        let res = self.stream.dequeue();
+        if let Err(e) = &res {
+            self.observer.error(e);
+        }
+        res
+    }
+
+    fn get(&self, index: usize) -> io::Result<(&Self::Item, &Metadata, &[v4l2_plane])> {
+        self.stream.get(index)
+    }
+
+    fn next(&'a mut self) -> io::Result<(&'a Self::Item, &'a Metadata, &'a [v4l2_plane])> {
+        match self.stream.next() {
+            Ok(frame) => {
+                report(&self.observer, &self.queue, &mut self.last, frame.1);
+                Ok(frame)
+            }
+            Err(e) => {
+                self.observer.error(&e);
+                Err(e)
+            }
+        }
+    }
+}
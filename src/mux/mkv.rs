@@ -0,0 +1,384 @@
+use std::io::{self, Write};
+
+use crate::buffer::Metadata;
+use crate::mux::{Codec, Samples, Sink, Track};
+
+const EBML: u32 = 0x1a45_dfa3;
+const EBML_VERSION: u32 = 0x4286;
+const EBML_READ_VERSION: u32 = 0x42f7;
+const EBML_MAX_ID_LENGTH: u32 = 0x42f2;
+const EBML_MAX_SIZE_LENGTH: u32 = 0x42f3;
+const DOC_TYPE: u32 = 0x4282;
+const DOC_TYPE_VERSION: u32 = 0x4287;
+const DOC_TYPE_READ_VERSION: u32 = 0x4285;
+const SEGMENT: u32 = 0x1853_8067;
+const INFO: u32 = 0x1549_a966;
+const TIMESTAMP_SCALE: u32 = 0x2a_d7b1;
+const MUXING_APP: u32 = 0x4d80;
+const WRITING_APP: u32 = 0x5741;
+const TRACKS: u32 = 0x1654_ae6b;
+const TRACK_ENTRY: u32 = 0xae;
+const TRACK_NUMBER: u32 = 0xd7;
+const TRACK_UID: u32 = 0x73c5;
+const TRACK_TYPE: u32 = 0x83;
+const FLAG_LACING: u32 = 0x9c;
+const DEFAULT_DURATION: u32 = 0x23_e383;
+const CODEC_ID: u32 = 0x86;
+const CODEC_PRIVATE: u32 = 0x63a2;
+const VIDEO: u32 = 0xe0;
+const PIXEL_WIDTH: u32 = 0xb0;
+const PIXEL_HEIGHT: u32 = 0xba;
+const CLUSTER: u32 = 0x1f43_b675;
+const TIMESTAMP: u32 = 0xe7;
+const SIMPLE_BLOCK: u32 = 0xa3;
+
+/// Size of elements whose size is not known upfront
+const UNKNOWN_SIZE: [u8; 8] = [0x01, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff];
+
+/// Appends the ID of an element
+fn put_id(buf: &mut Vec<u8>, id: u32) {
+    let bytes = id.to_be_bytes();
+    let skip = bytes.iter().take_while(|&&byte| byte == 0).count();
+    buf.extend_from_slice(&bytes[skip..]);
+}
+
+/// Appends the size of an element, always encoded using eight bytes
+fn put_size(buf: &mut Vec<u8>, size: usize) {
+    buf.extend_from_slice(&(size as u64 | 1 << 56).to_be_bytes());
+}
+
+/// Appends an element
+fn put_element(buf: &mut Vec<u8>, id: u32, data: &[u8]) {
+    put_id(buf, id);
+    put_size(buf, data.len());
+    buf.extend_from_slice(data);
+}
+
+/// Appends an unsigned integer element using as few bytes as possible
+fn put_uint(buf: &mut Vec<u8>, id: u32, val: u64) {
+    let bytes = val.to_be_bytes();
+    let skip = bytes.iter().take_while(|&&byte| byte == 0).count().min(7);
+    put_element(buf, id, &bytes[skip..]);
+}
+
+/// Appends a master element whose children are written by `content`
+fn put_master(buf: &mut Vec<u8>, id: u32, content: impl FnOnce(&mut Vec<u8>)) {
+    let mut children = Vec::new();
+    content(&mut children);
+    put_element(buf, id, &children);
+}
+
+/// Matroska writer
+///
+/// The header is written as soon as the first keyframe along with its parameter sets arrives.
+/// Frames are collected into clusters which start at a keyframe, a cluster is written once the
+/// next one begins. The segment size is left unspecified, so the output can be played while it
+/// is being written and does not need to be seekable.
+///
+/// # Example
+///
+/// ```
+/// use std::fs::File;
+/// use v4l::buffer::Type;
+/// use v4l::frame::Frame;
+/// use v4l::mux::{mkv::MkvWriter, Codec, Sink, Track};
+/// use v4l::prelude::*;
+///
+/// if let Ok(dev) = Device::new(0) {
+///     let track = Track::new(Codec::H264, 1920, 1080);
+///     let mut mkv = MkvWriter::new(File::create("capture.mkv").unwrap(), track);
+///
+///     let mut stream = MmapStream::new(&dev, Type::VideoCapture).unwrap();
+///     for _ in 0..300 {
+///         mkv.write_frame(&Frame::capture(&mut stream).unwrap()).unwrap();
+///     }
+///     mkv.finish().unwrap();
+/// }
+/// ```
+pub struct MkvWriter<W: Write> {
+    output: W,
+    samples: Samples,
+    /// Whether the header was written already
+    initialized: bool,
+    /// Timestamp of the current cluster in milliseconds
+    cluster: u64,
+    /// Blocks of the current cluster
+    blocks: Vec<u8>,
+}
+
+impl<W: Write> MkvWriter<W> {
+    /// Returns a writer
+    ///
+    /// # Arguments
+    ///
+    /// * `output` - Output to write the file to, does not need to be seekable
+    /// * `track` - Video track description
+    pub fn new(output: W, track: Track) -> Self {
+        MkvWriter {
+            output,
+            samples: Samples::new(track),
+            initialized: false,
+            cluster: 0,
+            blocks: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the output
+    pub fn get_ref(&self) -> &W {
+        &self.output
+    }
+
+    /// Returns a mutable reference to the output
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.output
+    }
+
+    fn write_header(&mut self) -> io::Result<()> {
+        let track = self.samples.track;
+        let config = self.samples.decoder_config()?;
+        let codec = match track.codec {
+            Codec::H264 => "V_MPEG4/ISO/AVC",
+            Codec::Hevc => "V_MPEGH/ISO/HEVC",
+        };
+        let app = concat!("libv4l-rs ", env!("CARGO_PKG_VERSION"));
+
+        let mut buf = Vec::new();
+        put_master(&mut buf, EBML, |buf| {
+            put_uint(buf, EBML_VERSION, 1);
+            put_uint(buf, EBML_READ_VERSION, 1);
+            put_uint(buf, EBML_MAX_ID_LENGTH, 4);
+            put_uint(buf, EBML_MAX_SIZE_LENGTH, 8);
+            put_element(buf, DOC_TYPE, b"matroska");
+            put_uint(buf, DOC_TYPE_VERSION, 4);
+            put_uint(buf, DOC_TYPE_READ_VERSION, 2);
+        });
+
+        put_id(&mut buf, SEGMENT);
+        buf.extend_from_slice(&UNKNOWN_SIZE);
+
+        put_master(&mut buf, INFO, |buf| {
+            // timestamps are in milliseconds
+            put_uint(buf, TIMESTAMP_SCALE, 1_000_000);
+            put_element(buf, MUXING_APP, app.as_bytes());
+            put_element(buf, WRITING_APP, app.as_bytes());
+        });
+
+        put_master(&mut buf, TRACKS, |buf| {
+            put_master(buf, TRACK_ENTRY, |buf| {
+                put_uint(buf, TRACK_NUMBER, 1);
+                put_uint(buf, TRACK_UID, 1);
+                // video
+                put_uint(buf, TRACK_TYPE, 1);
+                put_uint(buf, FLAG_LACING, 0);
+                put_uint(buf, DEFAULT_DURATION, self.samples.interval() * 1000);
+                put_element(buf, CODEC_ID, codec.as_bytes());
+                put_element(buf, CODEC_PRIVATE, &config);
+                put_master(buf, VIDEO, |buf| {
+                    put_uint(buf, PIXEL_WIDTH, u64::from(track.width));
+                    put_uint(buf, PIXEL_HEIGHT, u64::from(track.height));
+                });
+            });
+        });
+
+        self.output.write_all(&buf)
+    }
+
+    fn write_cluster(&mut self) -> io::Result<()> {
+        if self.blocks.is_empty() {
+            return Ok(());
+        }
+
+        let mut timestamp = Vec::new();
+        put_uint(&mut timestamp, TIMESTAMP, self.cluster);
+
+        // the blocks are written separately to avoid copying them
+        let mut buf = Vec::new();
+        put_id(&mut buf, CLUSTER);
+        put_size(&mut buf, timestamp.len() + self.blocks.len());
+        buf.extend_from_slice(&timestamp);
+
+        self.output.write_all(&buf)?;
+        self.output.write_all(&self.blocks)?;
+        self.blocks.clear();
+        Ok(())
+    }
+}
+
+impl<W: Write> Sink for MkvWriter<W> {
+    fn write(&mut self, data: &[u8], meta: &Metadata) -> io::Result<()> {
+        let sample = match self.samples.push(data, meta) {
+            Some(sample) => sample,
+            None => return Ok(()),
+        };
+
+        if !self.initialized {
+            self.write_header()?;
+            self.initialized = true;
+        }
+
+        // block timestamps are signed 16 bit offsets relative to the cluster
+        let pts = sample.pts / 1000;
+        if sample.keyframe || self.blocks.is_empty() || pts - self.cluster > i16::MAX as u64 {
+            self.write_cluster()?;
+            self.cluster = pts;
+        }
+
+        let mut block = Vec::with_capacity(sample.data.len() + 4);
+        // track number as variable size integer
+        block.push(0x81);
+        block.extend_from_slice(&((pts - self.cluster) as i16).to_be_bytes());
+        block.push(if sample.keyframe { 0x80 } else { 0x00 });
+        block.extend_from_slice(&sample.data);
+        put_element(&mut self.blocks, SIMPLE_BLOCK, &block);
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.write_cluster()?;
+        self.output.flush()
+    }
+}
+
+impl<W: Write> Drop for MkvWriter<W> {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mux::tests::{annexb, keyframe, meta, IDR, SLICE};
+
+    /// Returns the value of a variable size integer and its length, `None` for unknown sizes
+    fn vint(data: &[u8], keep_marker: bool) -> (Option<u64>, usize) {
+        let len = data[0].leading_zeros() as usize + 1;
+        let mut val = data[..len]
+            .iter()
+            .fold(0u64, |val, &byte| val << 8 | u64::from(byte));
+        let marker = 1 << (7 * len);
+        if val == marker | (marker - 1) {
+            return (None, len);
+        }
+        if !keep_marker {
+            val &= marker - 1;
+        }
+        (Some(val), len)
+    }
+
+    /// Returns the ID and data of the elements following each other in `data`
+    ///
+    /// Elements of unknown size extend to the end of the data.
+    fn elements(mut data: &[u8]) -> Vec<(u32, &[u8])> {
+        let mut elements = Vec::new();
+        while !data.is_empty() {
+            let (id, id_len) = vint(data, true);
+            let (size, size_len) = vint(&data[id_len..], false);
+            let start = id_len + size_len;
+            let end = size.map_or(data.len(), |size| start + size as usize);
+            elements.push((id.unwrap() as u32, &data[start..end]));
+            data = &data[end..];
+        }
+        elements
+    }
+
+    fn child(data: &[u8], id: u32) -> &[u8] {
+        elements(data)
+            .into_iter()
+            .find(|(i, _)| *i == id)
+            .unwrap_or_else(|| panic!("missing element {:x}", id))
+            .1
+    }
+
+    #[test]
+    fn encodes_integers_with_few_bytes() {
+        let mut buf = Vec::new();
+        put_uint(&mut buf, TRACK_NUMBER, 0);
+        put_uint(&mut buf, TIMESTAMP_SCALE, 1_000_000);
+        #[rustfmt::skip]
+        assert_eq!(buf, [
+            0xd7, 0x01, 0, 0, 0, 0, 0, 0, 0x01, 0x00,
+            0x2a, 0xd7, 0xb1, 0x01, 0, 0, 0, 0, 0, 0, 0x03, 0x0f, 0x42, 0x40,
+        ]);
+    }
+
+    #[test]
+    fn writes_a_cluster_per_group_of_pictures() {
+        let mut mkv = MkvWriter::new(Vec::new(), Track::new(Codec::H264, 64, 48));
+        mkv.write(&annexb(&[&SLICE]), &meta(0)).unwrap();
+        assert!(mkv.get_ref().is_empty());
+
+        for (i, ms) in [1000, 1040, 1080, 1120, 1160].iter().enumerate() {
+            let data = if i % 3 == 0 {
+                keyframe()
+            } else {
+                annexb(&[&SLICE])
+            };
+            mkv.write(&data, &meta(*ms)).unwrap();
+        }
+        mkv.finish().unwrap();
+
+        let output = mkv.get_ref().clone();
+        let top = elements(&output);
+        assert_eq!(top.len(), 2);
+        assert_eq!(top[0].0, EBML);
+        assert_eq!(child(top[0].1, DOC_TYPE), b"matroska");
+        assert_eq!(top[1].0, SEGMENT);
+
+        let segment = elements(top[1].1);
+        let ids: Vec<_> = segment.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, [INFO, TRACKS, CLUSTER, CLUSTER]);
+
+        let track = child(segment[1].1, TRACK_ENTRY);
+        assert_eq!(child(track, CODEC_ID), b"V_MPEG4/ISO/AVC");
+        assert_eq!(&child(track, CODEC_PRIVATE)[..4], &[1, 0x64, 0x00, 0x1f]);
+        // 33.333 ms in nanoseconds, the interval is kept in microseconds
+        assert_eq!(child(track, DEFAULT_DURATION), &[0x01, 0xfc, 0x9f, 0x08]);
+        assert_eq!(child(child(track, VIDEO), PIXEL_WIDTH), &[64]);
+
+        // (cluster timestamp, [(relative timestamp, flags, payload)])
+        let clusters: Vec<_> = segment[2..]
+            .iter()
+            .map(|(_, cluster)| {
+                let elements = elements(cluster);
+                assert_eq!(elements[0].0, TIMESTAMP);
+                let blocks: Vec<_> = elements[1..]
+                    .iter()
+                    .map(|(id, block)| {
+                        assert_eq!(*id, SIMPLE_BLOCK);
+                        assert_eq!(block[0], 0x81);
+                        let ts = i16::from_be_bytes([block[1], block[2]]);
+                        (ts, block[3], &block[8..])
+                    })
+                    .collect();
+                (elements[0].1, blocks)
+            })
+            .collect();
+        assert_eq!(
+            clusters,
+            [
+                (
+                    &[0][..],
+                    vec![(0, 0x80, &IDR[..]), (40, 0, &SLICE), (80, 0, &SLICE)]
+                ),
+                (&[120][..], vec![(0, 0x80, &IDR[..]), (40, 0, &SLICE)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn starts_clusters_before_block_timestamps_overflow() {
+        let mut mkv = MkvWriter::new(Vec::new(), Track::new(Codec::H264, 64, 48));
+        mkv.write(&keyframe(), &meta(0)).unwrap();
+        mkv.write(&annexb(&[&SLICE]), &meta(40_000)).unwrap();
+        mkv.finish().unwrap();
+
+        let output = mkv.get_ref().clone();
+        let segment = elements(elements(&output)[1].1);
+        let timestamps: Vec<_> = segment[2..]
+            .iter()
+            .map(|(_, cluster)| child(cluster, TIMESTAMP))
+            .collect();
+        assert_eq!(timestamps, [&[0][..], &[0x9c, 0x40]]);
+    }
+}
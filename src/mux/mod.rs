@@ -0,0 +1,493 @@
+//! Muxing of encoded video into container files
+//!
+//! Encoded capture (e.g. from a hardware encoder or a UVC camera delivering H264) results in a
+//! raw Annex B byte stream which most players refuse to open. The sinks in here wrap the stream
+//! into a container, using the buffer timestamps for timing:
+//!
+//! * [`mp4::Mp4Writer`] writes fragmented MP4, one fragment per frame
+//! * [`mkv::MkvWriter`] writes Matroska, one cluster per group of pictures
+//!
+//! Both formats can be written to non-seekable outputs such as pipes or sockets. Frames in front
+//! of the first keyframe are discarded because they cannot be decoded anyway.
+//!
+//! This module is only available with the `mux` feature.
+
+use std::io;
+
+use crate::buffer::Metadata;
+use crate::codec::annexb;
+use crate::format::FourCC;
+use crate::fraction::Fraction;
+use crate::frame::Frame;
+
+pub mod mkv;
+pub mod mp4;
+
+/// Video codec of the encoded stream
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Codec {
+    /// H.264 / AVC
+    H264,
+    /// H.265 / HEVC
+    Hevc,
+}
+
+impl Codec {
+    /// Returns the codec of a pixel format, if it is supported
+    ///
+    /// # Arguments
+    ///
+    /// * `fourcc` - Pixel format of the device
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::mux::Codec;
+    /// use v4l::FourCC;
+    ///
+    /// assert_eq!(Codec::from_fourcc(&FourCC::new(b"H264")), Some(Codec::H264));
+    /// assert_eq!(Codec::from_fourcc(&FourCC::new(b"MJPG")), None);
+    /// ```
+    pub fn from_fourcc(fourcc: &FourCC) -> Option<Self> {
+        match &fourcc.repr {
+            b"H264" => Some(Codec::H264),
+            b"HEVC" => Some(Codec::Hevc),
+            _ => None,
+        }
+    }
+
+    fn classify(self, nal: &[u8]) -> NalKind {
+        let header = nal.first().copied().unwrap_or(0);
+        match self {
+            Codec::H264 => match header & 0x1f {
+                5 => NalKind::Keyframe,
+                7 => NalKind::Sps,
+                8 => NalKind::Pps,
+                9 => NalKind::Aud,
+                _ => NalKind::Other,
+            },
+            Codec::Hevc => match (header >> 1) & 0x3f {
+                // IRAP pictures (BLA, IDR, CRA)
+                16..=23 => NalKind::Keyframe,
+                32 => NalKind::Vps,
+                33 => NalKind::Sps,
+                34 => NalKind::Pps,
+                35 => NalKind::Aud,
+                _ => NalKind::Other,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum NalKind {
+    Vps,
+    Sps,
+    Pps,
+    Aud,
+    Keyframe,
+    Other,
+}
+
+/// Description of the video track
+#[derive(Debug, Copy, Clone)]
+pub struct Track {
+    /// Codec of the encoded stream
+    pub codec: Codec,
+    /// Width in pixels
+    pub width: u32,
+    /// Height in pixels
+    pub height: u32,
+    /// Nominal frame interval in seconds, used for buffers without valid timestamps
+    pub interval: Fraction,
+}
+
+impl Track {
+    /// Returns a track description with a nominal frame rate of 30 fps
+    ///
+    /// # Arguments
+    ///
+    /// * `codec` - Codec of the encoded stream
+    /// * `width` - Width in pixels
+    /// * `height` - Height in pixels
+    pub fn new(codec: Codec, width: u32, height: u32) -> Self {
+        Track {
+            codec,
+            width,
+            height,
+            interval: Fraction::new(1, 30),
+        }
+    }
+}
+
+/// Sink consuming encoded frames
+pub trait Sink {
+    /// Writes an encoded buffer
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Annex B byte stream of one frame, as dequeued from the device
+    /// * `meta` - Buffer metadata, its timestamp is used for timing
+    fn write(&mut self, data: &[u8], meta: &Metadata) -> io::Result<()>;
+
+    /// Writes a captured frame
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - Captured frame
+    fn write_frame(&mut self, frame: &Frame) -> io::Result<()> {
+        self.write(frame.data(), &frame.meta)
+    }
+
+    /// Writes everything which is still buffered and flushes the output
+    ///
+    /// Sinks finish automatically when they are dropped, but errors are lost then.
+    fn finish(&mut self) -> io::Result<()>;
+}
+
+/// Encoded frame with its NAL units converted to length prefixed form
+struct Sample {
+    /// Presentation time relative to the first keyframe in microseconds
+    pts: u64,
+    keyframe: bool,
+    data: Vec<u8>,
+}
+
+/// Turns Annex B buffers into samples, collecting the parameter sets along the way
+struct Samples {
+    track: Track,
+    vps: Option<Vec<u8>>,
+    sps: Option<Vec<u8>>,
+    pps: Option<Vec<u8>>,
+    /// Timestamp of the first sample in microseconds
+    start: Option<u64>,
+    /// Presentation time of the last sample
+    last: u64,
+}
+
+impl Samples {
+    fn new(track: Track) -> Self {
+        Samples {
+            track,
+            vps: None,
+            sps: None,
+            pps: None,
+            start: None,
+            last: 0,
+        }
+    }
+
+    /// Returns the nominal frame interval in microseconds
+    fn interval(&self) -> u64 {
+        let interval = self.track.interval;
+        if interval.denominator == 0 {
+            return 0;
+        }
+        u64::from(interval.numerator) * 1_000_000 / u64::from(interval.denominator)
+    }
+
+    /// Returns the next sample, or `None` as long as no decodable keyframe has been seen
+    fn push(&mut self, data: &[u8], meta: &Metadata) -> Option<Sample> {
+        let mut keyframe = false;
+        let mut payload = Vec::with_capacity(data.len() + 16);
+        for nal in annexb::nal_units(data) {
+            // parameter sets go into the decoder configuration, delimiters are not allowed
+            match self.track.codec.classify(nal.data) {
+                NalKind::Vps => self.vps = Some(nal.data.to_vec()),
+                NalKind::Sps => self.sps = Some(nal.data.to_vec()),
+                NalKind::Pps => self.pps = Some(nal.data.to_vec()),
+                NalKind::Aud => {}
+                kind => {
+                    keyframe |= kind == NalKind::Keyframe;
+                    payload.extend_from_slice(&(nal.data.len() as u32).to_be_bytes());
+                    payload.extend_from_slice(nal.data);
+                }
+            }
+        }
+
+        if payload.is_empty() {
+            return None;
+        }
+
+        let timestamp = meta.timestamp.sec as u64 * 1_000_000 + meta.timestamp.usec as u64;
+        // drivers which do not set timestamps get a constant frame rate
+        let pts = match self.start {
+            Some(start) => match timestamp.checked_sub(start) {
+                Some(pts) if pts > self.last => pts,
+                _ => self.last + self.interval(),
+            },
+            None if keyframe && self.decoder_config().is_ok() => {
+                self.start = Some(timestamp);
+                0
+            }
+            None => return None,
+        };
+        self.last = pts;
+
+        Some(Sample {
+            pts,
+            keyframe,
+            data: payload,
+        })
+    }
+
+    /// Returns the decoder configuration record (`avcC` or `hvcC`)
+    fn decoder_config(&self) -> io::Result<Vec<u8>> {
+        let missing = || {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "stream does not contain parameter sets",
+            )
+        };
+        let sps = self.sps.as_ref().ok_or_else(missing)?;
+        let pps = self.pps.as_ref().ok_or_else(missing)?;
+
+        match self.track.codec {
+            Codec::H264 => avc_config(sps, pps),
+            Codec::Hevc => hevc_config(self.vps.as_ref().ok_or_else(missing)?, sps, pps),
+        }
+    }
+}
+
+/// Returns an AVCDecoderConfigurationRecord (ISO/IEC 14496-15, 5.3.3.1)
+fn avc_config(sps: &[u8], pps: &[u8]) -> io::Result<Vec<u8>> {
+    if sps.len() < 4 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "truncated sequence parameter set",
+        ));
+    }
+
+    let mut config = vec![
+        1,      // configurationVersion
+        sps[1], // AVCProfileIndication
+        sps[2], // profile_compatibility
+        sps[3], // AVCLevelIndication
+        0xff,   // lengthSizeMinusOne = 3
+        0xe1,   // numOfSequenceParameterSets = 1
+    ];
+    config.extend_from_slice(&(sps.len() as u16).to_be_bytes());
+    config.extend_from_slice(sps);
+    config.push(1);
+    config.extend_from_slice(&(pps.len() as u16).to_be_bytes());
+    config.extend_from_slice(pps);
+    Ok(config)
+}
+
+/// Returns an HEVCDecoderConfigurationRecord (ISO/IEC 14496-15, 8.3.3.1)
+///
+/// The profile, tier and level are taken from the SPS. Chroma format and bit depth are not
+/// parsed, 8 bit 4:2:0 is assumed as produced by virtually all hardware encoders.
+fn hevc_config(vps: &[u8], sps: &[u8], pps: &[u8]) -> io::Result<Vec<u8>> {
+    // NAL unit header (2), sub-layer info (1) and general profile_tier_level (12)
+    let rbsp = unescape(sps, 15);
+    if rbsp.len() < 15 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "truncated sequence parameter set",
+        ));
+    }
+    let sub_layers = ((rbsp[2] >> 1) & 0x7) + 1;
+    let temporal_id_nesting = rbsp[2] & 0x1;
+
+    let mut config = vec![1];
+    config.extend_from_slice(&rbsp[3..15]);
+    config.extend_from_slice(&[
+        0xf0,
+        0x00, // min_spatial_segmentation_idc
+        0xfc, // parallelismType
+        0xfd, // chroma_format_idc = 1
+        0xf8, // bit_depth_luma_minus8
+        0xf8, // bit_depth_chroma_minus8
+        0x00,
+        0x00, // avgFrameRate
+        (sub_layers << 3) | (temporal_id_nesting << 2) | 0x3,
+        3, // numOfArrays
+    ]);
+
+    for (typ, nal) in [(32u8, vps), (33, sps), (34, pps)].iter() {
+        config.push(0x80 | typ);
+        config.extend_from_slice(&1u16.to_be_bytes());
+        config.extend_from_slice(&(nal.len() as u16).to_be_bytes());
+        config.extend_from_slice(nal);
+    }
+    Ok(config)
+}
+
+/// Removes the emulation prevention bytes from the first `len` bytes of a NAL unit
+fn unescape(nal: &[u8], len: usize) -> Vec<u8> {
+    let mut rbsp = Vec::with_capacity(len);
+    let mut zeros = 0;
+    for &byte in nal {
+        if rbsp.len() == len {
+            break;
+        }
+        if zeros >= 2 && byte == 3 {
+            zeros = 0;
+            continue;
+        }
+
+        zeros = if byte == 0 { zeros + 1 } else { 0 };
+        rbsp.push(byte);
+    }
+    rbsp
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::Memory;
+    use crate::timestamp::Timestamp;
+
+    pub(super) const SPS: [u8; 5] = [0x67, 0x64, 0x00, 0x1f, 0xac];
+    pub(super) const PPS: [u8; 4] = [0x68, 0xee, 0x3c, 0x80];
+    pub(super) const IDR: [u8; 3] = [0x65, 0x88, 0x84];
+    pub(super) const SLICE: [u8; 3] = [0x41, 0x9a, 0x02];
+    const AUD: [u8; 2] = [0x09, 0xf0];
+
+    /// Returns an Annex B buffer of the given NAL units
+    pub(super) fn annexb(nals: &[&[u8]]) -> Vec<u8> {
+        nals.iter()
+            .flat_map(|nal| [0, 0, 0, 1].iter().chain(nal.iter()))
+            .copied()
+            .collect()
+    }
+
+    /// Returns a keyframe buffer including the parameter sets
+    pub(super) fn keyframe() -> Vec<u8> {
+        annexb(&[&SPS, &PPS, &IDR])
+    }
+
+    /// Returns the metadata of a buffer captured at the given time in milliseconds
+    pub(super) fn meta(ms: i64) -> Metadata {
+        Metadata {
+            timestamp: Timestamp::new(ms / 1000, ms % 1000 * 1000),
+            ..Metadata::with_memory(Memory::Mmap)
+        }
+    }
+
+    /// Returns the NAL units of a length prefixed sample
+    fn nals(mut data: &[u8]) -> Vec<&[u8]> {
+        let mut nals = Vec::new();
+        while !data.is_empty() {
+            let len = u32::from_be_bytes([data[0], data[1], data[2], data[3]]) as usize;
+            nals.push(&data[4..4 + len]);
+            data = &data[4 + len..];
+        }
+        nals
+    }
+
+    #[test]
+    fn starts_at_the_first_decodable_keyframe() {
+        let mut samples = Samples::new(Track::new(Codec::H264, 64, 48));
+        assert!(samples.push(&annexb(&[&SLICE]), &meta(1000)).is_none());
+        // a keyframe without parameter sets cannot be decoded
+        assert!(samples.push(&annexb(&[&IDR]), &meta(1033)).is_none());
+
+        let sample = samples.push(&keyframe(), &meta(1066)).unwrap();
+        assert_eq!(sample.pts, 0);
+        assert!(sample.keyframe);
+        assert_eq!(nals(&sample.data), [&IDR]);
+
+        // delimiters and parameter sets are removed from the samples
+        let sample = samples.push(&annexb(&[&AUD, &SLICE]), &meta(1100)).unwrap();
+        assert_eq!(sample.pts, 34_000);
+        assert!(!sample.keyframe);
+        assert_eq!(nals(&sample.data), [&SLICE]);
+    }
+
+    #[test]
+    fn falls_back_to_the_nominal_interval() {
+        let mut track = Track::new(Codec::H264, 64, 48);
+        track.interval = Fraction::new(1, 25);
+        let mut samples = Samples::new(track);
+        assert_eq!(samples.interval(), 40_000);
+
+        let pts: Vec<_> = [0, 0, 0, 200, 100]
+            .iter()
+            .map(|&ms| {
+                let data = if ms == 0 {
+                    keyframe()
+                } else {
+                    annexb(&[&SLICE])
+                };
+                samples.push(&data, &meta(ms)).unwrap().pts
+            })
+            .collect();
+        // timestamps which do not increase are replaced
+        assert_eq!(pts, [0, 40_000, 80_000, 200_000, 240_000]);
+
+        samples.track.interval = Fraction::new(1, 0);
+        assert_eq!(samples.interval(), 0);
+    }
+
+    #[test]
+    fn builds_avc_configurations() {
+        let mut samples = Samples::new(Track::new(Codec::H264, 64, 48));
+        assert!(samples.decoder_config().is_err());
+        samples.push(&keyframe(), &meta(0)).unwrap();
+
+        #[rustfmt::skip]
+        assert_eq!(samples.decoder_config().unwrap(), [
+            0x01, 0x64, 0x00, 0x1f, 0xff,
+            0xe1, 0x00, 0x05, 0x67, 0x64, 0x00, 0x1f, 0xac,
+            0x01, 0x00, 0x04, 0x68, 0xee, 0x3c, 0x80,
+        ]);
+        assert!(avc_config(&SPS[..3], &PPS).is_err());
+    }
+
+    #[test]
+    fn builds_hevc_configurations() {
+        let vps = [0x40, 0x01, 0x0c];
+        // two sub-layers with temporal ID nesting, profile_tier_level escaped after two zeros
+        #[rustfmt::skip]
+        let sps = [
+            0x42, 0x01, 0x03, 0x01, 0x60, 0x00, 0x00, 0x03, 0x00, 0xb0, 0x00, 0x00, 0x03, 0x00,
+            0x00, 0x03, 0x00, 0x5d, 0xa0,
+        ];
+        let pps = [0x44, 0x01, 0xc1];
+
+        let mut samples = Samples::new(Track::new(Codec::Hevc, 64, 48));
+        let cra = [0x2a, 0x01, 0xaf];
+        assert!(samples
+            .push(&annexb(&[&sps, &pps, &cra]), &meta(0))
+            .is_none());
+        let sample = samples
+            .push(&annexb(&[&vps, &sps, &pps, &cra]), &meta(33))
+            .unwrap();
+        assert!(sample.keyframe);
+
+        let config = samples.decoder_config().unwrap();
+        #[rustfmt::skip]
+        assert_eq!(&config[..23], &[
+            0x01, 0x01, 0x60, 0x00, 0x00, 0x00, 0xb0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x5d,
+            0xf0, 0x00, 0xfc, 0xfd, 0xf8, 0xf8, 0x00, 0x00, 0x17, 0x03,
+        ]);
+        assert_eq!(&config[23..28], &[0xa0, 0x00, 0x01, 0x00, 0x03]);
+        assert_eq!(&config[28..31], &vps);
+        assert_eq!(config.len(), 23 + 3 * 5 + 3 + sps.len() + 3);
+    }
+
+    #[test]
+    fn removes_emulation_prevention_bytes() {
+        assert_eq!(
+            unescape(&[0, 0, 3, 1, 0, 0, 3, 0, 3], 9),
+            [0, 0, 1, 0, 0, 0, 3]
+        );
+        assert_eq!(unescape(&[0, 0, 3, 0, 0, 3, 1], 3), [0, 0, 0]);
+    }
+
+    #[test]
+    fn classifies_nal_units() {
+        assert_eq!(Codec::H264.classify(&IDR), NalKind::Keyframe);
+        assert_eq!(Codec::H264.classify(&SPS), NalKind::Sps);
+        assert_eq!(Codec::H264.classify(&AUD), NalKind::Aud);
+        assert_eq!(Codec::H264.classify(&[]), NalKind::Other);
+        // BLA_W_LP, IDR_N_LP and a trailing picture
+        assert_eq!(Codec::Hevc.classify(&[0x20, 0x01]), NalKind::Keyframe);
+        assert_eq!(Codec::Hevc.classify(&[0x28, 0x01]), NalKind::Keyframe);
+        assert_eq!(Codec::Hevc.classify(&[0x02, 0x01]), NalKind::Other);
+        assert_eq!(Codec::Hevc.classify(&[0x40, 0x01]), NalKind::Vps);
+        assert_eq!(Codec::Hevc.classify(&[0x46, 0x01]), NalKind::Aud);
+    }
+}
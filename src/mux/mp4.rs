@@ -0,0 +1,444 @@
+use std::io::{self, Write};
+
+use crate::buffer::Metadata;
+use crate::mux::{Codec, Sample, Samples, Sink, Track};
+
+/// Timescale of the media track, timestamps are kept in microseconds
+const TIMESCALE: u32 = 1_000_000;
+
+/// Sample flags (ISO/IEC 14496-12, 8.8.3.1) of sync samples
+const SYNC_SAMPLE: u32 = 0x0200_0000;
+/// Sample flags of samples which depend on others and are no sync samples
+const NON_SYNC_SAMPLE: u32 = 0x0101_0000;
+
+/// Appends a box whose content is written by `content`
+fn write_box(buf: &mut Vec<u8>, typ: &[u8; 4], content: impl FnOnce(&mut Vec<u8>)) {
+    let start = buf.len();
+    buf.extend_from_slice(&[0; 4]);
+    buf.extend_from_slice(typ);
+    content(buf);
+
+    let size = (buf.len() - start) as u32;
+    buf[start..start + 4].copy_from_slice(&size.to_be_bytes());
+}
+
+/// Appends a full box, i.e. a box with version and flags
+fn write_full_box(
+    buf: &mut Vec<u8>,
+    typ: &[u8; 4],
+    version: u8,
+    flags: u32,
+    content: impl FnOnce(&mut Vec<u8>),
+) {
+    write_box(buf, typ, |buf| {
+        buf.extend_from_slice(&((u32::from(version) << 24) | flags).to_be_bytes());
+        content(buf);
+    })
+}
+
+fn put_u16(buf: &mut Vec<u8>, val: u16) {
+    buf.extend_from_slice(&val.to_be_bytes());
+}
+
+fn put_u32(buf: &mut Vec<u8>, val: u32) {
+    buf.extend_from_slice(&val.to_be_bytes());
+}
+
+/// Appends the unity transformation matrix
+fn put_matrix(buf: &mut Vec<u8>) {
+    for val in &[0x0001_0000, 0, 0, 0, 0x0001_0000, 0, 0, 0, 0x4000_0000] {
+        put_u32(buf, *val);
+    }
+}
+
+/// Fragmented MP4 writer
+///
+/// The initialization segment (`ftyp` and `moov`) is written as soon as the first keyframe
+/// along with its parameter sets arrives. Every frame is written as a fragment (`moof` and
+/// `mdat`) of its own, delayed by one frame because its duration is only known once the next
+/// frame arrived. The output can be played while it is being written and is suitable for
+/// streaming (e.g. via HLS or Media Source Extensions).
+///
+/// # Example
+///
+/// ```
+/// use std::fs::File;
+/// use v4l::buffer::Type;
+/// use v4l::io::traits::CaptureStream;
+/// use v4l::mux::{mp4::Mp4Writer, Codec, Sink, Track};
+/// use v4l::prelude::*;
+///
+/// if let Ok(dev) = Device::new(0) {
+///     let track = Track::new(Codec::H264, 1280, 720);
+///     let mut mp4 = Mp4Writer::new(File::create("capture.mp4").unwrap(), track);
+///
+///     let mut stream = MmapStream::new(&dev, Type::VideoCapture).unwrap();
+///     for _ in 0..300 {
+///         let (buf, meta, _) = stream.next().unwrap();
+///         mp4.write(&buf[0][..meta.bytesused as usize], meta).unwrap();
+///     }
+///     mp4.finish().unwrap();
+/// }
+/// ```
+pub struct Mp4Writer<W: Write> {
+    output: W,
+    samples: Samples,
+    /// Whether the initialization segment was written already
+    initialized: bool,
+    /// Sample waiting for its successor to determine its duration
+    pending: Option<Sample>,
+    /// Sequence number of the next fragment
+    sequence: u32,
+}
+
+impl<W: Write> Mp4Writer<W> {
+    /// Returns a writer
+    ///
+    /// # Arguments
+    ///
+    /// * `output` - Output to write the file to, does not need to be seekable
+    /// * `track` - Video track description
+    pub fn new(output: W, track: Track) -> Self {
+        Mp4Writer {
+            output,
+            samples: Samples::new(track),
+            initialized: false,
+            pending: None,
+            sequence: 1,
+        }
+    }
+
+    /// Returns a reference to the output
+    pub fn get_ref(&self) -> &W {
+        &self.output
+    }
+
+    /// Returns a mutable reference to the output
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.output
+    }
+
+    fn write_init(&mut self) -> io::Result<()> {
+        let track = self.samples.track;
+        let config = self.samples.decoder_config()?;
+        let (entry, config_box) = match track.codec {
+            Codec::H264 => (b"avc1", b"avcC"),
+            Codec::Hevc => (b"hvc1", b"hvcC"),
+        };
+
+        let mut buf = Vec::new();
+        write_box(&mut buf, b"ftyp", |buf| {
+            buf.extend_from_slice(b"iso5");
+            put_u32(buf, 0x200);
+            buf.extend_from_slice(b"iso5iso6mp41");
+        });
+
+        write_box(&mut buf, b"moov", |buf| {
+            write_full_box(buf, b"mvhd", 0, 0, |buf| {
+                put_u32(buf, 0); // creation_time
+                put_u32(buf, 0); // modification_time
+                put_u32(buf, TIMESCALE);
+                put_u32(buf, 0); // duration
+                put_u32(buf, 0x0001_0000); // rate
+                put_u16(buf, 0x0100); // volume
+                buf.extend_from_slice(&[0; 10]);
+                put_matrix(buf);
+                buf.extend_from_slice(&[0; 24]);
+                put_u32(buf, 2); // next_track_ID
+            });
+
+            write_box(buf, b"trak", |buf| {
+                // track enabled and in movie
+                write_full_box(buf, b"tkhd", 0, 0x3, |buf| {
+                    put_u32(buf, 0); // creation_time
+                    put_u32(buf, 0); // modification_time
+                    put_u32(buf, 1); // track_ID
+                    put_u32(buf, 0);
+                    put_u32(buf, 0); // duration
+                    buf.extend_from_slice(&[0; 8]);
+                    put_u16(buf, 0); // layer
+                    put_u16(buf, 0); // alternate_group
+                    put_u16(buf, 0); // volume
+                    put_u16(buf, 0);
+                    put_matrix(buf);
+                    put_u32(buf, track.width << 16);
+                    put_u32(buf, track.height << 16);
+                });
+
+                write_box(buf, b"mdia", |buf| {
+                    write_full_box(buf, b"mdhd", 0, 0, |buf| {
+                        put_u32(buf, 0); // creation_time
+                        put_u32(buf, 0); // modification_time
+                        put_u32(buf, TIMESCALE);
+                        put_u32(buf, 0); // duration
+                        put_u16(buf, 0x55c4); // language: und
+                        put_u16(buf, 0);
+                    });
+
+                    write_full_box(buf, b"hdlr", 0, 0, |buf| {
+                        put_u32(buf, 0);
+                        buf.extend_from_slice(b"vide");
+                        buf.extend_from_slice(&[0; 12]);
+                        buf.extend_from_slice(b"VideoHandler\0");
+                    });
+
+                    write_box(buf, b"minf", |buf| {
+                        write_full_box(buf, b"vmhd", 0, 0x1, |buf| {
+                            buf.extend_from_slice(&[0; 8]);
+                        });
+
+                        write_box(buf, b"dinf", |buf| {
+                            write_full_box(buf, b"dref", 0, 0, |buf| {
+                                put_u32(buf, 1);
+                                // media data is in the same file
+                                write_full_box(buf, b"url ", 0, 0x1, |_| {});
+                            });
+                        });
+
+                        write_box(buf, b"stbl", |buf| {
+                            write_full_box(buf, b"stsd", 0, 0, |buf| {
+                                put_u32(buf, 1);
+                                write_box(buf, entry, |buf| {
+                                    buf.extend_from_slice(&[0; 6]);
+                                    put_u16(buf, 1); // data_reference_index
+                                    buf.extend_from_slice(&[0; 16]);
+                                    put_u16(buf, track.width as u16);
+                                    put_u16(buf, track.height as u16);
+                                    put_u32(buf, 0x0048_0000); // horizresolution: 72 dpi
+                                    put_u32(buf, 0x0048_0000); // vertresolution: 72 dpi
+                                    put_u32(buf, 0);
+                                    put_u16(buf, 1); // frame_count
+                                    buf.extend_from_slice(&[0; 32]); // compressorname
+                                    put_u16(buf, 0x0018); // depth
+                                    put_u16(buf, 0xffff);
+                                    write_box(buf, config_box, |buf| {
+                                        buf.extend_from_slice(&config);
+                                    });
+                                });
+                            });
+
+                            // the sample tables are empty, samples are described by fragments
+                            write_full_box(buf, b"stts", 0, 0, |buf| put_u32(buf, 0));
+                            write_full_box(buf, b"stsc", 0, 0, |buf| put_u32(buf, 0));
+                            write_full_box(buf, b"stsz", 0, 0, |buf| {
+                                put_u32(buf, 0);
+                                put_u32(buf, 0);
+                            });
+                            write_full_box(buf, b"stco", 0, 0, |buf| put_u32(buf, 0));
+                        });
+                    });
+                });
+            });
+
+            write_box(buf, b"mvex", |buf| {
+                write_full_box(buf, b"trex", 0, 0, |buf| {
+                    put_u32(buf, 1); // track_ID
+                    put_u32(buf, 1); // default_sample_description_index
+                    put_u32(buf, 0); // default_sample_duration
+                    put_u32(buf, 0); // default_sample_size
+                    put_u32(buf, 0); // default_sample_flags
+                });
+            });
+        });
+
+        self.output.write_all(&buf)
+    }
+
+    fn write_fragment(&mut self, sample: &Sample, duration: u64) -> io::Result<()> {
+        let flags = if sample.keyframe {
+            SYNC_SAMPLE
+        } else {
+            NON_SYNC_SAMPLE
+        };
+
+        let mut buf = Vec::new();
+        let mut data_offset = 0;
+        write_box(&mut buf, b"moof", |buf| {
+            write_full_box(buf, b"mfhd", 0, 0, |buf| put_u32(buf, self.sequence));
+            write_box(buf, b"traf", |buf| {
+                // default-base-is-moof
+                write_full_box(buf, b"tfhd", 0, 0x02_0000, |buf| put_u32(buf, 1));
+                write_full_box(buf, b"tfdt", 1, 0, |buf| {
+                    buf.extend_from_slice(&sample.pts.to_be_bytes());
+                });
+                // data-offset, sample-duration, sample-size and sample-flags present
+                write_full_box(buf, b"trun", 0, 0x00_0701, |buf| {
+                    put_u32(buf, 1); // sample_count
+                    data_offset = buf.len();
+                    put_u32(buf, 0);
+                    put_u32(buf, duration as u32);
+                    put_u32(buf, sample.data.len() as u32);
+                    put_u32(buf, flags);
+                });
+            });
+        });
+
+        // the sample data follows right after the moof and the mdat header
+        let offset = (buf.len() + 8) as u32;
+        buf[data_offset..data_offset + 4].copy_from_slice(&offset.to_be_bytes());
+        write_box(&mut buf, b"mdat", |_| {});
+        let size = (8 + sample.data.len()) as u32;
+        let mdat = buf.len() - 8;
+        buf[mdat..mdat + 4].copy_from_slice(&size.to_be_bytes());
+
+        self.output.write_all(&buf)?;
+        self.output.write_all(&sample.data)?;
+        self.sequence += 1;
+        Ok(())
+    }
+}
+
+impl<W: Write> Sink for Mp4Writer<W> {
+    fn write(&mut self, data: &[u8], meta: &Metadata) -> io::Result<()> {
+        let sample = match self.samples.push(data, meta) {
+            Some(sample) => sample,
+            None => return Ok(()),
+        };
+
+        if !self.initialized {
+            self.write_init()?;
+            self.initialized = true;
+        }
+
+        if let Some(pending) = self.pending.take() {
+            self.write_fragment(&pending, sample.pts - pending.pts)?;
+        }
+        self.pending = Some(sample);
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        if let Some(pending) = self.pending.take() {
+            let duration = self.samples.interval();
+            self.write_fragment(&pending, duration)?;
+        }
+        self.output.flush()
+    }
+}
+
+impl<W: Write> Drop for Mp4Writer<W> {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use super::*;
+    use crate::mux::tests::{annexb, keyframe, meta, IDR, SLICE};
+
+    /// Returns the type and content of the boxes following each other in `data`
+    fn boxes(mut data: &[u8]) -> Vec<([u8; 4], &[u8])> {
+        let mut boxes = Vec::new();
+        while !data.is_empty() {
+            let size = u32::from_be_bytes([data[0], data[1], data[2], data[3]]) as usize;
+            let typ = [data[4], data[5], data[6], data[7]];
+            boxes.push((typ, &data[8..size]));
+            data = &data[size..];
+        }
+        boxes
+    }
+
+    /// Returns the content of the box at the given path, skipping `skip` bytes of each full box
+    fn find<'a>(data: &'a [u8], path: &[(&[u8; 4], usize)]) -> &'a [u8] {
+        path.iter().fold(data, |data, (typ, skip)| {
+            let (_, content) = boxes(data)
+                .into_iter()
+                .find(|(t, _)| t == *typ)
+                .unwrap_or_else(|| panic!("missing box {:?}", typ));
+            &content[*skip..]
+        })
+    }
+
+    fn u32_at(data: &[u8], offset: usize) -> u32 {
+        u32::from_be_bytes([
+            data[offset],
+            data[offset + 1],
+            data[offset + 2],
+            data[offset + 3],
+        ])
+    }
+
+    #[test]
+    fn writes_a_fragment_per_frame() {
+        let mut mp4 = Mp4Writer::new(Vec::new(), Track::new(Codec::H264, 64, 48));
+        mp4.write(&annexb(&[&SLICE]), &meta(0)).unwrap();
+        assert!(mp4.get_ref().is_empty());
+
+        mp4.write(&keyframe(), &meta(1000)).unwrap();
+        mp4.write(&annexb(&[&SLICE]), &meta(1040)).unwrap();
+        mp4.finish().unwrap();
+
+        let output = mp4.get_ref().clone();
+        let top = boxes(&output);
+        let types: Vec<_> = top.iter().map(|(typ, _)| typ).collect();
+        assert_eq!(
+            types,
+            [b"ftyp", b"moov", b"moof", b"mdat", b"moof", b"mdat"]
+        );
+
+        let stsd = find(
+            top[1].1,
+            &[
+                (b"trak", 0),
+                (b"mdia", 0),
+                (b"minf", 0),
+                (b"stbl", 0),
+                (b"stsd", 8),
+            ],
+        );
+        // the sample entry holds 78 bytes of visual fields in front of the configuration
+        let avc1 = find(stsd, &[(b"avc1", 0)]);
+        assert_eq!(&avc1[24..28], &[0, 64, 0, 48]);
+        assert_eq!(
+            &find(&avc1[78..], &[(b"avcC", 0)])[..4],
+            &[1, 0x64, 0x00, 0x1f]
+        );
+
+        // (sequence, decode time, duration, size, flags) of the fragments
+        let mut start = output.len() - top[2..].iter().map(|(_, c)| 8 + c.len()).sum::<usize>();
+        let mut fragments = Vec::new();
+        for pair in top[2..].chunks(2) {
+            let (moof, mdat) = (pair[0].1, pair[1].1);
+            let mfhd = find(moof, &[(b"mfhd", 4)]);
+            let traf = find(moof, &[(b"traf", 0)]);
+            let tfdt = find(traf, &[(b"tfdt", 4)]);
+            let trun = find(traf, &[(b"trun", 4)]);
+            assert_eq!(u32_at(trun, 0), 1);
+            // the data offset is relative to the moof and points to the mdat content
+            let offset = u32_at(trun, 4) as usize;
+            assert_eq!(&output[start + offset..][..mdat.len()], mdat);
+            fragments.push((
+                u32_at(mfhd, 0),
+                u64::from_be_bytes(tfdt[..8].try_into().unwrap()),
+                u32_at(trun, 8),
+                u32_at(trun, 12),
+                u32_at(trun, 16),
+            ));
+            start += 16 + moof.len() + mdat.len();
+        }
+        assert_eq!(
+            fragments,
+            [
+                (1, 0, 40_000, 7, SYNC_SAMPLE),
+                (2, 40_000, 33_333, 7, NON_SYNC_SAMPLE)
+            ]
+        );
+        assert_eq!(top[3].1, [&[0, 0, 0, 3][..], &IDR].concat());
+        assert_eq!(top[5].1, [&[0, 0, 0, 3][..], &SLICE].concat());
+    }
+
+    #[test]
+    fn finishes_when_dropped() {
+        let mut output = Vec::new();
+        {
+            let mut mp4 = Mp4Writer::new(&mut output, Track::new(Codec::H264, 64, 48));
+            mp4.write(&keyframe(), &meta(0)).unwrap();
+        }
+        let top = boxes(&output);
+        let types: Vec<_> = top.iter().map(|(typ, _)| typ).collect();
+        assert_eq!(types, [b"ftyp", b"moov", b"moof", b"mdat"]);
+    }
+}
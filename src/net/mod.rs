@@ -0,0 +1,5 @@
+//! Helpers for streaming video over the network
+//!
+//! This module is only available with the `net` feature.
+
+pub mod rtp;
@@ -0,0 +1,622 @@
+//! RTP packetization of encoded frames
+//!
+//! Frames are split into RTP packets (RFC 3550) which fit into a single UDP datagram, using the
+//! payload formats for H.264 (RFC 6184, non-interleaved mode) and JPEG (RFC 2435). The packets
+//! are timestamped with the capture time of the buffer on the 90 kHz video clock, so receivers
+//! see the actual frame timing of the device.
+//!
+//! Session handling (RTSP, SDP) is left to the user; the packets can be sent as they are.
+
+use std::io;
+
+use crate::buffer::Metadata;
+use crate::codec::{annexb, mjpeg};
+use crate::format::FourCC;
+use crate::frame::Planes;
+use crate::io::traits::CaptureStream;
+use crate::timestamp::Timestamp;
+
+/// Clock rate of video payloads in Hz
+pub const CLOCK_RATE: u32 = 90_000;
+
+/// Size of the fixed RTP header
+const HEADER_LEN: usize = 12;
+/// Smallest supported packet size, large enough for the JPEG headers
+const MIN_MTU: usize = 512;
+/// Default packet size, leaving headroom for IP/UDP headers and tunnels in typical networks
+const DEFAULT_MTU: usize = 1400;
+
+/// Payload format
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Payload {
+    /// H.264 Annex B byte stream (RFC 6184)
+    H264,
+    /// Baseline JPEG images, i.e. MJPEG (RFC 2435)
+    Jpeg,
+}
+
+impl Payload {
+    /// Returns the payload format of a pixel format, if it is supported
+    ///
+    /// # Arguments
+    ///
+    /// * `fourcc` - Pixel format of the device
+    pub fn from_fourcc(fourcc: &FourCC) -> Option<Self> {
+        match &fourcc.repr {
+            b"H264" => Some(Payload::H264),
+            b"MJPG" | b"JPEG" => Some(Payload::Jpeg),
+            _ => None,
+        }
+    }
+
+    /// Returns the default payload type
+    ///
+    /// JPEG has a static payload type (26), H.264 uses the first dynamic one (96).
+    pub fn payload_type(&self) -> u8 {
+        match self {
+            Payload::H264 => 96,
+            Payload::Jpeg => 26,
+        }
+    }
+}
+
+/// Converts a buffer timestamp to an RTP timestamp on the 90 kHz clock
+///
+/// # Arguments
+///
+/// * `ts` - Buffer timestamp
+///
+/// # Example
+///
+/// ```
+/// use v4l::net::rtp;
+/// use v4l::Timestamp;
+///
+/// assert_eq!(rtp::timestamp(&Timestamp::new(1, 500_000)), 135_000);
+/// ```
+pub fn timestamp(ts: &Timestamp) -> u32 {
+    let ticks = ts.sec as u64 * u64::from(CLOCK_RATE) + ts.usec as u64 * 9 / 100;
+    // RTP timestamps wrap around
+    ticks as u32
+}
+
+/// Splits encoded frames into RTP packets
+///
+/// # Example
+///
+/// ```
+/// use std::net::UdpSocket;
+/// use v4l::buffer::Type;
+/// use v4l::net::rtp::{Packetizer, Payload};
+/// use v4l::prelude::*;
+///
+/// if let Ok(dev) = Device::new(0) {
+///     let socket = UdpSocket::bind("0.0.0.0:0").unwrap();
+///     let mut stream = MmapStream::new(&dev, Type::VideoCapture).unwrap();
+///     let mut rtp = Packetizer::new(Payload::Jpeg, 0x1234_5678);
+///
+///     loop {
+///         for packet in rtp.capture(&mut stream).unwrap() {
+///             socket.send_to(&packet, "239.0.0.1:5004").unwrap();
+///         }
+///     }
+/// }
+/// ```
+pub struct Packetizer {
+    payload: Payload,
+    payload_type: u8,
+    ssrc: u32,
+    sequence: u16,
+    mtu: usize,
+}
+
+impl Packetizer {
+    /// Returns a packetizer using the default payload type and a packet size of 1400 bytes
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - Payload format
+    /// * `ssrc` - Synchronization source identifier, should be chosen randomly
+    pub fn new(payload: Payload, ssrc: u32) -> Self {
+        Packetizer {
+            payload,
+            payload_type: payload.payload_type(),
+            ssrc,
+            sequence: 0,
+            mtu: DEFAULT_MTU,
+        }
+    }
+
+    /// Changes the payload type, e.g. to match the one negotiated through SDP
+    ///
+    /// # Arguments
+    ///
+    /// * `payload_type` - Payload type (7 bit)
+    pub fn set_payload_type(&mut self, payload_type: u8) {
+        self.payload_type = payload_type & 0x7f;
+    }
+
+    /// Changes the maximum packet size including the RTP header
+    ///
+    /// Sizes below 512 bytes are raised to 512 bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `mtu` - Maximum packet size in bytes
+    pub fn set_mtu(&mut self, mtu: usize) {
+        self.mtu = mtu.max(MIN_MTU);
+    }
+
+    /// Changes the sequence number of the next packet
+    ///
+    /// The initial sequence number should be chosen randomly.
+    ///
+    /// # Arguments
+    ///
+    /// * `sequence` - Sequence number
+    pub fn set_sequence(&mut self, sequence: u16) {
+        self.sequence = sequence;
+    }
+
+    /// Returns the sequence number of the next packet
+    pub fn sequence(&self) -> u16 {
+        self.sequence
+    }
+
+    /// Splits a frame into packets
+    ///
+    /// The marker bit is set on the last packet of the frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Encoded frame
+    /// * `meta` - Buffer metadata, its timestamp is used for the packets
+    pub fn packetize(&mut self, data: &[u8], meta: &Metadata) -> io::Result<Vec<Vec<u8>>> {
+        let ts = timestamp(&meta.timestamp);
+        match self.payload {
+            Payload::H264 => Ok(self.packetize_h264(data, ts)),
+            Payload::Jpeg => self.packetize_jpeg(data, ts),
+        }
+    }
+
+    /// Captures the next frame of a stream and splits it into packets
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - Capture stream
+    pub fn capture<S>(&mut self, stream: &mut S) -> io::Result<Vec<Vec<u8>>>
+    where
+        S: for<'b> CaptureStream<'b>,
+        S::Item: Planes,
+    {
+        let (buf, meta, _) = stream.next()?;
+        let planes = buf.planes();
+        let data = planes.first().copied().unwrap_or(&[]);
+        let used = match meta.bytesused as usize {
+            0 => data.len(),
+            used => used.min(data.len()),
+        };
+
+        self.packetize(&data[..used], meta)
+    }
+
+    fn packet(&mut self, ts: u32, marker: bool, parts: &[&[u8]]) -> Vec<u8> {
+        let len = parts.iter().map(|part| part.len()).sum::<usize>();
+        let mut packet = Vec::with_capacity(HEADER_LEN + len);
+        // version 2, no padding, no extension, no CSRCs
+        packet.push(0x80);
+        packet.push(self.payload_type | if marker { 0x80 } else { 0 });
+        packet.extend_from_slice(&self.sequence.to_be_bytes());
+        packet.extend_from_slice(&ts.to_be_bytes());
+        packet.extend_from_slice(&self.ssrc.to_be_bytes());
+        for part in parts {
+            packet.extend_from_slice(part);
+        }
+
+        self.sequence = self.sequence.wrapping_add(1);
+        packet
+    }
+
+    fn packetize_h264(&mut self, data: &[u8], ts: u32) -> Vec<Vec<u8>> {
+        let max = self.mtu - HEADER_LEN;
+        let nals: Vec<_> = annexb::nal_units(data)
+            .filter(|nal| !nal.data.is_empty())
+            .collect();
+
+        let mut packets = Vec::new();
+        for (i, nal) in nals.iter().enumerate() {
+            let last = i + 1 == nals.len();
+            let nal = nal.data;
+            if nal.len() <= max {
+                packets.push(self.packet(ts, last, &[nal]));
+                continue;
+            }
+
+            // fragmentation units (FU-A), the NAL unit header is split across their headers
+            let indicator = (nal[0] & 0xe0) | 28;
+            let chunks: Vec<_> = nal[1..].chunks(max - 2).collect();
+            for (j, chunk) in chunks.iter().enumerate() {
+                let start = j == 0;
+                let end = j + 1 == chunks.len();
+                let mut header = nal[0] & 0x1f;
+                if start {
+                    header |= 0x80;
+                }
+                if end {
+                    header |= 0x40;
+                }
+                packets.push(self.packet(ts, last && end, &[&[indicator, header], chunk]));
+            }
+        }
+        packets
+    }
+
+    fn packetize_jpeg(&mut self, data: &[u8], ts: u32) -> io::Result<Vec<Vec<u8>>> {
+        let unsupported = |msg| io::Error::new(io::ErrorKind::InvalidData, msg);
+        let data = mjpeg::validate(data)?;
+
+        let mut tables: [Option<&[u8]>; 4] = [None; 4];
+        let mut size = None;
+        let mut typ = None;
+        let mut restart_interval = 0;
+        let mut scan = None;
+        for segment in mjpeg::segments(data)? {
+            match segment.marker {
+                // DQT, a segment may define multiple tables
+                0xdb => {
+                    let mut rest = segment.data;
+                    while !rest.is_empty() {
+                        if rest[0] >> 4 != 0 {
+                            return Err(unsupported("16 bit quantization tables"));
+                        }
+                        if rest.len() < 65 {
+                            return Err(unsupported("truncated quantization table"));
+                        }
+                        tables[usize::from(rest[0] & 0x3)] = Some(&rest[1..65]);
+                        rest = &rest[65..];
+                    }
+                }
+                // SOF0, only baseline images are supported by the payload format
+                0xc0 => {
+                    let sof = segment.data;
+                    if sof.len() < 6 + 3 * 3 || sof[5] != 3 {
+                        return Err(unsupported("JPEG images must have three components"));
+                    }
+                    let height = u16::from_be_bytes([sof[1], sof[2]]);
+                    let width = u16::from_be_bytes([sof[3], sof[4]]);
+                    size = Some((width, height));
+                    // the sampling factors of the luma component determine the subsampling
+                    typ = match (sof[7], sof[10], sof[13]) {
+                        (0x21, 0x11, 0x11) => Some(0),
+                        (0x22, 0x11, 0x11) => Some(1),
+                        _ => return Err(unsupported("unsupported JPEG chroma subsampling")),
+                    };
+                }
+                0xc1..=0xcf if segment.marker != 0xc4 && segment.marker != 0xcc => {
+                    return Err(unsupported("only baseline JPEG images are supported"));
+                }
+                // DRI
+                0xdd if segment.data.len() >= 2 => {
+                    restart_interval = u16::from_be_bytes([segment.data[0], segment.data[1]]);
+                }
+                // SOS, the entropy coded data follows right after its header
+                0xda => {
+                    let start = segment.offset + 4 + segment.data.len();
+                    scan = Some(&data[start..data.len() - mjpeg::EOI.len()]);
+                }
+                _ => {}
+            }
+        }
+
+        let (width, height) = size.ok_or_else(|| unsupported("missing JPEG frame header"))?;
+        let mut typ = typ.unwrap_or(0);
+        let scan = scan.ok_or_else(|| unsupported("missing JPEG start of scan marker"))?;
+        if width > 2040 || height > 2040 {
+            return Err(unsupported("JPEG images must not exceed 2040x2040 pixels"));
+        }
+
+        let tables: Vec<u8> = tables
+            .iter()
+            .flatten()
+            .flat_map(|table| *table)
+            .copied()
+            .collect();
+        if tables.is_empty() {
+            return Err(unsupported("missing JPEG quantization tables"));
+        }
+
+        let mut restart = Vec::new();
+        if restart_interval > 0 {
+            typ += 64;
+            restart.extend_from_slice(&restart_interval.to_be_bytes());
+            // first and last bits set, restart count 0x3fff
+            restart.extend_from_slice(&[0xff, 0xff]);
+        }
+
+        // quantization table header, sent with the first packet only (Q = 255)
+        let mut qt = vec![0, 0];
+        qt.extend_from_slice(&(tables.len() as u16).to_be_bytes());
+        qt.extend_from_slice(&tables);
+
+        let mut packets = Vec::new();
+        let mut offset = 0;
+        while offset < scan.len() {
+            let qt: &[u8] = if offset == 0 { &qt } else { &[] };
+            let max = self.mtu - HEADER_LEN - 8 - restart.len() - qt.len();
+            let len = max.min(scan.len() - offset);
+
+            let off = (offset as u32).to_be_bytes();
+            let header = [
+                0,
+                off[1],
+                off[2],
+                off[3],
+                typ,
+                255,
+                width.div_ceil(8) as u8,
+                height.div_ceil(8) as u8,
+            ];
+            let end = offset + len == scan.len();
+            let chunk = &scan[offset..offset + len];
+            packets.push(self.packet(ts, end, &[&header, &restart, qt, chunk]));
+            offset += len;
+        }
+        Ok(packets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Mock;
+    use crate::buffer::Type;
+    use crate::device::Device;
+    use crate::io::mmap::Stream;
+    use crate::memory::Memory;
+
+    const SSRC: u32 = 0x1234_5678;
+
+    fn meta(sec: i64, usec: i64) -> Metadata {
+        Metadata {
+            timestamp: Timestamp::new(sec, usec),
+            ..Metadata::with_memory(Memory::Mmap)
+        }
+    }
+
+    /// Returns the sequence number, timestamp and marker bit of a packet, verifying the rest
+    fn header(packet: &[u8], payload_type: u8) -> (u16, u32, bool) {
+        assert_eq!(packet[0], 0x80);
+        assert_eq!(packet[1] & 0x7f, payload_type);
+        assert_eq!(&packet[8..12], &SSRC.to_be_bytes());
+        let sequence = u16::from_be_bytes([packet[2], packet[3]]);
+        let ts = u32::from_be_bytes([packet[4], packet[5], packet[6], packet[7]]);
+        (sequence, ts, packet[1] & 0x80 != 0)
+    }
+
+    /// Reassembles the NAL units of H.264 packets, joining fragmentation units
+    fn depacketize_h264(packets: &[Vec<u8>]) -> Vec<Vec<u8>> {
+        let mut nals = Vec::new();
+        let mut fragments: Option<Vec<u8>> = None;
+        for packet in packets {
+            let payload = &packet[HEADER_LEN..];
+            if payload[0] & 0x1f != 28 {
+                assert!(fragments.is_none(), "unterminated fragmentation unit");
+                nals.push(payload.to_vec());
+                continue;
+            }
+
+            let (indicator, header) = (payload[0], payload[1]);
+            if header & 0x80 != 0 {
+                assert!(fragments.is_none(), "fragmentation unit started twice");
+                fragments = Some(vec![(indicator & 0xe0) | (header & 0x1f)]);
+            }
+            let nal = fragments
+                .as_mut()
+                .expect("fragmentation unit without start");
+            nal.extend_from_slice(&payload[2..]);
+            if header & 0x40 != 0 {
+                nals.push(fragments.take().unwrap());
+            }
+        }
+        assert!(fragments.is_none(), "unterminated fragmentation unit");
+        nals
+    }
+
+    /// Returns the scan data of JPEG packets joined by fragment offset and the tables
+    fn depacketize_jpeg(packets: &[Vec<u8>]) -> (Vec<u8>, Vec<u8>) {
+        let mut scan = Vec::new();
+        let mut tables = Vec::new();
+        for packet in packets {
+            let payload = &packet[HEADER_LEN..];
+            let offset = u32::from_be_bytes([0, payload[1], payload[2], payload[3]]) as usize;
+            assert_eq!(offset, scan.len());
+            let mut data = &payload[8..];
+            if payload[4] >= 64 {
+                data = &data[4..];
+            }
+            if offset == 0 {
+                let len = u16::from_be_bytes([data[2], data[3]]) as usize;
+                tables = data[4..4 + len].to_vec();
+                data = &data[4 + len..];
+            }
+            scan.extend_from_slice(data);
+        }
+        (scan, tables)
+    }
+
+    /// Returns a baseline 4:2:2 JPEG image of 16x8 pixels with the given scan data
+    fn jpeg(scan: &[u8], restart_interval: Option<u16>) -> Vec<u8> {
+        let mut image = mjpeg::SOI.to_vec();
+        // DQT, both tables in one segment
+        image.extend_from_slice(&[0xff, 0xdb, 0x00, 0x84, 0x00]);
+        image.extend_from_slice(&[1; 64]);
+        image.push(0x01);
+        image.extend_from_slice(&[2; 64]);
+        // SOF0, the luma component is subsampled 2x1
+        #[rustfmt::skip]
+        image.extend_from_slice(&[
+            0xff, 0xc0, 0x00, 0x11, 0x08, 0x00, 0x08, 0x00, 0x10, 0x03,
+            0x01, 0x21, 0x00, 0x02, 0x11, 0x01, 0x03, 0x11, 0x01,
+        ]);
+        if let Some(interval) = restart_interval {
+            image.extend_from_slice(&[0xff, 0xdd, 0x00, 0x04]);
+            image.extend_from_slice(&interval.to_be_bytes());
+        }
+        #[rustfmt::skip]
+        image.extend_from_slice(&[
+            0xff, mjpeg::SOS, 0x00, 0x0c, 0x03, 0x01, 0x00, 0x02, 0x11, 0x03, 0x11, 0x00, 0x3f,
+            0x00,
+        ]);
+        image.extend_from_slice(scan);
+        image.extend_from_slice(&mjpeg::EOI);
+        image
+    }
+
+    /// Returns scan data without any markers
+    fn scan(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i % 255) as u8).collect()
+    }
+
+    #[test]
+    fn sends_small_nal_units_as_they_are() {
+        let mut rtp = Packetizer::new(Payload::H264, SSRC);
+        rtp.set_sequence(0xfffe);
+        let frame = [
+            0, 0, 0, 1, 0x67, 1, 2, 0, 0, 1, 0x68, 3, 0, 0, 1, 0x65, 4, 5, 6,
+        ];
+        let packets = rtp.packetize(&frame, &meta(1, 500_000)).unwrap();
+
+        let headers: Vec<_> = packets.iter().map(|p| header(p, 96)).collect();
+        // the sequence number wraps around, only the last packet of the frame is marked
+        assert_eq!(
+            headers,
+            [
+                (0xfffe, 135_000, false),
+                (0xffff, 135_000, false),
+                (0, 135_000, true)
+            ]
+        );
+        assert_eq!(rtp.sequence(), 1);
+        assert_eq!(
+            depacketize_h264(&packets),
+            [&[0x67, 1, 2][..], &[0x68, 3], &[0x65, 4, 5, 6]]
+        );
+    }
+
+    #[test]
+    fn fragments_large_nal_units() {
+        let mut rtp = Packetizer::new(Payload::H264, SSRC);
+        rtp.set_mtu(100);
+        let sps = [0x67, 1, 2, 3];
+        let idr: Vec<u8> = [0x65].iter().copied().chain(scan(2000)).collect();
+        let mut frame = vec![0, 0, 0, 1];
+        frame.extend_from_slice(&sps);
+        frame.extend_from_slice(&[0, 0, 1]);
+        frame.extend_from_slice(&idr);
+
+        let packets = rtp.packetize(&frame, &meta(0, 0)).unwrap();
+        // the MTU is raised to the minimum, each fragment carries 512 - 12 - 2 bytes
+        assert_eq!(packets.len(), 1 + 5);
+        assert!(packets.iter().all(|packet| packet.len() <= MIN_MTU));
+        assert_eq!(packets[1].len(), MIN_MTU);
+        // FU indicator with the NRI of the IDR slice, FU header with start bit and type
+        assert_eq!(
+            &packets[1][HEADER_LEN..HEADER_LEN + 2],
+            &[0x60 | 28, 0x80 | 5]
+        );
+        assert_eq!(packets[5][HEADER_LEN + 1], 0x40 | 5);
+        let markers: Vec<_> = packets.iter().map(|p| header(p, 96).2).collect();
+        assert_eq!(markers, [false, false, false, false, false, true]);
+
+        assert_eq!(depacketize_h264(&packets), [&sps[..], &idr]);
+    }
+
+    #[test]
+    fn splits_jpeg_scans_by_offset() {
+        let mut rtp = Packetizer::new(Payload::Jpeg, SSRC);
+        rtp.set_mtu(MIN_MTU);
+        let data = scan(1500);
+        let image = jpeg(&data, None);
+        // padding after the image is ignored
+        let mut frame = image.clone();
+        frame.extend_from_slice(&[0; 100]);
+
+        let packets = rtp.packetize(&frame, &meta(0, 100)).unwrap();
+        // the first packet also carries the 4 byte header and 128 bytes of tables
+        assert_eq!(packets.len(), 4);
+        assert!(packets.iter().all(|packet| packet.len() <= MIN_MTU));
+        for (i, packet) in packets.iter().enumerate() {
+            assert_eq!(header(packet, 26), (i as u16, 9, i == 3));
+            // type 0 (4:2:2), Q 255, 16x8 pixels in blocks
+            assert_eq!(&packet[HEADER_LEN + 4..HEADER_LEN + 8], &[0, 255, 2, 1]);
+        }
+
+        let (scan, tables) = depacketize_jpeg(&packets);
+        assert_eq!(scan, data);
+        let expected: Vec<u8> = [1; 64].iter().chain(&[2; 64]).copied().collect();
+        assert_eq!(tables, expected);
+    }
+
+    #[test]
+    fn announces_restart_intervals() {
+        let mut rtp = Packetizer::new(Payload::Jpeg, SSRC);
+        let data = scan(3000);
+        let packets = rtp.packetize(&jpeg(&data, Some(4)), &meta(0, 0)).unwrap();
+        assert_eq!(packets.len(), 3);
+        for packet in &packets {
+            assert_eq!(packet[HEADER_LEN + 4], 64);
+            assert_eq!(
+                &packet[HEADER_LEN + 8..HEADER_LEN + 12],
+                &[0, 4, 0xff, 0xff]
+            );
+        }
+        assert_eq!(depacketize_jpeg(&packets).0, data);
+    }
+
+    #[test]
+    fn rejects_unsupported_jpeg_images() {
+        let mut rtp = Packetizer::new(Payload::Jpeg, SSRC);
+        let image = jpeg(&scan(10), None);
+        let sof = image.windows(2).position(|w| w == [0xff, 0xc0]).unwrap();
+
+        // progressive
+        let mut progressive = image.clone();
+        progressive[sof + 1] = 0xc2;
+        // 4:4:4
+        let mut subsampling = image.clone();
+        subsampling[sof + 11] = 0x11;
+        // too wide
+        let mut wide = image.clone();
+        wide[sof + 7..sof + 9].copy_from_slice(&2048u16.to_be_bytes());
+
+        for image in [progressive, subsampling, wide] {
+            let err = rtp.packetize(&image, &meta(0, 0)).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        }
+        assert!(rtp
+            .packetize(&image[..image.len() - 2], &meta(0, 0))
+            .is_err());
+        assert_eq!(rtp.sequence(), 0);
+    }
+
+    #[test]
+    fn captures_with_buffer_timestamps() {
+        let mock = Mock::new();
+        mock.set_generator(|buf, _, sequence| {
+            // the zeros after the slice are trailing padding, so it must not end with one itself
+            buf.fill(0);
+            buf[..6].copy_from_slice(&[0, 0, 0, 1, 0x65, 0x80 | sequence as u8]);
+        });
+        let dev: Device = mock.device().unwrap();
+        let mut stream = Stream::new(&dev, Type::VideoCapture).unwrap();
+        let mut rtp = Packetizer::new(Payload::H264, SSRC);
+
+        for frame in 0..3u8 {
+            let packets = rtp.capture(&mut stream).unwrap();
+            assert_eq!(packets.len(), 1);
+            let ts = timestamp(&Timestamp::new(0, i64::from(frame) * 1_000_000 / 30));
+            assert_eq!(header(&packets[0], 96), (frame as u16, ts, true));
+            assert_eq!(&packets[0][HEADER_LEN..], &[0x65, 0x80 | frame]);
+        }
+    }
+}
@@ -2,12 +2,22 @@ use bitflags::bitflags;
 use std::fmt;
 
 bitflags! {
+    /// Streaming parameter capabilities (`capability` of `v4l2_captureparm`/`v4l2_outputparm`)
     pub struct Capabilities: u32 {
+        /// The frame interval can be changed
         #[allow(clippy::unreadable_literal)]
         const TIME_PER_FRAME    = 0x1000;
     }
 }
 
+bitflags! {
+    /// Capture and output modes (`capturemode`/`outputmode`)
+    pub struct Modes: u32 {
+        /// High quality imaging mode, e.g. for still images
+        const HIGH_QUALITY      = 0x0001;
+    }
+}
+
 impl From<u32> for Capabilities {
     fn from(caps: u32) -> Self {
         Self::from_bits_truncate(caps)
@@ -25,3 +35,21 @@ impl fmt::Display for Capabilities {
         fmt::Debug::fmt(self, f)
     }
 }
+
+impl From<u32> for Modes {
+    fn from(modes: u32) -> Self {
+        Self::from_bits_truncate(modes)
+    }
+}
+
+impl From<Modes> for u32 {
+    fn from(modes: Modes) -> Self {
+        modes.bits()
+    }
+}
+
+impl fmt::Display for Modes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
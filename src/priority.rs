@@ -0,0 +1,94 @@
+//! Access priorities of file handles
+//!
+//! A device node may be opened several times, by different applications or by one application
+//! which streams on one file handle and watches events on another (see
+//! [`Handle::reopen`](crate::device::Handle::reopen)). V4L2 arbitrates between the file handles
+//! through priorities: while one of them holds [`Priority::Record`], the others cannot change
+//! settings such as formats, controls or the input, their attempts fail with
+//! [`io::ErrorKind::ResourceBusy`]. Reading settings is not affected. The priority of a file
+//! handle is given up once it is closed.
+
+use std::{fmt, io, os::raw::c_int};
+
+use crate::device::VideoDevice;
+use crate::v4l2;
+
+/// Access priority of a file handle
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Priority {
+    /// Lowest priority, e.g. for background monitoring
+    Background = 1,
+    /// Priority of new file handles
+    Interactive = 2,
+    /// Exclusive access to the settings, e.g. while recording
+    Record = 3,
+}
+
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Background => write!(f, "background"),
+            Self::Interactive => write!(f, "interactive"),
+            Self::Record => write!(f, "record"),
+        }
+    }
+}
+
+/// Returns the highest priority held by any file handle of the device
+///
+/// # Arguments
+///
+/// * `dev` - Device (single- or multi-planar)
+pub fn priority<D: VideoDevice>(dev: &D) -> io::Result<Priority> {
+    let mut prio: c_int = 0;
+    unsafe {
+        dev.handle().ioctl(
+            v4l2::vidioc::VIDIOC_G_PRIORITY,
+            &mut prio as *mut _ as *mut std::os::raw::c_void,
+        )?;
+    }
+
+    match prio {
+        1 => Ok(Priority::Background),
+        2 => Ok(Priority::Interactive),
+        3 => Ok(Priority::Record),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unknown priority: {}", prio),
+        )),
+    }
+}
+
+/// Changes the priority of the file handle
+///
+/// Raising it to [`Priority::Record`] fails with [`io::ErrorKind::ResourceBusy`] while another
+/// file handle holds it.
+///
+/// # Arguments
+///
+/// * `dev` - Device (single- or multi-planar)
+/// * `priority` - Priority to take
+///
+/// # Example
+///
+/// ```
+/// use v4l::prelude::*;
+/// use v4l::priority::{self, Priority};
+///
+/// if let Ok(dev) = Device::new(0) {
+///     match priority::set_priority(&dev, Priority::Record) {
+///         Ok(()) => println!("other applications cannot change the settings anymore"),
+///         Err(e) => println!("device is in use: {}", e),
+///     }
+/// }
+/// ```
+pub fn set_priority<D: VideoDevice>(dev: &D, priority: Priority) -> io::Result<()> {
+    let mut prio = priority as c_int;
+    unsafe {
+        dev.handle().ioctl(
+            v4l2::vidioc::VIDIOC_S_PRIORITY,
+            &mut prio as *mut _ as *mut std::os::raw::c_void,
+        )
+    }
+}
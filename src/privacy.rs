@@ -0,0 +1,157 @@
+//! Privacy shutter and switch state
+//!
+//! Laptop cameras and some USB webcams have a physical shutter or a privacy switch which blanks
+//! the sensor. The uvcvideo driver reports their state through `V4L2_CID_PRIVACY`, which is
+//! read-only for hardware switches and writable where the privacy mode is software controlled.
+//! The stream keeps delivering (black) frames while the privacy mode is engaged, so
+//! applications have to watch the control to tell the user why the image went dark.
+
+use std::{io, sync::Arc, time::Duration, time::Instant};
+
+use crate::control::{self, Control, Value};
+use crate::device::{Handle, PollFlags, VideoDevice};
+use crate::event::{self, Kind};
+use crate::v4l2;
+use crate::v4l_sys::*;
+
+/// Returns whether the privacy mode is engaged
+///
+/// Devices without a privacy control fail with [`io::ErrorKind::InvalidInput`].
+///
+/// # Arguments
+///
+/// * `dev` - Device (single- or multi-planar)
+pub fn privacy<D: VideoDevice>(dev: &D) -> io::Result<bool> {
+    match dev.handle().control(V4L2_CID_PRIVACY)?.value {
+        Value::Boolean(engaged) => Ok(engaged),
+        Value::Integer(value) => Ok(value != 0),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "privacy control is not a boolean",
+        )),
+    }
+}
+
+/// Returns whether the privacy mode is controlled by hardware, e.g. a physical shutter
+///
+/// Such privacy controls are read-only, [`set_privacy`] fails for them.
+///
+/// # Arguments
+///
+/// * `dev` - Device (single- or multi-planar)
+pub fn is_hardware<D: VideoDevice>(dev: &D) -> io::Result<bool> {
+    let desc = dev
+        .handle()
+        .query_controls()?
+        .into_iter()
+        .find(|desc| desc.id == V4L2_CID_PRIVACY)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no privacy control"))?;
+    Ok(desc.flags.contains(control::Flags::READ_ONLY))
+}
+
+/// Engages or disengages a software controlled privacy mode
+///
+/// # Arguments
+///
+/// * `dev` - Device (single- or multi-planar)
+/// * `engaged` - Whether the sensor should be blanked
+pub fn set_privacy<D: VideoDevice>(dev: &D, engaged: bool) -> io::Result<()> {
+    dev.handle().set_controls(
+        vec![Control {
+            id: V4L2_CID_PRIVACY,
+            value: Value::Boolean(engaged),
+        }],
+        None,
+    )
+}
+
+/// Watches the privacy control for changes
+///
+/// The current state is reported by the first call to [`Monitor::wait`], changes afterwards.
+/// Events are queued per file handle, so the monitor must not share its device with other code
+/// dequeuing events: it discards every event not related to the privacy control. A separate file
+/// handle is opened through [`PlanarDevice::reopen`](crate::device::PlanarDevice::reopen).
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use v4l::prelude::*;
+/// use v4l::privacy::Monitor;
+///
+/// if let Ok(dev) = Device::new(0) {
+///     if let Ok(monitor) = Monitor::new(&dev) {
+///         while let Ok(Some(engaged)) = monitor.wait(Some(Duration::from_secs(1))) {
+///             println!("privacy shutter {}", if engaged { "closed" } else { "open" });
+///         }
+///     }
+/// }
+/// ```
+pub struct Monitor {
+    handle: Arc<Handle>,
+}
+
+impl Monitor {
+    /// Returns a monitor subscribed to the privacy control
+    ///
+    /// # Arguments
+    ///
+    /// * `dev` - Device (single- or multi-planar)
+    pub fn new<D: VideoDevice>(dev: &D) -> io::Result<Self> {
+        let handle = dev.handle();
+        // the initial event reports the state at the time of subscribing
+        let mut sub = event::subscription(
+            event::Type::Ctrl,
+            V4L2_CID_PRIVACY,
+            V4L2_EVENT_SUB_FL_SEND_INITIAL,
+        );
+        unsafe {
+            handle.ioctl(
+                v4l2::vidioc::VIDIOC_SUBSCRIBE_EVENT,
+                &mut sub as *mut _ as *mut std::os::raw::c_void,
+            )?;
+        }
+        Ok(Monitor { handle })
+    }
+
+    /// Returns the privacy state once it changed, `None` in case of a timeout
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Time to wait for a change, `None` waits forever
+    pub fn wait(&self, timeout: Option<Duration>) -> io::Result<Option<bool>> {
+        let start = Instant::now();
+        loop {
+            while let Ok(ev) = self.handle.dequeue_event() {
+                if let Kind::Ctrl { changes, value, .. } = ev.kind {
+                    if ev.id == V4L2_CID_PRIVACY && changes & V4L2_EVENT_CTRL_CH_VALUE != 0 {
+                        return Ok(Some(value != 0));
+                    }
+                }
+            }
+
+            let wait = match timeout {
+                Some(timeout) => match timeout.checked_sub(start.elapsed()) {
+                    Some(left) => left.as_millis() as i32,
+                    None => return Ok(None),
+                },
+                None => -1,
+            };
+            if !self
+                .handle
+                .poll(PollFlags::PRI, wait)?
+                .contains(PollFlags::PRI)
+            {
+                return Ok(None);
+            }
+        }
+    }
+}
+
+impl Drop for Monitor {
+    fn drop(&mut self) {
+        let _ = self
+            .handle
+            .unsubscribe_event(event::Type::Ctrl, V4L2_CID_PRIVACY);
+    }
+}
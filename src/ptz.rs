@@ -0,0 +1,297 @@
+//! Pan, tilt and zoom
+//!
+//! Conferencing cameras expose their motors through the camera class controls, but vendors
+//! disagree on which of them they implement and on their ranges: one camera only has absolute
+//! positions in arc seconds, another relative moves of a single step, a third continuous
+//! movement at a given speed. [`Ptz`] maps all of them to normalized values, so the same code
+//! drives any of these cameras.
+
+use std::{io, sync::Arc};
+
+use crate::control::{Control, Description, Value};
+use crate::device::{Handle, VideoDevice};
+use crate::v4l_sys::*;
+
+/// Axis of movement
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Axis {
+    Pan,
+    Tilt,
+    Zoom,
+}
+
+impl Axis {
+    /// Returns the absolute, relative and speed control identifiers
+    fn controls(self) -> (u32, u32, u32) {
+        match self {
+            Axis::Pan => (
+                V4L2_CID_PAN_ABSOLUTE,
+                V4L2_CID_PAN_RELATIVE,
+                V4L2_CID_PAN_SPEED,
+            ),
+            Axis::Tilt => (
+                V4L2_CID_TILT_ABSOLUTE,
+                V4L2_CID_TILT_RELATIVE,
+                V4L2_CID_TILT_SPEED,
+            ),
+            Axis::Zoom => (
+                V4L2_CID_ZOOM_ABSOLUTE,
+                V4L2_CID_ZOOM_RELATIVE,
+                V4L2_CID_ZOOM_CONTINUOUS,
+            ),
+        }
+    }
+}
+
+/// Value range of a control
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Range {
+    id: u32,
+    minimum: i64,
+    maximum: i64,
+    step: u64,
+}
+
+impl Range {
+    /// Returns the control value at a position between 0.0 (minimum) and 1.0 (maximum)
+    fn value(&self, position: f64) -> i64 {
+        let span = (self.maximum - self.minimum) as f64;
+        let step = self.step.max(1) as f64;
+        let steps = (position.clamp(0.0, 1.0) * span / step).round();
+        (self.minimum + (steps * step) as i64).min(self.maximum)
+    }
+
+    /// Returns the position between 0.0 (minimum) and 1.0 (maximum) of a control value
+    fn position(&self, value: i64) -> f64 {
+        if self.maximum == self.minimum {
+            return 0.0;
+        }
+        (value - self.minimum) as f64 / (self.maximum - self.minimum) as f64
+    }
+
+    /// Returns the control value for a signed fraction between -1.0 and 1.0
+    ///
+    /// Negative and positive values are scaled separately, zero always maps to zero.
+    fn signed(&self, fraction: f64) -> i64 {
+        let fraction = fraction.clamp(-1.0, 1.0);
+        let limit = if fraction < 0.0 {
+            -self.minimum
+        } else {
+            self.maximum
+        };
+        let value = (fraction * limit as f64).round() as i64;
+        if value != 0 {
+            // round away from zero, a move must not be lost to the step size
+            let step = self.step.max(1) as i64;
+            let steps = (value.abs() + step - 1) / step;
+            value.signum() * (steps * step).min(limit.abs())
+        } else {
+            0
+        }
+    }
+}
+
+impl From<&Description> for Range {
+    fn from(desc: &Description) -> Self {
+        Range {
+            id: desc.id,
+            minimum: desc.minimum,
+            maximum: desc.maximum,
+            step: desc.step,
+        }
+    }
+}
+
+/// Controls implemented for one axis
+#[derive(Debug, Copy, Clone, Default)]
+struct Controls {
+    absolute: Option<Range>,
+    relative: Option<Range>,
+    speed: Option<Range>,
+}
+
+/// Pan, tilt and zoom with normalized values
+///
+/// Positions range from 0.0 to 1.0 (e.g. leftmost to rightmost for pan, widest to narrowest
+/// for zoom), relative moves and speeds from -1.0 to 1.0 in terms of the control ranges
+/// reported by the driver.
+///
+/// # Example
+///
+/// ```
+/// use v4l::prelude::*;
+/// use v4l::ptz::{Axis, Ptz};
+///
+/// if let Ok(dev) = Device::new(0) {
+///     if let Ok(ptz) = Ptz::new(&dev) {
+///         if ptz.supports(Axis::Zoom) {
+///             ptz.set_position(Axis::Zoom, 0.5).unwrap();
+///         }
+///         ptz.center().unwrap();
+///     }
+/// }
+/// ```
+pub struct Ptz {
+    handle: Arc<Handle>,
+    pan: Controls,
+    tilt: Controls,
+    zoom: Controls,
+}
+
+impl Ptz {
+    /// Returns the PTZ controls of a device
+    ///
+    /// The control ranges are queried once, devices without any PTZ control are accepted as
+    /// well ([`Ptz::supports`] reports `false` for every axis then).
+    ///
+    /// # Arguments
+    ///
+    /// * `dev` - Device (single- or multi-planar)
+    pub fn new<D: VideoDevice>(dev: &D) -> io::Result<Self> {
+        let handle = dev.handle();
+        let descs = handle.query_controls()?;
+
+        let controls = |axis: Axis| {
+            let (absolute, relative, speed) = axis.controls();
+            let range = |id| descs.iter().find(|desc| desc.id == id).map(Range::from);
+            Controls {
+                absolute: range(absolute),
+                relative: range(relative),
+                speed: range(speed),
+            }
+        };
+
+        Ok(Ptz {
+            pan: controls(Axis::Pan),
+            tilt: controls(Axis::Tilt),
+            zoom: controls(Axis::Zoom),
+            handle,
+        })
+    }
+
+    fn axis(&self, axis: Axis) -> &Controls {
+        match axis {
+            Axis::Pan => &self.pan,
+            Axis::Tilt => &self.tilt,
+            Axis::Zoom => &self.zoom,
+        }
+    }
+
+    /// Returns whether the device can move along an axis at all
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - Axis of movement
+    pub fn supports(&self, axis: Axis) -> bool {
+        let controls = self.axis(axis);
+        controls.absolute.is_some() || controls.relative.is_some() || controls.speed.is_some()
+    }
+
+    /// Returns whether the absolute position of an axis is known and can be set
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - Axis of movement
+    pub fn is_absolute(&self, axis: Axis) -> bool {
+        self.axis(axis).absolute.is_some()
+    }
+
+    /// Returns the current position between 0.0 and 1.0
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - Axis of movement
+    pub fn position(&self, axis: Axis) -> io::Result<f64> {
+        let range = self.axis(axis).absolute.ok_or_else(|| unsupported(axis))?;
+        match self.handle.control(range.id)?.value {
+            Value::Integer(value) => Ok(range.position(value)),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "position control is not an integer",
+            )),
+        }
+    }
+
+    /// Moves to a position between 0.0 and 1.0
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - Axis of movement
+    /// * `position` - Target position, clamped to the valid range
+    pub fn set_position(&self, axis: Axis, position: f64) -> io::Result<()> {
+        let range = self.axis(axis).absolute.ok_or_else(|| unsupported(axis))?;
+        self.set(range.id, range.value(position))
+    }
+
+    /// Moves by a fraction between -1.0 and 1.0
+    ///
+    /// Devices with a relative control move by that fraction of its range, which is often a
+    /// single step in either direction. Devices with absolute positions only move by that
+    /// fraction of the full range instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - Axis of movement
+    /// * `delta` - Distance and direction to move
+    pub fn move_by(&self, axis: Axis, delta: f64) -> io::Result<()> {
+        let controls = self.axis(axis);
+        if let Some(range) = controls.relative {
+            let value = range.signed(delta);
+            if value == 0 {
+                return Ok(());
+            }
+            return self.set(range.id, value);
+        }
+
+        let position = self.position(axis)?;
+        self.set_position(axis, position + delta)
+    }
+
+    /// Starts moving at a speed between -1.0 and 1.0, zero stops
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - Axis of movement
+    /// * `speed` - Speed and direction of the movement
+    pub fn set_speed(&self, axis: Axis, speed: f64) -> io::Result<()> {
+        let range = self.axis(axis).speed.ok_or_else(|| unsupported(axis))?;
+        self.set(range.id, range.signed(speed))
+    }
+
+    /// Stops all continuous movements
+    pub fn stop(&self) -> io::Result<()> {
+        for axis in [Axis::Pan, Axis::Tilt, Axis::Zoom] {
+            if self.axis(axis).speed.is_some() {
+                self.set_speed(axis, 0.0)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Moves pan and tilt to the center and zooms out, as far as the device supports it
+    pub fn center(&self) -> io::Result<()> {
+        for (axis, position) in [(Axis::Pan, 0.5), (Axis::Tilt, 0.5), (Axis::Zoom, 0.0)] {
+            if self.is_absolute(axis) {
+                self.set_position(axis, position)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn set(&self, id: u32, value: i64) -> io::Result<()> {
+        self.handle.set_controls(
+            vec![Control {
+                id,
+                value: Value::Integer(value),
+            }],
+            None,
+        )
+    }
+}
+
+fn unsupported(axis: Axis) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!("device does not support this kind of {:?} control", axis),
+    )
+}
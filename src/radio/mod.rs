@@ -0,0 +1,489 @@
+//! Radio devices
+//!
+//! AM/FM receivers are exposed as /dev/radioX nodes. They do not stream frames, instead the tuner
+//! is controlled through ioctls and the audio is routed to a sound card (or an ALSA device of the
+//! same driver). Receivers supporting RDS deliver the raw data blocks through read().
+
+use bitflags::bitflags;
+use std::path::Path;
+use std::{
+    fmt, io, mem,
+    os::fd::{AsFd, AsRawFd, BorrowedFd, OwnedFd, RawFd},
+    str,
+    sync::Arc,
+};
+
+use crate::capability::Capabilities;
+use crate::control::{self, Control};
+use crate::device::Handle;
+use crate::v4l2;
+use crate::v4l_sys::*;
+
+pub mod rds;
+
+bitflags! {
+    #[allow(clippy::unreadable_literal)]
+    pub struct TunerFlags: u32 {
+        const LOW                   = 0x0001;
+        const NORM                  = 0x0002;
+        const HWSEEK_BOUNDED        = 0x0004;
+        const HWSEEK_WRAP           = 0x0008;
+        const STEREO                = 0x0010;
+        const LANG2                 = 0x0020;
+        const LANG1                 = 0x0040;
+        const RDS                   = 0x0080;
+        const RDS_BLOCK_IO          = 0x0100;
+        const RDS_CONTROLS          = 0x0200;
+        const FREQ_BANDS            = 0x0400;
+        const HWSEEK_PROG_LIM       = 0x0800;
+        const ONE_HZ                = 0x1000;
+    }
+}
+
+impl From<u32> for TunerFlags {
+    fn from(flags: u32) -> Self {
+        Self::from_bits_truncate(flags)
+    }
+}
+
+impl From<TunerFlags> for u32 {
+    fn from(flags: TunerFlags) -> Self {
+        flags.bits()
+    }
+}
+
+impl fmt::Display for TunerFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl TunerFlags {
+    /// Returns the frequency in Hz of one tuning unit
+    fn unit(&self) -> f64 {
+        if self.contains(TunerFlags::ONE_HZ) {
+            1.0
+        } else if self.contains(TunerFlags::LOW) {
+            62.5
+        } else {
+            62_500.0
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Radio tuner
+pub struct Tuner {
+    /// Tuner index
+    pub index: u32,
+    /// Tuner name
+    pub name: String,
+    /// Capability flags
+    pub capabilities: TunerFlags,
+    /// Lowest tunable frequency in Hz
+    pub range_low: u64,
+    /// Highest tunable frequency in Hz
+    pub range_high: u64,
+    /// Received audio subprograms and RDS, as `V4L2_TUNER_SUB_*` flags
+    pub rx_subchans: u32,
+    /// Signal strength, from 0 to 65535 if known
+    pub signal: u16,
+    /// Automatic frequency control, negative if the frequency is too low
+    pub afc: i32,
+}
+
+impl Tuner {
+    /// Returns whether a stereo signal is received
+    pub fn is_stereo(&self) -> bool {
+        self.rx_subchans & V4L2_TUNER_SUB_STEREO != 0
+    }
+
+    /// Returns whether RDS data is received
+    pub fn has_rds(&self) -> bool {
+        self.rx_subchans & V4L2_TUNER_SUB_RDS != 0
+    }
+}
+
+impl From<v4l2_tuner> for Tuner {
+    fn from(tuner: v4l2_tuner) -> Self {
+        let capabilities = TunerFlags::from(tuner.capability);
+        let unit = capabilities.unit();
+        Tuner {
+            index: tuner.index,
+            name: str::from_utf8(&tuner.name)
+                .unwrap_or_default()
+                .trim_matches(char::from(0))
+                .to_string(),
+            capabilities,
+            range_low: (f64::from(tuner.rangelow) * unit) as u64,
+            range_high: (f64::from(tuner.rangehigh) * unit) as u64,
+            rx_subchans: tuner.rxsubchans,
+            signal: tuner.signal.clamp(0, 65535) as u16,
+            afc: tuner.afc,
+        }
+    }
+}
+
+impl fmt::Display for Tuner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Name         : {}", self.name)?;
+        writeln!(f, "Capabilities : {}", self.capabilities)?;
+        writeln!(
+            f,
+            "Range        : {} Hz - {} Hz",
+            self.range_low, self.range_high
+        )?;
+        writeln!(f, "Signal       : {}", self.signal)?;
+        writeln!(f, "Stereo       : {}", self.is_stereo())?;
+        writeln!(f, "RDS          : {}", self.has_rds())?;
+        Ok(())
+    }
+}
+
+/// Hardware seek parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Seek {
+    /// Seek towards higher frequencies
+    pub upward: bool,
+    /// Continue at the other end of the band once the limit was reached
+    pub wrap_around: bool,
+    /// Channel spacing in Hz, zero selects the driver default
+    pub spacing: u32,
+}
+
+impl Default for Seek {
+    fn default() -> Self {
+        Seek {
+            upward: true,
+            wrap_around: true,
+            spacing: 0,
+        }
+    }
+}
+
+/// Raw RDS block as delivered by the driver
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RdsBlock {
+    /// Block data
+    pub data: u16,
+    /// Block position within the group, one of `V4L2_RDS_BLOCK_*`
+    pub id: u8,
+    /// Bit errors were corrected by the receiver
+    pub corrected: bool,
+    /// The block contains uncorrectable errors
+    pub error: bool,
+}
+
+impl From<v4l2_rds_data> for RdsBlock {
+    fn from(rds: v4l2_rds_data) -> Self {
+        RdsBlock {
+            data: u16::from_le_bytes([rds.lsb, rds.msb]),
+            id: rds.block & V4L2_RDS_BLOCK_MSK as u8,
+            corrected: u32::from(rds.block) & V4L2_RDS_BLOCK_CORRECTED != 0,
+            error: u32::from(rds.block) & V4L2_RDS_BLOCK_ERROR != 0,
+        }
+    }
+}
+
+/// Linux radio device abstraction
+pub struct RadioDevice {
+    /// Raw handle
+    handle: Arc<Handle>,
+}
+
+impl RadioDevice {
+    /// Returns a radio device by index
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index (0: first, 1: second, ..)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::radio::RadioDevice;
+    /// let dev = RadioDevice::new(0);
+    /// ```
+    pub fn new(index: usize) -> io::Result<Self> {
+        Self::with_path(format!("{}{}", "/dev/radio", index))
+    }
+
+    /// Returns a radio device by path
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path (e.g. "/dev/radio0")
+    pub fn with_path<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(RadioDevice {
+            handle: Arc::new(Handle::open(path)?),
+        })
+    }
+
+    /// Returns a radio device wrapping an already opened file descriptor
+    ///
+    /// # Arguments
+    ///
+    /// * `fd` - File descriptor of an opened radio device node
+    pub fn with_fd(fd: OwnedFd) -> io::Result<Self> {
+        Ok(RadioDevice {
+            handle: Arc::new(Handle::from_fd(fd)?),
+        })
+    }
+
+    /// Returns the raw device handle
+    pub fn handle(&self) -> Arc<Handle> {
+        self.handle.clone()
+    }
+
+    /// Returns video4linux framework defined information such as card, driver, etc.
+    pub fn query_caps(&self) -> io::Result<Capabilities> {
+        unsafe {
+            let mut v4l2_caps: v4l2_capability = mem::zeroed();
+            self.handle.ioctl(
+                v4l2::vidioc::VIDIOC_QUERYCAP,
+                &mut v4l2_caps as *mut _ as *mut std::os::raw::c_void,
+            )?;
+
+            Ok(Capabilities::from(v4l2_caps))
+        }
+    }
+
+    /// Returns the supported controls such as volume, mute, deemphasis, etc.
+    pub fn query_controls(&self) -> io::Result<Vec<control::Description>> {
+        self.handle.query_controls()
+    }
+
+    /// Returns the control value for an ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Control identifier
+    pub fn control(&self, id: u32) -> io::Result<Control> {
+        self.handle.control(id)
+    }
+
+    /// Modifies the control value
+    ///
+    /// # Arguments
+    ///
+    /// * `ctrl` - Control to be set
+    pub fn set_control(&self, ctrl: Control) -> io::Result<()> {
+        self.handle.set_controls(vec![ctrl], None)
+    }
+
+    fn v4l2_tuner(&self, index: u32) -> io::Result<v4l2_tuner> {
+        unsafe {
+            let mut v4l2_tuner = v4l2_tuner {
+                index,
+                ..mem::zeroed()
+            };
+            self.handle.ioctl(
+                v4l2::vidioc::VIDIOC_G_TUNER,
+                &mut v4l2_tuner as *mut _ as *mut std::os::raw::c_void,
+            )?;
+
+            Ok(v4l2_tuner)
+        }
+    }
+
+    /// Returns the tuner state, including the current signal strength
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Tuner index, zero for most devices
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::radio::RadioDevice;
+    ///
+    /// if let Ok(dev) = RadioDevice::new(0) {
+    ///     if let Ok(tuner) = dev.tuner(0) {
+    ///         print!("{}", tuner);
+    ///     }
+    /// }
+    /// ```
+    pub fn tuner(&self, index: u32) -> io::Result<Tuner> {
+        Ok(Tuner::from(self.v4l2_tuner(index)?))
+    }
+
+    /// Returns the frequency the tuner is tuned to in Hz
+    ///
+    /// # Arguments
+    ///
+    /// * `tuner` - Tuner index
+    pub fn frequency(&self, tuner: u32) -> io::Result<u64> {
+        let unit = TunerFlags::from(self.v4l2_tuner(tuner)?.capability).unit();
+        unsafe {
+            let mut v4l2_freq = v4l2_frequency {
+                tuner,
+                ..mem::zeroed()
+            };
+            self.handle.ioctl(
+                v4l2::vidioc::VIDIOC_G_FREQUENCY,
+                &mut v4l2_freq as *mut _ as *mut std::os::raw::c_void,
+            )?;
+
+            Ok((f64::from(v4l2_freq.frequency) * unit) as u64)
+        }
+    }
+
+    /// Tunes to a frequency
+    ///
+    /// The frequency is rounded to the resolution of the tuner and clamped to its range by the
+    /// driver.
+    ///
+    /// # Arguments
+    ///
+    /// * `tuner` - Tuner index
+    /// * `hz` - Frequency in Hz
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::radio::RadioDevice;
+    ///
+    /// if let Ok(dev) = RadioDevice::new(0) {
+    ///     let res = dev.set_frequency(0, 101_300_000);
+    /// }
+    /// ```
+    pub fn set_frequency(&self, tuner: u32, hz: u64) -> io::Result<()> {
+        let v4l2_tuner = self.v4l2_tuner(tuner)?;
+        let unit = TunerFlags::from(v4l2_tuner.capability).unit();
+        unsafe {
+            let mut v4l2_freq = v4l2_frequency {
+                tuner,
+                type_: v4l2_tuner.type_,
+                frequency: (hz as f64 / unit).round() as u32,
+                ..mem::zeroed()
+            };
+            self.handle.ioctl(
+                v4l2::vidioc::VIDIOC_S_FREQUENCY,
+                &mut v4l2_freq as *mut _ as *mut std::os::raw::c_void,
+            )
+        }
+    }
+
+    /// Seeks to the next station and returns its frequency in Hz
+    ///
+    /// This blocks until a station was found. Fails with [`io::ErrorKind::NotFound`] if the
+    /// whole band was searched without success.
+    ///
+    /// # Arguments
+    ///
+    /// * `tuner` - Tuner index
+    /// * `seek` - Seek parameters
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::radio::{RadioDevice, Seek};
+    ///
+    /// if let Ok(dev) = RadioDevice::new(0) {
+    ///     if let Ok(hz) = dev.seek(0, Seek::default()) {
+    ///         println!("found station at {} Hz", hz);
+    ///     }
+    /// }
+    /// ```
+    pub fn seek(&self, tuner: u32, seek: Seek) -> io::Result<u64> {
+        let v4l2_tuner = self.v4l2_tuner(tuner)?;
+        let mut v4l2_seek = v4l2_hw_freq_seek {
+            tuner,
+            type_: v4l2_tuner.type_,
+            seek_upward: seek.upward as u32,
+            wrap_around: seek.wrap_around as u32,
+            spacing: seek.spacing,
+            ..unsafe { mem::zeroed() }
+        };
+
+        // the seek ioctl refuses to run on non-blocking file handles
+        let fd = self.handle.as_raw_fd();
+        let flags = unsafe { libc::fcntl(fd, libc::F_GETFL) };
+        if flags == -1 || unsafe { libc::fcntl(fd, libc::F_SETFL, flags & !libc::O_NONBLOCK) } == -1
+        {
+            return Err(io::Error::last_os_error());
+        }
+        let res = unsafe {
+            self.handle.ioctl(
+                v4l2::vidioc::VIDIOC_S_HW_FREQ_SEEK,
+                &mut v4l2_seek as *mut _ as *mut std::os::raw::c_void,
+            )
+        };
+        unsafe {
+            libc::fcntl(fd, libc::F_SETFL, flags);
+        }
+
+        match res {
+            Err(e) if e.raw_os_error() == Some(libc::ENODATA) => {
+                Err(io::Error::new(io::ErrorKind::NotFound, "no station found"))
+            }
+            Err(e) => Err(e),
+            Ok(()) => self.frequency(tuner),
+        }
+    }
+
+    /// Reads the RDS blocks received so far
+    ///
+    /// Returns an empty vector if no block is pending. Use [`Handle::poll`] with
+    /// `PollFlags::IN` to wait for new blocks.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_blocks` - Maximum number of blocks to read
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::device::PollFlags;
+    /// use v4l::radio::RadioDevice;
+    ///
+    /// if let Ok(dev) = RadioDevice::new(0) {
+    ///     if dev.handle().poll(PollFlags::IN, 1000).is_ok_and(|ready| !ready.is_empty()) {
+    ///         for block in dev.read_rds(64).unwrap_or_default() {
+    ///             println!("{:?}", block);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn read_rds(&self, max_blocks: usize) -> io::Result<Vec<RdsBlock>> {
+        let size = mem::size_of::<v4l2_rds_data>();
+        let mut buf = vec![0u8; max_blocks * size];
+        let ret = unsafe {
+            libc::read(
+                self.handle.as_raw_fd(),
+                buf.as_mut_ptr() as *mut std::os::raw::c_void,
+                buf.len(),
+            )
+        };
+        if ret == -1 {
+            let err = io::Error::last_os_error();
+            if err.kind() == io::ErrorKind::WouldBlock {
+                return Ok(Vec::new());
+            }
+            return Err(err);
+        }
+
+        Ok(buf[..ret as usize]
+            .chunks_exact(size)
+            .map(|block| {
+                RdsBlock::from(v4l2_rds_data {
+                    lsb: block[0],
+                    msb: block[1],
+                    block: block[2],
+                })
+            })
+            .collect())
+    }
+}
+
+impl AsFd for RadioDevice {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.handle.as_fd()
+    }
+}
+
+impl AsRawFd for RadioDevice {
+    fn as_raw_fd(&self) -> RawFd {
+        self.handle.as_raw_fd()
+    }
+}
@@ -0,0 +1,391 @@
+//! RDS decoding
+//!
+//! Receivers deliver RDS as a sequence of 16 bit blocks. Four blocks make up a group, the type of
+//! a group is encoded in its second block. [`Decoder`] reassembles the groups and collects the
+//! station information which is commonly shown by radio applications.
+
+use super::RdsBlock;
+use crate::v4l_sys::*;
+
+/// Decoder of the basic RDS station information
+///
+/// Groups containing a block with uncorrectable errors are dropped entirely.
+///
+/// # Example
+///
+/// ```
+/// use v4l::device::PollFlags;
+/// use v4l::radio::rds::Decoder;
+/// use v4l::radio::RadioDevice;
+///
+/// if let Ok(dev) = RadioDevice::new(0) {
+///     let mut decoder = Decoder::new();
+///     while decoder.program_service().is_none() {
+///         let ready = dev.handle().poll(PollFlags::IN, 1000);
+///         if ready.map_or(true, |ready| ready.is_empty()) {
+///             break;
+///         }
+///         for block in dev.read_rds(64).unwrap_or_default() {
+///             decoder.push(block);
+///         }
+///     }
+///     println!("station: {:?}", decoder.program_service());
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Decoder {
+    group: [Option<u16>; 4],
+    pi: Option<u16>,
+    pty: Option<u8>,
+
+    ps: [u8; 8],
+    ps_segments: u8,
+    ps_complete: Option<String>,
+
+    rt: [u8; 64],
+    rt_segments: u64,
+    rt_end: Option<usize>,
+    rt_ab: Option<bool>,
+    rt_complete: Option<String>,
+
+    af: Vec<u64>,
+}
+
+/// Decodes text characters
+///
+/// The RDS character table matches ASCII for the printable characters, others are mapped as if
+/// they were Latin-1.
+fn text(bytes: &[u8]) -> String {
+    bytes.iter().map(|c| char::from(*c)).collect()
+}
+
+impl Default for Decoder {
+    fn default() -> Self {
+        Decoder {
+            group: [None; 4],
+            pi: None,
+            pty: None,
+            ps: [b' '; 8],
+            ps_segments: 0,
+            ps_complete: None,
+            rt: [b' '; 64],
+            rt_segments: 0,
+            rt_end: None,
+            rt_ab: None,
+            rt_complete: None,
+            af: Vec::new(),
+        }
+    }
+}
+
+impl Decoder {
+    /// Returns a decoder without any station information
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drops all station information, e.g. after tuning to another frequency
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Feeds a block, returns whether it completed a group
+    ///
+    /// # Arguments
+    ///
+    /// * `block` - Block as read from the device
+    pub fn push(&mut self, block: RdsBlock) -> bool {
+        if block.error {
+            self.group = [None; 4];
+            return false;
+        }
+
+        let position = match u32::from(block.id) {
+            V4L2_RDS_BLOCK_A => 0,
+            V4L2_RDS_BLOCK_B => 1,
+            V4L2_RDS_BLOCK_C | V4L2_RDS_BLOCK_C_ALT => 2,
+            V4L2_RDS_BLOCK_D => 3,
+            _ => {
+                self.group = [None; 4];
+                return false;
+            }
+        };
+        if position == 0 {
+            self.group = [None; 4];
+        } else if self.group[position - 1].is_none() {
+            // a block got lost, the group is incomplete
+            self.group = [None; 4];
+            return false;
+        }
+        self.group[position] = Some(block.data);
+
+        match self.group {
+            [Some(a), Some(b), Some(c), Some(d)] => {
+                self.group = [None; 4];
+                self.decode(a, b, c, d);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn decode(&mut self, a: u16, b: u16, c: u16, d: u16) {
+        if self.pi.is_some() && self.pi != Some(a) {
+            // another station, the collected information is stale
+            self.reset();
+        }
+        self.pi = Some(a);
+        self.pty = Some(((b >> 5) & 0x1f) as u8);
+
+        let version_b = b & 0x0800 != 0;
+        match b >> 12 {
+            0 => {
+                let segment = (b & 0x3) as usize;
+                self.ps[segment * 2..segment * 2 + 2].copy_from_slice(&d.to_be_bytes());
+                self.ps_segments |= 1 << segment;
+                if self.ps_segments == 0xf {
+                    self.ps_complete = Some(text(&self.ps).trim_end().to_string());
+                    self.ps_segments = 0;
+                }
+                if !version_b {
+                    self.push_af(c);
+                }
+            }
+            2 => {
+                let ab = b & 0x10 != 0;
+                if self.rt_ab.is_some() && self.rt_ab != Some(ab) {
+                    // the station started transmitting a new text
+                    self.rt = [b' '; 64];
+                    self.rt_segments = 0;
+                    self.rt_end = None;
+                }
+                self.rt_ab = Some(ab);
+
+                let segment = (b & 0xf) as usize;
+                let mut chars = [0u8; 4];
+                let chars = if version_b {
+                    chars[..2].copy_from_slice(&d.to_be_bytes());
+                    &chars[..2]
+                } else {
+                    chars[..2].copy_from_slice(&c.to_be_bytes());
+                    chars[2..].copy_from_slice(&d.to_be_bytes());
+                    &chars[..]
+                };
+                let offset = segment * chars.len();
+                self.rt[offset..offset + chars.len()].copy_from_slice(chars);
+                self.rt_segments |= 1 << segment;
+                if let Some(end) = chars.iter().position(|c| *c == 0x0d) {
+                    self.rt_end = Some(offset + end);
+                }
+
+                // version B texts are at most 32 characters long
+                let len = self.rt_end.unwrap_or(if version_b { 32 } else { 64 });
+                let segments = len.div_ceil(chars.len());
+                let mask = (1u64 << segments) - 1;
+                if len > 0 && self.rt_segments & mask == mask {
+                    self.rt_complete = Some(text(&self.rt[..len]).trim_end().to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn push_af(&mut self, codes: u16) {
+        for code in codes.to_be_bytes() {
+            // VHF codes, in steps of 100 kHz from 87.6 MHz on; the others announce the number of
+            // frequencies in the list, pad it or escape LF/MF frequencies
+            if (1..=204).contains(&code) {
+                let hz = 87_500_000 + u64::from(code) * 100_000;
+                if let Err(pos) = self.af.binary_search(&hz) {
+                    self.af.insert(pos, hz);
+                }
+            }
+        }
+    }
+
+    /// Returns the program identification code
+    pub fn pi(&self) -> Option<u16> {
+        self.pi
+    }
+
+    /// Returns the program type code (e.g. 10 for pop music in Europe)
+    pub fn program_type(&self) -> Option<u8> {
+        self.pty
+    }
+
+    /// Returns the program service name, usually the station name
+    ///
+    /// Some stations cycle through several texts, this is the one completed most recently.
+    pub fn program_service(&self) -> Option<&str> {
+        self.ps_complete.as_deref()
+    }
+
+    /// Returns the radio text, e.g. the title being played
+    pub fn radio_text(&self) -> Option<&str> {
+        self.rt_complete.as_deref()
+    }
+
+    /// Returns the alternative frequencies in Hz the program is also transmitted on
+    pub fn alternative_frequencies(&self) -> &[u64] {
+        &self.af
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PI: u16 = 0xd3c2;
+    /// Program type 10, pop music
+    const PTY: u16 = 10 << 5;
+
+    /// Returns a block as read from the device
+    ///
+    /// The receiver reports the syndrome check in the block byte: bits 0-2 hold the position after
+    /// correction, bits 3-5 as received.
+    fn block(id: u32, data: u16, flags: u32) -> RdsBlock {
+        let [msb, lsb] = data.to_be_bytes();
+        RdsBlock::from(v4l2_rds_data {
+            lsb,
+            msb,
+            block: (id | id << 3 | flags) as u8,
+        })
+    }
+
+    /// Feeds a group without errors, returns whether it was decoded
+    fn group(decoder: &mut Decoder, [a, b, c, d]: [u16; 4]) -> bool {
+        let c_id = if b & 0x0800 != 0 {
+            V4L2_RDS_BLOCK_C_ALT
+        } else {
+            V4L2_RDS_BLOCK_C
+        };
+        decoder.push(block(V4L2_RDS_BLOCK_A, a, 0));
+        decoder.push(block(V4L2_RDS_BLOCK_B, b, 0));
+        decoder.push(block(c_id, c, 0));
+        decoder.push(block(V4L2_RDS_BLOCK_D, d, 0))
+    }
+
+    fn chars(text: &[u8; 2]) -> u16 {
+        u16::from_be_bytes(*text)
+    }
+
+    #[test]
+    fn decodes_block_bytes() {
+        let rds = v4l2_rds_data {
+            lsb: 0x34,
+            msb: 0x12,
+            block: (V4L2_RDS_BLOCK_C_ALT | V4L2_RDS_BLOCK_C << 3 | V4L2_RDS_BLOCK_CORRECTED) as u8,
+        };
+        let block = RdsBlock::from(rds);
+        assert_eq!(block.data, 0x1234);
+        assert_eq!(u32::from(block.id), V4L2_RDS_BLOCK_C_ALT);
+        assert!(block.corrected);
+        assert!(!block.error);
+
+        let rds = v4l2_rds_data {
+            block: (V4L2_RDS_BLOCK_D | V4L2_RDS_BLOCK_ERROR) as u8,
+            ..rds
+        };
+        assert!(RdsBlock::from(rds).error);
+    }
+
+    #[test]
+    fn decodes_program_service_and_alternative_frequencies() {
+        let mut decoder = Decoder::new();
+        // two frequencies follow (224 + 2): 88.0 MHz, then 89.1 and 90.7 MHz and a filler
+        let afs = [0xe205, 0x1020, 0xcdcd, 0xcdcd];
+        for (segment, text) in [b"RA", b"DI", b"O ", b"1 "].iter().enumerate() {
+            assert_eq!(decoder.program_service(), None);
+            let b = PTY | segment as u16;
+            assert!(group(&mut decoder, [PI, b, afs[segment], chars(text)]));
+        }
+
+        assert_eq!(decoder.pi(), Some(PI));
+        assert_eq!(decoder.program_type(), Some(10));
+        assert_eq!(decoder.program_service(), Some("RADIO 1"));
+        assert_eq!(
+            decoder.alternative_frequencies(),
+            [88_000_000, 89_100_000, 90_700_000]
+        );
+    }
+
+    #[test]
+    fn drops_groups_with_uncorrectable_blocks() {
+        let mut decoder = Decoder::new();
+        for (segment, text) in [b"RA", b"DI", b"O "].iter().enumerate() {
+            group(
+                &mut decoder,
+                [PI, PTY | segment as u16, 0xcdcd, chars(text)],
+            );
+        }
+
+        // corrected blocks are as good as any other, blocks with errors spoil the group
+        let b = PTY | 3;
+        decoder.push(block(V4L2_RDS_BLOCK_A, PI, V4L2_RDS_BLOCK_CORRECTED));
+        decoder.push(block(V4L2_RDS_BLOCK_B, b, 0));
+        decoder.push(block(V4L2_RDS_BLOCK_C, 0xcdcd, V4L2_RDS_BLOCK_CORRECTED));
+        assert!(!decoder.push(block(V4L2_RDS_BLOCK_D, chars(b"1 "), V4L2_RDS_BLOCK_ERROR)));
+        assert_eq!(decoder.program_service(), None);
+
+        decoder.push(block(V4L2_RDS_BLOCK_A, PI, V4L2_RDS_BLOCK_CORRECTED));
+        decoder.push(block(V4L2_RDS_BLOCK_B, b, 0));
+        decoder.push(block(V4L2_RDS_BLOCK_C, 0xcdcd, V4L2_RDS_BLOCK_CORRECTED));
+        assert!(decoder.push(block(V4L2_RDS_BLOCK_D, chars(b"1 "), 0)));
+        assert_eq!(decoder.program_service(), Some("RADIO 1"));
+    }
+
+    #[test]
+    fn drops_groups_with_lost_blocks() {
+        let mut decoder = Decoder::new();
+        decoder.push(block(V4L2_RDS_BLOCK_A, PI, 0));
+        decoder.push(block(V4L2_RDS_BLOCK_B, PTY, 0));
+        assert!(!decoder.push(block(V4L2_RDS_BLOCK_D, chars(b"RA"), 0)));
+        assert_eq!(decoder.pi(), None);
+    }
+
+    #[test]
+    fn decodes_radio_text() {
+        let mut decoder = Decoder::new();
+        // version A, four characters per group, terminated by a carriage return
+        let b = (2 << 12) | PTY;
+        group(&mut decoder, [PI, b, chars(b"He"), chars(b"ll")]);
+        assert_eq!(decoder.radio_text(), None);
+        group(&mut decoder, [PI, b | 1, chars(b"o\r"), chars(b"  ")]);
+        assert_eq!(decoder.radio_text(), Some("Hello"));
+
+        // the A/B flag toggles with every new text
+        let b = b | 0x10;
+        group(&mut decoder, [PI, b | 1, chars(b"d\r"), chars(b"  ")]);
+        assert_eq!(decoder.radio_text(), Some("Hello"));
+        group(&mut decoder, [PI, b, chars(b"Wo"), chars(b"rl")]);
+        assert_eq!(decoder.radio_text(), Some("World"));
+    }
+
+    #[test]
+    fn decodes_short_radio_text() {
+        let mut decoder = Decoder::new();
+        // version B, two characters per group, block C' repeats the PI code
+        let b = (2 << 12) | 0x0800 | PTY;
+        group(&mut decoder, [PI, b, PI, chars(b"Hi")]);
+        group(&mut decoder, [PI, b | 1, PI, chars(b"!\r")]);
+        assert_eq!(decoder.radio_text(), Some("Hi!"));
+    }
+
+    #[test]
+    fn forgets_the_previous_station() {
+        let mut decoder = Decoder::new();
+        for (segment, text) in [b"RA", b"DI", b"O ", b"1 "].iter().enumerate() {
+            group(
+                &mut decoder,
+                [PI, PTY | segment as u16, 0xe205, chars(text)],
+            );
+        }
+        assert_eq!(decoder.program_service(), Some("RADIO 1"));
+
+        group(&mut decoder, [0xc201, 3 << 5, 0xcdcd, chars(b"JA")]);
+        assert_eq!(decoder.pi(), Some(0xc201));
+        assert_eq!(decoder.program_type(), Some(3));
+        assert_eq!(decoder.program_service(), None);
+        assert!(decoder.alternative_frequencies().is_empty());
+    }
+}
@@ -0,0 +1,303 @@
+//! Device disconnect detection and recovery
+//!
+//! USB cameras get unplugged, reset by flaky hubs or power cycled by watchdogs. Once that
+//! happens, every ioctl on the old file descriptor fails with ENODEV (or EIO for some drivers)
+//! and the device has to be opened again, possibly under a different index. Failing ioctls of
+//! a vanished device are reported as [`Disconnected`] errors, which can be told apart from
+//! other errors with [`is_disconnected`].
+//!
+//! [`Capture`] builds on that for long-running applications like kiosks or security cameras:
+//! it reopens the device by its [`DeviceId`], restores the saved [`Settings`] and restarts the
+//! stream transparently.
+
+use std::{error, fmt, io, thread, time::Duration, time::Instant};
+
+use crate::buffer::Type;
+use crate::control::{self, Control};
+use crate::device::{Device, DeviceId};
+use crate::format::Format;
+use crate::frame::Frame;
+use crate::io::mmap::Stream as MmapStream;
+use crate::video::capture::Parameters as CaptureParameters;
+use crate::video::Capture as _;
+
+/// Error payload of I/O errors caused by the device going away
+///
+/// The errors are of kind [`io::ErrorKind::NotConnected`] and carry the original OS error.
+#[derive(Debug)]
+pub struct Disconnected {
+    source: io::Error,
+}
+
+impl Disconnected {
+    /// Returns an I/O error wrapping the original one
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Error reported by the failing call, e.g. ENODEV
+    pub fn error(source: io::Error) -> io::Error {
+        io::Error::new(io::ErrorKind::NotConnected, Disconnected { source })
+    }
+}
+
+impl fmt::Display for Disconnected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "device disconnected ({})", self.source)
+    }
+}
+
+impl error::Error for Disconnected {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Returns whether an error was caused by the device going away
+///
+/// Plain ENODEV errors, e.g. from raw file descriptor operations, are recognized as well.
+///
+/// # Arguments
+///
+/// * `e` - Error returned by any device or stream operation
+pub fn is_disconnected(e: &io::Error) -> bool {
+    e.get_ref().is_some_and(|inner| inner.is::<Disconnected>())
+        || e.raw_os_error() == Some(libc::ENODEV)
+}
+
+/// Device state which is lost when the device is disconnected
+#[derive(Debug, Clone)]
+pub struct Settings {
+    /// Identity to find the device again
+    pub id: DeviceId,
+    /// Capture format
+    pub format: Format,
+    /// Streaming parameters, `None` if the device does not support them
+    pub params: Option<CaptureParameters>,
+    /// Values of the writable controls
+    pub controls: Vec<Control>,
+}
+
+impl Settings {
+    /// Returns the current state of a device
+    ///
+    /// Only controls with plain values are saved, read-only, inactive and volatile ones are
+    /// skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `dev` - Device to save the state of
+    pub fn save(dev: &Device) -> io::Result<Self> {
+        let skip = control::Flags::DISABLED
+            | control::Flags::READ_ONLY
+            | control::Flags::INACTIVE
+            | control::Flags::WRITE_ONLY
+            | control::Flags::VOLATILE;
+        let mut controls = Vec::new();
+        for desc in dev.query_controls()? {
+            let plain = matches!(
+                desc.typ,
+                control::Type::Integer
+                    | control::Type::Boolean
+                    | control::Type::Menu
+                    | control::Type::IntegerMenu
+                    | control::Type::Integer64
+                    | control::Type::Bitmask
+            );
+            if !plain || desc.flags.intersects(skip) {
+                continue;
+            }
+            controls.push(dev.control(desc.id)?);
+        }
+
+        Ok(Settings {
+            id: dev.id()?,
+            format: dev.format()?,
+            params: dev.params().ok(),
+            controls,
+        })
+    }
+
+    /// Applies the settings to a device
+    ///
+    /// Controls are set one by one in the order they were saved, so auto modes are restored
+    /// before the manual values depending on them. Controls the device rejects (e.g. because
+    /// they are inactive in the restored mode) are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `dev` - Device to configure
+    pub fn apply(&self, dev: &Device) -> io::Result<()> {
+        dev.set_format(&self.format)?;
+        if let Some(params) = &self.params {
+            dev.set_params(params)?;
+        }
+        for ctrl in &self.controls {
+            match dev.set_control(ctrl.clone()) {
+                Err(e) if is_disconnected(&e) => return Err(e),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the device opened again by its identity, with the settings applied
+    pub fn reopen(&self) -> io::Result<Device> {
+        let dev = Device::open_by_id(&self.id)?;
+        self.apply(&dev)?;
+        Ok(dev)
+    }
+}
+
+type Hook = Box<dyn FnMut(&Device) -> io::Result<()> + Send>;
+
+/// Capture stream which survives device disconnects
+///
+/// Once the device vanishes, the stream is torn down and the device is reopened by its identity
+/// every retry interval until it shows up again. The saved settings are applied, the optional
+/// hook is called and streaming resumes. Frames are copied out of the stream buffers, so no
+/// references into a stream which might be gone have to be held.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use v4l::prelude::*;
+/// use v4l::reconnect::Capture;
+///
+/// if let Ok(dev) = Device::new(0) {
+///     let mut capture = Capture::new(dev, 4).unwrap();
+///     capture.set_retry_interval(Duration::from_secs(2));
+///     capture.on_reconnect(|dev| {
+///         println!("reconnected to {}", dev.id()?);
+///         Ok(())
+///     });
+///
+///     for _ in 0..100 {
+///         let frame = capture.next_frame().unwrap();
+///         println!("frame {}", frame.meta.sequence);
+///     }
+/// }
+/// ```
+pub struct Capture {
+    settings: Settings,
+    dev: Device,
+    stream: Option<MmapStream<'static>>,
+    buf_count: u32,
+    retry: Duration,
+    timeout: Option<Duration>,
+    hook: Option<Hook>,
+    reconnects: usize,
+}
+
+impl Capture {
+    /// Returns a capture stream for the device in its current configuration
+    ///
+    /// # Arguments
+    ///
+    /// * `dev` - Device to capture from
+    /// * `buf_count` - Number of buffers to stream with
+    pub fn new(dev: Device, buf_count: u32) -> io::Result<Self> {
+        Ok(Capture {
+            settings: Settings::save(&dev)?,
+            dev,
+            stream: None,
+            buf_count,
+            retry: Duration::from_secs(1),
+            timeout: None,
+            hook: None,
+            reconnects: 0,
+        })
+    }
+
+    /// Returns the device currently captured from
+    pub fn device(&self) -> &Device {
+        &self.dev
+    }
+
+    /// Returns the settings restored after reconnecting
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Returns the number of reconnects so far
+    pub fn reconnects(&self) -> usize {
+        self.reconnects
+    }
+
+    /// Sets the time to wait between attempts to reopen the device (one second by default)
+    pub fn set_retry_interval(&mut self, interval: Duration) {
+        self.retry = interval;
+    }
+
+    /// Gives up reconnecting after `timeout`, the default is to retry forever
+    pub fn set_reconnect_timeout(&mut self, timeout: Option<Duration>) {
+        self.timeout = timeout;
+    }
+
+    /// Registers a hook called after reconnecting, before streaming resumes
+    ///
+    /// Use it to restore state which is not covered by [`Settings`], e.g. event subscriptions.
+    /// Errors returned by the hook abort the reconnect.
+    pub fn on_reconnect<F>(&mut self, hook: F)
+    where
+        F: FnMut(&Device) -> io::Result<()> + Send + 'static,
+    {
+        self.hook = Some(Box::new(hook));
+    }
+
+    /// Returns the next frame, reconnecting as often as necessary
+    ///
+    /// Errors other than disconnects are returned as they are. Frames lost while the device
+    /// was gone are not made up for.
+    pub fn next_frame(&mut self) -> io::Result<Frame> {
+        loop {
+            match self.capture() {
+                Err(e) if is_disconnected(&e) => {
+                    debug!(id = %self.settings.id, "device disconnected, reconnecting");
+                    // the buffers of the old stream cannot be released anymore anyway
+                    self.stream = None;
+                    self.reconnect()?;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Returns the next frame, (re)starting the stream if necessary
+    fn capture(&mut self) -> io::Result<Frame> {
+        if self.stream.is_none() {
+            let stream = MmapStream::with_buffers(&self.dev, Type::VideoCapture, self.buf_count)?;
+            self.stream = Some(stream);
+        }
+        Frame::capture(self.stream.as_mut().unwrap())
+    }
+
+    /// Reopens the device, waiting for it to show up again
+    fn reconnect(&mut self) -> io::Result<()> {
+        let start = Instant::now();
+        loop {
+            match self.settings.reopen() {
+                Ok(dev) => {
+                    if let Some(hook) = &mut self.hook {
+                        hook(&dev)?;
+                    }
+                    self.dev = dev;
+                    self.reconnects += 1;
+                    debug!(id = %self.settings.id, "device reconnected");
+                    return Ok(());
+                }
+                // the device may show up before its driver is ready, so any error is retried
+                Err(e) => {
+                    if self
+                        .timeout
+                        .is_some_and(|timeout| start.elapsed() >= timeout)
+                    {
+                        return Err(e);
+                    }
+                    debug!(id = %self.settings.id, error = %e, "reconnect failed");
+                }
+            }
+            thread::sleep(self.retry);
+        }
+    }
+}
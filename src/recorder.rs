@@ -0,0 +1,214 @@
+use std::{
+    collections::VecDeque,
+    io,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::frame::{Frame, Planes};
+use crate::io::traits::CaptureStream;
+use crate::sched::ThreadOptions;
+
+/// Bounded ring of recently captured frames
+struct Ring {
+    frames: VecDeque<(Instant, Arc<Frame>)>,
+    bytes: usize,
+    window: Duration,
+    max_bytes: usize,
+}
+
+impl Ring {
+    fn push(&mut self, frame: Frame) {
+        let now = Instant::now();
+        self.bytes += frame.len();
+        self.frames.push_back((now, Arc::new(frame)));
+
+        // evict frames which are too old or do not fit anymore, but always keep the latest one
+        while self.frames.len() > 1 {
+            let (captured, frame) = &self.frames[0];
+            if now.duration_since(*captured) <= self.window && self.bytes <= self.max_bytes {
+                break;
+            }
+
+            self.bytes -= frame.len();
+            self.frames.pop_front();
+        }
+    }
+}
+
+struct Shared {
+    ring: Mutex<Ring>,
+    stop: AtomicBool,
+}
+
+/// Pre-event recorder keeping the most recent frames in memory
+///
+/// Frames are continuously captured into a ring which is bounded by time as well as by memory.
+/// Once an event is triggered (e.g. motion was detected or a button was pressed), the frames of
+/// the last few seconds can be dumped and written to persistent storage, just like dashcams and
+/// security cameras do.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use v4l::buffer::Type;
+/// use v4l::prelude::*;
+/// use v4l::recorder::RingRecorder;
+///
+/// if let Ok(dev) = Device::new(0) {
+///     let stream = UserptrStream::new(&dev, Type::VideoCapture).unwrap();
+///     let mut recorder = RingRecorder::spawn(stream, Duration::from_secs(10), 256 << 20);
+///
+///     // ... wait for an event
+///     let frames = recorder.dump(Duration::from_secs(5));
+///     recorder.stop().unwrap();
+/// }
+/// ```
+pub struct RingRecorder {
+    shared: Arc<Shared>,
+    thread: Option<thread::JoinHandle<io::Result<()>>>,
+}
+
+impl RingRecorder {
+    /// Returns a recorder which is fed manually through [`RingRecorder::push`]
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - Maximum age of the frames kept in the ring
+    /// * `max_bytes` - Maximum amount of frame data kept in the ring
+    pub fn new(window: Duration, max_bytes: usize) -> Self {
+        RingRecorder {
+            shared: Arc::new(Shared {
+                ring: Mutex::new(Ring {
+                    frames: VecDeque::new(),
+                    bytes: 0,
+                    window,
+                    max_bytes,
+                }),
+                stop: AtomicBool::new(false),
+            }),
+            thread: None,
+        }
+    }
+
+    /// Returns a recorder which continuously captures from a stream on a background thread
+    ///
+    /// Capturing stops once the recorder is stopped or dropped, or if the stream reports an
+    /// error. Set a timeout on the stream to make sure stopping the recorder does not block
+    /// forever in case the device stops delivering frames.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - Capture stream
+    /// * `window` - Maximum age of the frames kept in the ring
+    /// * `max_bytes` - Maximum amount of frame data kept in the ring
+    pub fn spawn<S>(stream: S, window: Duration, max_bytes: usize) -> Self
+    where
+        S: for<'b> CaptureStream<'b> + Send + 'static,
+        S::Item: Planes,
+    {
+        Self::spawn_with(stream, window, max_bytes, ThreadOptions::default())
+    }
+
+    /// Returns a recorder capturing on a background thread with the given scheduling
+    ///
+    /// Like [`RingRecorder::spawn`], but the thread is pinned to CPUs or raised to realtime
+    /// priority first. Failing to apply the options stops the recorder right away.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - Capture stream
+    /// * `window` - Maximum age of the frames kept in the ring
+    /// * `max_bytes` - Maximum amount of frame data kept in the ring
+    /// * `options` - Scheduling options of the capture thread
+    pub fn spawn_with<S>(
+        mut stream: S,
+        window: Duration,
+        max_bytes: usize,
+        options: ThreadOptions,
+    ) -> Self
+    where
+        S: for<'b> CaptureStream<'b> + Send + 'static,
+        S::Item: Planes,
+    {
+        let mut recorder = Self::new(window, max_bytes);
+        let shared = recorder.shared.clone();
+        recorder.thread = Some(thread::spawn(move || {
+            options.apply()?;
+            while !shared.stop.load(Ordering::Relaxed) {
+                let frame = Frame::capture(&mut stream)?;
+                shared.ring.lock().unwrap().push(frame);
+            }
+            Ok(())
+        }));
+
+        recorder
+    }
+
+    /// Adds a frame to the ring, evicting the oldest frames if necessary
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - Captured frame
+    pub fn push(&self, frame: Frame) {
+        self.shared.ring.lock().unwrap().push(frame);
+    }
+
+    /// Returns the frames captured within the last `duration`, oldest first
+    ///
+    /// The frames stay in the ring, so overlapping events can be dumped as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - Period of time to dump
+    pub fn dump(&self, duration: Duration) -> Vec<Arc<Frame>> {
+        let ring = self.shared.ring.lock().unwrap();
+        let now = Instant::now();
+        ring.frames
+            .iter()
+            .filter(|(captured, _)| now.duration_since(*captured) <= duration)
+            .map(|(_, frame)| frame.clone())
+            .collect()
+    }
+
+    /// Returns the number of frames in the ring
+    pub fn len(&self) -> usize {
+        self.shared.ring.lock().unwrap().frames.len()
+    }
+
+    /// Returns whether the ring is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all frames from the ring
+    pub fn clear(&self) {
+        let mut ring = self.shared.ring.lock().unwrap();
+        ring.frames.clear();
+        ring.bytes = 0;
+    }
+
+    /// Stops capturing
+    ///
+    /// Returns the error which made the background thread stop prematurely, if any.
+    pub fn stop(&mut self) -> io::Result<()> {
+        self.shared.stop.store(true, Ordering::Relaxed);
+        match self.thread.take() {
+            Some(thread) => thread
+                .join()
+                .unwrap_or_else(|_| Err(io::Error::other("recorder thread panicked"))),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for RingRecorder {
+    fn drop(&mut self) {
+        let _ = self.stop();
+    }
+}
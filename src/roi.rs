@@ -0,0 +1,203 @@
+//! Region of interest
+//!
+//! UVC 1.5 cameras can restrict their automatic algorithms (exposure, focus, white balance, ..)
+//! to a region of the image, e.g. a face found by the application. The uvcvideo driver exposes
+//! this through two controls: a rectangle compound control and a bitmask of the algorithms
+//! which take it into account. Both are set atomically by [`set_region`].
+
+use bitflags::bitflags;
+use std::{fmt, io, mem};
+
+use crate::control::{Control, Value};
+use crate::device::{Handle, VideoDevice};
+use crate::rect::Rect;
+use crate::v4l2;
+use crate::v4l2::videodev::v4l2_ext_controls;
+use crate::v4l_sys::*;
+
+/// Region of interest rectangle (`V4L2_CID_UVC_REGION_OF_INTEREST_RECT`)
+pub const V4L2_CID_UVC_REGION_OF_INTEREST_RECT: u32 = V4L2_CID_CAMERA_CLASS_BASE + 0x1000 + 1;
+/// Algorithms using the region of interest (`V4L2_CID_UVC_REGION_OF_INTEREST_AUTO`)
+pub const V4L2_CID_UVC_REGION_OF_INTEREST_AUTO: u32 = V4L2_CID_CAMERA_CLASS_BASE + 0x1000 + 2;
+
+// Not in the headers of older kernels
+const V4L2_CTRL_WHICH_MIN_VAL: u32 = 0x0f020000;
+const V4L2_CTRL_WHICH_MAX_VAL: u32 = 0x0f030000;
+
+bitflags! {
+    /// Automatic algorithms which take the region of interest into account
+    #[allow(clippy::unreadable_literal)]
+    pub struct Auto: u32 {
+        const EXPOSURE              = 0x0001;
+        const IRIS                  = 0x0002;
+        const WHITE_BALANCE         = 0x0004;
+        const FOCUS                 = 0x0008;
+        const FACE_DETECT           = 0x0010;
+        const DETECT_AND_TRACK      = 0x0020;
+        const IMAGE_STABILIZATION   = 0x0040;
+        const HIGHER_QUALITY        = 0x0080;
+    }
+}
+
+impl From<u32> for Auto {
+    fn from(flags: u32) -> Self {
+        Self::from_bits_truncate(flags)
+    }
+}
+
+impl From<Auto> for u32 {
+    fn from(flags: Auto) -> Self {
+        flags.bits()
+    }
+}
+
+impl fmt::Display for Auto {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Region of interest and the algorithms using it
+pub struct Region {
+    /// Rectangle in pixels of the current format
+    pub rect: Rect,
+    /// Algorithms restricted to the rectangle
+    pub auto: Auto,
+}
+
+impl Region {
+    /// Returns a region of interest representation
+    ///
+    /// # Arguments
+    ///
+    /// * `rect` - Rectangle in pixels
+    /// * `auto` - Algorithms restricted to the rectangle
+    pub fn new(rect: Rect, auto: Auto) -> Self {
+        Region { rect, auto }
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.rect, self.auto)
+    }
+}
+
+/// Returns the current region of interest
+///
+/// Devices without region of interest support fail with [`io::ErrorKind::InvalidInput`].
+///
+/// # Arguments
+///
+/// * `dev` - Device (single- or multi-planar)
+///
+/// # Example
+///
+/// ```
+/// use v4l::prelude::*;
+/// use v4l::roi;
+///
+/// if let Ok(dev) = Device::new(0) {
+///     if let Ok(region) = roi::region(&dev) {
+///         println!("region of interest: {}", region);
+///     }
+/// }
+/// ```
+pub fn region<D: VideoDevice>(dev: &D) -> io::Result<Region> {
+    let handle = dev.handle();
+    let rect = rect(&handle, 0)?;
+    let auto = match handle.control(V4L2_CID_UVC_REGION_OF_INTEREST_AUTO)?.value {
+        Value::Integer(value) => Auto::from(value as u32),
+        _ => Auto::empty(),
+    };
+    Ok(Region { rect, auto })
+}
+
+/// Sets the region of interest and the algorithms using it
+///
+/// The driver adjusts the rectangle to what the device supports, read it back with [`region`]
+/// to get the effective one.
+///
+/// # Arguments
+///
+/// * `dev` - Device (single- or multi-planar)
+/// * `region` - Region of interest
+///
+/// # Example
+///
+/// ```
+/// use v4l::prelude::*;
+/// use v4l::rect::Rect;
+/// use v4l::roi::{self, Auto, Region};
+///
+/// if let Ok(dev) = Device::new(0) {
+///     let face = Region::new(Rect::new(280, 160, 80, 80), Auto::EXPOSURE | Auto::FOCUS);
+///     roi::set_region(&dev, &face).ok();
+/// }
+/// ```
+pub fn set_region<D: VideoDevice>(dev: &D, region: &Region) -> io::Result<()> {
+    let rect = v4l2_rect::from(region.rect);
+    let bytes = unsafe {
+        std::slice::from_raw_parts(&rect as *const _ as *const u8, mem::size_of::<v4l2_rect>())
+    };
+
+    dev.handle().set_controls(
+        vec![
+            Control {
+                id: V4L2_CID_UVC_REGION_OF_INTEREST_RECT,
+                value: Value::CompoundPtr(bytes.to_vec()),
+            },
+            Control {
+                id: V4L2_CID_UVC_REGION_OF_INTEREST_AUTO,
+                value: Value::Integer(region.auto.bits() as i64),
+            },
+        ],
+        None,
+    )
+}
+
+/// Returns the smallest and the largest rectangle the device accepts
+///
+/// Reading the limits of compound controls requires Linux 6.15 or newer.
+///
+/// # Arguments
+///
+/// * `dev` - Device (single- or multi-planar)
+pub fn bounds<D: VideoDevice>(dev: &D) -> io::Result<(Rect, Rect)> {
+    let handle = dev.handle();
+    Ok((
+        rect(&handle, V4L2_CTRL_WHICH_MIN_VAL)?,
+        rect(&handle, V4L2_CTRL_WHICH_MAX_VAL)?,
+    ))
+}
+
+/// Returns the value of the rectangle control
+///
+/// # Arguments
+///
+/// * `handle` - Device handle
+/// * `which` - Value to get, e.g. the current or the maximum one
+fn rect(handle: &Handle, which: u32) -> io::Result<Rect> {
+    let mut rect: v4l2_rect = unsafe { mem::zeroed() };
+    let mut ctrl = v4l2_ext_control {
+        id: V4L2_CID_UVC_REGION_OF_INTEREST_RECT,
+        size: mem::size_of::<v4l2_rect>() as u32,
+        ..unsafe { mem::zeroed() }
+    };
+    ctrl.__bindgen_anon_1.ptr = &mut rect as *mut _ as *mut std::os::raw::c_void;
+    let mut controls = v4l2_ext_controls {
+        which,
+        count: 1,
+        controls: &mut ctrl,
+        ..unsafe { mem::zeroed() }
+    };
+
+    unsafe {
+        handle.ioctl(
+            v4l2::vidioc::VIDIOC_G_EXT_CTRLS,
+            &mut controls as *mut _ as *mut std::os::raw::c_void,
+        )?;
+    }
+    Ok(Rect::from(rect))
+}
@@ -0,0 +1,119 @@
+//! Scheduling of capture threads
+//!
+//! The scheduler migrating a capture thread between CPUs, or preempting it in favor of some
+//! batch job, adds milliseconds of jitter between the driver completing a buffer and the
+//! application dequeuing it. [`ThreadOptions`] pins a thread to a set of CPUs and raises it to
+//! the `SCHED_FIFO` realtime policy. The background threads of
+//! [`FrameBroadcaster`](crate::broadcast::FrameBroadcaster) and
+//! [`RingRecorder`](crate::recorder::RingRecorder) accept them at startup.
+
+use std::{io, mem};
+
+/// Scheduling options of a capture thread
+///
+/// # Example
+///
+/// ```
+/// use v4l::sched::ThreadOptions;
+///
+/// let options = ThreadOptions::new().cpus(&[2, 3]).realtime(50);
+/// let thread = std::thread::spawn(move || {
+///     if let Err(e) = options.apply() {
+///         eprintln!("failed to pin the capture thread: {}", e);
+///     }
+///     // ... capture
+/// });
+/// thread.join().unwrap();
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ThreadOptions {
+    cpus: Option<Vec<usize>>,
+    priority: Option<i32>,
+}
+
+impl ThreadOptions {
+    /// Returns options which leave the scheduling untouched
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pins the thread to a set of CPUs
+    ///
+    /// # Arguments
+    ///
+    /// * `cpus` - Indices of the CPUs the thread may run on
+    pub fn cpus(mut self, cpus: &[usize]) -> Self {
+        self.cpus = Some(cpus.to_vec());
+        self
+    }
+
+    /// Runs the thread with the `SCHED_FIFO` policy
+    ///
+    /// Realtime scheduling requires `CAP_SYS_NICE` or a matching `RLIMIT_RTPRIO`. Without
+    /// them, the thread keeps its normal priority.
+    ///
+    /// # Arguments
+    ///
+    /// * `priority` - Realtime priority, clamped to the range of the policy (usually 1 to 99)
+    pub fn realtime(mut self, priority: i32) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Applies the options to the calling thread
+    ///
+    /// Failing to pin the thread is reported as an error. Being denied realtime scheduling is
+    /// not, since it is expected for unprivileged processes.
+    pub fn apply(&self) -> io::Result<()> {
+        if let Some(cpus) = &self.cpus {
+            set_affinity(cpus)?;
+        }
+
+        if let Some(priority) = self.priority {
+            match set_fifo(priority) {
+                Err(e) if e.raw_os_error() == Some(libc::EPERM) => {
+                    debug!(priority, "not permitted to use realtime scheduling");
+                }
+                res => res?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn set_affinity(cpus: &[usize]) -> io::Result<()> {
+    unsafe {
+        let mut set: libc::cpu_set_t = mem::zeroed();
+        for cpu in cpus {
+            if *cpu >= libc::CPU_SETSIZE as usize {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "CPU index out of range",
+                ));
+            }
+            libc::CPU_SET(*cpu, &mut set);
+        }
+
+        // zero selects the calling thread
+        if libc::sched_setaffinity(0, mem::size_of::<libc::cpu_set_t>(), &set) < 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+fn set_fifo(priority: i32) -> io::Result<()> {
+    unsafe {
+        let min = libc::sched_get_priority_min(libc::SCHED_FIFO);
+        let max = libc::sched_get_priority_max(libc::SCHED_FIFO);
+        let param = libc::sched_param {
+            sched_priority: priority.clamp(min, max),
+        };
+
+        // pthread functions return the error instead of setting errno
+        match libc::pthread_setschedparam(libc::pthread_self(), libc::SCHED_FIFO, &param) {
+            0 => Ok(()),
+            e => Err(io::Error::from_raw_os_error(e)),
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! Luma statistics of a single frame
+
+use std::io;
+
+use crate::format::{Format, Info};
+
+/// Position of the luma information within a line
+enum Luma {
+    /// Luma samples every `step` bytes, starting at `offset`
+    Samples { offset: usize, step: usize },
+    /// RGB pixels of `bpp` bytes, luma is computed from the color components
+    Rgb { r: usize, g: usize, b: usize, bpp: usize },
+}
+
+impl Luma {
+    fn new(format: &Format) -> Option<Self> {
+        let samples = |offset, step| Some(Luma::Samples { offset, step });
+        let rgb = |r, g, b, bpp| Some(Luma::Rgb { r, g, b, bpp });
+
+        match &format.fourcc.repr {
+            b"YUYV" | b"YVYU" => samples(0, 2),
+            b"UYVY" | b"VYUY" => samples(1, 2),
+            b"RGB3" => rgb(0, 1, 2, 3),
+            b"BGR3" => rgb(2, 1, 0, 3),
+            b"AR24" | b"XR24" | b"BGR4" => rgb(2, 1, 0, 4),
+            b"AB24" | b"XB24" => rgb(0, 1, 2, 4),
+            b"GREY" => samples(0, 1),
+            // planar YUV formats start with a plane of 8 bit luma samples
+            _ => match Info::new(format.fourcc) {
+                Some(info) if info.comp_planes > 1 && info.bpp[0] == 1 => samples(0, 1),
+                _ => None,
+            },
+        }
+    }
+
+    /// Bytes per pixel within a line
+    fn bpp(&self) -> usize {
+        match *self {
+            Luma::Samples { step, .. } => step,
+            Luma::Rgb { bpp, .. } => bpp,
+        }
+    }
+}
+
+/// Distribution of the luma values of a frame
+#[derive(Debug, Clone)]
+pub struct Histogram {
+    /// Number of pixels per luma value
+    pub bins: [u32; 256],
+    /// Total number of pixels
+    pub count: u64,
+}
+
+impl Histogram {
+    /// Returns the histogram of the luma values of a frame
+    ///
+    /// Packed YUV, 8 bit RGB and the planar (or semi-planar) 8 bit YUV formats are supported.
+    /// For planar formats, only the luma plane at the start of the buffer is read. Luma of RGB
+    /// pixels is approximated with the BT.601 coefficients.
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - Frame data
+    /// * `format` - Format of the frame
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::stats::frame::Histogram;
+    /// use v4l::{Format, FourCC};
+    ///
+    /// let format = Format::new(4, 1, FourCC::new(b"GREY"));
+    /// let hist = Histogram::new(&[0, 64, 128, 255], &format).unwrap();
+    /// assert_eq!(hist.mean(), 111.75);
+    /// ```
+    pub fn new(buf: &[u8], format: &Format) -> io::Result<Self> {
+        let luma = Luma::new(format).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::Unsupported,
+                "no luma statistics for the pixel format",
+            )
+        })?;
+
+        let width = format.width as usize;
+        let height = format.height as usize;
+        let line = width * luma.bpp();
+        let stride = match format.stride as usize {
+            0 => line,
+            stride => stride,
+        };
+        if height > 0 && buf.len() < stride * (height - 1) + line {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "buffer is smaller than the format",
+            ));
+        }
+
+        // Consecutive pixels often have the same value, incrementing the same counter in a row
+        // stalls on the store-to-load dependency. Spreading the counts over four histograms
+        // lets the CPU overlap the increments, they are merged at the end.
+        let mut partial = [[0u32; 256]; 4];
+        for row in buf.chunks(stride).take(height) {
+            let row = &row[..line];
+            match luma {
+                Luma::Samples { offset, step } => {
+                    for (i, &y) in row[offset..].iter().step_by(step).enumerate() {
+                        partial[i % 4][y as usize] += 1;
+                    }
+                }
+                Luma::Rgb { r, g, b, bpp } => {
+                    for (i, px) in row.chunks_exact(bpp).enumerate() {
+                        let y = (77 * px[r] as u32 + 150 * px[g] as u32 + 29 * px[b] as u32) >> 8;
+                        partial[i % 4][y as usize] += 1;
+                    }
+                }
+            }
+        }
+
+        let mut bins = [0u32; 256];
+        for hist in &partial {
+            for (bin, count) in bins.iter_mut().zip(hist.iter()) {
+                *bin += count;
+            }
+        }
+
+        Ok(Histogram {
+            bins,
+            count: (width * height) as u64,
+        })
+    }
+
+    /// Returns the mean luma value, zero for empty frames
+    pub fn mean(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+
+        let sum: u64 = self
+            .bins
+            .iter()
+            .enumerate()
+            .map(|(value, &count)| value as u64 * count as u64)
+            .sum();
+        sum as f64 / self.count as f64
+    }
+
+    /// Returns the ratio of pixels which are clipped, i.e. at or below `low` or at or above
+    /// `high`
+    ///
+    /// # Arguments
+    ///
+    /// * `low` - Highest value which counts as black, usually 0
+    /// * `high` - Lowest value which counts as white, usually 255
+    pub fn clipped(&self, low: u8, high: u8) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+
+        let clipped: u64 = self
+            .bins
+            .iter()
+            .enumerate()
+            .filter(|&(value, _)| value <= low as usize || value >= high as usize)
+            .map(|(_, &count)| count as u64)
+            .sum();
+        clipped as f64 / self.count as f64
+    }
+
+    /// Returns the luma value below which the given fraction of pixels lies
+    ///
+    /// # Arguments
+    ///
+    /// * `fraction` - Fraction of the pixels, between 0.0 and 1.0
+    pub fn percentile(&self, fraction: f64) -> u8 {
+        let target = (self.count as f64 * fraction.clamp(0.0, 1.0)).ceil() as u64;
+        let mut sum = 0u64;
+        for (value, &count) in self.bins.iter().enumerate() {
+            sum += count as u64;
+            if sum >= target && sum > 0 {
+                return value as u8;
+            }
+        }
+        u8::MAX
+    }
+}
@@ -0,0 +1,6 @@
+//! Image statistics
+//!
+//! Userspace control loops (e.g. auto exposure for sensors without an ISP) need a summary of
+//! the captured image. The helpers in here compute it directly on dequeued buffers.
+
+pub mod frame;
@@ -0,0 +1,165 @@
+//! Still image capture
+//!
+//! Some sensors deliver higher quality (or larger) images when asked for a still image instead of
+//! a video frame. V4L2 has no dedicated still image ioctl, drivers either switch to a high
+//! quality mode through the streaming parameters ([`Modes::HIGH_QUALITY`]) or expose a driver
+//! specific control which triggers a snapshot. [`Still`] covers both: it reconfigures the device,
+//! grabs a single frame and restores the previous configuration afterwards.
+
+use std::io;
+
+use crate::buffer::Type;
+use crate::control::Control;
+use crate::device::Device;
+use crate::format::Format;
+use crate::frame::Frame;
+use crate::io::mmap::Stream as MmapStream;
+use crate::io::Profile;
+use crate::parameters::Modes;
+use crate::video::capture::Parameters;
+use crate::video::Capture as _;
+
+/// How the driver is asked for a still image
+#[derive(Debug, Clone)]
+pub enum Trigger {
+    /// Switch to [`Modes::HIGH_QUALITY`] while capturing
+    HighQuality,
+    /// Set a control once streaming, e.g. a driver specific snapshot button
+    Control(Control),
+}
+
+/// Single frame capture in still image mode
+///
+/// The device must not be streaming already, the frame is captured with a stream of its own.
+///
+/// # Example
+///
+/// ```
+/// use v4l::prelude::*;
+/// use v4l::still::{Still, Trigger};
+///
+/// if let Ok(dev) = Device::new(0) {
+///     let still = Still::new(Trigger::HighQuality).skip_frames(2);
+///     if let Ok(frame) = still.capture(&dev) {
+///         println!("still image of {} bytes", frame.len());
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Still {
+    trigger: Trigger,
+    format: Option<Format>,
+    skip: u32,
+}
+
+impl Still {
+    /// Returns a still capture using the given trigger
+    ///
+    /// # Arguments
+    ///
+    /// * `trigger` - How the driver is asked for a still image
+    pub fn new(trigger: Trigger) -> Self {
+        Still {
+            trigger,
+            format: None,
+            skip: 0,
+        }
+    }
+
+    /// Captures with a different format, e.g. the full sensor resolution
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - Format of the still image
+    pub fn format(mut self, format: Format) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Drops the first frames after the trigger, so exposure can settle in the new mode
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of frames to drop
+    pub fn skip_frames(mut self, count: u32) -> Self {
+        self.skip = count;
+        self
+    }
+
+    /// Returns a still image captured from the device
+    ///
+    /// The format and streaming parameters in use before are restored afterwards, even if the
+    /// capture failed. Drivers which do not support high quality mode are reported as
+    /// [`io::ErrorKind::Unsupported`].
+    ///
+    /// # Arguments
+    ///
+    /// * `dev` - Device to capture from
+    pub fn capture(&self, dev: &Device) -> io::Result<Frame> {
+        let format = match &self.format {
+            Some(format) => {
+                let previous = dev.format()?;
+                dev.set_format(format)?;
+                Some(previous)
+            }
+            None => None,
+        };
+
+        let mut params = None;
+        let result = self
+            .switch_mode(dev, &mut params)
+            .and_then(|_| self.grab(dev));
+
+        // restore in reverse order, but report the first error
+        let params = params.map_or(Ok(()), |params| dev.set_params(&params).map(|_| ()));
+        let format = format.map_or(Ok(()), |format| dev.set_format(&format).map(|_| ()));
+        let frame = result?;
+        params.and(format)?;
+        Ok(frame)
+    }
+
+    /// Switches to high quality mode if requested, saving the previous parameters
+    fn switch_mode(&self, dev: &Device, saved: &mut Option<Parameters>) -> io::Result<()> {
+        if !matches!(self.trigger, Trigger::HighQuality) {
+            return Ok(());
+        }
+
+        let params = dev.params()?;
+        *saved = Some(params);
+        let mut still = params;
+        still.modes |= Modes::HIGH_QUALITY;
+        if !dev.set_params(&still)?.modes.contains(Modes::HIGH_QUALITY) {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "driver does not support high quality mode",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the first frame after the trigger and the frames to skip
+    fn grab(&self, dev: &Device) -> io::Result<Frame> {
+        let mut stream = MmapStream::with_profile(dev, Type::VideoCapture, Profile::LowLatency)?;
+        if let Trigger::Control(ctrl) = &self.trigger {
+            // the snapshot must not fire before the queue is running
+            stream.prime()?;
+            dev.set_control(ctrl.clone())?;
+        }
+        for _ in 0..self.skip {
+            Frame::capture(&mut stream)?;
+        }
+        Frame::capture(&mut stream)
+    }
+}
+
+/// Returns a still image captured from the device
+///
+/// Shorthand for `Still::new(trigger).capture(dev)`.
+///
+/// # Arguments
+///
+/// * `dev` - Device to capture from
+/// * `trigger` - How the driver is asked for a still image
+pub fn capture_still(dev: &Device, trigger: Trigger) -> io::Result<Frame> {
+    Still::new(trigger).capture(dev)
+}
@@ -0,0 +1,97 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+use crate::format::{Colorspace, FieldOrder, Quantization, TransferFunction};
+use crate::v4l2::subdev::v4l2_mbus_framefmt;
+
+#[derive(Debug, Copy, Clone)]
+/// Media bus format of a sub-device pad
+pub struct Format {
+    /// width in pixels
+    pub width: u32,
+    /// height in pixels
+    pub height: u32,
+    /// media bus code, e.g. 0x300f (MEDIA_BUS_FMT_SRGGB10_1X10)
+    pub code: u32,
+    /// field order for interlacing
+    pub field_order: FieldOrder,
+
+    /// supplements the media bus code information
+    pub colorspace: Colorspace,
+    /// the way colors are mapped
+    pub quantization: Quantization,
+    /// the transfer function for the colorspace
+    pub transfer: TransferFunction,
+}
+
+impl Format {
+    /// Returns a media bus format
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Width in pixels
+    /// * `height` - Height in pixels
+    /// * `code` - Media bus code
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::subdev::Format;
+    /// let fmt = Format::new(1920, 1080, 0x300f);
+    /// ```
+    pub const fn new(width: u32, height: u32, code: u32) -> Self {
+        Format {
+            width,
+            height,
+            code,
+            field_order: FieldOrder::Progressive,
+            colorspace: Colorspace::Default,
+            quantization: Quantization::Default,
+            transfer: TransferFunction::Default,
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "width          : {}", self.width)?;
+        writeln!(f, "height         : {}", self.height)?;
+        writeln!(f, "code           : {:#06x}", self.code)?;
+        writeln!(f, "field          : {}", self.field_order)?;
+        writeln!(f, "colorspace     : {}", self.colorspace)?;
+        writeln!(f, "quantization   : {}", self.quantization)?;
+        writeln!(f, "transfer       : {}", self.transfer)?;
+        Ok(())
+    }
+}
+
+impl From<v4l2_mbus_framefmt> for Format {
+    fn from(fmt: v4l2_mbus_framefmt) -> Self {
+        Self {
+            width: fmt.width,
+            height: fmt.height,
+            code: fmt.code,
+            field_order: FieldOrder::try_from(fmt.field).expect("Invalid field order"),
+            colorspace: Colorspace::try_from(fmt.colorspace).expect("Invalid colorspace"),
+            quantization: Quantization::try_from(u32::from(fmt.quantization))
+                .expect("Invalid quantization"),
+            transfer: TransferFunction::try_from(u32::from(fmt.xfer_func))
+                .expect("Invalid transfer function"),
+        }
+    }
+}
+
+impl From<Format> for v4l2_mbus_framefmt {
+    fn from(format: Format) -> Self {
+        Self {
+            width: format.width,
+            height: format.height,
+            code: format.code,
+            field: format.field_order as u32,
+            colorspace: format.colorspace as u32,
+            quantization: format.quantization as u16,
+            xfer_func: format.transfer as u16,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,404 @@
+use bitflags::bitflags;
+use std::path::Path;
+use std::{
+    fmt, io,
+    os::fd::{AsFd, AsRawFd, BorrowedFd, OwnedFd, RawFd},
+    sync::Arc,
+};
+
+use crate::control::{self, Control};
+use crate::device::Handle;
+use crate::event::{self, Event};
+use crate::v4l2;
+use crate::v4l2::subdev::*;
+
+pub mod format;
+pub use format::Format;
+
+pub mod routing;
+use routing::Route;
+
+/// Configuration a sub-device call operates on
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Which {
+    /// Scratch configuration of the file handle, used to validate configurations without
+    /// touching the hardware state
+    Try,
+    /// Configuration applied to the hardware
+    Active,
+}
+
+impl From<Which> for u32 {
+    fn from(which: Which) -> Self {
+        match which {
+            Which::Try => V4L2_SUBDEV_FORMAT_TRY,
+            Which::Active => V4L2_SUBDEV_FORMAT_ACTIVE,
+        }
+    }
+}
+
+bitflags! {
+    pub struct ClientCapabilities: u64 {
+        /// The client is aware of streams, required by the routing API
+        const STREAMS              = 0x00000001;
+        /// The client supports the `which` field of frame interval calls
+        const INTERVAL_USES_WHICH  = 0x00000002;
+    }
+}
+
+impl From<u64> for ClientCapabilities {
+    fn from(caps: u64) -> Self {
+        Self::from_bits_truncate(caps)
+    }
+}
+
+impl From<ClientCapabilities> for u64 {
+    fn from(caps: ClientCapabilities) -> Self {
+        caps.bits()
+    }
+}
+
+impl fmt::Display for ClientCapabilities {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Linux sub-device abstraction
+///
+/// In media controller based pipelines (e.g. the camera interfaces of most SoCs), the video node
+/// only represents the DMA engine. Image sensors, bridges and ISP blocks are exposed as separate
+/// sub-device nodes (/dev/v4l-subdevX) instead, and so are their controls such as exposure or
+/// analogue gain.
+pub struct Subdevice {
+    /// Raw handle
+    handle: Arc<Handle>,
+}
+
+impl Subdevice {
+    /// Returns a sub-device by index
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index (0: first, 1: second, ..)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::subdev::Subdevice;
+    /// let subdev = Subdevice::new(0);
+    /// ```
+    pub fn new(index: usize) -> io::Result<Self> {
+        Self::with_path(format!("{}{}", "/dev/v4l-subdev", index))
+    }
+
+    /// Returns a sub-device by path
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path (e.g. "/dev/v4l-subdev0")
+    pub fn with_path<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Subdevice {
+            handle: Arc::new(Handle::open(path)?),
+        })
+    }
+
+    /// Returns a sub-device wrapping an already opened file descriptor
+    ///
+    /// # Arguments
+    ///
+    /// * `fd` - File descriptor of an opened sub-device node
+    pub fn with_fd(fd: OwnedFd) -> io::Result<Self> {
+        Ok(Subdevice {
+            handle: Arc::new(Handle::from_fd(fd)?),
+        })
+    }
+
+    /// Returns the raw device handle
+    pub fn handle(&self) -> Arc<Handle> {
+        self.handle.clone()
+    }
+
+    /// Returns the supported controls of the sub-device
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::subdev::Subdevice;
+    ///
+    /// if let Ok(subdev) = Subdevice::new(0) {
+    ///     for ctrl in subdev.query_controls().unwrap() {
+    ///         println!("{}", ctrl);
+    ///     }
+    /// }
+    /// ```
+    pub fn query_controls(&self) -> io::Result<Vec<control::Description>> {
+        self.handle.query_controls()
+    }
+
+    /// Returns the control value for an ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Control identifier
+    pub fn control(&self, id: u32) -> io::Result<Control> {
+        self.handle.control(id)
+    }
+
+    /// Modifies the control value
+    ///
+    /// # Arguments
+    ///
+    /// * `ctrl` - Control to be set
+    pub fn set_control(&self, ctrl: Control) -> io::Result<()> {
+        self.handle.set_controls(vec![ctrl], None)
+    }
+
+    /// Modifies the control values atomically
+    ///
+    /// # Arguments
+    ///
+    /// * `ctrls` - Vec of the controls to be set
+    pub fn set_controls(&self, ctrls: Vec<Control>) -> io::Result<()> {
+        self.handle.set_controls(ctrls, None)
+    }
+
+    /// Subscribes to an event
+    ///
+    /// Sensors and CSI-2 receivers usually signal the start of a frame through
+    /// [`event::Type::FrameSync`]. Pending events are signalled by `POLLPRI` on the sub-device
+    /// file descriptor.
+    ///
+    /// # Arguments
+    ///
+    /// * `typ` - Event type
+    /// * `id` - Object to watch, e.g. a control ID (zero for most event types)
+    pub fn subscribe_event(&self, typ: event::Type, id: u32) -> io::Result<()> {
+        self.handle.subscribe_event(typ, id)
+    }
+
+    /// Unsubscribes from an event
+    ///
+    /// # Arguments
+    ///
+    /// * `typ` - Event type, [`event::Type::All`] unsubscribes from all events
+    /// * `id` - Object which was watched
+    pub fn unsubscribe_event(&self, typ: event::Type, id: u32) -> io::Result<()> {
+        self.handle.unsubscribe_event(typ, id)
+    }
+
+    /// Removes the oldest pending event from the queue
+    ///
+    /// Fails with [`io::ErrorKind::WouldBlock`] if no event is pending.
+    pub fn dequeue_event(&self) -> io::Result<Event> {
+        self.handle.dequeue_event()
+    }
+
+    /// Returns the media bus format of a pad
+    ///
+    /// # Arguments
+    ///
+    /// * `pad` - Pad index
+    /// * `which` - Configuration to query
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::subdev::{Subdevice, Which};
+    ///
+    /// if let Ok(subdev) = Subdevice::new(0) {
+    ///     if let Ok(fmt) = subdev.format(0, Which::Active) {
+    ///         println!("{}", fmt);
+    ///     }
+    /// }
+    /// ```
+    pub fn format(&self, pad: u32, which: Which) -> io::Result<Format> {
+        let mut fmt = v4l2_subdev_format {
+            which: which.into(),
+            pad,
+            ..Default::default()
+        };
+        unsafe {
+            self.handle.ioctl(
+                v4l2::vidioc::VIDIOC_SUBDEV_G_FMT,
+                &mut fmt as *mut _ as *mut std::os::raw::c_void,
+            )?;
+        }
+
+        Ok(Format::from(fmt.format))
+    }
+
+    /// Modifies the media bus format of a pad
+    ///
+    /// Drivers adjust the format to fit their constraints, the applied format is returned.
+    /// Setting the format of a sink pad usually resets the formats of the source pads it is
+    /// routed to.
+    ///
+    /// # Arguments
+    ///
+    /// * `pad` - Pad index
+    /// * `fmt` - Desired format
+    /// * `which` - Configuration to modify
+    pub fn set_format(&self, pad: u32, fmt: &Format, which: Which) -> io::Result<Format> {
+        let mut fmt = v4l2_subdev_format {
+            which: which.into(),
+            pad,
+            format: (*fmt).into(),
+            ..Default::default()
+        };
+        unsafe {
+            self.handle.ioctl(
+                v4l2::vidioc::VIDIOC_SUBDEV_S_FMT,
+                &mut fmt as *mut _ as *mut std::os::raw::c_void,
+            )?;
+        }
+
+        Ok(Format::from(fmt.format))
+    }
+
+    /// Returns the capabilities the client announced to the sub-device
+    pub fn client_capabilities(&self) -> io::Result<ClientCapabilities> {
+        let mut caps = v4l2_subdev_client_capability::default();
+        unsafe {
+            self.handle.ioctl(
+                v4l2::vidioc::VIDIOC_SUBDEV_G_CLIENT_CAP,
+                &mut caps as *mut _ as *mut std::os::raw::c_void,
+            )?;
+        }
+
+        Ok(ClientCapabilities::from(caps.capabilities))
+    }
+
+    /// Announces the capabilities of the client to the sub-device
+    ///
+    /// The capabilities apply to the file handle of this sub-device instance. The kernel drops
+    /// the ones it does not support, the effective capabilities are returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `caps` - Client capabilities
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::subdev::{ClientCapabilities, Subdevice};
+    ///
+    /// if let Ok(subdev) = Subdevice::new(0) {
+    ///     let caps = subdev.set_client_capabilities(ClientCapabilities::STREAMS);
+    /// }
+    /// ```
+    pub fn set_client_capabilities(
+        &self,
+        caps: ClientCapabilities,
+    ) -> io::Result<ClientCapabilities> {
+        let mut caps = v4l2_subdev_client_capability {
+            capabilities: caps.into(),
+        };
+        unsafe {
+            self.handle.ioctl(
+                v4l2::vidioc::VIDIOC_SUBDEV_S_CLIENT_CAP,
+                &mut caps as *mut _ as *mut std::os::raw::c_void,
+            )?;
+        }
+
+        Ok(ClientCapabilities::from(caps.capabilities))
+    }
+
+    /// Returns the routing table of the sub-device
+    ///
+    /// Requires Linux 6.8 or newer and a driver supporting the streams API. The client must have
+    /// announced the [`ClientCapabilities::STREAMS`] capability.
+    ///
+    /// # Arguments
+    ///
+    /// * `which` - Configuration to query
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::subdev::{ClientCapabilities, Subdevice, Which};
+    ///
+    /// if let Ok(subdev) = Subdevice::new(0) {
+    ///     subdev.set_client_capabilities(ClientCapabilities::STREAMS).unwrap();
+    ///     if let Ok(routes) = subdev.routing(Which::Active) {
+    ///         for route in routes {
+    ///             println!("{}", route);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn routing(&self, which: Which) -> io::Result<Vec<Route>> {
+        let mut routes = vec![v4l2_subdev_route::default(); 8];
+        loop {
+            let mut routing = v4l2_subdev_routing {
+                which: which.into(),
+                len_routes: routes.len() as u32,
+                routes: routes.as_mut_ptr() as u64,
+                ..Default::default()
+            };
+
+            let res = unsafe {
+                self.handle.ioctl(
+                    v4l2::vidioc::VIDIOC_SUBDEV_G_ROUTING,
+                    &mut routing as *mut _ as *mut std::os::raw::c_void,
+                )
+            };
+            match res {
+                Ok(()) => {
+                    routes.truncate(routing.num_routes as usize);
+                    return Ok(routes.into_iter().map(Route::from).collect());
+                }
+                // the table is larger than anticipated, the driver tells us its actual size
+                Err(e)
+                    if e.raw_os_error() == Some(libc::ENOSPC)
+                        && routing.num_routes as usize > routes.len() =>
+                {
+                    routes.resize(routing.num_routes as usize, v4l2_subdev_route::default());
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Replaces the routing table of the sub-device
+    ///
+    /// Drivers may adjust the routes to fit their constraints, the applied routing table is
+    /// returned. Changing the routes resets the formats of all pads of the sub-device.
+    ///
+    /// # Arguments
+    ///
+    /// * `routes` - Routes to be set
+    /// * `which` - Configuration to modify
+    pub fn set_routing(&self, routes: &[Route], which: Which) -> io::Result<Vec<Route>> {
+        let mut routes: Vec<v4l2_subdev_route> = routes.iter().map(|&route| route.into()).collect();
+        let mut routing = v4l2_subdev_routing {
+            which: which.into(),
+            len_routes: routes.len() as u32,
+            routes: routes.as_mut_ptr() as u64,
+            num_routes: routes.len() as u32,
+            ..Default::default()
+        };
+
+        unsafe {
+            self.handle.ioctl(
+                v4l2::vidioc::VIDIOC_SUBDEV_S_ROUTING,
+                &mut routing as *mut _ as *mut std::os::raw::c_void,
+            )?;
+        }
+
+        routes.truncate(routing.num_routes as usize);
+        Ok(routes.into_iter().map(Route::from).collect())
+    }
+}
+
+impl AsFd for Subdevice {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.handle.as_fd()
+    }
+}
+
+impl AsRawFd for Subdevice {
+    fn as_raw_fd(&self) -> RawFd {
+        self.handle.as_raw_fd()
+    }
+}
@@ -0,0 +1,110 @@
+use bitflags::bitflags;
+use std::fmt;
+
+use crate::v4l2::subdev::v4l2_subdev_route;
+
+bitflags! {
+    pub struct RouteFlags: u32 {
+        /// The route is enabled and can be used for streaming
+        const ACTIVE = 0x00000001;
+    }
+}
+
+impl From<u32> for RouteFlags {
+    fn from(flags: u32) -> Self {
+        Self::from_bits_truncate(flags)
+    }
+}
+
+impl From<RouteFlags> for u32 {
+    fn from(flags: RouteFlags) -> Self {
+        flags.bits()
+    }
+}
+
+impl fmt::Display for RouteFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Route of a stream through a sub-device
+///
+/// Sub-devices which multiplex several streams onto one pad (e.g. CSI-2 aggregators bridging
+/// multiple sensors, or sensors emitting embedded data next to the image) describe how the
+/// streams of their sink pads map to the streams of their source pads through routes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Route {
+    /// Sink pad
+    pub sink_pad: u32,
+    /// Stream on the sink pad
+    pub sink_stream: u32,
+    /// Source pad
+    pub source_pad: u32,
+    /// Stream on the source pad
+    pub source_stream: u32,
+    /// Route flags
+    pub flags: RouteFlags,
+}
+
+impl Route {
+    /// Returns an active route
+    ///
+    /// # Arguments
+    ///
+    /// * `sink` - Sink pad and stream
+    /// * `source` - Source pad and stream
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::subdev::routing::{Route, RouteFlags};
+    ///
+    /// let route = Route::new((0, 0), (4, 1));
+    /// assert_eq!(route.flags, RouteFlags::ACTIVE);
+    /// ```
+    pub fn new(sink: (u32, u32), source: (u32, u32)) -> Self {
+        Route {
+            sink_pad: sink.0,
+            sink_stream: sink.1,
+            source_pad: source.0,
+            source_stream: source.1,
+            flags: RouteFlags::ACTIVE,
+        }
+    }
+}
+
+impl fmt::Display for Route {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{} -> {}/{} [{}]",
+            self.sink_pad, self.sink_stream, self.source_pad, self.source_stream, self.flags
+        )
+    }
+}
+
+impl From<v4l2_subdev_route> for Route {
+    fn from(route: v4l2_subdev_route) -> Self {
+        Route {
+            sink_pad: route.sink_pad,
+            sink_stream: route.sink_stream,
+            source_pad: route.source_pad,
+            source_stream: route.source_stream,
+            flags: RouteFlags::from(route.flags),
+        }
+    }
+}
+
+impl From<Route> for v4l2_subdev_route {
+    fn from(route: Route) -> Self {
+        v4l2_subdev_route {
+            sink_pad: route.sink_pad,
+            sink_stream: route.sink_stream,
+            source_pad: route.source_pad,
+            source_stream: route.source_stream,
+            flags: route.flags.into(),
+            ..Default::default()
+        }
+    }
+}
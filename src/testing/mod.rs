@@ -0,0 +1,5 @@
+//! Helpers for testing applications against real (virtual) devices
+//!
+//! See [`crate::backend::Mock`] for testing without any device nodes at all.
+
+pub mod vivid;
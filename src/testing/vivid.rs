@@ -0,0 +1,285 @@
+//! vivid test device helpers
+//!
+//! The Virtual Video Test Driver (vivid) ships with the Linux kernel and emulates video capture
+//! and output hardware. Once the module is loaded (`modprobe vivid`), its capture nodes generate
+//! test patterns which are perfectly reproducible after moving parts such as the on-screen
+//! display text have been disabled. This makes vivid a good fit for integration tests on CI
+//! machines: [`configure`] puts a device into such a deterministic state and [`checksum`] as
+//! well as the `assert_*` helpers verify the captured frames.
+
+use std::{io, path::PathBuf};
+
+use crate::capability::Flags;
+use crate::context;
+use crate::control::{self, Control, MenuItem};
+use crate::device::Device;
+
+/// Driver name reported by vivid devices
+pub const DRIVER: &str = "vivid";
+
+/// Test patterns generated by vivid
+///
+/// The variants correspond to items of the "Test Pattern" menu control.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pattern {
+    ColorBar75,
+    ColorBar100,
+    CscColorBar,
+    HorizontalColorBar100,
+    ColorSquares100,
+    Black,
+    White,
+    Red,
+    Green,
+    Blue,
+    Checkers16x16,
+    Checkers2x2,
+    Checkers1x1,
+    AlternatingHorizontalLines,
+    AlternatingVerticalLines,
+    GrayRamp,
+    Noise,
+}
+
+impl Pattern {
+    /// Returns the name of the menu item selecting this pattern
+    pub fn name(&self) -> &'static str {
+        match self {
+            Pattern::ColorBar75 => "75% Colorbar",
+            Pattern::ColorBar100 => "100% Colorbar",
+            Pattern::CscColorBar => "CSC Colorbar",
+            Pattern::HorizontalColorBar100 => "Horizontal 100% Colorbar",
+            Pattern::ColorSquares100 => "100% Color Squares",
+            Pattern::Black => "100% Black",
+            Pattern::White => "100% White",
+            Pattern::Red => "100% Red",
+            Pattern::Green => "100% Green",
+            Pattern::Blue => "100% Blue",
+            Pattern::Checkers16x16 => "16x16 Checkers",
+            Pattern::Checkers2x2 => "2x2 Checkers",
+            Pattern::Checkers1x1 => "1x1 Checkers",
+            Pattern::AlternatingHorizontalLines => "Alternating Hor Lines",
+            Pattern::AlternatingVerticalLines => "Alternating Vert Lines",
+            Pattern::GrayRamp => "Gray Ramp",
+            Pattern::Noise => "Noise",
+        }
+    }
+}
+
+/// Returns the paths of all vivid video capture nodes known to the system
+///
+/// # Example
+///
+/// ```
+/// use v4l::testing::vivid;
+/// for path in vivid::devices() {
+///     println!("{}", path.display());
+/// }
+/// ```
+pub fn devices() -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = context::enum_devices()
+        .into_iter()
+        .filter(|node| match Device::with_path(node.path()) {
+            Ok(dev) => validate(&dev).is_ok(),
+            Err(_) => false,
+        })
+        .map(|node| node.path().to_path_buf())
+        .collect();
+
+    paths.sort();
+    paths
+}
+
+/// Opens the first vivid video capture device
+///
+/// An error of kind [`io::ErrorKind::NotFound`] is returned if the vivid module is not loaded,
+/// which allows tests to skip themselves on machines without vivid.
+///
+/// # Example
+///
+/// ```
+/// use v4l::testing::vivid;
+///
+/// let dev = match vivid::open() {
+///     Ok(dev) => dev,
+///     Err(_) => return,
+/// };
+/// ```
+pub fn open() -> io::Result<Device> {
+    match devices().first() {
+        Some(path) => Device::with_path(path),
+        None => Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "no vivid capture device found (is the vivid module loaded?)",
+        )),
+    }
+}
+
+/// Verifies that a device is a vivid video capture device
+///
+/// # Arguments
+///
+/// * `dev` - Device to check
+pub fn validate(dev: &Device) -> io::Result<()> {
+    let caps = dev.query_caps()?;
+    if caps.driver != DRIVER {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("unexpected driver: {}", caps.driver),
+        ));
+    }
+    if !caps
+        .capabilities
+        .contains(Flags::VIDEO_CAPTURE | Flags::STREAMING)
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "device is not a streaming video capture node",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Configures a vivid device to generate reproducible frames
+///
+/// Besides selecting the test pattern, the on-screen display text (which contains timestamps
+/// and frame counters) as well as the horizontal and vertical pattern movement are disabled, so
+/// all captured frames are identical.
+///
+/// # Arguments
+///
+/// * `dev` - vivid capture device
+/// * `pattern` - Test pattern to generate
+///
+/// # Example
+///
+/// ```
+/// use v4l::testing::vivid::{self, Pattern};
+///
+/// if let Ok(dev) = vivid::open() {
+///     vivid::configure(&dev, Pattern::ColorBar75).unwrap();
+/// }
+/// ```
+pub fn configure(dev: &Device, pattern: Pattern) -> io::Result<()> {
+    let controls = dev.query_controls()?;
+
+    set_menu(dev, &controls, "Test Pattern", pattern.name())?;
+    set_menu(dev, &controls, "OSD Text Mode", "None")?;
+    set_menu(dev, &controls, "Horizontal Movement", "No Movement")?;
+    set_menu(dev, &controls, "Vertical Movement", "No Movement")?;
+    Ok(())
+}
+
+/// Selects a menu control item by name
+fn set_menu(
+    dev: &Device,
+    controls: &[control::Description],
+    name: &str,
+    item: &str,
+) -> io::Result<()> {
+    let desc = controls
+        .iter()
+        .find(|desc| desc.name == name)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("control not found: {}", name),
+            )
+        })?;
+
+    let index = desc
+        .items
+        .iter()
+        .flatten()
+        .find(|(_, menu_item)| matches!(menu_item, MenuItem::Name(n) if n == item))
+        .map(|(index, _)| *index)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("menu item not found: {} / {}", name, item),
+            )
+        })?;
+
+    dev.set_control(Control {
+        id: desc.id,
+        value: control::Value::Integer(index as i64),
+    })
+}
+
+/// Returns a checksum of the frame data
+///
+/// The checksum is a 64-bit FNV-1a hash. It is not suitable for cryptographic purposes, but good
+/// enough to compare captured frames against reference values.
+///
+/// # Arguments
+///
+/// * `data` - Frame data
+///
+/// # Example
+///
+/// ```
+/// use v4l::testing::vivid;
+/// assert_eq!(vivid::checksum(&[]), 0xcbf29ce484222325);
+/// ```
+pub fn checksum(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// Asserts that the frame data matches a reference checksum
+///
+/// # Arguments
+///
+/// * `data` - Frame data
+/// * `expected` - Reference checksum as returned by [`checksum`]
+#[track_caller]
+pub fn assert_checksum(data: &[u8], expected: u64) {
+    let actual = checksum(data);
+    assert!(
+        actual == expected,
+        "frame checksum mismatch: expected {:#018x}, got {:#018x}",
+        expected,
+        actual
+    );
+}
+
+/// Asserts that all frames have identical content
+///
+/// Returns the checksum shared by all frames.
+///
+/// # Arguments
+///
+/// * `frames` - Frame data, at least one frame is required
+///
+/// # Example
+///
+/// ```
+/// use v4l::testing::vivid;
+/// let frames = vec![vec![1, 2, 3], vec![1, 2, 3]];
+/// vivid::assert_static(&frames);
+/// ```
+#[track_caller]
+pub fn assert_static<I, T>(frames: I) -> u64
+where
+    I: IntoIterator<Item = T>,
+    T: AsRef<[u8]>,
+{
+    let mut reference = None;
+    for (i, frame) in frames.into_iter().enumerate() {
+        let actual = checksum(frame.as_ref());
+        match reference {
+            None => reference = Some(actual),
+            Some(expected) => assert!(
+                actual == expected,
+                "frame {} differs from the first frame: expected {:#018x}, got {:#018x}",
+                i,
+                expected,
+                actual
+            ),
+        }
+    }
+
+    reference.expect("no frames to compare")
+}
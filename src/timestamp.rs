@@ -1,12 +1,22 @@
-use std::{fmt, time};
+use std::{fmt, mem, time};
 
 use crate::v4l_sys::*;
 
-#[derive(Debug, Default, Clone, Copy)]
+/// Whether the bindings use a 64-bit `time_t` in buffer and event timestamps
+///
+/// The ioctls passing timestamps encode the size of their argument, so the time64 variants are
+/// used automatically then. They work past 2038 on 32-bit systems, kernel 5.6 or newer is
+/// required for that. 64-bit systems always use 64-bit timestamps. With the `libv4l` feature,
+/// the `time_t` of the system headers is kept, so the ioctls match what the library expects.
+pub const TIME64: bool = mem::size_of::<time_t>() == mem::size_of::<i64>();
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 /// Timestamp consisting of a seconds and a microseconds component
+///
+/// The components are 64-bit wide regardless of the `time_t` of the target, see [`TIME64`].
 pub struct Timestamp {
-    pub sec: time_t,
-    pub usec: time_t,
+    pub sec: i64,
+    pub usec: i64,
 }
 
 impl Timestamp {
@@ -23,7 +33,7 @@ impl Timestamp {
     /// use v4l::timestamp::Timestamp;
     /// let ts = Timestamp::new(5, 5);
     /// ```
-    pub fn new(sec: time_t, usec: time_t) -> Self {
+    pub fn new(sec: i64, usec: i64) -> Self {
         Timestamp { sec, usec }
     }
 }
@@ -36,10 +46,12 @@ impl fmt::Display for Timestamp {
 }
 
 impl From<timeval> for Timestamp {
+    // the fields are 32-bit wide on some targets
+    #[allow(clippy::useless_conversion)]
     fn from(tv: timeval) -> Self {
         Self {
-            sec: tv.tv_sec as time_t,
-            usec: tv.tv_usec as time_t,
+            sec: i64::from(tv.tv_sec),
+            usec: i64::from(tv.tv_usec),
         }
     }
 }
@@ -47,8 +59,8 @@ impl From<timeval> for Timestamp {
 impl From<Timestamp> for timeval {
     fn from(timestamp: Timestamp) -> Self {
         Self {
-            tv_sec: timestamp.sec as time_t,
-            tv_usec: timestamp.usec as time_t,
+            tv_sec: timestamp.sec as _,
+            tv_usec: timestamp.usec as _,
         }
     }
 }
@@ -56,8 +68,8 @@ impl From<Timestamp> for timeval {
 impl From<time::Duration> for Timestamp {
     fn from(duration: time::Duration) -> Self {
         Self::new(
-            duration.as_secs() as time_t,
-            duration.subsec_micros() as time_t,
+            duration.as_secs() as i64,
+            i64::from(duration.subsec_micros()),
         )
     }
 }
@@ -0,0 +1,180 @@
+//! Touch devices
+//!
+//! Some touchscreen controllers expose their raw sensor data as video capture device
+//! (/dev/v4l-touchX) next to the regular input device. Each frame is a heatmap holding one
+//! capacitance value per sensor node, either as raw reading (TU08, TU16) or as delta against the
+//! baseline (TD08, TD16). This is mostly useful for bringing up and tuning such controllers.
+
+use std::io;
+
+use crate::capability::Flags;
+use crate::device::Device;
+use crate::format::{Format, FourCC};
+
+/// Returns a touch device by index
+///
+/// The device is checked to actually be a touch device.
+///
+/// # Arguments
+///
+/// * `index` - Index (0: first, 1: second, ..)
+///
+/// # Example
+///
+/// ```
+/// use v4l::touch;
+/// let dev = touch::device(0);
+/// ```
+pub fn device(index: usize) -> io::Result<Device> {
+    let dev = Device::with_path(format!("{}{}", "/dev/v4l-touch", index))?;
+    if !dev.query_caps()?.capabilities.contains(Flags::TOUCH) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "device is not a touch device",
+        ));
+    }
+
+    Ok(dev)
+}
+
+/// Returns whether a pixel format is a touch format
+///
+/// # Arguments
+///
+/// * `fourcc` - Pixel format
+pub fn is_touch_format(fourcc: FourCC) -> bool {
+    matches!(&fourcc.repr, b"TU08" | b"TU16" | b"TD08" | b"TD16")
+}
+
+/// Touch sensor readings of one frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heatmap {
+    /// Number of sensor nodes per row
+    pub width: u32,
+    /// Number of rows
+    pub height: u32,
+    /// Readings in row-major order, deltas can be negative
+    pub values: Vec<i32>,
+}
+
+impl Heatmap {
+    /// Interprets a captured frame as heatmap
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Frame data
+    /// * `format` - Format of the frame, one of TU08, TU16, TD08 and TD16
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::buffer::Type;
+    /// use v4l::io::mmap::Stream;
+    /// use v4l::io::traits::CaptureStream;
+    /// use v4l::touch::{self, Heatmap};
+    /// use v4l::video::Capture;
+    ///
+    /// if let Ok(dev) = touch::device(0) {
+    ///     let format = dev.format().unwrap();
+    ///     let mut stream = Stream::new(&dev, Type::VideoCapture).unwrap();
+    ///     let (buf, _, _) = stream.next().unwrap();
+    ///     let heatmap = Heatmap::from_frame(&buf[0], &format).unwrap();
+    ///     println!("strongest touch: {:?}", heatmap.peak());
+    /// }
+    /// ```
+    pub fn from_frame(data: &[u8], format: &Format) -> io::Result<Self> {
+        let (bytes, signed) = match &format.fourcc.repr {
+            b"TU08" => (1, false),
+            b"TU16" => (2, false),
+            b"TD08" => (1, true),
+            b"TD16" => (2, true),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "not a touch format",
+                ))
+            }
+        };
+
+        let (width, height) = (format.width as usize, format.height as usize);
+        let stride = match format.stride as usize {
+            0 => width * bytes,
+            stride => stride,
+        };
+        if height > 0 && data.len() < stride * (height - 1) + width * bytes {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "frame is smaller than its format",
+            ));
+        }
+
+        let mut values = Vec::with_capacity(width * height);
+        for row in 0..height {
+            let line = &data[row * stride..row * stride + width * bytes];
+            values.extend(line.chunks_exact(bytes).map(|node| match (bytes, signed) {
+                (1, false) => i32::from(node[0]),
+                (1, true) => i32::from(node[0] as i8),
+                (_, false) => i32::from(u16::from_le_bytes([node[0], node[1]])),
+                (_, true) => i32::from(i16::from_le_bytes([node[0], node[1]])),
+            }));
+        }
+
+        Ok(Heatmap {
+            width: format.width,
+            height: format.height,
+            values,
+        })
+    }
+
+    /// Returns the reading of a sensor node
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Column
+    /// * `y` - Row
+    pub fn value(&self, x: u32, y: u32) -> Option<i32> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        self.values
+            .get(y as usize * self.width as usize + x as usize)
+            .copied()
+    }
+
+    /// Returns column, row and reading of the strongest sensor node
+    pub fn peak(&self) -> Option<(u32, u32, i32)> {
+        self.values
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, value)| **value)
+            .map(|(i, value)| {
+                let i = i as u32;
+                (i % self.width, i / self.width, *value)
+            })
+    }
+
+    /// Returns the weighted center of all sensor nodes above a threshold
+    ///
+    /// For a single touch, this locates the contact with sub-node precision.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - Minimum reading of a node to be considered touched
+    pub fn centroid(&self, threshold: i32) -> Option<(f32, f32)> {
+        let (mut sum, mut x, mut y) = (0f64, 0f64, 0f64);
+        for (i, value) in self.values.iter().enumerate() {
+            if *value <= threshold {
+                continue;
+            }
+            let weight = f64::from(*value - threshold);
+            sum += weight;
+            x += weight * (i % self.width as usize) as f64;
+            y += weight * (i / self.width as usize) as f64;
+        }
+
+        if sum == 0.0 {
+            return None;
+        }
+        Some(((x / sum) as f32, (y / sum) as f32))
+    }
+}
@@ -5,14 +5,17 @@ use std::{io, path::Path};
 use crate::v4l2::vidioc;
 
 #[cfg(feature = "v4l-sys")]
-mod detail {
-    use crate::v4l2::vidioc;
-    use crate::v4l_sys::*;
+mod libv4l {
+    use crate::v4l2::{platform, vidioc};
+    use ::v4l_sys::{v4l2_close, v4l2_fd_open, v4l2_ioctl, v4l2_mmap, v4l2_munmap, v4l2_open};
     use std::convert::TryInto;
 
     pub unsafe fn open(path: *const std::os::raw::c_char, flags: i32) -> std::os::raw::c_int {
         v4l2_open(path, flags)
     }
+    pub unsafe fn fd_open(fd: std::os::raw::c_int, flags: i32) -> std::os::raw::c_int {
+        v4l2_fd_open(fd, flags)
+    }
     pub unsafe fn close(fd: std::os::raw::c_int) -> std::os::raw::c_int {
         v4l2_close(fd)
     }
@@ -21,14 +24,9 @@ mod detail {
         request: vidioc::_IOC_TYPE,
         argp: *mut std::os::raw::c_void,
     ) -> std::os::raw::c_int {
-        // libv4l expects `request` to be a u64, but this is not guaranteed on all platforms.
-        // For the default CI platform (x86_64) clippy will complain about a useless conversion.
+        // libv4l expects `request` to be an unsigned long, whatever the C library declares
         #![allow(clippy::useless_conversion)]
-        v4l2_ioctl(
-            fd,
-            request.try_into().expect("vidioc::_IOC_TYPE -> u64 failed"),
-            argp,
-        )
+        v4l2_ioctl(fd, platform::request_bits(request).into(), argp)
     }
     pub unsafe fn mmap(
         start: *mut std::os::raw::c_void,
@@ -56,12 +54,15 @@ mod detail {
 }
 
 #[cfg(feature = "v4l2-sys")]
-mod detail {
-    use crate::v4l2::vidioc;
+mod kernel {
+    use crate::v4l2::{platform, vidioc};
 
     pub unsafe fn open(path: *const std::os::raw::c_char, flags: i32) -> std::os::raw::c_int {
         libc::open(path, flags)
     }
+    pub unsafe fn fd_open(fd: std::os::raw::c_int, _flags: i32) -> std::os::raw::c_int {
+        fd
+    }
     pub unsafe fn close(fd: std::os::raw::c_int) -> std::os::raw::c_int {
         libc::close(fd)
     }
@@ -70,14 +71,7 @@ mod detail {
         request: vidioc::_IOC_TYPE,
         argp: *mut std::os::raw::c_void,
     ) -> std::os::raw::c_int {
-        /*
-         * It turns out the libc crate (and libc itself!) defines ioctl() with
-         * different, incompatible argument types on different platforms. To
-         * hack around this without conditional compilation, use syscall()
-         * instead as a drop-in replacement. Details:
-         * https://github.com/rust-lang/libc/issues/1036
-         */
-        libc::syscall(libc::SYS_ioctl, fd, request, argp) as std::os::raw::c_int
+        platform::ioctl(fd, request, argp)
     }
     pub unsafe fn mmap(
         start: *mut std::os::raw::c_void,
@@ -94,6 +88,167 @@ mod detail {
     }
 }
 
+/// Userspace library the device calls are routed through
+///
+/// With both the `libv4l` and `v4l2` features enabled, the library can be chosen per device
+/// (see [`crate::device::PlanarDevice::with_library`]), e.g. to get the format emulation of
+/// libv4l for a webcam while talking to a codec directly. It defaults to libv4l if available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Library {
+    /// Issue the calls directly to the kernel
+    #[cfg(feature = "v4l2-sys")]
+    Kernel,
+    /// Route the calls through libv4l2, which converts formats through libv4lconvert
+    #[cfg(feature = "v4l-sys")]
+    Libv4l,
+}
+
+impl Default for Library {
+    #[cfg(feature = "v4l-sys")]
+    fn default() -> Self {
+        Library::Libv4l
+    }
+
+    #[cfg(not(feature = "v4l-sys"))]
+    fn default() -> Self {
+        Library::Kernel
+    }
+}
+
+macro_rules! dispatch {
+    ($lib:expr, $func:ident($($arg:expr),*)) => {
+        match $lib {
+            #[cfg(feature = "v4l2-sys")]
+            Library::Kernel => kernel::$func($($arg),*),
+            #[cfg(feature = "v4l-sys")]
+            Library::Libv4l => libv4l::$func($($arg),*),
+        }
+    };
+}
+
+impl Library {
+    /// Opens a device node, see [`open`]
+    pub fn open<P: AsRef<Path>>(self, path: P, flags: i32) -> io::Result<std::os::raw::c_int> {
+        let fd: std::os::raw::c_int;
+        let c_path = CString::new(path.as_ref().as_os_str().as_bytes()).unwrap();
+
+        unsafe {
+            fd = dispatch!(self, open(c_path.as_ptr(), flags));
+        }
+
+        if fd == -1 {
+            Err(io::Error::last_os_error())
+        } else {
+            Ok(fd)
+        }
+    }
+
+    /// Registers a file descriptor which was opened elsewhere, see [`fd_open`]
+    pub fn fd_open(self, fd: std::os::raw::c_int, flags: i32) -> io::Result<std::os::raw::c_int> {
+        let ret: std::os::raw::c_int;
+        unsafe {
+            ret = dispatch!(self, fd_open(fd, flags));
+        }
+
+        if ret == -1 {
+            Err(io::Error::last_os_error())
+        } else {
+            Ok(ret)
+        }
+    }
+
+    /// Closes a device, see [`close`]
+    pub fn close(self, fd: std::os::raw::c_int) -> io::Result<()> {
+        let ret: std::os::raw::c_int;
+        unsafe {
+            ret = dispatch!(self, close(fd));
+        }
+
+        if ret == -1 {
+            Err(io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Performs an ioctl, see [`ioctl`]
+    ///
+    /// # Safety
+    ///
+    /// `argp` must point to valid memory with the layout expected for `request`.
+    pub unsafe fn ioctl(
+        self,
+        fd: std::os::raw::c_int,
+        request: vidioc::_IOC_TYPE,
+        argp: *mut std::os::raw::c_void,
+    ) -> io::Result<()> {
+        #[cfg(feature = "tracing")]
+        let start = std::time::Instant::now();
+
+        let res = loop {
+            let ret = dispatch!(self, ioctl(fd, request, argp));
+
+            if ret == -1 {
+                let err = io::Error::last_os_error();
+                if err.kind() == io::ErrorKind::Interrupted {
+                    // A signal arrived before the driver could complete the request, so it is
+                    // safe to just issue it again.
+                    continue;
+                }
+                break Err(err);
+            } else {
+                break Ok(());
+            }
+        };
+
+        trace!(
+            fd,
+            request = vidioc::name(request).unwrap_or("unknown"),
+            elapsed = ?start.elapsed(),
+            result = ?res,
+            "ioctl"
+        );
+
+        res
+    }
+
+    /// Maps device memory, see [`mmap`]
+    ///
+    /// # Safety
+    ///
+    /// Start must be a raw pointer.
+    pub unsafe fn mmap(
+        self,
+        start: *mut std::os::raw::c_void,
+        length: usize,
+        prot: std::os::raw::c_int,
+        flags: std::os::raw::c_int,
+        fd: std::os::raw::c_int,
+        offset: libc::off_t,
+    ) -> io::Result<*mut std::os::raw::c_void> {
+        let ret = dispatch!(self, mmap(start, length, prot, flags, fd, offset));
+        if ret as usize == usize::MAX {
+            Err(io::Error::last_os_error())
+        } else {
+            Ok(ret)
+        }
+    }
+
+    /// Unmaps device memory, see [`munmap`]
+    ///
+    /// # Safety
+    ///
+    /// The memory must not be accessed anymore afterwards.
+    pub unsafe fn munmap(self, start: *mut std::os::raw::c_void, length: usize) -> io::Result<()> {
+        let ret = dispatch!(self, munmap(start, length));
+        if ret == -1 {
+            Err(io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+}
+
 /// A convenience wrapper around v4l2_open.
 ///
 /// Returns the file descriptor on success.
@@ -114,18 +269,23 @@ mod detail {
 /// let fd = v4l2::open("/dev/video0", libc::O_RDWR);
 /// ```
 pub fn open<P: AsRef<Path>>(path: P, flags: i32) -> io::Result<std::os::raw::c_int> {
-    let fd: std::os::raw::c_int;
-    let c_path = CString::new(path.as_ref().as_os_str().as_bytes()).unwrap();
-
-    unsafe {
-        fd = detail::open(c_path.as_ptr(), flags);
-    }
+    Library::default().open(path, flags)
+}
 
-    if fd == -1 {
-        Err(io::Error::last_os_error())
-    } else {
-        Ok(fd)
-    }
+/// A convenience wrapper around v4l2_fd_open.
+///
+/// Registers a file descriptor which was opened elsewhere (e.g. received from a privileged
+/// process) with the backend. For the plain v4l2 backend, this is a no-op.
+///
+/// Returns the file descriptor on success.
+/// In case of errors, the last OS error will be reported, aka errno on Linux.
+///
+/// # Arguments
+///
+/// * `fd` - File descriptor of an opened device node
+/// * `flags` - Backend specific flags (e.g. libv4l2 conversion flags), usually 0
+pub fn fd_open(fd: std::os::raw::c_int, flags: i32) -> io::Result<std::os::raw::c_int> {
+    Library::default().fd_open(fd, flags)
 }
 
 /// A convenience wrapper around v4l2_close.
@@ -149,21 +309,13 @@ pub fn open<P: AsRef<Path>>(path: P, flags: i32) -> io::Result<std::os::raw::c_i
 /// }
 /// ```
 pub fn close(fd: std::os::raw::c_int) -> io::Result<()> {
-    let ret: std::os::raw::c_int;
-    unsafe {
-        ret = detail::close(fd);
-    }
-
-    if ret == -1 {
-        Err(io::Error::last_os_error())
-    } else {
-        Ok(())
-    }
+    Library::default().close(fd)
 }
 
 /// A convenience wrapper around v4l2_ioctl.
 ///
 /// In case of errors, the last OS error will be reported, aka errno on Linux.
+/// Calls interrupted by a signal (EINTR) are restarted transparently.
 ///
 /// # Arguments
 ///
@@ -203,13 +355,7 @@ pub unsafe fn ioctl(
     request: vidioc::_IOC_TYPE,
     argp: *mut std::os::raw::c_void,
 ) -> io::Result<()> {
-    let ret = detail::ioctl(fd, request, argp);
-
-    if ret == -1 {
-        Err(io::Error::last_os_error())
-    } else {
-        Ok(())
-    }
+    Library::default().ioctl(fd, request, argp)
 }
 
 /// A convenience wrapper around v4l2_mmap.
@@ -259,12 +405,7 @@ pub unsafe fn mmap(
     fd: std::os::raw::c_int,
     offset: libc::off_t,
 ) -> io::Result<*mut std::os::raw::c_void> {
-    let ret = detail::mmap(start, length, prot, flags, fd, offset);
-    if ret as usize == std::usize::MAX {
-        Err(io::Error::last_os_error())
-    } else {
-        Ok(ret)
-    }
+    Library::default().mmap(start, length, prot, flags, fd, offset)
 }
 
 /// A convenience wrapper around v4l2_munmap.
@@ -306,10 +447,5 @@ pub unsafe fn mmap(
 /// }
 /// ```
 pub unsafe fn munmap(start: *mut std::os::raw::c_void, length: usize) -> io::Result<()> {
-    let ret = detail::munmap(start, length);
-    if ret == -1 {
-        Err(io::Error::last_os_error())
-    } else {
-        Ok(())
-    }
+    Library::default().munmap(start, length)
 }
@@ -0,0 +1,99 @@
+// Media controller API (linux/media.h). The header is not part of the generated bindings, so we
+// carry our own copies of the structs.
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub(crate) struct media_device_info {
+    pub driver: [u8; 16],
+    pub model: [u8; 32],
+    pub serial: [u8; 40],
+    pub bus_info: [u8; 32],
+    pub media_version: u32,
+    pub hw_revision: u32,
+    pub driver_version: u32,
+    pub reserved: [u32; 31],
+}
+
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+pub(crate) struct media_v2_entity {
+    pub id: u32,
+    pub name: [u8; 64],
+    pub function: u32,
+    pub flags: u32,
+    pub reserved: [u32; 5],
+}
+
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+pub(crate) struct media_v2_interface {
+    pub id: u32,
+    pub intf_type: u32,
+    pub flags: u32,
+    pub reserved: [u32; 9],
+    // union of media_v2_intf_devnode (major, minor) and raw[16]
+    pub raw: [u32; 16],
+}
+
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+pub(crate) struct media_v2_pad {
+    pub id: u32,
+    pub entity_id: u32,
+    pub flags: u32,
+    pub index: u32,
+    pub reserved: [u32; 4],
+}
+
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+pub(crate) struct media_v2_link {
+    pub id: u32,
+    pub source_id: u32,
+    pub sink_id: u32,
+    pub flags: u32,
+    pub reserved: [u32; 6],
+}
+
+#[repr(C, packed)]
+#[derive(Copy, Clone, Default)]
+pub(crate) struct media_v2_topology {
+    pub topology_version: u64,
+    pub num_entities: u32,
+    pub reserved1: u32,
+    pub ptr_entities: u64,
+    pub num_interfaces: u32,
+    pub reserved2: u32,
+    pub ptr_interfaces: u64,
+    pub num_pads: u32,
+    pub reserved3: u32,
+    pub ptr_pads: u64,
+    pub num_links: u32,
+    pub reserved4: u32,
+    pub ptr_links: u64,
+}
+
+pub(crate) const MEDIA_LNK_FL_LINK_TYPE: u32 = 0xf << 28;
+pub(crate) const MEDIA_LNK_FL_DATA_LINK: u32 = 0;
+pub(crate) const MEDIA_LNK_FL_INTERFACE_LINK: u32 = 1 << 28;
+pub(crate) const MEDIA_LNK_FL_ANCILLARY_LINK: u32 = 2 << 28;
+
+pub(crate) const MEDIA_LNK_FL_ENABLED: u32 = 1 << 0;
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub(crate) struct media_pad_desc {
+    pub entity: u32,
+    pub index: u16,
+    pub flags: u32,
+    pub reserved: [u32; 2],
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub(crate) struct media_link_desc {
+    pub source: media_pad_desc,
+    pub sink: media_pad_desc,
+    pub flags: u32,
+    pub reserved: [u32; 2],
+}
@@ -1,5 +1,8 @@
 pub mod api;
 pub use api::*;
 
+pub mod media;
+pub(crate) mod platform;
+pub mod subdev;
 pub mod videodev;
 pub mod vidioc;
@@ -0,0 +1,80 @@
+//! Platform differences of the ioctl interface
+//!
+//! The C libraries disagree on the type of the ioctl request (glibc uses `unsigned long`, musl
+//! and bionic use `int`) and a few architectures lay out the request codes differently than the
+//! generic Linux definition. Everything depending on the target lives in here, so the rest of
+//! the crate can be written against a single set of definitions.
+
+use std::os::raw::{c_int, c_void};
+
+/// Type of the ioctl request, as declared by the C library
+#[cfg(not(any(target_env = "musl", target_os = "android")))]
+#[allow(non_camel_case_types)]
+pub type _IOC_TYPE = std::os::raw::c_ulong;
+/// Type of the ioctl request, as declared by the C library
+#[cfg(any(target_env = "musl", target_os = "android"))]
+#[allow(non_camel_case_types)]
+pub type _IOC_TYPE = std::os::raw::c_int;
+
+// asm-generic/ioctl.h, overridden by the architectures below
+#[cfg(not(any(
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+)))]
+mod layout {
+    pub const _IOC_SIZEBITS: u8 = 14;
+
+    pub const _IOC_NONE: u8 = 0;
+    pub const _IOC_WRITE: u8 = 1;
+    pub const _IOC_READ: u8 = 2;
+}
+
+// arch/{powerpc,mips,sparc}/include/uapi/asm/ioctl.h: three direction bits
+#[cfg(any(
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+))]
+mod layout {
+    pub const _IOC_SIZEBITS: u8 = 13;
+
+    pub const _IOC_NONE: u8 = 1;
+    pub const _IOC_WRITE: u8 = 4;
+    pub const _IOC_READ: u8 = 2;
+}
+
+pub(crate) use layout::*;
+
+/// Issues an ioctl without going through the C library wrapper
+///
+/// `libc::ioctl` is declared with different, incompatible request types on different
+/// platforms (<https://github.com/rust-lang/libc/issues/1036>). The raw system call takes the
+/// request as a register sized value everywhere and the kernel only looks at its lower 32 bits,
+/// so requests of either type are passed zero extended.
+///
+/// # Safety
+///
+/// `argp` must point to valid memory with the layout expected for `request`.
+pub(crate) unsafe fn ioctl(fd: c_int, request: _IOC_TYPE, argp: *mut c_void) -> c_int {
+    libc::syscall(
+        libc::SYS_ioctl,
+        fd,
+        std::os::raw::c_ulong::from(request_bits(request)),
+        argp,
+    ) as c_int
+}
+
+/// Returns the 32 bits of a request code
+///
+/// Requests with the read direction bit set are negative where the request is an `int`, they
+/// must not be sign extended when passed on as an `unsigned long`.
+pub(crate) const fn request_bits(request: _IOC_TYPE) -> u32 {
+    request as u32
+}
@@ -0,0 +1,59 @@
+// The sub-device streams API (routing, client capabilities) was added in Linux 6.3 and changed
+// its layout in 6.8. We carry our own copies of the structs, so the crate builds against older
+// kernel headers as well. The layouts match Linux 6.8 and newer.
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub(crate) struct v4l2_subdev_route {
+    pub sink_pad: u32,
+    pub sink_stream: u32,
+    pub source_pad: u32,
+    pub source_stream: u32,
+    pub flags: u32,
+    pub reserved: [u32; 5],
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub(crate) struct v4l2_subdev_routing {
+    pub which: u32,
+    pub len_routes: u32,
+    pub routes: u64,
+    pub num_routes: u32,
+    pub reserved: [u32; 11],
+}
+
+pub(crate) const V4L2_SUBDEV_FORMAT_TRY: u32 = 0;
+pub(crate) const V4L2_SUBDEV_FORMAT_ACTIVE: u32 = 1;
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub(crate) struct v4l2_subdev_client_capability {
+    pub capabilities: u64,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub(crate) struct v4l2_mbus_framefmt {
+    pub width: u32,
+    pub height: u32,
+    pub code: u32,
+    pub field: u32,
+    pub colorspace: u32,
+    // union of ycbcr_enc and hsv_enc
+    pub ycbcr_enc: u16,
+    pub quantization: u16,
+    pub xfer_func: u16,
+    pub flags: u16,
+    pub reserved: [u16; 10],
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub(crate) struct v4l2_subdev_format {
+    pub which: u32,
+    pub pad: u32,
+    pub format: v4l2_mbus_framefmt,
+    pub stream: u32,
+    pub reserved: [u32; 7],
+}
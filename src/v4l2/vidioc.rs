@@ -1,27 +1,19 @@
+use crate::v4l2::media::{media_device_info, media_link_desc, media_v2_topology};
+use crate::v4l2::subdev::{v4l2_subdev_client_capability, v4l2_subdev_format, v4l2_subdev_routing};
+use crate::v4l2::platform::{_IOC_NONE, _IOC_READ, _IOC_SIZEBITS, _IOC_WRITE};
 use crate::v4l_sys::*;
 
-#[cfg(not(target_env = "musl"))]
-#[allow(non_camel_case_types)]
-pub type _IOC_TYPE = std::os::raw::c_ulong;
-#[cfg(target_env = "musl")]
-#[allow(non_camel_case_types)]
-pub type _IOC_TYPE = std::os::raw::c_int;
+pub use crate::v4l2::platform::_IOC_TYPE;
 
-// linux ioctl.h
+// linux ioctl.h, the direction and size bits depend on the architecture
 const _IOC_NRBITS: u8 = 8;
 const _IOC_TYPEBITS: u8 = 8;
 
-const _IOC_SIZEBITS: u8 = 14;
-
 const _IOC_NRSHIFT: u8 = 0;
 const _IOC_TYPESHIFT: u8 = _IOC_NRSHIFT + _IOC_NRBITS;
 const _IOC_SIZESHIFT: u8 = _IOC_TYPESHIFT + _IOC_TYPEBITS;
 const _IOC_DIRSHIFT: u8 = _IOC_SIZESHIFT + _IOC_SIZEBITS;
 
-const _IOC_NONE: u8 = 0;
-const _IOC_WRITE: u8 = 1;
-const _IOC_READ: u8 = 2;
-
 macro_rules! _IOC_TYPECHECK {
     ($type:ty) => {
         std::mem::size_of::<$type>()
@@ -76,12 +68,72 @@ macro_rules! _IOWR {
     };
 }
 
+/// Returns an ioctl request code which carries no argument (`_IO`)
+///
+/// # Arguments
+///
+/// * `typ` - Driver specific type, e.g. b'V' for V4L2
+/// * `nr` - Request number
+pub const fn io(typ: u8, nr: u8) -> _IOC_TYPE {
+    _IO!(typ, nr)
+}
+
+/// Returns an ioctl request code for reading a `T` from the driver (`_IOR`)
+///
+/// # Arguments
+///
+/// * `typ` - Driver specific type, e.g. b'V' for V4L2
+/// * `nr` - Request number
+///
+/// # Example
+///
+/// ```
+/// use v4l::v4l_sys::v4l2_capability;
+/// use v4l::vidioc;
+///
+/// assert_eq!(vidioc::ior::<v4l2_capability>(b'V', 0), vidioc::VIDIOC_QUERYCAP);
+/// ```
+pub const fn ior<T>(typ: u8, nr: u8) -> _IOC_TYPE {
+    _IOR!(typ, nr, T)
+}
+
+/// Returns an ioctl request code for writing a `T` to the driver (`_IOW`)
+///
+/// # Arguments
+///
+/// * `typ` - Driver specific type, e.g. b'V' for V4L2
+/// * `nr` - Request number
+pub const fn iow<T>(typ: u8, nr: u8) -> _IOC_TYPE {
+    _IOW!(typ, nr, T)
+}
+
+/// Returns an ioctl request code for exchanging a `T` with the driver (`_IOWR`)
+///
+/// # Arguments
+///
+/// * `typ` - Driver specific type, e.g. b'V' for V4L2
+/// * `nr` - Request number
+pub const fn iowr<T>(typ: u8, nr: u8) -> _IOC_TYPE {
+    _IOWR!(typ, nr, T)
+}
+
+/// Returns the size of the argument encoded in an ioctl request code
+///
+/// # Arguments
+///
+/// * `request` - IO control code
+pub const fn size(request: _IOC_TYPE) -> usize {
+    ((request >> _IOC_SIZESHIFT) & (((1 as _IOC_TYPE) << _IOC_SIZEBITS) - 1)) as usize
+}
+
 pub const VIDIOC_QUERYCAP: _IOC_TYPE = _IOR!(b'V', 0, v4l2_capability);
 pub const VIDIOC_RESERVED: _IOC_TYPE = _IO!(b'V', 1);
 pub const VIDIOC_ENUM_FMT: _IOC_TYPE = _IOWR!(b'V', 2, v4l2_fmtdesc);
 pub const VIDIOC_G_FMT: _IOC_TYPE = _IOWR!(b'V', 4, v4l2_format);
 pub const VIDIOC_S_FMT: _IOC_TYPE = _IOWR!(b'V', 5, v4l2_format);
 pub const VIDIOC_REQBUFS: _IOC_TYPE = _IOWR!(b'V', 8, v4l2_requestbuffers);
+// The argument size is part of the number, so the buffer and event ioctls select the time32 or
+// time64 variant depending on the time_t of the bindings, see crate::timestamp::TIME64.
 pub const VIDIOC_QUERYBUF: _IOC_TYPE = _IOWR!(b'V', 9, v4l2_buffer);
 pub const VIDIOC_G_FBUF: _IOC_TYPE = _IOR!(b'V', 10, v4l2_framebuffer);
 pub const VIDIOC_S_FBUF: _IOC_TYPE = _IOW!(b'V', 11, v4l2_framebuffer);
@@ -139,4 +191,126 @@ pub const VIDIOC_ENUM_FRAMEINTERVALS: _IOC_TYPE = _IOWR!(b'V', 75, v4l2_frmivale
 pub const VIDIOC_G_ENC_INDEX: _IOC_TYPE = _IOR!(b'V', 76, v4l2_enc_idx);
 pub const VIDIOC_ENCODER_CMD: _IOC_TYPE = _IOWR!(b'V', 77, v4l2_encoder_cmd);
 pub const VIDIOC_TRY_ENCODER_CMD: _IOC_TYPE = _IOWR!(b'V', 78, v4l2_encoder_cmd);
+pub const VIDIOC_S_HW_FREQ_SEEK: _IOC_TYPE = _IOW!(b'V', 82, v4l2_hw_freq_seek);
+pub const VIDIOC_DQEVENT: _IOC_TYPE = _IOR!(b'V', 89, v4l2_event);
+pub const VIDIOC_SUBSCRIBE_EVENT: _IOC_TYPE = _IOW!(b'V', 90, v4l2_event_subscription);
+pub const VIDIOC_UNSUBSCRIBE_EVENT: _IOC_TYPE = _IOW!(b'V', 91, v4l2_event_subscription);
+pub const VIDIOC_DECODER_CMD: _IOC_TYPE = _IOWR!(b'V', 96, v4l2_decoder_cmd);
+pub const VIDIOC_TRY_DECODER_CMD: _IOC_TYPE = _IOWR!(b'V', 97, v4l2_decoder_cmd);
 pub const VIDIOC_QUERY_EXT_CTRL: _IOC_TYPE = _IOWR!(b'V', 103, v4l2_query_ext_ctrl);
+
+// sub-device ioctls, some of them share their number with the video device ones
+pub const VIDIOC_SUBDEV_G_FMT: _IOC_TYPE = _IOWR!(b'V', 4, v4l2_subdev_format);
+pub const VIDIOC_SUBDEV_S_FMT: _IOC_TYPE = _IOWR!(b'V', 5, v4l2_subdev_format);
+pub const VIDIOC_SUBDEV_G_ROUTING: _IOC_TYPE = _IOWR!(b'V', 38, v4l2_subdev_routing);
+pub const VIDIOC_SUBDEV_S_ROUTING: _IOC_TYPE = _IOWR!(b'V', 39, v4l2_subdev_routing);
+pub const VIDIOC_SUBDEV_G_CLIENT_CAP: _IOC_TYPE = _IOR!(b'V', 101, v4l2_subdev_client_capability);
+pub const VIDIOC_SUBDEV_S_CLIENT_CAP: _IOC_TYPE = _IOWR!(b'V', 102, v4l2_subdev_client_capability);
+
+// media controller ioctls
+pub const MEDIA_IOC_DEVICE_INFO: _IOC_TYPE = _IOWR!(b'|', 0x00, media_device_info);
+pub const MEDIA_IOC_SETUP_LINK: _IOC_TYPE = _IOWR!(b'|', 0x03, media_link_desc);
+pub const MEDIA_IOC_G_TOPOLOGY: _IOC_TYPE = _IOWR!(b'|', 0x04, media_v2_topology);
+pub const MEDIA_IOC_REQUEST_ALLOC: _IOC_TYPE = _IOR!(b'|', 0x05, std::os::raw::c_int);
+pub const MEDIA_REQUEST_IOC_QUEUE: _IOC_TYPE = _IO!(b'|', 0x80);
+pub const MEDIA_REQUEST_IOC_REINIT: _IOC_TYPE = _IO!(b'|', 0x81);
+
+/// Returns the symbolic name of a well known ioctl request code
+///
+/// # Arguments
+///
+/// * `request` - IO control code
+///
+/// # Example
+///
+/// ```
+/// use v4l::vidioc;
+/// assert_eq!(vidioc::name(vidioc::VIDIOC_QUERYCAP), Some("VIDIOC_QUERYCAP"));
+/// ```
+pub fn name(request: _IOC_TYPE) -> Option<&'static str> {
+    match request {
+        VIDIOC_QUERYCAP => Some("VIDIOC_QUERYCAP"),
+        VIDIOC_RESERVED => Some("VIDIOC_RESERVED"),
+        VIDIOC_ENUM_FMT => Some("VIDIOC_ENUM_FMT"),
+        VIDIOC_G_FMT => Some("VIDIOC_G_FMT"),
+        VIDIOC_S_FMT => Some("VIDIOC_S_FMT"),
+        VIDIOC_REQBUFS => Some("VIDIOC_REQBUFS"),
+        VIDIOC_QUERYBUF => Some("VIDIOC_QUERYBUF"),
+        VIDIOC_G_FBUF => Some("VIDIOC_G_FBUF"),
+        VIDIOC_S_FBUF => Some("VIDIOC_S_FBUF"),
+        VIDIOC_OVERLAY => Some("VIDIOC_OVERLAY"),
+        VIDIOC_QBUF => Some("VIDIOC_QBUF"),
+        VIDIOC_EXPBUF => Some("VIDIOC_EXPBUF"),
+        VIDIOC_DQBUF => Some("VIDIOC_DQBUF"),
+        VIDIOC_STREAMON => Some("VIDIOC_STREAMON"),
+        VIDIOC_STREAMOFF => Some("VIDIOC_STREAMOFF"),
+        VIDIOC_G_PARM => Some("VIDIOC_G_PARM"),
+        VIDIOC_S_PARM => Some("VIDIOC_S_PARM"),
+        VIDIOC_G_STD => Some("VIDIOC_G_STD"),
+        VIDIOC_S_STD => Some("VIDIOC_S_STD"),
+        VIDIOC_ENUMSTD => Some("VIDIOC_ENUMSTD"),
+        VIDIOC_ENUMINPUT => Some("VIDIOC_ENUMINPUT"),
+        VIDIOC_G_CTRL => Some("VIDIOC_G_CTRL"),
+        VIDIOC_S_CTRL => Some("VIDIOC_S_CTRL"),
+        VIDIOC_G_TUNER => Some("VIDIOC_G_TUNER"),
+        VIDIOC_S_TUNER => Some("VIDIOC_S_TUNER"),
+        VIDIOC_G_AUDIO => Some("VIDIOC_G_AUDIO"),
+        VIDIOC_S_AUDIO => Some("VIDIOC_S_AUDIO"),
+        VIDIOC_QUERYCTRL => Some("VIDIOC_QUERYCTRL"),
+        VIDIOC_QUERYMENU => Some("VIDIOC_QUERYMENU"),
+        VIDIOC_G_INPUT => Some("VIDIOC_G_INPUT"),
+        VIDIOC_S_INPUT => Some("VIDIOC_S_INPUT"),
+        VIDIOC_G_EDID => Some("VIDIOC_G_EDID"),
+        VIDIOC_S_EDID => Some("VIDIOC_S_EDID"),
+        VIDIOC_G_OUTPUT => Some("VIDIOC_G_OUTPUT"),
+        VIDIOC_S_OUTPUT => Some("VIDIOC_S_OUTPUT"),
+        VIDIOC_ENUMOUTPUT => Some("VIDIOC_ENUMOUTPUT"),
+        VIDIOC_G_AUDOUT => Some("VIDIOC_G_AUDOUT"),
+        VIDIOC_S_AUDOUT => Some("VIDIOC_S_AUDOUT"),
+        VIDIOC_G_MODULATOR => Some("VIDIOC_G_MODULATOR"),
+        VIDIOC_S_MODULATOR => Some("VIDIOC_S_MODULATOR"),
+        VIDIOC_G_FREQUENCY => Some("VIDIOC_G_FREQUENCY"),
+        VIDIOC_S_FREQUENCY => Some("VIDIOC_S_FREQUENCY"),
+        VIDIOC_CROPCAP => Some("VIDIOC_CROPCAP"),
+        VIDIOC_G_CROP => Some("VIDIOC_G_CROP"),
+        VIDIOC_S_CROP => Some("VIDIOC_S_CROP"),
+        VIDIOC_G_JPEGCOMP => Some("VIDIOC_G_JPEGCOMP"),
+        VIDIOC_S_JPEGCOMP => Some("VIDIOC_S_JPEGCOMP"),
+        VIDIOC_QUERYSTD => Some("VIDIOC_QUERYSTD"),
+        VIDIOC_TRY_FMT => Some("VIDIOC_TRY_FMT"),
+        VIDIOC_ENUMAUDIO => Some("VIDIOC_ENUMAUDIO"),
+        VIDIOC_ENUMAUDOUT => Some("VIDIOC_ENUMAUDOUT"),
+        VIDIOC_G_PRIORITY => Some("VIDIOC_G_PRIORITY"),
+        VIDIOC_S_PRIORITY => Some("VIDIOC_S_PRIORITY"),
+        VIDIOC_G_SLICED_VBI_CAP => Some("VIDIOC_G_SLICED_VBI_CAP"),
+        VIDIOC_LOG_STATUS => Some("VIDIOC_LOG_STATUS"),
+        VIDIOC_G_EXT_CTRLS => Some("VIDIOC_G_EXT_CTRLS"),
+        VIDIOC_S_EXT_CTRLS => Some("VIDIOC_S_EXT_CTRLS"),
+        VIDIOC_TRY_EXT_CTRLS => Some("VIDIOC_TRY_EXT_CTRLS"),
+        VIDIOC_ENUM_FRAMESIZES => Some("VIDIOC_ENUM_FRAMESIZES"),
+        VIDIOC_ENUM_FRAMEINTERVALS => Some("VIDIOC_ENUM_FRAMEINTERVALS"),
+        VIDIOC_G_ENC_INDEX => Some("VIDIOC_G_ENC_INDEX"),
+        VIDIOC_ENCODER_CMD => Some("VIDIOC_ENCODER_CMD"),
+        VIDIOC_TRY_ENCODER_CMD => Some("VIDIOC_TRY_ENCODER_CMD"),
+        VIDIOC_S_HW_FREQ_SEEK => Some("VIDIOC_S_HW_FREQ_SEEK"),
+        VIDIOC_DQEVENT => Some("VIDIOC_DQEVENT"),
+        VIDIOC_SUBSCRIBE_EVENT => Some("VIDIOC_SUBSCRIBE_EVENT"),
+        VIDIOC_UNSUBSCRIBE_EVENT => Some("VIDIOC_UNSUBSCRIBE_EVENT"),
+        VIDIOC_DECODER_CMD => Some("VIDIOC_DECODER_CMD"),
+        VIDIOC_TRY_DECODER_CMD => Some("VIDIOC_TRY_DECODER_CMD"),
+        VIDIOC_QUERY_EXT_CTRL => Some("VIDIOC_QUERY_EXT_CTRL"),
+        VIDIOC_SUBDEV_G_FMT => Some("VIDIOC_SUBDEV_G_FMT"),
+        VIDIOC_SUBDEV_S_FMT => Some("VIDIOC_SUBDEV_S_FMT"),
+        VIDIOC_SUBDEV_G_ROUTING => Some("VIDIOC_SUBDEV_G_ROUTING"),
+        VIDIOC_SUBDEV_S_ROUTING => Some("VIDIOC_SUBDEV_S_ROUTING"),
+        VIDIOC_SUBDEV_G_CLIENT_CAP => Some("VIDIOC_SUBDEV_G_CLIENT_CAP"),
+        VIDIOC_SUBDEV_S_CLIENT_CAP => Some("VIDIOC_SUBDEV_S_CLIENT_CAP"),
+        MEDIA_IOC_DEVICE_INFO => Some("MEDIA_IOC_DEVICE_INFO"),
+        MEDIA_IOC_SETUP_LINK => Some("MEDIA_IOC_SETUP_LINK"),
+        MEDIA_IOC_G_TOPOLOGY => Some("MEDIA_IOC_G_TOPOLOGY"),
+        MEDIA_IOC_REQUEST_ALLOC => Some("MEDIA_IOC_REQUEST_ALLOC"),
+        MEDIA_REQUEST_IOC_QUEUE => Some("MEDIA_REQUEST_IOC_QUEUE"),
+        MEDIA_REQUEST_IOC_REINIT => Some("MEDIA_REQUEST_IOC_REINIT"),
+        _ => None,
+    }
+}
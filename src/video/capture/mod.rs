@@ -1,19 +1,16 @@
 pub mod parameters;
 pub use parameters::Parameters;
-pub mod mplane;
 
-use std::{io, mem, os::fd::AsRawFd};
+use std::io;
 
-use crate::buffer::Type;
-use crate::device::Device;
-use crate::format::{Description as FormatDescription, Format, FourCC};
+use crate::format::{Description as FormatDescription, FourCC};
 use crate::frameinterval::FrameInterval;
 use crate::framesize::FrameSize;
-use crate::v4l2;
-use crate::v4l_sys::*;
 use crate::video::traits::{Capture, Video, VideoBase};
 
-impl Capture for Device {
+impl<T: Video> Capture for T {
+    type Format = <T as Video>::Format;
+
     fn enum_frameintervals(
         &self,
         fourcc: FourCC,
@@ -28,50 +25,26 @@ impl Capture for Device {
     }
 
     fn enum_formats(&self) -> io::Result<Vec<FormatDescription>> {
-        <Self as VideoBase>::enum_formats(self, Type::VideoCapture)
+        <Self as VideoBase>::enum_formats(self, T::CAPTURE)
     }
 
-    fn format(&self) -> io::Result<Format> {
-        <Self as Video>::format(self, Type::VideoCapture)
+    fn enum_formats_for_mbus_code(&self, mbus_code: u32) -> io::Result<Vec<FormatDescription>> {
+        <Self as VideoBase>::enum_formats_for_mbus_code(self, T::CAPTURE, mbus_code)
     }
 
-    fn set_format(&self, fmt: &Format) -> io::Result<Format> {
-        <Self as Video>::set_format(self, Type::VideoCapture, fmt)
+    fn format(&self) -> io::Result<<T as Video>::Format> {
+        <Self as Video>::format(self, T::CAPTURE)
     }
 
-    type Format = Format;
+    fn set_format(&self, fmt: &<T as Video>::Format) -> io::Result<<T as Video>::Format> {
+        <Self as Video>::set_format(self, T::CAPTURE, fmt)
+    }
 
     fn params(&self) -> io::Result<Parameters> {
-        unsafe {
-            let mut v4l2_params = v4l2_streamparm {
-                type_: Type::VideoCapture as u32,
-                ..mem::zeroed()
-            };
-            v4l2::ioctl(
-                self.handle().as_raw_fd(),
-                v4l2::vidioc::VIDIOC_G_PARM,
-                &mut v4l2_params as *mut _ as *mut std::os::raw::c_void,
-            )?;
-
-            Ok(Parameters::from(v4l2_params.parm.capture))
-        }
+        <Self as Video>::stream_params(self, T::CAPTURE)
     }
 
     fn set_params(&self, params: &Parameters) -> io::Result<Parameters> {
-        unsafe {
-            let mut v4l2_params = v4l2_streamparm {
-                type_: Type::VideoCapture as u32,
-                parm: v4l2_streamparm__bindgen_ty_1 {
-                    capture: (*params).into(),
-                },
-            };
-            v4l2::ioctl(
-                self.handle().as_raw_fd(),
-                v4l2::vidioc::VIDIOC_S_PARM,
-                &mut v4l2_params as *mut _ as *mut std::os::raw::c_void,
-            )?;
-        }
-
-        self.params()
+        <Self as Video>::set_stream_params(self, T::CAPTURE, params)
     }
 }
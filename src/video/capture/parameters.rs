@@ -1,39 +1,20 @@
-use bitflags::bitflags;
 use std::{fmt, mem};
 
+use crate::buffer;
 use crate::fraction::Fraction;
 use crate::parameters::Capabilities;
+pub use crate::parameters::Modes;
 use crate::v4l_sys::*;
-
-bitflags! {
-    pub struct Modes: u32 {
-        const HIGH_QUALITY      = 0x1000;
-    }
-}
-
-impl From<u32> for Modes {
-    fn from(caps: u32) -> Self {
-        Self::from_bits_truncate(caps)
-    }
-}
-
-impl From<Modes> for u32 {
-    fn from(modes: Modes) -> Self {
-        modes.bits()
-    }
-}
-
-impl fmt::Display for Modes {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Debug::fmt(self, f)
-    }
-}
+use crate::video::traits::StreamParameters;
 
 #[derive(Debug, Copy, Clone)]
-/// Streaming parameters (single-planar)
+/// Streaming parameters of capture queues, single- and multi-planar
 pub struct Parameters {
     pub capabilities: Capabilities,
+    /// Some drivers need [`Modes::HIGH_QUALITY`] for still images
     pub modes: Modes,
+    /// Driver specific mode, zero unless the driver documents otherwise
+    pub extended_mode: u32,
     pub interval: Fraction,
 }
 
@@ -42,6 +23,7 @@ impl Parameters {
         Parameters {
             capabilities: Capabilities::from(0),
             modes: Modes::from(0),
+            extended_mode: 0,
             interval: frac,
         }
     }
@@ -50,6 +32,7 @@ impl Parameters {
         Parameters {
             capabilities: Capabilities::from(0),
             modes: Modes::from(0),
+            extended_mode: 0,
             interval: Fraction::new(1, fps),
         }
     }
@@ -59,6 +42,7 @@ impl fmt::Display for Parameters {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "capabilities : {}", self.capabilities)?;
         writeln!(f, "modes        : {}", self.modes)?;
+        writeln!(f, "ext. mode    : {:#x}", self.extended_mode)?;
         writeln!(f, "interval     : {} [s]", self.interval)?;
         Ok(())
     }
@@ -69,6 +53,7 @@ impl From<v4l2_captureparm> for Parameters {
         Self {
             capabilities: Capabilities::from(params.capability),
             modes: Modes::from(params.capturemode),
+            extended_mode: params.extendedmode,
             interval: Fraction::from(params.timeperframe),
         }
     }
@@ -79,8 +64,24 @@ impl From<Parameters> for v4l2_captureparm {
         Self {
             capability: parameters.capabilities.into(),
             capturemode: parameters.modes.into(),
+            extendedmode: parameters.extended_mode,
             timeperframe: parameters.interval.into(),
             ..unsafe { mem::zeroed() }
         }
     }
 }
+
+impl StreamParameters for Parameters {
+    fn from_streamparm(params: &v4l2_streamparm) -> Self {
+        Parameters::from(unsafe { params.parm.capture })
+    }
+
+    fn to_streamparm(&self, typ: buffer::Type) -> v4l2_streamparm {
+        v4l2_streamparm {
+            type_: typ as u32,
+            parm: v4l2_streamparm__bindgen_ty_1 {
+                capture: (*self).into(),
+            },
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::{convert::TryFrom, io, mem, os::fd::AsRawFd};
+use std::{convert::TryFrom, io, mem};
 
 use crate::v4l_sys::*;
 use crate::{
@@ -9,8 +9,10 @@ use crate::{
 pub mod traits;
 
 pub mod capture;
+pub mod node;
 pub mod output;
 
+pub use node::{CaptureNode, Node, OutputNode};
 pub use traits::{Capture, Output};
 use crate::device::{MultiPlaneDevice, PlanarDevice};
 use crate::format::MultiPlaneFormat;
@@ -33,8 +35,7 @@ impl<const M: bool> traits::VideoBase for PlanarDevice<M> {
 
         loop {
             let ret = unsafe {
-                v4l2::ioctl(
-                    self.handle().as_raw_fd(),
+                self.handle().ioctl(
                     v4l2::vidioc::VIDIOC_ENUM_FRAMEINTERVALS,
                     &mut v4l2_struct as *mut _ as *mut std::os::raw::c_void,
                 )
@@ -66,8 +67,7 @@ impl<const M: bool> traits::VideoBase for PlanarDevice<M> {
 
         loop {
             let ret = unsafe {
-                v4l2::ioctl(
-                    self.handle().as_raw_fd(),
+                self.handle().ioctl(
                     v4l2::vidioc::VIDIOC_ENUM_FRAMESIZES,
                     &mut v4l2_struct as *mut _ as *mut std::os::raw::c_void,
                 )
@@ -90,61 +90,102 @@ impl<const M: bool> traits::VideoBase for PlanarDevice<M> {
     }
 
     fn enum_formats(&self, typ: buffer::Type) -> io::Result<Vec<FormatDescription>> {
-        let mut formats: Vec<FormatDescription> = Vec::new();
-        let mut v4l2_fmt = v4l2_fmtdesc {
-            index: 0,
-            type_: typ as u32,
-            ..unsafe { mem::zeroed() }
-        };
+        enum_formats(self, typ, 0)
+    }
 
-        let mut ret: io::Result<()>;
+    fn enum_formats_for_mbus_code(
+        &self,
+        typ: buffer::Type,
+        mbus_code: u32,
+    ) -> io::Result<Vec<FormatDescription>> {
+        enum_formats(self, typ, mbus_code)
+    }
 
+    fn streamparm(&self, typ: buffer::Type) -> io::Result<v4l2_streamparm> {
         unsafe {
-            ret = v4l2::ioctl(
-                self.handle().as_raw_fd(),
-                v4l2::vidioc::VIDIOC_ENUM_FMT,
-                &mut v4l2_fmt as *mut _ as *mut std::os::raw::c_void,
-            );
+            let mut v4l2_params = v4l2_streamparm {
+                type_: typ as u32,
+                ..mem::zeroed()
+            };
+            self.handle().ioctl(
+                v4l2::vidioc::VIDIOC_G_PARM,
+                &mut v4l2_params as *mut _ as *mut std::os::raw::c_void,
+            )?;
+
+            Ok(v4l2_params)
         }
+    }
 
-        if ret.is_err() {
-            // Enumerating the first format (at index 0) failed, so there are no formats available
-            // for this device. Just return an empty vec in this case.
-            return Ok(Vec::new());
+    fn set_streamparm(&self, params: &mut v4l2_streamparm) -> io::Result<()> {
+        unsafe {
+            self.handle().ioctl(
+                v4l2::vidioc::VIDIOC_S_PARM,
+                params as *mut _ as *mut std::os::raw::c_void,
+            )
         }
+    }
+}
+
+fn enum_formats<const M: bool>(
+    dev: &PlanarDevice<M>,
+    typ: buffer::Type,
+    mbus_code: u32,
+) -> io::Result<Vec<FormatDescription>> {
+    let mut formats: Vec<FormatDescription> = Vec::new();
+    let mut v4l2_fmt = v4l2_fmtdesc {
+        index: 0,
+        type_: typ as u32,
+        mbus_code,
+        ..unsafe { mem::zeroed() }
+    };
+
+    let mut ret: io::Result<()>;
+
+    unsafe {
+        ret = dev.handle().ioctl(
+            v4l2::vidioc::VIDIOC_ENUM_FMT,
+            &mut v4l2_fmt as *mut _ as *mut std::os::raw::c_void,
+        );
+    }
 
-        while ret.is_ok() {
-            formats.push(FormatDescription::from(v4l2_fmt));
-            v4l2_fmt.index += 1;
+    if ret.is_err() {
+        // Enumerating the first format (at index 0) failed, so there are no formats available
+        // for this device. Just return an empty vec in this case.
+        return Ok(Vec::new());
+    }
 
-            unsafe {
-                v4l2_fmt.description = mem::zeroed();
-            }
+    while ret.is_ok() {
+        formats.push(FormatDescription::from(v4l2_fmt));
+        v4l2_fmt.index += 1;
 
-            unsafe {
-                ret = v4l2::ioctl(
-                    self.handle().as_raw_fd(),
-                    v4l2::vidioc::VIDIOC_ENUM_FMT,
-                    &mut v4l2_fmt as *mut _ as *mut std::os::raw::c_void,
-                );
-            }
+        unsafe {
+            v4l2_fmt.description = mem::zeroed();
         }
 
-        Ok(formats)
+        unsafe {
+            ret = dev.handle().ioctl(
+                v4l2::vidioc::VIDIOC_ENUM_FMT,
+                &mut v4l2_fmt as *mut _ as *mut std::os::raw::c_void,
+            );
+        }
     }
+
+    Ok(formats)
 }
 
 impl traits::Video for Device {
     type Format = Format;
 
+    const CAPTURE: buffer::Type = buffer::Type::VideoCapture;
+    const OUTPUT: buffer::Type = buffer::Type::VideoOutput;
+
     fn format(&self, typ: buffer::Type) -> io::Result<Format> {
         unsafe {
             let mut v4l2_fmt = v4l2_format {
                 type_: typ as u32,
                 ..mem::zeroed()
             };
-            v4l2::ioctl(
-                self.handle().as_raw_fd(),
+            self.handle().ioctl(
                 v4l2::vidioc::VIDIOC_G_FMT,
                 &mut v4l2_fmt as *mut _ as *mut std::os::raw::c_void,
             )?;
@@ -159,14 +200,14 @@ impl traits::Video for Device {
                 type_: typ as u32,
                 fmt: v4l2_format__bindgen_ty_1 { pix: (*fmt).into() },
             };
-            v4l2::ioctl(
-                self.handle().as_raw_fd(),
+            self.handle().ioctl(
                 v4l2::vidioc::VIDIOC_S_FMT,
                 &mut v4l2_fmt as *mut _ as *mut std::os::raw::c_void,
             )?;
-        }
 
-        <Self as traits::Video>::format(self, typ)
+            // the driver writes the format it actually applied back into the struct
+            Ok(Format::from(v4l2_fmt.fmt.pix))
+        }
     }
 }
 
@@ -174,14 +215,16 @@ impl traits::Video for Device {
 impl traits::Video for MultiPlaneDevice {
     type Format = MultiPlaneFormat;
 
+    const CAPTURE: buffer::Type = buffer::Type::VideoCaptureMplane;
+    const OUTPUT: buffer::Type = buffer::Type::VideoOutputMplane;
+
     fn format(&self, typ: buffer::Type) -> io::Result<MultiPlaneFormat> {
         unsafe {
             let mut v4l2_fmt = v4l2_format {
                 type_: typ as u32,
                 ..mem::zeroed()
             };
-            v4l2::ioctl(
-                self.handle().as_raw_fd(),
+            self.handle().ioctl(
                 v4l2::vidioc::VIDIOC_G_FMT,
                 &mut v4l2_fmt as *mut _ as *mut std::os::raw::c_void,
             )?;
@@ -196,13 +239,13 @@ impl traits::Video for MultiPlaneDevice {
                 type_: typ as u32,
                 fmt: v4l2_format__bindgen_ty_1 { pix_mp: fmt.clone().into() },
             };
-            v4l2::ioctl(
-                self.handle().as_raw_fd(),
+            self.handle().ioctl(
                 v4l2::vidioc::VIDIOC_S_FMT,
                 &mut v4l2_fmt as *mut _ as *mut std::os::raw::c_void,
             )?;
-        }
 
-        <Self as traits::Video>::format(self, typ)
+            // the driver writes the format it actually applied back into the struct
+            Ok(MultiPlaneFormat::from(v4l2_fmt.fmt.pix_mp))
+        }
     }
 }
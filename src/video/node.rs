@@ -0,0 +1,332 @@
+use std::{io, marker::PhantomData, sync::Arc};
+
+use crate::buffer::{Metadata, Type};
+use crate::capability::Flags;
+use crate::device::{Handle, PlanarDevice, VideoDevice};
+use crate::format::{Description as FormatDescription, FourCC};
+use crate::frameinterval::FrameInterval;
+use crate::framesize::FrameSize;
+use crate::io::mmap;
+use crate::io::traits::{CaptureStream, OutputStream, Stream};
+use crate::media::Request;
+use crate::v4l_sys::*;
+use crate::video::capture::Parameters as CaptureParameters;
+use crate::video::output::Parameters as OutputParameters;
+use crate::video::traits;
+
+/// Direction markers
+pub mod direction {
+    /// Frames flow from the device to the application
+    pub struct Capture;
+
+    /// Frames flow from the application to the device
+    pub struct Output;
+}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::direction::Capture {}
+    impl Sealed for super::direction::Output {}
+}
+
+/// Direction of a video node
+pub trait Direction: sealed::Sealed {
+    /// Returns the device capabilities of which at least one has to be present
+    fn caps() -> Flags;
+
+    /// Returns the buffer type of the direction
+    ///
+    /// # Arguments
+    ///
+    /// * `mplane` - Whether the device uses the multi-planar API
+    fn buf_type(mplane: bool) -> Type;
+}
+
+impl Direction for direction::Capture {
+    fn caps() -> Flags {
+        Flags::VIDEO_CAPTURE
+            | Flags::VIDEO_CAPTURE_MPLANE
+            | Flags::VIDEO_M2M
+            | Flags::VIDEO_M2M_MPLANE
+    }
+
+    fn buf_type(mplane: bool) -> Type {
+        if mplane {
+            Type::VideoCaptureMplane
+        } else {
+            Type::VideoCapture
+        }
+    }
+}
+
+impl Direction for direction::Output {
+    fn caps() -> Flags {
+        Flags::VIDEO_OUTPUT
+            | Flags::VIDEO_OUTPUT_MPLANE
+            | Flags::VIDEO_M2M
+            | Flags::VIDEO_M2M_MPLANE
+    }
+
+    fn buf_type(mplane: bool) -> Type {
+        if mplane {
+            Type::VideoOutputMplane
+        } else {
+            Type::VideoOutput
+        }
+    }
+}
+
+/// Video device restricted to one direction
+///
+/// [`Device`](crate::Device) implements both the [`Capture`](traits::Capture) and the
+/// [`Output`](traits::Output) protocol, although most nodes only support one of them. Calling
+/// the wrong one fails with EINVAL at runtime. A node only implements the protocol of its
+/// direction, so such mistakes are caught by the compiler instead. Streams created from a node
+/// are restricted the same way.
+///
+/// # Example
+///
+/// ```
+/// use v4l::device::Device;
+/// use v4l::video::{Capture, CaptureNode};
+///
+/// if let Ok(dev) = Device::new(0) {
+///     if let Ok(node) = CaptureNode::new(dev) {
+///         let fmt = node.format().unwrap();
+///         println!("{}", fmt);
+///     }
+/// }
+/// ```
+pub struct Node<D, Dir> {
+    dev: D,
+    _marker: PhantomData<Dir>,
+}
+
+/// Node of a capture device
+pub type CaptureNode<D> = Node<D, direction::Capture>;
+/// Node of an output device
+pub type OutputNode<D> = Node<D, direction::Output>;
+
+impl<const M: bool, Dir: Direction> Node<PlanarDevice<M>, Dir> {
+    /// Returns a node restricted to the direction
+    ///
+    /// Fails if the device capabilities do not include the direction.
+    ///
+    /// # Arguments
+    ///
+    /// * `dev` - Video device
+    pub fn new(dev: PlanarDevice<M>) -> io::Result<Self> {
+        if !dev.query_caps()?.capabilities.intersects(Dir::caps()) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "device does not support the direction",
+            ));
+        }
+
+        Ok(Node {
+            dev,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns the buffer type used by the node
+    pub fn buf_type(&self) -> Type {
+        Dir::buf_type(M)
+    }
+
+    /// Returns the underlying device, e.g. to access its controls
+    pub fn device(&self) -> &PlanarDevice<M> {
+        &self.dev
+    }
+
+    /// Returns the underlying device, giving up the restriction
+    pub fn into_inner(self) -> PlanarDevice<M> {
+        self.dev
+    }
+
+    /// Returns a stream of mapped buffers in the direction of the node
+    ///
+    /// # Arguments
+    ///
+    /// * `buf_count` - Number of buffers to request
+    pub fn mmap_stream<'a>(&self, buf_count: u32) -> io::Result<Directed<mmap::Stream<'a>, Dir>> {
+        let stream = mmap::Stream::with_buffers(&self.dev, self.buf_type(), buf_count)?;
+        Ok(Directed {
+            stream,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<D: VideoDevice, Dir> VideoDevice for Node<D, Dir> {
+    fn handle(&self) -> Arc<Handle> {
+        self.dev.handle()
+    }
+}
+
+impl<D: traits::Capture> traits::Capture for Node<D, direction::Capture> {
+    type Format = D::Format;
+
+    fn enum_frameintervals(
+        &self,
+        fourcc: FourCC,
+        width: u32,
+        height: u32,
+    ) -> io::Result<Vec<FrameInterval>> {
+        self.dev.enum_frameintervals(fourcc, width, height)
+    }
+
+    fn enum_framesizes(&self, fourcc: FourCC) -> io::Result<Vec<FrameSize>> {
+        self.dev.enum_framesizes(fourcc)
+    }
+
+    fn enum_formats(&self) -> io::Result<Vec<FormatDescription>> {
+        self.dev.enum_formats()
+    }
+
+    fn enum_formats_for_mbus_code(&self, mbus_code: u32) -> io::Result<Vec<FormatDescription>> {
+        self.dev.enum_formats_for_mbus_code(mbus_code)
+    }
+
+    fn format(&self) -> io::Result<Self::Format> {
+        self.dev.format()
+    }
+
+    fn set_format(&self, fmt: &Self::Format) -> io::Result<Self::Format> {
+        self.dev.set_format(fmt)
+    }
+
+    fn params(&self) -> io::Result<CaptureParameters> {
+        self.dev.params()
+    }
+
+    fn set_params(&self, params: &CaptureParameters) -> io::Result<CaptureParameters> {
+        self.dev.set_params(params)
+    }
+}
+
+impl<D: traits::Output> traits::Output for Node<D, direction::Output> {
+    type Format = D::Format;
+
+    fn enum_frameintervals(
+        &self,
+        fourcc: FourCC,
+        width: u32,
+        height: u32,
+    ) -> io::Result<Vec<FrameInterval>> {
+        self.dev.enum_frameintervals(fourcc, width, height)
+    }
+
+    fn enum_framesizes(&self, fourcc: FourCC) -> io::Result<Vec<FrameSize>> {
+        self.dev.enum_framesizes(fourcc)
+    }
+
+    fn enum_formats(&self) -> io::Result<Vec<FormatDescription>> {
+        self.dev.enum_formats()
+    }
+
+    fn enum_formats_for_mbus_code(&self, mbus_code: u32) -> io::Result<Vec<FormatDescription>> {
+        self.dev.enum_formats_for_mbus_code(mbus_code)
+    }
+
+    fn format(&self) -> io::Result<Self::Format> {
+        self.dev.format()
+    }
+
+    fn set_format(&self, fmt: &Self::Format) -> io::Result<Self::Format> {
+        self.dev.set_format(fmt)
+    }
+
+    fn params(&self) -> io::Result<OutputParameters> {
+        self.dev.params()
+    }
+
+    fn set_params(&self, params: &OutputParameters) -> io::Result<OutputParameters> {
+        self.dev.set_params(params)
+    }
+}
+
+/// Stream restricted to the direction of the node it was created from
+pub struct Directed<S, Dir> {
+    stream: S,
+    _marker: PhantomData<Dir>,
+}
+
+impl<S, Dir> Directed<S, Dir> {
+    /// Returns the underlying stream, giving up the restriction
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+}
+
+impl<S: Stream, Dir> Stream for Directed<S, Dir> {
+    type Item = S::Item;
+
+    fn start(&mut self) -> io::Result<()> {
+        self.stream.start()
+    }
+
+    fn stop(&mut self) -> io::Result<()> {
+        self.stream.stop()
+    }
+
+    fn is_active(&self) -> bool {
+        self.stream.is_active()
+    }
+}
+
+impl<'a, S: CaptureStream<'a>> CaptureStream<'a> for Directed<S, direction::Capture> {
+    fn poll(&self) -> io::Result<bool> {
+        self.stream.poll()
+    }
+
+    fn queue(&mut self, index: usize) -> io::Result<()> {
+        self.stream.queue(index)
+    }
+
+    fn queue_request(&mut self, index: usize, request: Option<&Request>) -> io::Result<()> {
+        self.stream.queue_request(index, request)
+    }
+
+    fn dequeue(&mut self) -> io::Result<usize> {
+        self.stream.dequeue()
+    }
+
+    fn get(&self, index: usize) -> io::Result<(&Self::Item, &Metadata, &[v4l2_plane])> {
+        self.stream.get(index)
+    }
+
+    fn next(&'a mut self) -> io::Result<(&'a Self::Item, &'a Metadata, &'a [v4l2_plane])> {
+        self.stream.next()
+    }
+}
+
+impl<'a, S: OutputStream<'a>> OutputStream<'a> for Directed<S, direction::Output> {
+    fn poll(&self) -> io::Result<bool> {
+        self.stream.poll()
+    }
+
+    fn queue(&mut self, index: usize) -> io::Result<()> {
+        self.stream.queue(index)
+    }
+
+    fn queue_request(&mut self, index: usize, request: Option<&Request>) -> io::Result<()> {
+        self.stream.queue_request(index, request)
+    }
+
+    fn dequeue(&mut self) -> io::Result<usize> {
+        self.stream.dequeue()
+    }
+
+    fn get(
+        &mut self,
+        index: usize,
+    ) -> io::Result<(&mut Self::Item, &mut Metadata, &mut [v4l2_plane])> {
+        self.stream.get(index)
+    }
+
+    fn next(&'a mut self) -> io::Result<(&'a mut Self::Item, &'a mut Metadata)> {
+        self.stream.next()
+    }
+}
@@ -1,19 +1,16 @@
 pub mod parameters;
-pub mod mplane;
 pub use parameters::Parameters;
 
-use std::{io, mem, os::fd::AsRawFd};
+use std::io;
 
-use crate::buffer::Type;
-use crate::device::Device;
-use crate::format::{Description as FormatDescription, Format, FourCC};
+use crate::format::{Description as FormatDescription, FourCC};
 use crate::frameinterval::FrameInterval;
 use crate::framesize::FrameSize;
-use crate::v4l2;
-use crate::v4l_sys::*;
 use crate::video::traits::{Output, Video, VideoBase};
 
-impl Output for Device {
+impl<T: Video> Output for T {
+    type Format = <T as Video>::Format;
+
     fn enum_frameintervals(
         &self,
         fourcc: FourCC,
@@ -28,50 +25,26 @@ impl Output for Device {
     }
 
     fn enum_formats(&self) -> io::Result<Vec<FormatDescription>> {
-        <Self as VideoBase>::enum_formats(self, Type::VideoOutput)
+        <Self as VideoBase>::enum_formats(self, T::OUTPUT)
     }
 
-    fn format(&self) -> io::Result<Format> {
-        <Self as Video>::format(self, Type::VideoOutput)
+    fn enum_formats_for_mbus_code(&self, mbus_code: u32) -> io::Result<Vec<FormatDescription>> {
+        <Self as VideoBase>::enum_formats_for_mbus_code(self, T::OUTPUT, mbus_code)
     }
 
-    fn set_format(&self, fmt: &Format) -> io::Result<Format> {
-        <Self as Video>::set_format(self, Type::VideoOutput, fmt)
+    fn format(&self) -> io::Result<<T as Video>::Format> {
+        <Self as Video>::format(self, T::OUTPUT)
     }
 
-    type Format = Format;
+    fn set_format(&self, fmt: &<T as Video>::Format) -> io::Result<<T as Video>::Format> {
+        <Self as Video>::set_format(self, T::OUTPUT, fmt)
+    }
 
     fn params(&self) -> io::Result<Parameters> {
-        unsafe {
-            let mut v4l2_params = v4l2_streamparm {
-                type_: Type::VideoOutput as u32,
-                ..mem::zeroed()
-            };
-            v4l2::ioctl(
-                self.handle().as_raw_fd(),
-                v4l2::vidioc::VIDIOC_G_PARM,
-                &mut v4l2_params as *mut _ as *mut std::os::raw::c_void,
-            )?;
-
-            Ok(Parameters::from(v4l2_params.parm.output))
-        }
+        <Self as Video>::stream_params(self, T::OUTPUT)
     }
 
     fn set_params(&self, params: &Parameters) -> io::Result<Parameters> {
-        unsafe {
-            let mut v4l2_params = v4l2_streamparm {
-                type_: Type::VideoOutput as u32,
-                parm: v4l2_streamparm__bindgen_ty_1 {
-                    output: (*params).into(),
-                },
-            };
-            v4l2::ioctl(
-                self.handle().as_raw_fd(),
-                v4l2::vidioc::VIDIOC_S_PARM,
-                &mut v4l2_params as *mut _ as *mut std::os::raw::c_void,
-            )?;
-        }
-
-        self.params()
+        <Self as Video>::set_stream_params(self, T::OUTPUT, params)
     }
 }
@@ -1,13 +1,19 @@
 use std::{fmt, mem};
 
+use crate::buffer;
 use crate::fraction::Fraction;
-use crate::parameters::Capabilities;
+use crate::parameters::{Capabilities, Modes};
 use crate::v4l_sys::*;
+use crate::video::traits::StreamParameters;
 
 #[derive(Debug, Copy, Clone)]
-/// Output parameters (single-planar)
+/// Streaming parameters of output queues, single- and multi-planar
 pub struct Parameters {
     pub capabilities: Capabilities,
+    /// Some drivers need [`Modes::HIGH_QUALITY`] for still images
+    pub modes: Modes,
+    /// Driver specific mode, zero unless the driver documents otherwise
+    pub extended_mode: u32,
     pub interval: Fraction,
 }
 
@@ -15,6 +21,8 @@ impl Parameters {
     pub fn new(frac: Fraction) -> Self {
         Parameters {
             capabilities: Capabilities::from(0),
+            modes: Modes::from(0),
+            extended_mode: 0,
             interval: frac,
         }
     }
@@ -22,6 +30,8 @@ impl Parameters {
     pub fn with_fps(fps: u32) -> Self {
         Parameters {
             capabilities: Capabilities::from(0),
+            modes: Modes::from(0),
+            extended_mode: 0,
             interval: Fraction::new(1, fps),
         }
     }
@@ -30,6 +40,8 @@ impl Parameters {
 impl fmt::Display for Parameters {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "capabilities : {}", self.capabilities)?;
+        writeln!(f, "modes        : {}", self.modes)?;
+        writeln!(f, "ext. mode    : {:#x}", self.extended_mode)?;
         writeln!(f, "interval     : {} [s]", self.interval)?;
         Ok(())
     }
@@ -39,6 +51,8 @@ impl From<v4l2_outputparm> for Parameters {
     fn from(params: v4l2_outputparm) -> Self {
         Self {
             capabilities: Capabilities::from(params.capability),
+            modes: Modes::from(params.outputmode),
+            extended_mode: params.extendedmode,
             interval: Fraction::from(params.timeperframe),
         }
     }
@@ -48,8 +62,25 @@ impl From<Parameters> for v4l2_outputparm {
     fn from(parameters: Parameters) -> Self {
         Self {
             capability: parameters.capabilities.into(),
+            outputmode: parameters.modes.into(),
+            extendedmode: parameters.extended_mode,
             timeperframe: parameters.interval.into(),
             ..unsafe { mem::zeroed() }
         }
     }
 }
+
+impl StreamParameters for Parameters {
+    fn from_streamparm(params: &v4l2_streamparm) -> Self {
+        Parameters::from(unsafe { params.parm.output })
+    }
+
+    fn to_streamparm(&self, typ: buffer::Type) -> v4l2_streamparm {
+        v4l2_streamparm {
+            type_: typ as u32,
+            parm: v4l2_streamparm__bindgen_ty_1 {
+                output: (*self).into(),
+            },
+        }
+    }
+}
@@ -3,11 +3,14 @@ use std::io;
 use crate::video::capture::Parameters as CaptureParameters;
 use crate::video::output::Parameters as OutputParameters;
 use crate::{
-    buffer, format::Description as FormatDescription, format::FourCC,
-    frameinterval::FrameInterval, framesize::FrameSize,
+    format::Description as FormatDescription, format::FourCC, frameinterval::FrameInterval,
+    framesize::FrameSize,
 };
 
 /// Capture device protocol
+///
+/// The trait is implemented for all device kinds and is object safe, so applications can hold
+/// devices chosen at runtime as `Box<dyn Capture<Format = Format>>`.
 pub trait Capture {
     type Format;
     /// Returns a vector of all frame intervals that the device supports for the given pixel format
@@ -28,6 +31,17 @@ pub trait Capture {
     /// There may be a conversion related performance penalty when using them.
     fn enum_formats(&self) -> io::Result<Vec<FormatDescription>>;
 
+    /// Returns a vector of valid formats which can be used with the given media bus code
+    ///
+    /// Only devices advertising the `IO_MC` capability filter the formats, e.g. ISP nodes whose
+    /// pixel formats depend on the sensor mode. Other devices ignore the code and return all
+    /// formats.
+    ///
+    /// # Arguments
+    ///
+    /// * `mbus_code` - Media bus code of the connected subdevice pad (0: no filtering)
+    fn enum_formats_for_mbus_code(&self, mbus_code: u32) -> io::Result<Vec<FormatDescription>>;
+
     /// Returns the format currently in use
     fn format(&self) -> io::Result<Self::Format>;
 
@@ -48,6 +62,9 @@ pub trait Capture {
 
     /// Modifies the capture parameters and returns the actual parameters
     ///
+    /// The frame interval is only honored if the driver reports the
+    /// [`crate::parameters::Capabilities::TIME_PER_FRAME`] capability.
+    ///
     /// # Arguments
     ///
     /// * `params` - Desired parameters
@@ -55,6 +72,8 @@ pub trait Capture {
 }
 
 /// Output device protocol
+///
+/// Like [`Capture`], the trait is implemented for all device kinds and is object safe.
 pub trait Output {
     type Format;
     /// Returns a vector of all frame intervals that the device supports for the given pixel format
@@ -75,6 +94,17 @@ pub trait Output {
     /// There may be a conversion related performance penalty when using them.
     fn enum_formats(&self) -> io::Result<Vec<FormatDescription>>;
 
+    /// Returns a vector of valid formats which can be used with the given media bus code
+    ///
+    /// Only devices advertising the `IO_MC` capability filter the formats, e.g. ISP nodes whose
+    /// pixel formats depend on the sensor mode. Other devices ignore the code and return all
+    /// formats.
+    ///
+    /// # Arguments
+    ///
+    /// * `mbus_code` - Media bus code of the connected subdevice pad (0: no filtering)
+    fn enum_formats_for_mbus_code(&self, mbus_code: u32) -> io::Result<Vec<FormatDescription>>;
+
     /// Returns the format currently in use
     fn format(&self) -> io::Result<Self::Format>;
 
@@ -95,55 +125,129 @@ pub trait Output {
 
     /// Modifies the output parameters and returns the actual parameters
     ///
+    /// The frame interval is only honored if the driver reports the
+    /// [`crate::parameters::Capabilities::TIME_PER_FRAME`] capability.
+    ///
     /// # Arguments
     ///
     /// * `params` - Desired parameters
     fn set_params(&self, params: &OutputParameters) -> io::Result<OutputParameters>;
 }
 
-/// Shared video device protocol
-///
-/// This trait exists so we can reuse it in the Capture and Output traits. We want to reuse the
-/// code for methods such as `enum_formats`, but need to specifiy the buffer type `buf_type` each
-/// time. Since we already know the value of `buf_type` at compile time, it makes no sense to
-/// place the burden of specifying it on the user.
-///
-/// Hint: the value is known at compile time because we encode the information in the traits
-/// themselves, i.e. `Capture` implies buffer::Type::Capture, etc.
-pub(crate) trait VideoBase {
-    /// Returns a vector of all frame intervals that the device supports for the given pixel format
-    /// and frame size
-    fn enum_frameintervals(
-        &self,
-        fourcc: FourCC,
-        width: u32,
-        height: u32,
-    ) -> io::Result<Vec<FrameInterval>>;
-
-    /// Returns a vector of valid framesizes that the device supports for the given pixel format
-    fn enum_framesizes(&self, fourcc: FourCC) -> io::Result<Vec<FrameSize>>;
-
-    /// Returns a vector of valid formats for this device
-    ///
-    /// The "emulated" field describes formats filled in by libv4lconvert.
-    /// There may be a conversion related performance penalty when using them.
-    fn enum_formats(&self, typ: buffer::Type) -> io::Result<Vec<FormatDescription>>;
-}
-
-pub(crate) trait Video : VideoBase {
-    type Format;
-    /// Returns the format currently in use
-    fn format(&self, typ: buffer::Type) -> io::Result<Self::Format>;
-
-    /// Modifies the capture format and returns the actual format
-    ///
-    /// The driver tries to match the format parameters on a best effort basis.
-    /// Thus, if the combination of format properties cannot be achieved, the closest possible
-    /// settings are used and reported back.
-    ///
-    ///
-    /// # Arguments
-    ///
-    /// * `fmt` - Desired format
-    fn set_format(&self, typ: buffer::Type, fmt: &Self::Format) -> io::Result<Self::Format>;
+pub(crate) use sealed::{StreamParameters, Video, VideoBase};
+
+mod sealed {
+    use std::io;
+
+    use crate::v4l_sys::v4l2_streamparm;
+    use crate::{
+        buffer, format::Description as FormatDescription, format::FourCC,
+        frameinterval::FrameInterval, framesize::FrameSize,
+    };
+
+    /// Shared video device protocol
+    ///
+    /// This trait exists so we can reuse it in the Capture and Output traits. We want to reuse
+    /// the code for methods such as `enum_formats`, but need to specifiy the buffer type
+    /// `buf_type` each time. Since we already know the value of `buf_type` at compile time, it
+    /// makes no sense to place the burden of specifying it on the user.
+    ///
+    /// Hint: the value is known at compile time because we encode the information in the traits
+    /// themselves, i.e. `Capture` implies buffer::Type::Capture, etc.
+    ///
+    /// The trait is sealed: it is public so it may bound the blanket implementations of the
+    /// Capture and Output traits, but cannot be named outside of this crate.
+    pub trait VideoBase {
+        /// Returns a vector of all frame intervals that the device supports for the given pixel
+        /// format and frame size
+        fn enum_frameintervals(
+            &self,
+            fourcc: FourCC,
+            width: u32,
+            height: u32,
+        ) -> io::Result<Vec<FrameInterval>>;
+
+        /// Returns a vector of valid framesizes that the device supports for the given pixel
+        /// format
+        fn enum_framesizes(&self, fourcc: FourCC) -> io::Result<Vec<FrameSize>>;
+
+        /// Returns a vector of valid formats for this device
+        ///
+        /// The "emulated" field describes formats filled in by libv4lconvert.
+        /// There may be a conversion related performance penalty when using them.
+        fn enum_formats(&self, typ: buffer::Type) -> io::Result<Vec<FormatDescription>>;
+
+        /// Returns a vector of valid formats for the given media bus code
+        fn enum_formats_for_mbus_code(
+            &self,
+            typ: buffer::Type,
+            mbus_code: u32,
+        ) -> io::Result<Vec<FormatDescription>>;
+
+        /// Returns the raw streaming parameters of the given buffer type
+        fn streamparm(&self, typ: buffer::Type) -> io::Result<v4l2_streamparm>;
+
+        /// Modifies the raw streaming parameters, the buffer type is part of the struct
+        ///
+        /// The driver writes the parameters it actually applied back into the struct.
+        fn set_streamparm(&self, params: &mut v4l2_streamparm) -> io::Result<()>;
+    }
+
+    /// Typed view of the `parm` union of `v4l2_streamparm`
+    ///
+    /// Capture queues use the `capture` member, output queues the `output` member. Whether
+    /// the queue is single- or multi-planar does not matter.
+    pub trait StreamParameters: Sized {
+        /// Returns the parameters stored in the raw struct
+        fn from_streamparm(params: &v4l2_streamparm) -> Self;
+
+        /// Returns the raw struct for the given buffer type
+        fn to_streamparm(&self, typ: buffer::Type) -> v4l2_streamparm;
+    }
+
+    pub trait Video: VideoBase {
+        type Format;
+
+        /// Buffer type of the capture queue
+        const CAPTURE: buffer::Type;
+        /// Buffer type of the output queue
+        const OUTPUT: buffer::Type;
+
+        /// Returns the format currently in use
+        fn format(&self, typ: buffer::Type) -> io::Result<Self::Format>;
+
+        /// Modifies the capture format and returns the actual format
+        ///
+        /// The driver tries to match the format parameters on a best effort basis.
+        /// Thus, if the combination of format properties cannot be achieved, the closest
+        /// possible settings are used and reported back.
+        ///
+        ///
+        /// # Arguments
+        ///
+        /// * `fmt` - Desired format
+        fn set_format(&self, typ: buffer::Type, fmt: &Self::Format) -> io::Result<Self::Format>;
+
+        /// Returns the streaming parameters of the given buffer type
+        fn stream_params<P: StreamParameters>(&self, typ: buffer::Type) -> io::Result<P> {
+            let params = self.streamparm(typ)?;
+            Ok(P::from_streamparm(&params))
+        }
+
+        /// Modifies the streaming parameters and returns the actual parameters
+        ///
+        /// # Arguments
+        ///
+        /// * `typ` - Buffer type of the queue, capture or output
+        /// * `params` - Desired parameters
+        fn set_stream_params<P: StreamParameters>(
+            &self,
+            typ: buffer::Type,
+            params: &P,
+        ) -> io::Result<P> {
+            let mut v4l2_params = params.to_streamparm(typ);
+            self.set_streamparm(&mut v4l2_params)?;
+            Ok(P::from_streamparm(&v4l2_params))
+        }
+    }
 }
@@ -1,16 +1,55 @@
 extern crate bindgen;
 
 use std::env;
+use std::fs;
 use std::path::PathBuf;
 
+/// Environment variable which makes the build store freshly generated bindings as vendored ones
+const UPDATE_ENV: &str = "V4L2_SYS_UPDATE_BINDINGS";
+
 fn main() {
-    let bindings = bindgen::Builder::default()
-        .header("wrapper.h")
-        .generate()
-        .expect("Failed to generate bindings");
+    let target = env::var("TARGET").unwrap();
+    let vendored = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+        .join("bindings")
+        .join(format!("{}.rs", target));
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("v4l2_bindings.rs");
+
+    println!("cargo:rerun-if-changed=wrapper.h");
+    println!("cargo:rerun-if-env-changed={}", UPDATE_ENV);
+
+    if env::var_os("CARGO_FEATURE_VENDORED_BINDINGS").is_some() {
+        println!("cargo:rerun-if-changed={}", vendored.display());
+        // no headers or libclang needed, which is what cross builds want
+        if let Err(e) = fs::copy(&vendored, &out_path) {
+            panic!(
+                "No vendored bindings for {} ({}): build once for this target without the \
+                 vendored-bindings feature and {}=1 set to create them",
+                target, e, UPDATE_ENV
+            );
+        }
+        return;
+    }
 
-    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let mut builder = bindgen::Builder::default().header("wrapper.h");
+
+    // 32-bit glibc defaults to a 32-bit time_t, which makes buffer and event timestamps
+    // overflow in 2038. With a 64-bit time_t, the ioctl numbers derived from the struct sizes
+    // select the time64 variants of the buffer and event ioctls. musl always uses 64 bits.
+    let pointer_width = env::var("CARGO_CFG_TARGET_POINTER_WIDTH").unwrap_or_default();
+    let target_env = env::var("CARGO_CFG_TARGET_ENV").unwrap_or_default();
+    if pointer_width == "32" && target_env == "gnu" {
+        builder = builder
+            .clang_arg("-D_TIME_BITS=64")
+            .clang_arg("-D_FILE_OFFSET_BITS=64");
+    }
+
+    let bindings = builder.generate().expect("Failed to generate bindings");
     bindings
-        .write_to_file(out_path.join("v4l2_bindings.rs"))
+        .write_to_file(&out_path)
         .expect("Failed to write bindings");
+
+    if env::var_os(UPDATE_ENV).is_some() {
+        fs::create_dir_all(vendored.parent().unwrap()).expect("Failed to create bindings dir");
+        fs::copy(&out_path, &vendored).expect("Failed to store vendored bindings");
+    }
 }